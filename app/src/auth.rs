@@ -1,32 +1,47 @@
 use crate::{
-    error::ApiKeyError::{
+    error::BybitError::{
         self,
         NotFoundApiSecret,
+        InvalidPrivateKey,
     },
     v5::api::BybitApi,
     v5::ws::BybitWS,
 };
 
-use ring::hmac::{
-    Key,
-    HMAC_SHA256,
-    self,
+use ring::{
+    hmac::{
+        Key,
+        HMAC_SHA256,
+        self,
+    },
+    rand::SystemRandom,
+    signature::{
+        RsaKeyPair,
+        RSA_PKCS1_SHA256,
+    },
+};
+use base64::{
+    Engine,
+    engine::general_purpose::STANDARD,
 };
-use anyhow::Result;
+use std::sync::Arc;
 
 pub trait Auth {
-    fn require_api_secret(&self) -> Result<&str, ApiKeyError>;
-    fn create_signature(&self, message: &str) -> Result<String>;
+    fn require_api_secret(&self) -> Result<&str, BybitError>;
+    fn create_signature(&self, message: &str) -> Result<String, BybitError>;
 }
 
 impl Auth for BybitApi {
-    fn require_api_secret(&self) -> Result<&str, ApiKeyError> {
+    fn require_api_secret(&self) -> Result<&str, BybitError> {
         match self.api_secret() {
             Some(api_secret) => Ok(api_secret),
             None => Err(NotFoundApiSecret)
         }
     }
-    fn create_signature(&self, message: &str) -> Result<String> {
+    fn create_signature(&self, message: &str) -> Result<String, BybitError> {
+        if let Some(key_pair) = self.rsa_key_pair() {
+            return sign_rsa_sha256(key_pair, message);
+        }
         let api_secret = self.require_api_secret()?;
         let key = Key::new(HMAC_SHA256, api_secret.as_bytes());
         let signature = hmac::sign(&key, message.as_bytes());
@@ -35,17 +50,59 @@ impl Auth for BybitApi {
 }
 
 impl Auth for BybitWS {
-    fn require_api_secret(&self) -> Result<&str, ApiKeyError> {
+    fn require_api_secret(&self) -> Result<&str, BybitError> {
         match self.api_secret() {
             Some(api_secret) => Ok(api_secret),
             None => Err(NotFoundApiSecret)
         }
     }
-    fn create_signature(&self, expires: &str) -> Result<String> {
+    fn create_signature(&self, expires: &str) -> Result<String, BybitError> {
+        let message = format!("GET/realtime{}", expires);
+        if let Some(key_pair) = self.rsa_key_pair() {
+            return sign_rsa_sha256(key_pair, &message);
+        }
         let api_secret = self.require_api_secret()?;
         let key = hmac::Key::new(hmac::HMAC_SHA256, api_secret.as_bytes());
-        let message = format!("GET/realtime{}", expires);
         let signature = hmac::sign(&key, message.as_bytes());
         Ok(hex::encode(signature.as_ref()))
     }
-}
\ No newline at end of file
+}
+
+// Parses an RSA private key once, when it is set, so that signing does not decode the PEM on every request.
+// Both PKCS#8 (`BEGIN PRIVATE KEY`) and PKCS#1 (`BEGIN RSA PRIVATE KEY`) PEM keys are accepted.
+pub(crate) fn parse_rsa_private_key(pem: &str) -> Result<Arc<RsaKeyPair>, BybitError> {
+    let (label, der) = decode_pem(pem)?;
+    let key_pair = match label.as_str() {
+        "PRIVATE KEY" => RsaKeyPair::from_pkcs8(&der),
+        "RSA PRIVATE KEY" => RsaKeyPair::from_der(&der),
+        _ => return Err(InvalidPrivateKey(format!("unsupported PEM label: {}", label))),
+    }.map_err(|err| InvalidPrivateKey(err.to_string()))?;
+    Ok(Arc::new(key_pair))
+}
+
+// Signs with RSASSA-PKCS1-v1_5 over SHA-256 and encodes the signature in base64, as Bybit expects for RSA keys.
+fn sign_rsa_sha256(key_pair: &RsaKeyPair, message: &str) -> Result<String, BybitError> {
+    let mut signature = vec![0; key_pair.public().modulus_len()];
+    key_pair
+        .sign(&RSA_PKCS1_SHA256, &SystemRandom::new(), message.as_bytes(), &mut signature)
+        .map_err(|_| InvalidPrivateKey("failed to sign the message".to_string()))?;
+    Ok(STANDARD.encode(signature))
+}
+
+fn decode_pem(pem: &str) -> Result<(String, Vec<u8>), BybitError> {
+    let mut lines = pem.lines().map(str::trim).filter(|line| !line.is_empty());
+    let label = lines
+        .next()
+        .and_then(|line| line.strip_prefix("-----BEGIN "))
+        .and_then(|line| line.strip_suffix("-----"))
+        .ok_or_else(|| InvalidPrivateKey("missing PEM header".to_string()))?
+        .to_string();
+    let footer = format!("-----END {}-----", label);
+    let body = lines
+        .take_while(|line| *line != footer)
+        .collect::<String>();
+    let der = STANDARD
+        .decode(body)
+        .map_err(|err| InvalidPrivateKey(err.to_string()))?;
+    Ok((label, der))
+}
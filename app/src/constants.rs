@@ -1,31 +1,52 @@
-pub const TESTNET_API_URL: &'static str = "https://api-testnet.bybit.com";
-pub const MAINNET_API_URL: &'static str = "https://api.bybit.com";
-pub const DEFAULT_RECV_WINDOW: &'static str = "5000";
-
-pub const MAINNET_SPOT_PUBLIC_CHANNEL: &'static str = "wss://stream.bybit.com/v5/public/spot";
-pub const MAINNET_LINEAR_PUBLIC_CHANNEL: &'static str = "wss://stream.bybit.com/v5/public/linear";
-pub const MAINNET_INVERSE_PUBLIC_CHANNEL: &'static str = "wss://stream.bybit.com/v5/public/inverse";
-pub const MAINNET_OPTION_PUBLIC_CHANNEL: &'static str = "wss://stream.bybit.com/v5/public/option";
-
-pub const TESTNET_SPOT_PUBLIC_CHANNEL: &'static str = "wss://stream-testnet.bybit.com/v5/public/spot";
-pub const TESTNET_LINEAR_PUBLIC_CHANNEL: &'static str = "wss://stream-testnet.bybit.com/v5/public/linear";
-pub const TESTNET_INVERSE_PUBLIC_CHANNEL: &'static str = "wss://stream-testnet.bybit.com/v5/public/inverse";
-pub const TESTNET_OPTION_PUBLIC_CHANNEL: &'static str = "wss://stream-testnet.bybit.com/v5/public/option";
-
-pub const MAINNET_PRIVATE_CHANNEL: &'static str = "wss://stream.bybit.com/v5/private";
-pub const TESTNET_PRIVATE_CHANNEL: &'static str = "wss://stream-testnet.bybit.com/v5/private";
-
-pub const CATEGORY_LINEAR: &'static str = "linear";
-pub const CATEGORY_SPOT: &'static str = "spot";
-pub const CATEGORY_INVERSE: &'static str = "inverse";
-pub const CATEGORY_OPTION: &'static str = "option";
-
-pub const PUBLIC_TRADE_TOPIC: &'static str = "publicTrade";
-pub const PUBLIC_ORDERBOOK_TOPIC: &'static str = "orderbook";
-pub const PUBLIC_TICKERS_TOPIC: &'static str = "tickers";
-pub const PUBLIC_KLINE_TOPIC: &'static str = "kline";
-pub const PUBLIC_LIQUIDATION_TOPIC: &'static str = "liquidation";
-pub const PRIVATE_POSITION_TOPIC: &'static str = "position";
-pub const PRIVATE_EXECUTION_TOPIC: &'static str = "execution";
-pub const PRIVATE_ORDER_TOPIC: &'static str = "order";
-pub const PRIVATE_WALLET_TOPIC: &'static str = "wallet";
\ No newline at end of file
+pub const TESTNET_API_URL: &str = "https://api-testnet.bybit.com";
+pub const MAINNET_API_URL: &str = "https://api.bybit.com";
+pub const DEMO_API_URL: &str = "https://api-demo.bybit.com";
+pub const DEFAULT_RECV_WINDOW: &str = "5000";
+pub const DEFAULT_USER_AGENT: &str = concat!("rsbit/", env!("CARGO_PKG_VERSION"));
+pub const DEFAULT_POOL_IDLE_TIMEOUT_SECS: u64 = 90;
+pub const DEFAULT_TCP_KEEPALIVE_SECS: u64 = 60;
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+pub const DEFAULT_INITIAL_BACKOFF_MILLIS: u64 = 200;
+pub const DEFAULT_MAX_BACKOFF_MILLIS: u64 = 5000;
+pub const DEFAULT_BACKOFF_MULTIPLIER: f64 = 2.0;
+
+pub const RATE_LIMIT_HEADER: &str = "X-Bapi-Limit";
+pub const RATE_LIMIT_STATUS_HEADER: &str = "X-Bapi-Limit-Status";
+pub const RATE_LIMIT_RESET_TIMESTAMP_HEADER: &str = "X-Bapi-Limit-Reset-Timestamp";
+pub const TRACE_ID_HEADER: &str = "Traceid";
+
+pub const MAINNET_SPOT_PUBLIC_CHANNEL: &str = "wss://stream.bybit.com/v5/public/spot";
+pub const MAINNET_LINEAR_PUBLIC_CHANNEL: &str = "wss://stream.bybit.com/v5/public/linear";
+pub const MAINNET_INVERSE_PUBLIC_CHANNEL: &str = "wss://stream.bybit.com/v5/public/inverse";
+pub const MAINNET_OPTION_PUBLIC_CHANNEL: &str = "wss://stream.bybit.com/v5/public/option";
+
+pub const TESTNET_SPOT_PUBLIC_CHANNEL: &str = "wss://stream-testnet.bybit.com/v5/public/spot";
+pub const TESTNET_LINEAR_PUBLIC_CHANNEL: &str = "wss://stream-testnet.bybit.com/v5/public/linear";
+pub const TESTNET_INVERSE_PUBLIC_CHANNEL: &str = "wss://stream-testnet.bybit.com/v5/public/inverse";
+pub const TESTNET_OPTION_PUBLIC_CHANNEL: &str = "wss://stream-testnet.bybit.com/v5/public/option";
+
+pub const MAINNET_PRIVATE_CHANNEL: &str = "wss://stream.bybit.com/v5/private";
+pub const TESTNET_PRIVATE_CHANNEL: &str = "wss://stream-testnet.bybit.com/v5/private";
+pub const DEMO_PRIVATE_CHANNEL: &str = "wss://stream-demo.bybit.com/v5/private";
+
+pub const MAINNET_PUBLIC_CHANNEL: &str = "wss://stream.bybit.com/v5/public";
+pub const TESTNET_PUBLIC_CHANNEL: &str = "wss://stream-testnet.bybit.com/v5/public";
+
+pub const MAINNET_TRADE_CHANNEL: &str = "wss://stream.bybit.com/v5/trade";
+pub const TESTNET_TRADE_CHANNEL: &str = "wss://stream-testnet.bybit.com/v5/trade";
+pub const DEMO_TRADE_CHANNEL: &str = "wss://stream-demo.bybit.com/v5/trade";
+
+pub const CATEGORY_LINEAR: &str = "linear";
+pub const CATEGORY_SPOT: &str = "spot";
+pub const CATEGORY_INVERSE: &str = "inverse";
+pub const CATEGORY_OPTION: &str = "option";
+
+pub const PUBLIC_TRADE_TOPIC: &str = "publicTrade";
+pub const PUBLIC_ORDERBOOK_TOPIC: &str = "orderbook";
+pub const PUBLIC_TICKERS_TOPIC: &str = "tickers";
+pub const PUBLIC_KLINE_TOPIC: &str = "kline";
+pub const PUBLIC_LIQUIDATION_TOPIC: &str = "liquidation";
+pub const PRIVATE_POSITION_TOPIC: &str = "position";
+pub const PRIVATE_EXECUTION_TOPIC: &str = "execution";
+pub const PRIVATE_ORDER_TOPIC: &str = "order";
+pub const PRIVATE_WALLET_TOPIC: &str = "wallet";
\ No newline at end of file
@@ -4,20 +4,402 @@ use std::fmt::{
     Result
 };
 use std::error::Error;
+use std::time::Duration;
+use serde_json::Value;
+use reqwest::header::{
+    HeaderMap,
+    RETRY_AFTER,
+};
+use chrono::Utc;
+use crate::constants::{
+    RATE_LIMIT_RESET_TIMESTAMP_HEADER,
+    TRACE_ID_HEADER,
+};
+use crate::v5::api::post::trade::validation::OrderValidationError;
+
+// The body is cut to this many characters in `Display` to keep log lines readable.
+const DISPLAY_BODY_LIMIT: usize = 512;
 
+/// Errors returned by `BybitApi`.
 #[derive(Debug)]
-pub enum ApiKeyError {
+pub enum BybitError {
+    /// The API key is required but was not set.
     NotFoundApiKey,
+    /// The API secret is required but was not set.
     NotFoundApiSecret,
+    /// The RSA private key could not be parsed or used for signing.
+    InvalidPrivateKey(String),
+    /// Bybit answered with a non-zero `retCode`.
+    Api {
+        ret_code: i32,
+        ret_msg: String,
+        ret_ext_info: Value,
+        status: u16,
+        endpoint: String,
+    },
+    /// Bybit answered with a non-200 HTTP status.
+    ///
+    /// `body` is the raw response body, e.g. a WAF page on 403 or a gateway error on 5xx.
+    /// `headers` keeps only the headers useful for diagnosis: `Retry-After`, `X-Bapi-Limit*` and `Traceid`.
+    Http {
+        status: u16,
+        endpoint: String,
+        body: String,
+        headers: Box<HeaderMap>,
+    },
+    /// The order parameters failed client-side validation and were not sent.
+    InvalidOrder(OrderValidationError),
+    /// The request was held back by the client-side `RateLimiter`.
+    RateLimited {
+        endpoint: String,
+        retry_after: Duration,
+    },
+    /// The request could not be sent or the response could not be read.
+    Request(reqwest::Error),
+    /// The request URL could not be built.
+    Url(url::ParseError),
+    /// A request header could not be built.
+    Header(reqwest::header::InvalidHeaderValue),
+    /// The query string could not be encoded.
+    Query(serde_qs::Error),
+    /// The request body could not be encoded or the response body could not be decoded.
+    Json(serde_json::Error),
 }
 
-impl Display for ApiKeyError {
+/// Classification of a `BybitError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BybitErrorKind {
+    /// 10001, or client-side order validation: the request parameters are invalid.
+    InvalidParameter,
+    /// 10002: the request timestamp is outside of `recv_window`.
+    InvalidTimestamp,
+    /// 10003, 10004, 10007: the API key or signature was rejected, or credentials are missing.
+    Authentication,
+    /// 10005, 10010: the API key lacks the permission or the IP is not allowed.
+    PermissionDenied,
+    /// 10006, 10018, HTTP 429, or the client-side `RateLimiter`: the rate limit was exceeded.
+    RateLimitExceeded,
+    /// HTTP 403: the IP has been banned, usually after exceeding the rate limit.
+    IpBanned,
+    /// 10016, HTTP 5xx: Bybit failed to process the request.
+    ServerError,
+    /// 110001: the order does not exist.
+    OrderNotFound,
+    /// 110004, 110007, 110012: the balance is insufficient.
+    InsufficientBalance,
+    /// 110025: the position mode is already the requested one.
+    PositionModeNotModified,
+    /// 110026: the margin mode is already the requested one.
+    MarginModeNotModified,
+    /// 110043: the leverage is already the requested one.
+    LeverageNotModified,
+    /// 110072: the `orderLinkId` is already in use.
+    DuplicateOrderLinkId,
+    /// The request could not reach Bybit.
+    Network,
+    /// The request or response could not be encoded or decoded.
+    Serialization,
+    /// Any other error.
+    Other,
+}
+
+impl BybitErrorKind {
+    /// Classifies a Bybit `retCode`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ret_code` - The `retCode` returned by Bybit.
+    ///
+    /// # Returns
+    ///
+    /// The matching `BybitErrorKind`, or `Other` for unknown codes.
+    pub fn from_ret_code(ret_code: i32) -> Self {
+        match ret_code {
+            10001 => BybitErrorKind::InvalidParameter,
+            10002 => BybitErrorKind::InvalidTimestamp,
+            10003 | 10004 | 10007 => BybitErrorKind::Authentication,
+            10005 | 10010 => BybitErrorKind::PermissionDenied,
+            10006 | 10018 => BybitErrorKind::RateLimitExceeded,
+            10016 => BybitErrorKind::ServerError,
+            110001 => BybitErrorKind::OrderNotFound,
+            110004 | 110007 | 110012 => BybitErrorKind::InsufficientBalance,
+            110025 => BybitErrorKind::PositionModeNotModified,
+            110026 => BybitErrorKind::MarginModeNotModified,
+            110043 => BybitErrorKind::LeverageNotModified,
+            110072 => BybitErrorKind::DuplicateOrderLinkId,
+            _ => BybitErrorKind::Other,
+        }
+    }
+
+    /// Classifies a non-200 HTTP status.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The HTTP status code.
+    ///
+    /// # Returns
+    ///
+    /// The matching `BybitErrorKind`, or `Other` for unknown statuses.
+    pub fn from_status(status: u16) -> Self {
+        match status {
+            401 => BybitErrorKind::Authentication,
+            403 => BybitErrorKind::IpBanned,
+            429 => BybitErrorKind::RateLimitExceeded,
+            500..=599 => BybitErrorKind::ServerError,
+            _ => BybitErrorKind::Other,
+        }
+    }
+}
+
+impl BybitError {
+    /// Gets the classification of the error.
+    ///
+    /// # Returns
+    ///
+    /// The `BybitErrorKind` of the error.
+    pub fn kind(&self) -> BybitErrorKind {
+        match self {
+            BybitError::NotFoundApiKey
+            | BybitError::NotFoundApiSecret
+            | BybitError::InvalidPrivateKey(_) => BybitErrorKind::Authentication,
+            BybitError::Api { ret_code, .. } => BybitErrorKind::from_ret_code(*ret_code),
+            BybitError::InvalidOrder(_) => BybitErrorKind::InvalidParameter,
+            BybitError::Http { status, .. } => BybitErrorKind::from_status(*status),
+            BybitError::RateLimited { .. } => BybitErrorKind::RateLimitExceeded,
+            BybitError::Request(_) => BybitErrorKind::Network,
+            BybitError::Query(_) | BybitError::Json(_) => BybitErrorKind::Serialization,
+            BybitError::Url(_) | BybitError::Header(_) => BybitErrorKind::Other,
+        }
+    }
+
+    /// Checks whether the request may succeed if it is sent again.
+    ///
+    /// Connection failures, timeouts, HTTP 5xx responses, 10002 (timestamp outside of
+    /// `recv_window`) and 10016 (server error) are retryable.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            BybitError::Request(err) => err.is_connect() || err.is_timeout() || err.is_request(),
+            BybitError::Http { status, .. } => (500..=599).contains(status),
+            BybitError::Api { .. } => matches!(self.kind(), BybitErrorKind::InvalidTimestamp | BybitErrorKind::ServerError),
+            _ => false,
+        }
+    }
+
+    /// Gets the Bybit `retCode`, if Bybit returned one.
+    pub fn ret_code(&self) -> Option<i32> {
+        match self {
+            BybitError::Api { ret_code, .. } => Some(*ret_code),
+            _ => None,
+        }
+    }
+
+    /// Gets the Bybit `retMsg`, if Bybit returned one.
+    pub fn ret_msg(&self) -> Option<&str> {
+        match self {
+            BybitError::Api { ret_msg, .. } => Some(ret_msg),
+            _ => None,
+        }
+    }
+
+    /// Gets the Bybit `retExtInfo`, if Bybit returned one.
+    pub fn ret_ext_info(&self) -> Option<&Value> {
+        match self {
+            BybitError::Api { ret_ext_info, .. } => Some(ret_ext_info),
+            _ => None,
+        }
+    }
+
+    /// Gets the HTTP status, if a response was received.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            BybitError::Api { status, .. } | BybitError::Http { status, .. } => Some(*status),
+            BybitError::Request(err) => err.status().map(|status| status.as_u16()),
+            _ => None,
+        }
+    }
+
+    /// Gets the raw body of a non-200 response.
+    pub fn body(&self) -> Option<&str> {
+        match self {
+            BybitError::Http { body, .. } => Some(body),
+            _ => None,
+        }
+    }
+
+    /// Gets the diagnostic headers of a non-200 response.
+    pub fn headers(&self) -> Option<&HeaderMap> {
+        match self {
+            BybitError::Http { headers, .. } => Some(headers),
+            _ => None,
+        }
+    }
+
+    /// Gets how long to wait before sending the request again, if known.
+    ///
+    /// For a non-200 response this is taken from `Retry-After` (in seconds) or, failing that,
+    /// from `X-Bapi-Limit-Reset-Timestamp`. For `RateLimited` it is the wait computed by the limiter.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            BybitError::Http { headers, .. } => {
+                let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+                if let Some(seconds) = header(RETRY_AFTER.as_str()).and_then(|value| value.trim().parse::<u64>().ok()) {
+                    return Some(Duration::from_secs(seconds));
+                }
+                header(RATE_LIMIT_RESET_TIMESTAMP_HEADER)
+                    .and_then(|value| value.trim().parse::<i64>().ok())
+                    .map(|reset| Duration::from_millis((reset - Utc::now().timestamp_millis()).max(0) as u64))
+            },
+            BybitError::RateLimited { retry_after, .. } => Some(*retry_after),
+            _ => None,
+        }
+    }
+
+    /// Gets the endpoint of the failed request, if known.
+    pub fn endpoint(&self) -> Option<&str> {
+        match self {
+            BybitError::Api { endpoint, .. }
+            | BybitError::Http { endpoint, .. }
+            | BybitError::RateLimited { endpoint, .. } => Some(endpoint),
+            _ => None,
+        }
+    }
+}
+
+impl Display for BybitError {
     fn fmt(&self, f: &mut Formatter) -> Result {
-        match *self {
-            ApiKeyError::NotFoundApiKey => write!(f, "API key not found. Required for authentication."),
-            ApiKeyError::NotFoundApiSecret => write!(f, "API secret not found. Required for authentication."),
+        match self {
+            BybitError::NotFoundApiKey => write!(f, "API key not found. Required for authentication."),
+            BybitError::NotFoundApiSecret => write!(f, "API secret not found. Required for authentication."),
+            BybitError::InvalidPrivateKey(reason) => write!(f, "Invalid RSA private key: {}", reason),
+            BybitError::Api { ret_code, ret_msg, endpoint, .. } => write!(f, "Response error ret_code: {}, ret_msg: {}, endpoint: {}", ret_code, ret_msg, endpoint),
+            BybitError::Http { status, endpoint, body, .. } => {
+                write!(f, "Request error status code {}, endpoint: {}", status, endpoint)?;
+                if let Some(traceid) = self.headers().and_then(|headers| headers.get(TRACE_ID_HEADER)).and_then(|value| value.to_str().ok()) {
+                    write!(f, ", traceid: {}", traceid)?;
+                }
+                if let Some(retry_after) = self.retry_after() {
+                    write!(f, ", retry after {:?}", retry_after)?;
+                }
+                match body.char_indices().nth(DISPLAY_BODY_LIMIT) {
+                    Some((end, _)) => write!(f, ", body: {}...", &body[..end]),
+                    None if body.is_empty() => Ok(()),
+                    None => write!(f, ", body: {}", body),
+                }
+            },
+            BybitError::InvalidOrder(err) => write!(f, "{}", err),
+            BybitError::RateLimited { endpoint, retry_after } => write!(f, "Rate limit exceeded, retry after {:?}, endpoint: {}", retry_after, endpoint),
+            BybitError::Request(err) => write!(f, "Request error: {}", err),
+            BybitError::Url(err) => write!(f, "URL error: {}", err),
+            BybitError::Header(err) => write!(f, "Header error: {}", err),
+            BybitError::Query(err) => write!(f, "Query error: {}", err),
+            BybitError::Json(err) => write!(f, "JSON error: {}", err),
+        }
+    }
+}
+
+impl Error for BybitError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BybitError::InvalidOrder(err) => Some(err),
+            BybitError::Request(err) => Some(err),
+            BybitError::Url(err) => Some(err),
+            BybitError::Header(err) => Some(err),
+            BybitError::Query(err) => Some(err),
+            BybitError::Json(err) => Some(err),
+            _ => None,
         }
     }
 }
 
-impl Error for ApiKeyError {}
\ No newline at end of file
+/// The failure of a single request in a batch, taken from `retExtInfo.list`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BybitItemError {
+    index: usize,
+    code: i32,
+    msg: String,
+}
+
+impl BybitItemError {
+    /// Creates a new instance of `BybitItemError`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the request in the batch, starting at 0.
+    /// * `code` - The `code` returned for the request.
+    /// * `msg` - The `msg` returned for the request.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `BybitItemError`.
+    pub fn new(index: usize, code: i32, msg: String) -> Self {
+        Self {
+            index,
+            code,
+            msg,
+        }
+    }
+
+    /// Gets the position of the request in the batch, starting at 0.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Gets the `code` returned for the request.
+    pub fn code(&self) -> i32 {
+        self.code
+    }
+
+    /// Gets the `msg` returned for the request.
+    pub fn msg(&self) -> &str {
+        &self.msg
+    }
+
+    /// Gets the classification of the error, as for `BybitError::kind`.
+    pub fn kind(&self) -> BybitErrorKind {
+        BybitErrorKind::from_ret_code(self.code)
+    }
+}
+
+impl Display for BybitItemError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "Batch request {} failed with code: {}, msg: {}", self.index, self.code, self.msg)
+    }
+}
+
+impl Error for BybitItemError {}
+
+impl From<OrderValidationError> for BybitError {
+    fn from(err: OrderValidationError) -> Self {
+        BybitError::InvalidOrder(err)
+    }
+}
+
+impl From<reqwest::Error> for BybitError {
+    fn from(err: reqwest::Error) -> Self {
+        BybitError::Request(err)
+    }
+}
+
+impl From<url::ParseError> for BybitError {
+    fn from(err: url::ParseError) -> Self {
+        BybitError::Url(err)
+    }
+}
+
+impl From<reqwest::header::InvalidHeaderValue> for BybitError {
+    fn from(err: reqwest::header::InvalidHeaderValue) -> Self {
+        BybitError::Header(err)
+    }
+}
+
+impl From<serde_qs::Error> for BybitError {
+    fn from(err: serde_qs::Error) -> Self {
+        BybitError::Query(err)
+    }
+}
+
+impl From<serde_json::Error> for BybitError {
+    fn from(err: serde_json::Error) -> Self {
+        BybitError::Json(err)
+    }
+}
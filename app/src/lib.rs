@@ -1,5 +1,5 @@
 pub mod v5;
 pub mod error;
 pub mod constants;
-mod auth;
+pub mod auth;
 mod utils;
\ No newline at end of file
@@ -4,15 +4,25 @@ use serde::{
     Serializer,
     de::{self, Visitor},
 };
+use chrono::{
+    DateTime,
+    TimeZone,
+    Utc,
+};
+use crate::v5::types::Number;
+use serde_json::{
+    Map,
+    Value,
+};
 
-pub fn deserialize_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
+pub fn deserialize_number<'de, D>(deserializer: D) -> Result<Number, D::Error>
 where
     D: Deserializer<'de>,
 {
-    struct F64Visitor;
+    struct NumberVisitor;
 
-    impl<'de> Visitor<'de> for F64Visitor {
-        type Value = f64;
+    impl<'de> Visitor<'de> for NumberVisitor {
+        type Value = Number;
 
         fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
             formatter.write_str("a number or a string containing a number")
@@ -22,35 +32,35 @@ where
         where
             E: de::Error,
         {
-            Ok(value)
+            number_from_f64(value).map_err(E::custom)
         }
 
         fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
         where
             E: de::Error,
         {
-            Ok(value as f64)
+            Ok(number_from_i64(value))
         }
 
         fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
         where
             E: de::Error,
         {
-            Ok(value as f64)
+            Ok(number_from_u64(value))
         }
 
         fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
         where
             E: de::Error,
         {
-            value.parse::<f64>().map_err(E::custom)
+            parse_number(value).map_err(E::custom)
         }
     }
 
-    deserializer.deserialize_any(F64Visitor)
+    deserializer.deserialize_any(NumberVisitor)
 }
 
-pub fn deserialize_option_f64<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+pub fn deserialize_option_number<'de, D>(deserializer: D) -> Result<Option<Number>, D::Error>
 where
     D: Deserializer<'de>,
 {
@@ -60,13 +70,77 @@ where
             if s.is_empty() {
                 Ok(None)
             } else {
-                s.parse::<f64>().map(Some).map_err(serde::de::Error::custom)
+                parse_number(&s).map(Some).map_err(serde::de::Error::custom)
             }
         }
         None => Ok(None),
     }
 }
 
+#[cfg(not(feature = "decimal"))]
+pub fn parse_number(value: &str) -> Result<Number, String> {
+    value.parse::<f64>().map_err(|err| err.to_string())
+}
+
+// Bybit sends plain decimals, but exponent forms such as `1e-7` are accepted as well.
+#[cfg(feature = "decimal")]
+pub fn parse_number(value: &str) -> Result<Number, String> {
+    Number::from_str_exact(value)
+        .or_else(|_| Number::from_scientific(value))
+        .map_err(|err| err.to_string())
+}
+
+#[cfg(not(feature = "decimal"))]
+fn number_from_f64(value: f64) -> Result<Number, String> {
+    Ok(value)
+}
+
+#[cfg(feature = "decimal")]
+fn number_from_f64(value: f64) -> Result<Number, String> {
+    Number::try_from(value).map_err(|err| err.to_string())
+}
+
+#[cfg(not(feature = "decimal"))]
+fn number_from_i64(value: i64) -> Number {
+    value as f64
+}
+
+#[cfg(feature = "decimal")]
+fn number_from_i64(value: i64) -> Number {
+    Number::from(value)
+}
+
+#[cfg(not(feature = "decimal"))]
+fn number_from_u64(value: u64) -> Number {
+    value as f64
+}
+
+#[cfg(feature = "decimal")]
+fn number_from_u64(value: u64) -> Number {
+    Number::from(value)
+}
+
+// v5 のタイムスタンプは全てミリ秒。範囲外の値は UNIX epoch に丸める
+pub fn millis_to_datetime(millis: u64) -> DateTime<Utc> {
+    Utc.timestamp_millis_opt(millis as i64).single().unwrap_or_default()
+}
+
+pub fn option_millis_to_datetime(millis: Option<u64>) -> Option<DateTime<Utc>> {
+    millis.map(millis_to_datetime)
+}
+
+// 文字列で返ってくるミリ秒のタイムスタンプ用。空文字や "0" は None
+pub fn str_millis_to_datetime(millis: &str) -> Option<DateTime<Utc>> {
+    match millis.parse::<u64>() {
+        Ok(0) | Err(_) => None,
+        Ok(millis) => Some(millis_to_datetime(millis)),
+    }
+}
+
+pub fn datetime_to_millis(time: DateTime<Utc>) -> u64 {
+    time.timestamp_millis().max(0) as u64
+}
+
 pub fn deserialize_string_to_u64<'de, D>(deserializer: D) -> Result<u64, D::Error>
 where
     D: Deserializer<'de>,
@@ -109,4 +183,46 @@ where
         Some(value) => serializer.serialize_str(&value.to_string()),
         None => serializer.serialize_none(),
     }
-}
\ No newline at end of file
+}
+
+// レスポンスに未知のキーが含まれていた場合、ここに集める
+#[cfg(not(feature = "strict"))]
+pub fn deserialize_extra<'de, D>(deserializer: D) -> Result<Map<String, Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Map::deserialize(deserializer)
+}
+
+// strict では未知のキーをエラーにして、フィクスチャとのスキーマのずれをテストで検出する
+#[cfg(feature = "strict")]
+pub fn deserialize_extra<'de, D>(deserializer: D) -> Result<Map<String, Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let extra = Map::deserialize(deserializer)?;
+    if extra.is_empty() {
+        Ok(extra)
+    } else {
+        let keys: Vec<&str> = extra.keys().map(String::as_str).collect();
+        Err(de::Error::custom(format!("unknown fields: {}", keys.join(", "))))
+    }
+}
+
+pub fn deserialize_option_enum<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: From<String>,
+{
+    let s: Option<String> = Option::deserialize(deserializer)?;
+    match s {
+        Some(s) => {
+            if s.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(T::from(s)))
+            }
+        }
+        None => Ok(None),
+    }
+}
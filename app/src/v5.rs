@@ -1,2 +1,9 @@
 pub mod api;
-pub mod ws;
\ No newline at end of file
+pub mod ws;
+pub mod time_sync;
+pub mod environment;
+pub mod types;
+pub mod category;
+pub mod symbol;
+pub mod export;
+pub mod kline_store;
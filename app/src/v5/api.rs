@@ -1,19 +1,68 @@
 pub mod get;
+pub mod meta;
 pub mod post;
+pub mod rate_limit;
+pub mod retry;
+pub mod transport;
 mod request;
+mod pagination;
 
 use crate::constants::{
-    TESTNET_API_URL,
-    MAINNET_API_URL,
     DEFAULT_RECV_WINDOW,
+    DEFAULT_USER_AGENT,
+    DEFAULT_POOL_IDLE_TIMEOUT_SECS,
+    DEFAULT_TCP_KEEPALIVE_SECS,
+};
+use crate::v5::{
+    environment::Environment,
+    time_sync::TimeSync,
+    api::rate_limit::{
+        RateLimiter,
+        RateLimitStatus,
+    },
+    api::retry::RetryPolicy,
+    api::meta::MetaSink,
+    api::transport::{
+        Transport,
+        ReqwestTransport,
+    },
+};
+use crate::{
+    auth::parse_rsa_private_key,
+    error::BybitError,
+};
+use reqwest::{
+    Client,
+    Proxy,
+};
+use ring::signature::RsaKeyPair;
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
 };
 
 #[derive(Debug, Clone)]
 pub struct BybitApi {
-    base_url: &'static str,
+    environment: Environment,
     api_key: Option<String>,
     api_secret: Option<String>,
+    rsa_private_key: Option<String>,
+    rsa_key_pair: Option<Arc<RsaKeyPair>>,
     recv_window: String,
+    transport: Arc<dyn Transport>,
+    timeout: Option<Duration>,
+    user_agent: String,
+    proxy: Option<Proxy>,
+    rate_limiter: Option<RateLimiter>,
+    rate_limit_statuses: Arc<Mutex<HashMap<String, RateLimitStatus>>>,
+    retry_policy: RetryPolicy,
+    time_sync: Option<TimeSync>,
+    meta_sink: Option<MetaSink>,
+    order_validation: bool,
 }
 
 impl BybitApi {
@@ -22,8 +71,17 @@ impl BybitApi {
     /// # Returns
     ///
     /// The base URL.
-    pub fn base_url(&self) -> &'static str {
-        self.base_url
+    pub fn base_url(&self) -> &str {
+        self.environment.rest_url()
+    }
+
+    /// Gets the environment.
+    ///
+    /// # Returns
+    ///
+    /// The environment.
+    pub fn environment(&self) -> &Environment {
+        &self.environment
     }
 
     /// Gets the API key.
@@ -44,6 +102,19 @@ impl BybitApi {
         self.api_secret.as_ref()
     }
 
+    /// Gets the RSA private key.
+    ///
+    /// # Returns
+    ///
+    /// The RSA private key in PEM format.
+    pub fn rsa_private_key(&self) -> Option<&String> {
+        self.rsa_private_key.as_ref()
+    }
+
+    pub(crate) fn rsa_key_pair(&self) -> Option<&RsaKeyPair> {
+        self.rsa_key_pair.as_deref()
+    }
+
     /// Gets the receive window.
     ///
     /// # Returns
@@ -53,6 +124,53 @@ impl BybitApi {
         &self.recv_window
     }
 
+    /// Gets the transport that sends the HTTP requests.
+    ///
+    /// The transport owns the connection pool and is shared by every clone of this instance.
+    ///
+    /// # Returns
+    ///
+    /// The transport.
+    pub fn transport(&self) -> &Arc<dyn Transport> {
+        &self.transport
+    }
+
+    /// Gets the client-side rate limiter.
+    ///
+    /// # Returns
+    ///
+    /// The rate limiter, or `None` if rate limiting is disabled.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// Gets the retry policy.
+    ///
+    /// # Returns
+    ///
+    /// The retry policy.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Gets the server time synchronisation.
+    ///
+    /// # Returns
+    ///
+    /// The time synchronisation, or `None` if the local clock is used as is.
+    pub fn time_sync(&self) -> Option<&TimeSync> {
+        self.time_sync.as_ref()
+    }
+
+    /// Checks whether order parameters are validated before they are sent.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `place_order`, `amend_order` and `batch_place_order` call `validate()` first.
+    pub fn order_validation(&self) -> bool {
+        self.order_validation
+    }
+
     /// Create a new instance of the API.
     ///
     /// This method initializes a new instance of the API struct with default values.
@@ -91,6 +209,24 @@ impl BybitApi {
         self
     }
 
+    /// Sets the RSA private key used to sign requests.
+    ///
+    /// When set, requests are signed with RSA-SHA256 instead of HMAC-SHA256 and the API secret is not used.
+    /// The API key must be the one Bybit issued for the matching public key.
+    ///
+    /// # Arguments
+    ///
+    /// * `rsa_private_key` - The RSA private key in PKCS#8 or PKCS#1 PEM format.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Self` object, or `BybitError::InvalidPrivateKey` if the key cannot be parsed.
+    pub fn with_rsa_private_key(mut self, rsa_private_key: String) -> Result<Self, BybitError> {
+        self.rsa_key_pair = Some(parse_rsa_private_key(&rsa_private_key)?);
+        self.rsa_private_key = Some(rsa_private_key);
+        Ok(self)
+    }
+
     /// Sets the receive window.
     ///
     /// # Arguments
@@ -111,7 +247,7 @@ impl BybitApi {
     ///
     /// The modified `Self` object.
     pub fn with_testnet(mut self) -> Self {
-        self.base_url = TESTNET_API_URL;
+        self.environment = Environment::Testnet;
         self
     }
 
@@ -121,7 +257,181 @@ impl BybitApi {
     ///
     /// The modified `Self` object.
     pub fn with_mainnet(mut self) -> Self {
-        self.base_url = MAINNET_API_URL;
+        self.environment = Environment::Mainnet;
+        self
+    }
+
+    /// Sets the base URL to the demo trading API URL.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Self` object.
+    pub fn with_demo(mut self) -> Self {
+        self.environment = Environment::Demo;
+        self
+    }
+
+    /// Sets the environment, e.g. a regional host or a local server.
+    ///
+    /// # Arguments
+    ///
+    /// * `environment` - The environment to be set.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Self` object.
+    pub fn with_environment(mut self, environment: Environment) -> Self {
+        self.environment = environment;
+        self
+    }
+
+    /// Sets the HTTP client used to send requests.
+    ///
+    /// The client is used as is, so the timeout, user agent and proxy set on this instance are not applied to it.
+    /// Calling `with_timeout`, `with_user_agent` or `with_proxy` afterwards replaces it with a newly built client.
+    /// It also replaces a transport set with `with_transport`.
+    ///
+    /// # Arguments
+    ///
+    /// * `http_client` - The HTTP client to be set.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Self` object.
+    pub fn with_http_client(mut self, http_client: Client) -> Self {
+        self.transport = Arc::new(ReqwestTransport::new(http_client));
+        self
+    }
+
+    /// Sets the transport that sends the HTTP requests.
+    ///
+    /// Use `MockTransport` to answer requests with canned responses instead of calling Bybit.
+    /// Calling `with_http_client`, `with_timeout`, `with_user_agent` or `with_proxy` afterwards
+    /// replaces it with a `ReqwestTransport`.
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The transport to be set.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Self` object.
+    pub fn with_transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Sets the total timeout of each request.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The timeout to be set.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Self` object.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self.transport = build_transport(self.timeout, &self.user_agent, self.proxy.clone());
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with each request.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_agent` - The user agent to be set.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Self` object.
+    pub fn with_user_agent(mut self, user_agent: String) -> Self {
+        self.user_agent = user_agent;
+        self.transport = build_transport(self.timeout, &self.user_agent, self.proxy.clone());
+        self
+    }
+
+    /// Sets the proxy through which requests are sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `proxy` - The proxy to be set.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Self` object.
+    pub fn with_proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self.transport = build_transport(self.timeout, &self.user_agent, self.proxy.clone());
+        self
+    }
+
+    /// Enables client-side rate limiting.
+    ///
+    /// Requests are delayed or rejected before they are sent once the budget of their
+    /// endpoint group is exhausted. Clones of this instance share the limiter.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate_limiter` - The rate limiter to be set.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Self` object.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Sets the retry policy.
+    ///
+    /// Use `RetryPolicy::disabled()` to turn retries off.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry_policy` - The retry policy to be set.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Self` object.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Enables server time synchronisation for signed requests.
+    ///
+    /// The offset to the Bybit server clock is measured before the first signed request and
+    /// whenever it is older than the interval of `time_sync`, and is added to `X-BAPI-TIMESTAMP`.
+    /// The same `TimeSync` can be given to `BybitWS` to share the offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `time_sync` - The time synchronisation to be set.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Self` object.
+    pub fn with_time_sync(mut self, time_sync: TimeSync) -> Self {
+        self.time_sync = Some(time_sync);
+        self
+    }
+
+    /// Sets whether order parameters are validated before they are sent.
+    ///
+    /// Validation is on by default: `place_order`, `amend_order` and `batch_place_order` return
+    /// `BybitError::InvalidOrder` without sending the request when `validate()` finds a problem.
+    /// Turn it off to leave every check to Bybit, e.g. when the checks lag behind an API change.
+    ///
+    /// # Arguments
+    ///
+    /// * `order_validation` - Whether to validate order parameters.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Self` object.
+    pub fn with_order_validation(mut self, order_validation: bool) -> Self {
+        self.order_validation = order_validation;
         self
     }
 }
@@ -129,14 +439,42 @@ impl BybitApi {
 impl Default for BybitApi {
     fn default() -> Self {
         Self {
-            base_url: TESTNET_API_URL,
+            environment: Environment::Testnet,
             api_key: None,
             api_secret: None,
+            rsa_private_key: None,
+            rsa_key_pair: None,
             recv_window: DEFAULT_RECV_WINDOW.to_string(),
+            transport: build_transport(None, DEFAULT_USER_AGENT, None),
+            timeout: None,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            proxy: None,
+            rate_limiter: None,
+            rate_limit_statuses: Arc::new(Mutex::new(HashMap::new())),
+            retry_policy: RetryPolicy::new(),
+            time_sync: None,
+            meta_sink: None,
+            order_validation: true,
         }
     }
 }
 
+// Connections are kept alive in the pool and HTTP/2 is negotiated through ALPN when the server supports it.
+fn build_transport(timeout: Option<Duration>, user_agent: &str, proxy: Option<Proxy>) -> Arc<dyn Transport> {
+    let mut builder = Client::builder()
+        .user_agent(user_agent)
+        .pool_idle_timeout(Duration::from_secs(DEFAULT_POOL_IDLE_TIMEOUT_SECS))
+        .tcp_keepalive(Duration::from_secs(DEFAULT_TCP_KEEPALIVE_SECS))
+        .tcp_nodelay(true);
+    if let Some(timeout) = timeout {
+        builder = builder.timeout(timeout);
+    }
+    if let Some(proxy) = proxy {
+        builder = builder.proxy(proxy);
+    }
+    Arc::new(ReqwestTransport::new(builder.build().expect("Failed to build the HTTP client")))
+}
+
 
 
 
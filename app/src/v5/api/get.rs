@@ -11,7 +11,7 @@ use crate::v5::api::{
     },
     BybitApi,
 };
-use anyhow::Result;
+use crate::error::BybitError;
 use async_trait::async_trait;
 use serde::{
     Serialize,
@@ -20,13 +20,15 @@ use serde::{
 
 #[async_trait]
 trait Get: BybitApiRequest {
-    async fn get<T: Serialize + Send, P: DeserializeOwned>(&self, endpoint: &str, params: Option<T>, private: bool) -> Result<P>;
+    async fn get<T: Serialize + Send + Sync, P: DeserializeOwned + Send>(&self, endpoint: &str, params: Option<T>, private: bool) -> Result<P, BybitError>;
 }
 
 #[async_trait]
 impl Get for BybitApi {
-    async fn get<T: Serialize + Send, P: DeserializeOwned>(&self, endpoint: &str, params: Option<T>, private: bool) -> Result<P> {
-        let body = self.send_api_request(endpoint, params, private, RequestType::Get).await?;
-        self.deserialize_response(&body)
+    async fn get<T: Serialize + Send + Sync, P: DeserializeOwned + Send>(&self, endpoint: &str, params: Option<T>, private: bool) -> Result<P, BybitError> {
+        self.retry(true, || async {
+            let body = self.send_api_request(endpoint, params.as_ref(), private, RequestType::Get).await?;
+            self.deserialize_response(endpoint, &body)
+        }).await
     }
 }
\ No newline at end of file
@@ -1,24 +1,62 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
     v5::api::{
         BybitApi,
         get::Get,
     },
-    utils::deserialize_f64,
+    v5::types::Number,
+    utils::{
+        serialize_as_string,
+        datetime_to_millis,
+        deserialize_number,
+        millis_to_datetime,
+    },
 };
 
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
+use crate::v5::api::pagination::paginate;
+use futures_util::{
+    FutureExt,
+    stream::BoxStream,
+};
 
-const PATH: &'static str = "/v5/account/borrow-history";
+const PATH: &str = "/v5/account/borrow-history";
 
 impl BybitApi {
-    pub async fn get_borrow_history(&self, params: GetBorrowHistoryParameters) -> Result<GetBorrowHistoryResponse> {
+    pub async fn get_borrow_history(&self, params: GetBorrowHistoryParameters) -> Result<GetBorrowHistoryResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
+
+    /// Gets all borrow records, following `next_page_cursor` until the last page.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters of the first request.
+    /// * `max_items` - The maximum number of borrow records to yield, or `None` for all of them.
+    ///
+    /// # Returns
+    ///
+    /// A stream of borrow records. A failed request is yielded as an error and ends the stream.
+    pub fn get_borrow_history_stream(&self, params: GetBorrowHistoryParameters, max_items: Option<usize>) -> BoxStream<'_, Result<BorrowHistory, BybitError>> {
+        paginate(params, max_items, GetBorrowHistoryParameters::with_cursor, move |params| {
+            async move {
+                let result = self.get_borrow_history(params).await?.result;
+                Ok((result.list, result.next_page_cursor))
+            }.boxed()
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -31,6 +69,12 @@ pub struct GetBorrowHistoryParameters {
     cursor: Option<String>,
 }
 
+impl Default for GetBorrowHistoryParameters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl GetBorrowHistoryParameters {
     pub fn new() -> Self {
         Self {
@@ -52,11 +96,21 @@ impl GetBorrowHistoryParameters {
         self
     }
 
+    pub fn with_start_datetime(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(datetime_to_millis(start_time));
+        self
+    }
+
     pub fn with_end_time(mut self, end_time: u64) -> Self {
         self.end_time = Some(end_time);
         self
     }
 
+    pub fn with_end_datetime(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(datetime_to_millis(end_time));
+        self
+    }
+
     pub fn with_limit(mut self, limit: i32) -> Self {
         self.limit = Some(limit);
         self
@@ -68,7 +122,7 @@ impl GetBorrowHistoryParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetBorrowHistoryResponse {
     ret_code: i32,
@@ -76,6 +130,8 @@ pub struct GetBorrowHistoryResponse {
     result: BorrowHistoryResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetBorrowHistoryResponse {
     pub fn ret_code(&self) -> i32 {
@@ -117,13 +173,27 @@ impl GetBorrowHistoryResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BorrowHistoryResult {
     next_page_cursor: Option<String>,
-    list: Vec<BorrowHistory>
+    list: Vec<BorrowHistory>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl BorrowHistoryResult {
     pub fn next_page_cursor(&self) -> &Option<String> {
@@ -141,51 +211,61 @@ impl BorrowHistoryResult {
     pub fn set_list(&mut self, list: Vec<BorrowHistory>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BorrowHistory {
-    #[serde(deserialize_with = "deserialize_f64")]
-    borrow_amount: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    cost_exemption: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    free_borrowed_amount: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    borrow_amount: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    cost_exemption: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    free_borrowed_amount: Number,
     created_time: u64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    interest_bearing_borrow_size: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    interest_bearing_borrow_size: Number,
     currency: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    unrealised_loss: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    hourly_borrow_rate: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    borrow_cost: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    unrealised_loss: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    hourly_borrow_rate: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    borrow_cost: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl BorrowHistory {
-    pub fn borrow_amount(&self) -> f64 {
+    pub fn borrow_amount(&self) -> Number {
         self.borrow_amount
     }
 
-    pub fn set_borrow_amount(&mut self, borrow_amount: f64) {
+    pub fn set_borrow_amount(&mut self, borrow_amount: Number) {
         self.borrow_amount = borrow_amount;
     }
 
-    pub fn cost_exemption(&self) -> f64 {
+    pub fn cost_exemption(&self) -> Number {
         self.cost_exemption
     }
 
-    pub fn set_cost_exemption(&mut self, cost_exemption: f64) {
+    pub fn set_cost_exemption(&mut self, cost_exemption: Number) {
         self.cost_exemption = cost_exemption;
     }
 
-    pub fn free_borrowed_amount(&self) -> f64 {
+    pub fn free_borrowed_amount(&self) -> Number {
         self.free_borrowed_amount
     }
 
-    pub fn set_free_borrowed_amount(&mut self, free_borrowed_amount: f64) {
+    pub fn set_free_borrowed_amount(&mut self, free_borrowed_amount: Number) {
         self.free_borrowed_amount = free_borrowed_amount;
     }
 
@@ -197,11 +277,15 @@ impl BorrowHistory {
         self.created_time = created_time;
     }
 
-    pub fn interest_bearing_borrow_size(&self) -> f64 {
+    pub fn created_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.created_time)
+    }
+
+    pub fn interest_bearing_borrow_size(&self) -> Number {
         self.interest_bearing_borrow_size
     }
 
-    pub fn set_interest_bearing_borrow_size(&mut self, interest_bearing_borrow_size: f64) {
+    pub fn set_interest_bearing_borrow_size(&mut self, interest_bearing_borrow_size: Number) {
         self.interest_bearing_borrow_size = interest_bearing_borrow_size;
     }
 
@@ -213,28 +297,36 @@ impl BorrowHistory {
         self.currency = currency;
     }
 
-    pub fn unrealised_loss(&self) -> f64 {
+    pub fn unrealised_loss(&self) -> Number {
         self.unrealised_loss
     }
 
-    pub fn set_unrealised_loss(&mut self, unrealised_loss: f64) {
+    pub fn set_unrealised_loss(&mut self, unrealised_loss: Number) {
         self.unrealised_loss = unrealised_loss;
     }
 
-    pub fn hourly_borrow_rate(&self) -> f64 {
+    pub fn hourly_borrow_rate(&self) -> Number {
         self.hourly_borrow_rate
     }
 
-    pub fn set_hourly_borrow_rate(&mut self, hourly_borrow_rate: f64) {
+    pub fn set_hourly_borrow_rate(&mut self, hourly_borrow_rate: Number) {
         self.hourly_borrow_rate = hourly_borrow_rate;
     }
 
-    pub fn borrow_cost(&self) -> f64 {
+    pub fn borrow_cost(&self) -> Number {
         self.borrow_cost
     }
 
-    pub fn set_borrow_cost(&mut self, borrow_cost: f64) {
+    pub fn set_borrow_cost(&mut self, borrow_cost: Number) {
         self.borrow_cost = borrow_cost;
     }
 
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,11 +1,19 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::Number,
     utils::{
-        deserialize_f64,
-        deserialize_option_f64,
+        serialize_as_string,
+        serialize_option_as_string,
+        millis_to_datetime,
+        deserialize_number,
+        deserialize_option_number,
     }
 };
 
@@ -13,13 +21,17 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/account/collateral-info";
+const PATH: &str = "/v5/account/collateral-info";
 
 impl BybitApi {
-    pub async fn get_collateral_info(&self, params: GetCollateralInfoParameters) -> Result<GetCollateralInfoResponse> {
+    pub async fn get_collateral_info(&self, params: GetCollateralInfoParameters) -> Result<GetCollateralInfoResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
 }
@@ -30,6 +42,12 @@ pub struct GetCollateralInfoParameters {
     currency: Option<String>,
 }
 
+impl Default for GetCollateralInfoParameters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl GetCollateralInfoParameters {
     pub fn new() -> Self {
         Self {
@@ -44,7 +62,7 @@ impl GetCollateralInfoParameters {
 
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetCollateralInfoResponse {
     ret_code: i32,
@@ -52,6 +70,8 @@ pub struct GetCollateralInfoResponse {
     result: CollateralInfoResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetCollateralInfoResponse {
     pub fn ret_code(&self) -> i32 {
@@ -93,12 +113,26 @@ impl GetCollateralInfoResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CollateralInfoResult {
-    list: Vec<CollateralInfo>
+    list: Vec<CollateralInfo>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl CollateralInfoResult {
     pub fn list(&self) -> &Vec<CollateralInfo> {
@@ -108,81 +142,91 @@ impl CollateralInfoResult {
     pub fn set_list(&mut self, list: Vec<CollateralInfo>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CollateralInfo {
-    #[serde(deserialize_with = "deserialize_f64")]
-    available_to_borrow: f64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    free_borrowing_amount: Option<f64>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    free_borrow_amount: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    max_borrowing_amount: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    hourly_borrow_rate: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    borrow_usage_rate: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    available_to_borrow: Number,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    free_borrowing_amount: Option<Number>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    free_borrow_amount: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    max_borrowing_amount: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    hourly_borrow_rate: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    borrow_usage_rate: Number,
     collateral_switch: bool,
-    #[serde(deserialize_with = "deserialize_f64")]
-    borrow_amount: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    borrow_amount: Number,
     borrowable: bool,
     currency: String,
     margin_collateral: bool,
-    #[serde(deserialize_with = "deserialize_f64")]
-    free_borrowing_limit: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    collateral_ratio: f64,    
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    free_borrowing_limit: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    collateral_ratio: Number,    
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl CollateralInfo {
-    pub fn available_to_borrow(&self) -> f64 {
+    pub fn available_to_borrow(&self) -> Number {
         self.available_to_borrow
     }
 
-    pub fn set_available_to_borrow(&mut self, available_to_borrow: f64) {
+    pub fn set_available_to_borrow(&mut self, available_to_borrow: Number) {
         self.available_to_borrow = available_to_borrow;
     }
 
-    pub fn free_borrowing_amount(&self) -> Option<f64> {
+    pub fn free_borrowing_amount(&self) -> Option<Number> {
         self.free_borrowing_amount
     }
 
-    pub fn set_free_borrowing_amount(&mut self, free_borrowing_amount: Option<f64>) {
+    pub fn set_free_borrowing_amount(&mut self, free_borrowing_amount: Option<Number>) {
         self.free_borrowing_amount = free_borrowing_amount;
     }
 
-    pub fn free_borrow_amount(&self) -> f64 {
+    pub fn free_borrow_amount(&self) -> Number {
         self.free_borrow_amount
     }
 
-    pub fn set_free_borrow_amount(&mut self, free_borrow_amount: f64) {
+    pub fn set_free_borrow_amount(&mut self, free_borrow_amount: Number) {
         self.free_borrow_amount = free_borrow_amount;
     }
 
-    pub fn max_borrowing_amount(&self) -> f64 {
+    pub fn max_borrowing_amount(&self) -> Number {
         self.max_borrowing_amount
     }
 
-    pub fn set_max_borrowing_amount(&mut self, max_borrowing_amount: f64) {
+    pub fn set_max_borrowing_amount(&mut self, max_borrowing_amount: Number) {
         self.max_borrowing_amount = max_borrowing_amount;
     }
 
-    pub fn hourly_borrow_rate(&self) -> f64 {
+    pub fn hourly_borrow_rate(&self) -> Number {
         self.hourly_borrow_rate
     }
 
-    pub fn set_hourly_borrow_rate(&mut self, hourly_borrow_rate: f64) {
+    pub fn set_hourly_borrow_rate(&mut self, hourly_borrow_rate: Number) {
         self.hourly_borrow_rate = hourly_borrow_rate;
     }
 
-    pub fn borrow_usage_rate(&self) -> f64 {
+    pub fn borrow_usage_rate(&self) -> Number {
         self.borrow_usage_rate
     }
 
-    pub fn set_borrow_usage_rate(&mut self, borrow_usage_rate: f64) {
+    pub fn set_borrow_usage_rate(&mut self, borrow_usage_rate: Number) {
         self.borrow_usage_rate = borrow_usage_rate;
     }
 
@@ -194,11 +238,11 @@ impl CollateralInfo {
         self.collateral_switch = collateral_switch;
     }
 
-    pub fn borrow_amount(&self) -> f64 {
+    pub fn borrow_amount(&self) -> Number {
         self.borrow_amount
     }
 
-    pub fn set_borrow_amount(&mut self, borrow_amount: f64) {
+    pub fn set_borrow_amount(&mut self, borrow_amount: Number) {
         self.borrow_amount = borrow_amount;
     }
 
@@ -226,20 +270,28 @@ impl CollateralInfo {
         self.margin_collateral = margin_collateral;
     }
 
-    pub fn free_borrowing_limit(&self) -> f64 {
+    pub fn free_borrowing_limit(&self) -> Number {
         self.free_borrowing_limit
     }
 
-    pub fn set_free_borrowing_limit(&mut self, free_borrowing_limit: f64) {
+    pub fn set_free_borrowing_limit(&mut self, free_borrowing_limit: Number) {
         self.free_borrowing_limit = free_borrowing_limit;
     }
 
-    pub fn collateral_ratio(&self) -> f64 {
+    pub fn collateral_ratio(&self) -> Number {
         self.collateral_ratio
     }
 
-    pub fn set_collateral_ratio(&mut self, collateral_ratio: f64) {
+    pub fn set_collateral_ratio(&mut self, collateral_ratio: Number) {
         self.collateral_ratio = collateral_ratio;
     }
 
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,34 +1,41 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::Category,
     v5::api::{
         BybitApi,
         get::Get,
     },
-    utils::deserialize_f64,
+    v5::types::Number,
+    utils::{
+        serialize_as_string,
+        deserialize_number,
+        millis_to_datetime,
+    },
 };
 
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/account/fee-rate";
+const PATH: &str = "/v5/account/fee-rate";
 
 impl BybitApi {
-    pub async fn get_fee_rate(&self, params: GetFeeRateParameters) -> Result<GetFeeRateResponse> {
+    pub async fn get_fee_rate(&self, params: GetFeeRateParameters) -> Result<GetFeeRateResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetFeeRateCategory {
-    Linear,
-    Inverse,
-    Spot,
-    Option,
-}
+pub type GetFeeRateCategory = Category;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -58,7 +65,7 @@ impl GetFeeRateParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetFeeRateResponse {
     ret_code: i32,
@@ -66,6 +73,8 @@ pub struct GetFeeRateResponse {
     result: FeeRateResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetFeeRateResponse {
     pub fn ret_code(&self) -> i32 {
@@ -107,12 +116,26 @@ impl GetFeeRateResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FeeRateResult {
-    list: Vec<FeeRate>
+    list: Vec<FeeRate>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl FeeRateResult {
     pub fn list(&self) -> &Vec<FeeRate> {
@@ -122,16 +145,26 @@ impl FeeRateResult {
     pub fn set_list(&mut self, list: Vec<FeeRate>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FeeRate {
     symbol: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    taker_fee_rate: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    maker_fee_rate: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    taker_fee_rate: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    maker_fee_rate: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl FeeRate {
     pub fn symbol(&self) -> &str {
@@ -142,19 +175,27 @@ impl FeeRate {
         self.symbol = symbol;
     }
 
-    pub fn taker_fee_rate(&self) -> f64 {
+    pub fn taker_fee_rate(&self) -> Number {
         self.taker_fee_rate
     }
 
-    pub fn set_taker_fee_rate(&mut self, taker_fee_rate: f64) {
+    pub fn set_taker_fee_rate(&mut self, taker_fee_rate: Number) {
         self.taker_fee_rate = taker_fee_rate;
     }
 
-    pub fn maker_fee_rate(&self) -> f64 {
+    pub fn maker_fee_rate(&self) -> Number {
         self.maker_fee_rate
     }
 
-    pub fn set_maker_fee_rate(&mut self, maker_fee_rate: f64) {
+    pub fn set_maker_fee_rate(&mut self, maker_fee_rate: Number) {
         self.maker_fee_rate = maker_fee_rate;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
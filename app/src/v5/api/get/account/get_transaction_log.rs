@@ -1,11 +1,26 @@
+use chrono::{
+    DateTime,
+    Duration,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        SpotLinearOption,
+    },
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::Number,
     utils::{
-        deserialize_f64,
-        deserialize_option_f64,
+        serialize_as_string,
+        serialize_option_as_string,
+        datetime_to_millis,
+        millis_to_datetime,
+        str_millis_to_datetime,
+        deserialize_number,
+        deserialize_option_number,
     },
 };
 
@@ -13,15 +28,77 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
+use crate::v5::api::pagination::{
+    paginate,
+    paginate_time_range,
+    TimeRange,
+};
+use futures_util::{
+    FutureExt,
+    stream::BoxStream,
+};
 
-const PATH: &'static str = "/v5/account/transaction-log";
+const PATH: &str = "/v5/account/transaction-log";
+// startTime と endTime の間隔の上限
+const MAX_TIME_RANGE_DAYS: i64 = 7;
 
 impl BybitApi {
-    pub async fn get_transaction_log(&self, params: GetTransactionLogParameters) -> Result<GetTransactionLogResponse> {
+    pub async fn get_transaction_log(&self, params: GetTransactionLogParameters) -> Result<GetTransactionLogResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
+
+    /// Gets all transaction logs, following `next_page_cursor` until the last page.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters of the first request.
+    /// * `max_items` - The maximum number of transaction logs to yield, or `None` for all of them.
+    ///
+    /// # Returns
+    ///
+    /// A stream of transaction logs. A failed request is yielded as an error and ends the stream.
+    pub fn get_transaction_log_stream(&self, params: GetTransactionLogParameters, max_items: Option<usize>) -> BoxStream<'_, Result<TransactionLog, BybitError>> {
+        paginate(params, max_items, GetTransactionLogParameters::with_cursor, move |params| {
+            async move {
+                let result = self.get_transaction_log(params).await?.result;
+                Ok((result.list, result.next_page_cursor))
+            }.boxed()
+        })
+    }
+
+    /// Gets the transaction logs between `from` and `to`, split into the 7 day windows Bybit accepts.
+    ///
+    /// Each window is paged through with `next_page_cursor`. Transaction logs returned by two windows are yielded once.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The filters of every request. `start_time`, `end_time` and `cursor` are overwritten.
+    /// * `from` - The start of the range, inclusive.
+    /// * `to` - The end of the range, exclusive.
+    ///
+    /// # Returns
+    ///
+    /// A stream of transaction logs, oldest first. A failed request is yielded as an error and ends the stream.
+    pub fn get_transaction_log_range_stream(&self, params: GetTransactionLogParameters, from: DateTime<Utc>, to: DateTime<Utc>) -> BoxStream<'_, Result<TransactionLog, BybitError>> {
+        let range: TimeRange<GetTransactionLogParameters, TransactionLog> = TimeRange {
+            with_window: |params, start, end| params.with_start_time(start).with_end_time(end),
+            with_cursor: GetTransactionLogParameters::with_cursor,
+            time: |log| log.transaction_time.parse().unwrap_or_default(),
+            key: |log| log.id.clone(),
+        };
+        paginate_time_range(params, from, to, Duration::days(MAX_TIME_RANGE_DAYS), range, move |params| {
+            async move {
+                let result = self.get_transaction_log(params).await?.result;
+                Ok((result.list, result.next_page_cursor))
+            }.boxed()
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -29,13 +106,7 @@ pub enum GetTransactionLogAccountType {
     UNIFIED,
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetTransactionLogCategory {
-    Linear,
-    Spot,
-    Option,
-}
+pub type GetTransactionLogCategory = Category<SpotLinearOption>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -52,6 +123,12 @@ pub struct GetTransactionLogParameters {
     cursor: Option<String>,
 }
 
+impl Default for GetTransactionLogParameters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl GetTransactionLogParameters {
     pub fn new() -> Self {
         Self {
@@ -97,11 +174,21 @@ impl GetTransactionLogParameters {
         self
     }
 
+    pub fn with_start_datetime(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(datetime_to_millis(start_time));
+        self
+    }
+
     pub fn with_end_time(mut self, end_time: u64) -> Self {
         self.end_time = Some(end_time);
         self
     }
 
+    pub fn with_end_datetime(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(datetime_to_millis(end_time));
+        self
+    }
+
     pub fn with_limit(mut self, limit: i32) -> Self {
         self.limit = Some(limit);
         self
@@ -113,7 +200,7 @@ impl GetTransactionLogParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetTransactionLogResponse {
     ret_code: i32,
@@ -121,6 +208,8 @@ pub struct GetTransactionLogResponse {
     result: TransactionLogResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetTransactionLogResponse {
     pub fn ret_code(&self) -> i32 {
@@ -162,13 +251,27 @@ impl GetTransactionLogResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionLogResult {
     next_page_cursor: Option<String>,
-    list: Vec<TransactionLog>
+    list: Vec<TransactionLog>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl TransactionLogResult {
     pub fn next_page_cursor(&self) -> &Option<String> {
@@ -186,42 +289,52 @@ impl TransactionLogResult {
     pub fn set_list(&mut self, list: Vec<TransactionLog>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionLog {
     id: String,
     symbol: String,
     side: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    funding: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    funding: Number,
     order_link_id: String,
     order_id: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    fee: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    change: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    cash_flow: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    fee: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    change: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    cash_flow: Number,
     transaction_time: String,
     #[serde(rename = "type")]
     type_field: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    fee_rate: f64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    bonus_change: Option<f64>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    size: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    qty: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    cash_balance: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    fee_rate: Number,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    bonus_change: Option<Number>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    size: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    qty: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    cash_balance: Number,
     currency: String,
     category: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    trade_price: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    trade_price: Number,
     trade_id: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl TransactionLog {
@@ -249,11 +362,11 @@ impl TransactionLog {
         self.side = side;
     }
 
-    pub fn funding(&self) -> f64 {
+    pub fn funding(&self) -> Number {
         self.funding
     }
 
-    pub fn set_funding(&mut self, funding: f64) {
+    pub fn set_funding(&mut self, funding: Number) {
         self.funding = funding;
     }
 
@@ -273,27 +386,27 @@ impl TransactionLog {
         self.order_id = order_id;
     }
 
-    pub fn fee(&self) -> f64 {
+    pub fn fee(&self) -> Number {
         self.fee
     }
 
-    pub fn set_fee(&mut self, fee: f64) {
+    pub fn set_fee(&mut self, fee: Number) {
         self.fee = fee;
     }
 
-    pub fn change(&self) -> f64 {
+    pub fn change(&self) -> Number {
         self.change
     }
 
-    pub fn set_change(&mut self, change: f64) {
+    pub fn set_change(&mut self, change: Number) {
         self.change = change;
     }
 
-    pub fn cash_flow(&self) -> f64 {
+    pub fn cash_flow(&self) -> Number {
         self.cash_flow
     }
 
-    pub fn set_cash_flow(&mut self, cash_flow: f64) {
+    pub fn set_cash_flow(&mut self, cash_flow: Number) {
         self.cash_flow = cash_flow;
     }
 
@@ -305,6 +418,10 @@ impl TransactionLog {
         self.transaction_time = transaction_time;
     }
 
+    pub fn transaction_time_datetime(&self) -> Option<DateTime<Utc>> {
+        str_millis_to_datetime(&self.transaction_time)
+    }
+
     pub fn type_field(&self) -> &str {
         &self.type_field
     }
@@ -313,43 +430,43 @@ impl TransactionLog {
         self.type_field = type_field;
     }
 
-    pub fn fee_rate(&self) -> f64 {
+    pub fn fee_rate(&self) -> Number {
         self.fee_rate
     }
 
-    pub fn set_fee_rate(&mut self, fee_rate: f64) {
+    pub fn set_fee_rate(&mut self, fee_rate: Number) {
         self.fee_rate = fee_rate;
     }
 
-    pub fn bonus_change(&self) -> Option<f64> {
+    pub fn bonus_change(&self) -> Option<Number> {
         self.bonus_change
     }
 
-    pub fn set_bonus_change(&mut self, bonus_change: f64) {
+    pub fn set_bonus_change(&mut self, bonus_change: Number) {
         self.bonus_change = Some(bonus_change);
     }
 
-    pub fn size(&self) -> f64 {
+    pub fn size(&self) -> Number {
         self.size
     }
 
-    pub fn set_size(&mut self, size: f64) {
+    pub fn set_size(&mut self, size: Number) {
         self.size = size;
     }
 
-    pub fn qty(&self) -> f64 {
+    pub fn qty(&self) -> Number {
         self.qty
     }
 
-    pub fn set_qty(&mut self, qty: f64) {
+    pub fn set_qty(&mut self, qty: Number) {
         self.qty = qty;
     }
 
-    pub fn cash_balance(&self) -> f64 {
+    pub fn cash_balance(&self) -> Number {
         self.cash_balance
     }
 
-    pub fn set_cash_balance(&mut self, cash_balance: f64) {
+    pub fn set_cash_balance(&mut self, cash_balance: Number) {
         self.cash_balance = cash_balance;
     }
 
@@ -369,11 +486,11 @@ impl TransactionLog {
         self.category = category;
     }
 
-    pub fn trade_price(&self) -> f64 {
+    pub fn trade_price(&self) -> Number {
         self.trade_price
     }
 
-    pub fn set_trade_price(&mut self, trade_price: f64) {
+    pub fn set_trade_price(&mut self, trade_price: Number) {
         self.trade_price = trade_price;
     }
 
@@ -385,4 +502,12 @@ impl TransactionLog {
         self.trade_id = trade_id;
     }
     
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
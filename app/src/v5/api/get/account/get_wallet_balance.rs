@@ -1,11 +1,19 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::Number,
     utils::{
-        deserialize_f64,
-        deserialize_option_f64,
+        serialize_as_string,
+        serialize_option_as_string,
+        millis_to_datetime,
+        deserialize_number,
+        deserialize_option_number,
     },
 };
 
@@ -13,13 +21,17 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/account/wallet-balance";
+const PATH: &str = "/v5/account/wallet-balance";
 
 impl BybitApi {
-    pub async fn get_wallet_balance(&self, params: GetWalletBalanceParameters) -> Result<GetWalletBalanceResponse> {
+    pub async fn get_wallet_balance(&self, params: GetWalletBalanceParameters) -> Result<GetWalletBalanceResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
 }
@@ -52,7 +64,7 @@ impl GetWalletBalanceParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetWalletBalanceResponse {
     ret_code: i32,
@@ -60,6 +72,8 @@ pub struct GetWalletBalanceResponse {
     result: WalletBalanceResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetWalletBalanceResponse {
     pub fn ret_code(&self) -> i32 {
@@ -101,12 +115,26 @@ impl GetWalletBalanceResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WalletBalanceResult {
-    list: Vec<WalletBalance>
+    list: Vec<WalletBalance>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl WalletBalanceResult {
     pub fn list(&self) -> &Vec<WalletBalance> {
@@ -116,65 +144,75 @@ impl WalletBalanceResult {
     pub fn set_list(&mut self, list: Vec<WalletBalance>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WalletBalance {
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    pub total_equity: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    account_i_m_rate: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    total_margin_balance: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    total_initial_margin: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    pub total_equity: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    account_i_m_rate: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    total_margin_balance: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    total_initial_margin: Option<Number>,
     account_type: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    total_available_balance: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    account_m_m_rate: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    total_perp_u_p_l: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    total_wallet_balance: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    account_l_t_v: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    total_maintenance_margin: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    total_available_balance: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    account_m_m_rate: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    total_perp_u_p_l: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    total_wallet_balance: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    account_l_t_v: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    total_maintenance_margin: Option<Number>,
     coin: Vec<Coin>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl WalletBalance {
-    pub fn total_equity(&self) -> Option<f64> {
+    pub fn total_equity(&self) -> Option<Number> {
         self.total_equity
     }
 
-    pub fn set_total_equity(&mut self, total_equity: f64) {
+    pub fn set_total_equity(&mut self, total_equity: Number) {
         self.total_equity = Some(total_equity);
     }
     
-    pub fn account_i_m_rate(&self) -> Option<f64> {
+    pub fn account_i_m_rate(&self) -> Option<Number> {
         self.account_i_m_rate
     }
 
-    pub fn set_account_i_m_rate(&mut self, account_i_m_rate: f64) {
+    pub fn set_account_i_m_rate(&mut self, account_i_m_rate: Number) {
         self.account_i_m_rate = Some(account_i_m_rate);
     }
 
-    pub fn total_margin_balance(&self) -> Option<f64> {
+    pub fn total_margin_balance(&self) -> Option<Number> {
         self.total_margin_balance
     }
 
-    pub fn set_total_margin_balance(&mut self, total_margin_balance: f64) {
+    pub fn set_total_margin_balance(&mut self, total_margin_balance: Number) {
         self.total_margin_balance = Some(total_margin_balance);
     }
 
-    pub fn total_initial_margin(&self) -> Option<f64> {
+    pub fn total_initial_margin(&self) -> Option<Number> {
         self.total_initial_margin
     }
 
-    pub fn set_total_initial_margin(&mut self, total_initial_margin: f64) {
+    pub fn set_total_initial_margin(&mut self, total_initial_margin: Number) {
         self.total_initial_margin = Some(total_initial_margin);
     }
 
@@ -186,51 +224,51 @@ impl WalletBalance {
         self.account_type = account_type;
     }
 
-    pub fn total_available_balance(&self) -> Option<f64> {
+    pub fn total_available_balance(&self) -> Option<Number> {
         self.total_available_balance
     }
 
-    pub fn set_total_available_balance(&mut self, total_available_balance: f64) {
+    pub fn set_total_available_balance(&mut self, total_available_balance: Number) {
         self.total_available_balance = Some(total_available_balance);
     }
 
-    pub fn account_m_m_rate(&self) -> Option<f64> {
+    pub fn account_m_m_rate(&self) -> Option<Number> {
         self.account_m_m_rate
     }
 
-    pub fn set_account_m_m_rate(&mut self, account_m_m_rate: f64) {
+    pub fn set_account_m_m_rate(&mut self, account_m_m_rate: Number) {
         self.account_m_m_rate = Some(account_m_m_rate);
     }
 
-    pub fn total_perp_u_p_l(&self) -> Option<f64> {
+    pub fn total_perp_u_p_l(&self) -> Option<Number> {
         self.total_perp_u_p_l
     }
 
-    pub fn set_total_perp_u_p_l(&mut self, total_perp_u_p_l: f64) {
+    pub fn set_total_perp_u_p_l(&mut self, total_perp_u_p_l: Number) {
         self.total_perp_u_p_l = Some(total_perp_u_p_l);
     }
 
-    pub fn total_wallet_balance(&self) -> Option<f64> {
+    pub fn total_wallet_balance(&self) -> Option<Number> {
         self.total_wallet_balance
     }
 
-    pub fn set_total_wallet_balance(&mut self, total_wallet_balance: f64) {
+    pub fn set_total_wallet_balance(&mut self, total_wallet_balance: Number) {
         self.total_wallet_balance = Some(total_wallet_balance);
     }
 
-    pub fn account_l_t_v(&self) -> Option<f64> {
+    pub fn account_l_t_v(&self) -> Option<Number> {
         self.account_l_t_v
     }
 
-    pub fn set_account_l_t_v(&mut self, account_l_t_v: f64) {
+    pub fn set_account_l_t_v(&mut self, account_l_t_v: Number) {
         self.account_l_t_v = Some(account_l_t_v);
     }
 
-    pub fn total_maintenance_margin(&self) -> Option<f64> {
+    pub fn total_maintenance_margin(&self) -> Option<Number> {
         self.total_maintenance_margin
     }
 
-    pub fn set_total_maintenance_margin(&mut self, total_maintenance_margin: f64) {
+    pub fn set_total_maintenance_margin(&mut self, total_maintenance_margin: Number) {
         self.total_maintenance_margin = Some(total_maintenance_margin);
     }
 
@@ -242,124 +280,134 @@ impl WalletBalance {
         self.coin = coin;
     }
 
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Coin {
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    available_to_borrow: Option<f64>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    bonus: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    accrued_interest: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    available_to_withdraw: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    total_order_i_m: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    equity: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    total_position_m_m: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    usd_value: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    spot_hedging_qty: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    unrealised_pnl: f64,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    available_to_borrow: Option<Number>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    bonus: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    accrued_interest: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    available_to_withdraw: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    total_order_i_m: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    equity: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    total_position_m_m: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    usd_value: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    spot_hedging_qty: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    unrealised_pnl: Number,
     collateral_switch: bool,
-    #[serde(deserialize_with = "deserialize_f64")]
-    borrow_amount: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    total_position_i_m: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    wallet_balance: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    cum_realised_pnl: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    locked: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    borrow_amount: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    total_position_i_m: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    wallet_balance: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    cum_realised_pnl: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    locked: Number,
     margin_collateral: bool,
     coin: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl Coin {
-    pub fn available_to_borrow(&self) -> Option<f64> {
+    pub fn available_to_borrow(&self) -> Option<Number> {
         self.available_to_borrow
     }
 
-    pub fn set_available_to_borrow(&mut self, available_to_borrow: f64) {
+    pub fn set_available_to_borrow(&mut self, available_to_borrow: Number) {
         self.available_to_borrow = Some(available_to_borrow);
     }
 
-    pub fn bonus(&self) -> f64 {
+    pub fn bonus(&self) -> Number {
         self.bonus
     }
 
-    pub fn set_bonus(&mut self, bonus: f64) {
+    pub fn set_bonus(&mut self, bonus: Number) {
         self.bonus = bonus;
     }
 
-    pub fn accrued_interest(&self) -> f64 {
+    pub fn accrued_interest(&self) -> Number {
         self.accrued_interest
     }
 
-    pub fn set_accrued_interest(&mut self, accrued_interest: f64) {
+    pub fn set_accrued_interest(&mut self, accrued_interest: Number) {
         self.accrued_interest = accrued_interest;
     }
 
-    pub fn available_to_withdraw(&self) -> f64 {
+    pub fn available_to_withdraw(&self) -> Number {
         self.available_to_withdraw
     }
 
-    pub fn set_available_to_withdraw(&mut self, available_to_withdraw: f64) {
+    pub fn set_available_to_withdraw(&mut self, available_to_withdraw: Number) {
         self.available_to_withdraw = available_to_withdraw;
     }
 
-    pub fn total_order_i_m(&self) -> f64 {
+    pub fn total_order_i_m(&self) -> Number {
         self.total_order_i_m
     }
 
-    pub fn set_total_order_i_m(&mut self, total_order_i_m: f64) {
+    pub fn set_total_order_i_m(&mut self, total_order_i_m: Number) {
         self.total_order_i_m = total_order_i_m;
     }
 
-    pub fn equity(&self) -> f64 {
+    pub fn equity(&self) -> Number {
         self.equity
     }
 
-    pub fn set_equity(&mut self, equity: f64) {
+    pub fn set_equity(&mut self, equity: Number) {
         self.equity = equity;
     }
 
-    pub fn total_position_m_m(&self) -> f64 {
+    pub fn total_position_m_m(&self) -> Number {
         self.total_position_m_m
     }
 
-    pub fn set_total_position_m_m(&mut self, total_position_m_m: f64) {
+    pub fn set_total_position_m_m(&mut self, total_position_m_m: Number) {
         self.total_position_m_m = total_position_m_m;
     }
 
-    pub fn usd_value(&self) -> f64 {
+    pub fn usd_value(&self) -> Number {
         self.usd_value
     }
 
-    pub fn set_usd_value(&mut self, usd_value: f64) {
+    pub fn set_usd_value(&mut self, usd_value: Number) {
         self.usd_value = usd_value;
     }
 
-    pub fn spot_hedging_qty(&self) -> f64 {
+    pub fn spot_hedging_qty(&self) -> Number {
         self.spot_hedging_qty
     }
 
-    pub fn set_spot_hedging_qty(&mut self, spot_hedging_qty: f64) {
+    pub fn set_spot_hedging_qty(&mut self, spot_hedging_qty: Number) {
         self.spot_hedging_qty = spot_hedging_qty;
     }
 
-    pub fn unrealised_pnl(&self) -> f64 {
+    pub fn unrealised_pnl(&self) -> Number {
         self.unrealised_pnl
     }
 
-    pub fn set_unrealised_pnl(&mut self, unrealised_pnl: f64) {
+    pub fn set_unrealised_pnl(&mut self, unrealised_pnl: Number) {
         self.unrealised_pnl = unrealised_pnl;
     }
 
@@ -371,43 +419,43 @@ impl Coin {
         self.collateral_switch = collateral_switch;
     }
 
-    pub fn borrow_amount(&self) -> f64 {
+    pub fn borrow_amount(&self) -> Number {
         self.borrow_amount
     }
 
-    pub fn set_borrow_amount(&mut self, borrow_amount: f64) {
+    pub fn set_borrow_amount(&mut self, borrow_amount: Number) {
         self.borrow_amount = borrow_amount;
     }
 
-    pub fn total_position_i_m(&self) -> f64 {
+    pub fn total_position_i_m(&self) -> Number {
         self.total_position_i_m
     }
 
-    pub fn set_total_position_i_m(&mut self, total_position_i_m: f64) {
+    pub fn set_total_position_i_m(&mut self, total_position_i_m: Number) {
         self.total_position_i_m = total_position_i_m;
     }
 
-    pub fn wallet_balance(&self) -> f64 {
+    pub fn wallet_balance(&self) -> Number {
         self.wallet_balance
     }
 
-    pub fn set_wallet_balance(&mut self, wallet_balance: f64) {
+    pub fn set_wallet_balance(&mut self, wallet_balance: Number) {
         self.wallet_balance = wallet_balance;
     }
 
-    pub fn cum_realised_pnl(&self) -> f64 {
+    pub fn cum_realised_pnl(&self) -> Number {
         self.cum_realised_pnl
     }
 
-    pub fn set_cum_realised_pnl(&mut self, cum_realised_pnl: f64) {
+    pub fn set_cum_realised_pnl(&mut self, cum_realised_pnl: Number) {
         self.cum_realised_pnl = cum_realised_pnl;
     }
 
-    pub fn locked(&self) -> f64 {
+    pub fn locked(&self) -> Number {
         self.locked
     }
 
-    pub fn set_locked(&mut self, locked: f64) {
+    pub fn set_locked(&mut self, locked: Number) {
         self.locked = locked;
     }
 
@@ -426,4 +474,12 @@ impl Coin {
     pub fn set_coin(&mut self, coin: String) {
         self.coin = coin;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
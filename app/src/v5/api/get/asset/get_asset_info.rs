@@ -1,11 +1,19 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::Number,
     utils::{
-        deserialize_f64,
-        deserialize_option_f64,
+        serialize_as_string,
+        serialize_option_as_string,
+        millis_to_datetime,
+        deserialize_number,
+        deserialize_option_number,
     },
 };
 
@@ -13,13 +21,17 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/asset/transfer/query-asset-info";
+const PATH: &str = "/v5/asset/transfer/query-asset-info";
 
 impl BybitApi {
-    pub async fn get_asset_info(&self, params: GetAssetInfoParameters) -> Result<GetAssetInfoResponse> {
+    pub async fn get_asset_info(&self, params: GetAssetInfoParameters) -> Result<GetAssetInfoResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
 }
@@ -50,7 +62,7 @@ impl GetAssetInfoParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetAssetInfoResponse {
     ret_code: i32,
@@ -58,6 +70,8 @@ pub struct GetAssetInfoResponse {
     result: AssetInfoResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetAssetInfoResponse {
     pub fn ret_code(&self) -> i32 {
@@ -99,12 +113,26 @@ impl GetAssetInfoResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AssetInfoResult {
-    spot: AssetInfo
+    spot: AssetInfo,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl AssetInfoResult {
     pub fn spot(&self) -> &AssetInfo {
@@ -114,13 +142,23 @@ impl AssetInfoResult {
     pub fn set_spot(&mut self, spot: AssetInfo) {
         self.spot = spot;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AssetInfo {
     status: String,
     assets: Vec<Asset>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl AssetInfo {
@@ -140,17 +178,27 @@ impl AssetInfo {
         self.assets = assets;
     }
 
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Asset {
     coin: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    frozen: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    free: f64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    withdraw: Option<f64>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    frozen: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    free: Number,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    withdraw: Option<Number>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl Asset {
@@ -162,27 +210,35 @@ impl Asset {
         self.coin = coin;
     }
 
-    pub fn frozen(&self) -> f64 {
+    pub fn frozen(&self) -> Number {
         self.frozen
     }
 
-    pub fn set_frozen(&mut self, frozen: f64) {
+    pub fn set_frozen(&mut self, frozen: Number) {
         self.frozen = frozen;
     }
 
-    pub fn free(&self) -> f64 {
+    pub fn free(&self) -> Number {
         self.free
     }
 
-    pub fn set_free(&mut self, free: f64) {
+    pub fn set_free(&mut self, free: Number) {
         self.free = free;
     }
 
-    pub fn withdraw(&self) -> Option<f64> {
+    pub fn withdraw(&self) -> Option<Number> {
         self.withdraw
     }
 
-    pub fn set_withdraw(&mut self, withdraw: Option<f64>) {
+    pub fn set_withdraw(&mut self, withdraw: Option<Number>) {
         self.withdraw = withdraw;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
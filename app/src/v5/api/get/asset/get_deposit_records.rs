@@ -1,11 +1,21 @@
+use chrono::{
+    DateTime,
+    Duration,
+    Utc,
+};
 use crate::{
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::Number,
     utils::{
-        deserialize_f64,
-        deserialize_option_f64,
+        serialize_as_string,
+        serialize_option_as_string,
+        datetime_to_millis,
+        millis_to_datetime,
+        deserialize_number,
+        deserialize_option_number,
         deserialize_string_to_u64,
     },
 };
@@ -14,15 +24,77 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
+use crate::v5::api::pagination::{
+    paginate,
+    paginate_time_range,
+    TimeRange,
+};
+use futures_util::{
+    FutureExt,
+    stream::BoxStream,
+};
 
-const PATH: &'static str = "/v5/asset/deposit/query-record";
+const PATH: &str = "/v5/asset/deposit/query-record";
+// startTime と endTime の間隔の上限
+const MAX_TIME_RANGE_DAYS: i64 = 30;
 
 impl BybitApi {
-    pub async fn get_deposit_records(&self, params: GetDepositRecordsParameters) -> Result<GetDepositRecordsResponse> {
+    pub async fn get_deposit_records(&self, params: GetDepositRecordsParameters) -> Result<GetDepositRecordsResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
+
+    /// Gets all deposit records, following `next_page_cursor` until the last page.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters of the first request.
+    /// * `max_items` - The maximum number of deposit records to yield, or `None` for all of them.
+    ///
+    /// # Returns
+    ///
+    /// A stream of deposit records. A failed request is yielded as an error and ends the stream.
+    pub fn get_deposit_records_stream(&self, params: GetDepositRecordsParameters, max_items: Option<usize>) -> BoxStream<'_, Result<DepositRecord, BybitError>> {
+        paginate(params, max_items, GetDepositRecordsParameters::with_cursor, move |params| {
+            async move {
+                let result = self.get_deposit_records(params).await?.result;
+                Ok((result.rows, result.next_page_cursor))
+            }.boxed()
+        })
+    }
+
+    /// Gets the deposit records between `from` and `to`, split into the 30 day windows Bybit accepts.
+    ///
+    /// Each window is paged through with `next_page_cursor`. Deposit records returned by two windows are yielded once.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The filters of every request. `start_time`, `end_time` and `cursor` are overwritten.
+    /// * `from` - The start of the range, inclusive.
+    /// * `to` - The end of the range, exclusive.
+    ///
+    /// # Returns
+    ///
+    /// A stream of deposit records, oldest first. A failed request is yielded as an error and ends the stream.
+    pub fn get_deposit_records_range_stream(&self, params: GetDepositRecordsParameters, from: DateTime<Utc>, to: DateTime<Utc>) -> BoxStream<'_, Result<DepositRecord, BybitError>> {
+        let range: TimeRange<GetDepositRecordsParameters, DepositRecord> = TimeRange {
+            with_window: |params, start, end| params.with_start_time(start).with_end_time(end),
+            with_cursor: GetDepositRecordsParameters::with_cursor,
+            time: DepositRecord::success_at,
+            key: |record| format!("{}:{}", record.tx_id, record.tx_index),
+        };
+        paginate_time_range(params, from, to, Duration::days(MAX_TIME_RANGE_DAYS), range, move |params| {
+            async move {
+                let result = self.get_deposit_records(params).await?.result;
+                Ok((result.rows, result.next_page_cursor))
+            }.boxed()
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -51,11 +123,21 @@ impl GetDepositRecordsParameters {
         self
     }
 
+    pub fn with_start_datetime(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(datetime_to_millis(start_time));
+        self
+    }
+
     pub fn with_end_time(mut self, end_time: u64) -> Self {
         self.end_time = Some(end_time);
         self
     }
 
+    pub fn with_end_datetime(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(datetime_to_millis(end_time));
+        self
+    }
+
     pub fn with_limit(mut self, limit: i32) -> Self {
         self.limit = Some(limit);
         self
@@ -67,7 +149,7 @@ impl GetDepositRecordsParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetDepositRecordsResponse {
     ret_code: i32,
@@ -75,6 +157,8 @@ pub struct GetDepositRecordsResponse {
     result: DepositRecordsResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetDepositRecordsResponse {
     pub fn ret_code(&self) -> i32 {
@@ -116,13 +200,27 @@ impl GetDepositRecordsResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DepositRecordsResult {
     rows: Vec<DepositRecord>,
     next_page_cursor: Option<String>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl DepositRecordsResult {
     pub fn rows(&self) -> &Vec<DepositRecord> {
@@ -140,29 +238,39 @@ impl DepositRecordsResult {
     pub fn set_next_page_cursor(&mut self, next_page_cursor: Option<String>) {
         self.next_page_cursor = next_page_cursor;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DepositRecord {
     coin: String,
     chain: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    amount: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    amount: Number,
     tx_id: String,
     status: i64,
     to_address: String,
     tag: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    deposit_fee: Option<f64>,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    deposit_fee: Option<Number>,
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     success_at: u64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     confirmations: u64,
     tx_index: String,
     block_hash: String,
     batch_release_limit: String,
     deposit_type: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl DepositRecord {
@@ -182,11 +290,11 @@ impl DepositRecord {
         self.chain = chain;
     }
 
-    pub fn amount(&self) -> f64 {
+    pub fn amount(&self) -> Number {
         self.amount
     }
 
-    pub fn set_amount(&mut self, amount: f64) {
+    pub fn set_amount(&mut self, amount: Number) {
         self.amount = amount;
     }
 
@@ -222,11 +330,11 @@ impl DepositRecord {
         self.tag = tag;
     }
 
-    pub fn deposit_fee(&self) -> Option<f64> {
+    pub fn deposit_fee(&self) -> Option<Number> {
         self.deposit_fee
     }
 
-    pub fn set_deposit_fee(&mut self, deposit_fee: Option<f64>) {
+    pub fn set_deposit_fee(&mut self, deposit_fee: Option<Number>) {
         self.deposit_fee = deposit_fee;
     }
 
@@ -238,6 +346,10 @@ impl DepositRecord {
         self.success_at = success_at;
     }
 
+    pub fn success_at_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.success_at)
+    }
+
     pub fn confirmations(&self) -> u64 {
         self.confirmations
     }
@@ -278,4 +390,12 @@ impl DepositRecord {
         self.deposit_type = deposit_type;
     }
 
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,3 +1,10 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use crate::utils::{
+    millis_to_datetime,
+};
 use crate::v5::api::{
     BybitApi,
     get::Get,
@@ -7,13 +14,17 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/asset/deposit/query-sub-member-address";
+const PATH: &str = "/v5/asset/deposit/query-sub-member-address";
 
 impl BybitApi {
-    pub async fn get_sub_deposit_address(&self, params: GetSubDepositAddressParameters) -> Result<GetSubDepositAddressResponse> {
+    pub async fn get_sub_deposit_address(&self, params: GetSubDepositAddressParameters) -> Result<GetSubDepositAddressResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
 }
@@ -36,7 +47,7 @@ impl GetSubDepositAddressParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetSubDepositAddressResponse {
     ret_code: i32,
@@ -44,6 +55,8 @@ pub struct GetSubDepositAddressResponse {
     result: SubDepositAddressResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetSubDepositAddressResponse {
     pub fn ret_code(&self) -> i32 {
@@ -85,13 +98,27 @@ impl GetSubDepositAddressResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubDepositAddressResult {
     coin: String,
-    chains: SubDepositAddress
+    chains: SubDepositAddress,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl SubDepositAddressResult {
     pub fn coin(&self) -> &str {
@@ -109,9 +136,17 @@ impl SubDepositAddressResult {
     pub fn set_chains(&mut self, chains: SubDepositAddress) {
         self.chains = chains;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubDepositAddress {
     chain_type: String,
@@ -119,6 +154,8 @@ pub struct SubDepositAddress {
     tag_deposit: String,
     chain: String,
     batch_release_limit: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl SubDepositAddress {
@@ -161,4 +198,12 @@ impl SubDepositAddress {
     pub fn set_batch_release_limit(&mut self, batch_release_limit: String) {
         self.batch_release_limit = batch_release_limit;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
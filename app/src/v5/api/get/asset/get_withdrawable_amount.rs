@@ -1,22 +1,35 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
     v5::api::{
         BybitApi,
         get::Get,
     },
-    utils::deserialize_f64,
+    v5::types::Number,
+    utils::{
+        serialize_as_string,
+        deserialize_number,
+        millis_to_datetime,
+    },
 };
 
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/asset/withdraw/withdrawable-amount";
+const PATH: &str = "/v5/asset/withdraw/withdrawable-amount";
 
 impl BybitApi {
-    pub async fn get_withdrawable_amount(&self, params: GetWithdrawableAmountParameters) -> Result<GetWithdrawableAmountResponse> {
+    pub async fn get_withdrawable_amount(&self, params: GetWithdrawableAmountParameters) -> Result<GetWithdrawableAmountResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
 }
@@ -41,7 +54,7 @@ impl GetWithdrawableAmountParameters {
 
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetWithdrawableAmountResponse {
     ret_code: i32,
@@ -49,6 +62,8 @@ pub struct GetWithdrawableAmountResponse {
     result: WithdrawableAmountResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetWithdrawableAmountResponse {
     pub fn ret_code(&self) -> i32 {
@@ -90,21 +105,35 @@ impl GetWithdrawableAmountResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WithdrawableAmountResult {
-    #[serde(deserialize_with = "deserialize_f64")]
-    limit_amount_usd: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    limit_amount_usd: Number,
     withdrawable_amount: WithdrawableAmount,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl WithdrawableAmountResult {
-    pub fn limit_amount_usd(&self) -> f64 {
+    pub fn limit_amount_usd(&self) -> Number {
         self.limit_amount_usd
     }
 
-    pub fn set_limit_amount_usd(&mut self, limit_amount_usd: f64) {
+    pub fn set_limit_amount_usd(&mut self, limit_amount_usd: Number) {
         self.limit_amount_usd = limit_amount_usd;
     }
 
@@ -115,15 +144,25 @@ impl WithdrawableAmountResult {
     pub fn set_withdrawable_amount(&mut self, withdrawable_amount: WithdrawableAmount) {
         self.withdrawable_amount = withdrawable_amount;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WithdrawableAmount {
     #[serde(rename = "SPOT")]
     spot: Option<WithdrawableAmountDetail>,
     #[serde(rename = "FUND")]
     fund: Option<WithdrawableAmountDetail>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl WithdrawableAmount {
@@ -142,16 +181,26 @@ impl WithdrawableAmount {
     pub fn set_fund(&mut self, fund: WithdrawableAmountDetail) {
         self.fund = Some(fund);
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WithdrawableAmountDetail {
     coin: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    withdrawable_amount: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    available_balance: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    withdrawable_amount: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    available_balance: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl WithdrawableAmountDetail {
@@ -163,19 +212,27 @@ impl WithdrawableAmountDetail {
         self.coin = coin;
     }
 
-    pub fn withdrawable_amount(&self) -> f64 {
+    pub fn withdrawable_amount(&self) -> Number {
         self.withdrawable_amount
     }
 
-    pub fn set_withdrawable_amount(&mut self, withdrawable_amount: f64) {
+    pub fn set_withdrawable_amount(&mut self, withdrawable_amount: Number) {
         self.withdrawable_amount = withdrawable_amount;
     }
 
-    pub fn available_balance(&self) -> f64 {
+    pub fn available_balance(&self) -> Number {
         self.available_balance
     }
 
-    pub fn set_available_balance(&mut self, available_balance: f64) {
+    pub fn set_available_balance(&mut self, available_balance: Number) {
         self.available_balance = available_balance;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,11 +1,21 @@
+use chrono::{
+    DateTime,
+    Duration,
+    Utc,
+};
 use crate::{
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::Number,
     utils::{
-        deserialize_f64,
-        deserialize_option_f64,
+        serialize_as_string,
+        serialize_option_as_string,
+        datetime_to_millis,
+        millis_to_datetime,
+        deserialize_number,
+        deserialize_option_number,
         deserialize_string_to_u64,
     },
 };
@@ -14,15 +24,77 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
+use crate::v5::api::pagination::{
+    paginate,
+    paginate_time_range,
+    TimeRange,
+};
+use futures_util::{
+    FutureExt,
+    stream::BoxStream,
+};
 
-const PATH: &'static str = "/v5/asset/withdraw/query-record";
+const PATH: &str = "/v5/asset/withdraw/query-record";
+// startTime と endTime の間隔の上限
+const MAX_TIME_RANGE_DAYS: i64 = 30;
 
 impl BybitApi {
-    pub async fn get_withdrawal_records(&self, params: GetWithdrawalRecordsParameters) -> Result<GetWithdrawalRecordsResponse> {
+    pub async fn get_withdrawal_records(&self, params: GetWithdrawalRecordsParameters) -> Result<GetWithdrawalRecordsResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
+
+    /// Gets all withdrawal records, following `next_page_cursor` until the last page.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters of the first request.
+    /// * `max_items` - The maximum number of withdrawal records to yield, or `None` for all of them.
+    ///
+    /// # Returns
+    ///
+    /// A stream of withdrawal records. A failed request is yielded as an error and ends the stream.
+    pub fn get_withdrawal_records_stream(&self, params: GetWithdrawalRecordsParameters, max_items: Option<usize>) -> BoxStream<'_, Result<WithdrawalRecord, BybitError>> {
+        paginate(params, max_items, GetWithdrawalRecordsParameters::with_cursor, move |params| {
+            async move {
+                let result = self.get_withdrawal_records(params).await?.result;
+                Ok((result.rows, result.next_page_cursor))
+            }.boxed()
+        })
+    }
+
+    /// Gets the withdrawal records between `from` and `to`, split into the 30 day windows Bybit accepts.
+    ///
+    /// Each window is paged through with `next_page_cursor`. Withdrawal records returned by two windows are yielded once.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The filters of every request. `start_time`, `end_time` and `cursor` are overwritten.
+    /// * `from` - The start of the range, inclusive.
+    /// * `to` - The end of the range, exclusive.
+    ///
+    /// # Returns
+    ///
+    /// A stream of withdrawal records, oldest first. A failed request is yielded as an error and ends the stream.
+    pub fn get_withdrawal_records_range_stream(&self, params: GetWithdrawalRecordsParameters, from: DateTime<Utc>, to: DateTime<Utc>) -> BoxStream<'_, Result<WithdrawalRecord, BybitError>> {
+        let range: TimeRange<GetWithdrawalRecordsParameters, WithdrawalRecord> = TimeRange {
+            with_window: |params, start, end| params.with_start_time(start).with_end_time(end),
+            with_cursor: GetWithdrawalRecordsParameters::with_cursor,
+            time: WithdrawalRecord::create_time,
+            key: |record| record.withdraw_id.clone(),
+        };
+        paginate_time_range(params, from, to, Duration::days(MAX_TIME_RANGE_DAYS), range, move |params| {
+            async move {
+                let result = self.get_withdrawal_records(params).await?.result;
+                Ok((result.rows, result.next_page_cursor))
+            }.boxed()
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -38,6 +110,12 @@ pub struct GetWithdrawalRecordsParameters {
     cursor: Option<String>,
 }
 
+impl Default for GetWithdrawalRecordsParameters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl GetWithdrawalRecordsParameters {
     pub fn new() -> Self {
         Self {
@@ -77,11 +155,21 @@ impl GetWithdrawalRecordsParameters {
         self
     }
 
+    pub fn with_start_datetime(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(datetime_to_millis(start_time));
+        self
+    }
+
     pub fn with_end_time(mut self, end_time: u64) -> Self {
         self.end_time = Some(end_time);
         self
     }
 
+    pub fn with_end_datetime(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(datetime_to_millis(end_time));
+        self
+    }
+
     pub fn with_limit(mut self, limit: i32) -> Self {
         self.limit = Some(limit);
         self
@@ -158,7 +246,7 @@ impl GetWithdrawalRecordsParameters {
 
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetWithdrawalRecordsResponse {
     ret_code: i32,
@@ -166,6 +254,8 @@ pub struct GetWithdrawalRecordsResponse {
     result: WithdrawalRecordsResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetWithdrawalRecordsResponse {
     pub fn ret_code(&self) -> i32 {
@@ -207,13 +297,27 @@ impl GetWithdrawalRecordsResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WithdrawalRecordsResult {
     rows: Vec<WithdrawalRecord>,
     next_page_cursor: Option<String>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl WithdrawalRecordsResult {
     pub fn rows(&self) -> &Vec<WithdrawalRecord> {
@@ -231,27 +335,37 @@ impl WithdrawalRecordsResult {
     pub fn set_next_page_cursor(&mut self, next_page_cursor: Option<String>) {
         self.next_page_cursor = next_page_cursor;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WithdrawalRecord {
     coin: String,
     chain: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    amount: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    amount: Number,
     tx_id: String,
     status: i64,
     to_address: String,
     tag: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    withdraw_fee: Option<f64>,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    withdraw_fee: Option<Number>,
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     create_time: u64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     update_time: u64,
     withdraw_id: String,
     withdraw_type: i32,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl WithdrawalRecord {
@@ -271,11 +385,11 @@ impl WithdrawalRecord {
         self.chain = chain;
     }
 
-    pub fn amount(&self) -> f64 {
+    pub fn amount(&self) -> Number {
         self.amount
     }
 
-    pub fn set_amount(&mut self, amount: f64) {
+    pub fn set_amount(&mut self, amount: Number) {
         self.amount = amount;
     }
 
@@ -311,11 +425,11 @@ impl WithdrawalRecord {
         self.tag = tag;
     }
 
-    pub fn withdraw_fee(&self) -> &Option<f64> {
+    pub fn withdraw_fee(&self) -> &Option<Number> {
         &self.withdraw_fee
     }
 
-    pub fn set_withdraw_fee(&mut self, withdraw_fee: Option<f64>) {
+    pub fn set_withdraw_fee(&mut self, withdraw_fee: Option<Number>) {
         self.withdraw_fee = withdraw_fee;
     }
 
@@ -327,6 +441,10 @@ impl WithdrawalRecord {
         self.create_time = create_time;
     }
 
+    pub fn create_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.create_time)
+    }
+
     pub fn update_time(&self) -> u64 {
         self.update_time
     }
@@ -335,6 +453,10 @@ impl WithdrawalRecord {
         self.update_time = update_time;
     }
 
+    pub fn update_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.update_time)
+    }
+
     pub fn withdraw_id(&self) -> &str {
         &self.withdraw_id
     }
@@ -351,4 +473,12 @@ impl WithdrawalRecord {
         self.withdraw_type = withdraw_type;
     }
 
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
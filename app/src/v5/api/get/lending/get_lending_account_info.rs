@@ -1,22 +1,35 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
     v5::api::{
         BybitApi,
         get::Get,
     },
-    utils::deserialize_f64,
+    v5::types::Number,
+    utils::{
+        serialize_as_string,
+        deserialize_number,
+        millis_to_datetime,
+    },
 };
 
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/lending/account";
+const PATH: &str = "/v5/lending/account";
 
 impl BybitApi {
-    pub async fn get_lending_account_info(&self, params: GetLendingAccountInfoParameters) -> Result<GetLendingAccountInfoResponse> {
+    pub async fn get_lending_account_info(&self, params: GetLendingAccountInfoParameters) -> Result<GetLendingAccountInfoResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
 }
@@ -35,7 +48,7 @@ impl GetLendingAccountInfoParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetLendingAccountInfoResponse {
     ret_code: i32,
@@ -43,6 +56,8 @@ pub struct GetLendingAccountInfoResponse {
     result: LendingAccountInfoResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetLendingAccountInfoResponse {
     pub fn ret_code(&self) -> i32 {
@@ -84,20 +99,34 @@ impl GetLendingAccountInfoResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LendingAccountInfoResult {
     coin: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    principal_interest: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    principal_qty: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    principal_total: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    quantity: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    principal_interest: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    principal_qty: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    principal_total: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    quantity: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl LendingAccountInfoResult {
     pub fn coin(&self) -> &str {
@@ -108,35 +137,43 @@ impl LendingAccountInfoResult {
         self.coin = coin;
     }
 
-    pub fn principal_interest(&self) -> f64 {
+    pub fn principal_interest(&self) -> Number {
         self.principal_interest
     }
 
-    pub fn set_principal_interest(&mut self, principal_interest: f64) {
+    pub fn set_principal_interest(&mut self, principal_interest: Number) {
         self.principal_interest = principal_interest;
     }
 
-    pub fn principal_qty(&self) -> f64 {
+    pub fn principal_qty(&self) -> Number {
         self.principal_qty
     }
 
-    pub fn set_principal_qty(&mut self, principal_qty: f64) {
+    pub fn set_principal_qty(&mut self, principal_qty: Number) {
         self.principal_qty = principal_qty;
     }
 
-    pub fn principal_total(&self) -> f64 {
+    pub fn principal_total(&self) -> Number {
         self.principal_total
     }
 
-    pub fn set_principal_total(&mut self, principal_total: f64) {
+    pub fn set_principal_total(&mut self, principal_total: Number) {
         self.principal_total = principal_total;
     }
 
-    pub fn quantity(&self) -> f64 {
+    pub fn quantity(&self) -> Number {
         self.quantity
     }
 
-    pub fn set_quantity(&mut self, quantity: f64) {
+    pub fn set_quantity(&mut self, quantity: Number) {
         self.quantity = quantity;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
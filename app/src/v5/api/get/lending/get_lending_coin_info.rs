@@ -1,22 +1,35 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
     v5::api::{
         BybitApi,
         get::Get,
     },
-    utils::deserialize_f64,
+    v5::types::Number,
+    utils::{
+        serialize_as_string,
+        deserialize_number,
+        millis_to_datetime,
+    },
 };
 
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/lending/info";
+const PATH: &str = "/v5/lending/info";
 
 impl BybitApi {
-    pub async fn get_lending_coin_info(&self, params: GetLendingCoinInfoParameters) -> Result<GetLendingCoinInfoResponse> {
+    pub async fn get_lending_coin_info(&self, params: GetLendingCoinInfoParameters) -> Result<GetLendingCoinInfoResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
 }
@@ -27,6 +40,12 @@ pub struct GetLendingCoinInfoParameters {
     coin: Option<String>,
 }
 
+impl Default for GetLendingCoinInfoParameters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl GetLendingCoinInfoParameters {
     pub fn new() -> Self {
         Self {
@@ -40,7 +59,7 @@ impl GetLendingCoinInfoParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetLendingCoinInfoResponse {
     ret_code: i32,
@@ -48,6 +67,8 @@ pub struct GetLendingCoinInfoResponse {
     result: LendingCoinInfoResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetLendingCoinInfoResponse {
     pub fn ret_code(&self) -> i32 {
@@ -89,12 +110,26 @@ impl GetLendingCoinInfoResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LendingCoinInfoResult {
-    list: Vec<LendingCoinInfo>
+    list: Vec<LendingCoinInfo>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl LendingCoinInfoResult {
     pub fn list(&self) -> &Vec<LendingCoinInfo> {
@@ -104,32 +139,42 @@ impl LendingCoinInfoResult {
     pub fn set_list(&mut self, list: Vec<LendingCoinInfo>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LendingCoinInfo {
-    #[serde(deserialize_with = "deserialize_f64")]
-    actual_apy: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    actual_apy: Number,
     coin: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    loan_to_pool_ratio: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    max_redeem_qty: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    min_purchase_qty: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    precision: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    rate: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    loan_to_pool_ratio: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    max_redeem_qty: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    min_purchase_qty: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    precision: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    rate: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl LendingCoinInfo {
-    pub fn actual_apy(&self) -> f64 {
+    pub fn actual_apy(&self) -> Number {
         self.actual_apy
     }
 
-    pub fn set_actual_apy(&mut self, actual_apy: f64) {
+    pub fn set_actual_apy(&mut self, actual_apy: Number) {
         self.actual_apy = actual_apy;
     }
 
@@ -141,43 +186,51 @@ impl LendingCoinInfo {
         self.coin = coin;
     }
 
-    pub fn loan_to_pool_ratio(&self) -> f64 {
+    pub fn loan_to_pool_ratio(&self) -> Number {
         self.loan_to_pool_ratio
     }
 
-    pub fn set_loan_to_pool_ratio(&mut self, loan_to_pool_ratio: f64) {
+    pub fn set_loan_to_pool_ratio(&mut self, loan_to_pool_ratio: Number) {
         self.loan_to_pool_ratio = loan_to_pool_ratio;
     }
 
-    pub fn max_redeem_qty(&self) -> f64 {
+    pub fn max_redeem_qty(&self) -> Number {
         self.max_redeem_qty
     }
 
-    pub fn set_max_redeem_qty(&mut self, max_redeem_qty: f64) {
+    pub fn set_max_redeem_qty(&mut self, max_redeem_qty: Number) {
         self.max_redeem_qty = max_redeem_qty;
     }
 
-    pub fn min_purchase_qty(&self) -> f64 {
+    pub fn min_purchase_qty(&self) -> Number {
         self.min_purchase_qty
     }
 
-    pub fn set_min_purchase_qty(&mut self, min_purchase_qty: f64) {
+    pub fn set_min_purchase_qty(&mut self, min_purchase_qty: Number) {
         self.min_purchase_qty = min_purchase_qty;
     }
 
-    pub fn precision(&self) -> f64 {
+    pub fn precision(&self) -> Number {
         self.precision
     }
 
-    pub fn set_precision(&mut self, precision: f64) {
+    pub fn set_precision(&mut self, precision: Number) {
         self.precision = precision;
     }
 
-    pub fn rate(&self) -> f64 {
+    pub fn rate(&self) -> Number {
         self.rate
     }
 
-    pub fn set_rate(&mut self, rate: f64) {
+    pub fn set_rate(&mut self, rate: Number) {
         self.rate = rate;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
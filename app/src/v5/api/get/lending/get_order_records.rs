@@ -1,10 +1,18 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::Number,
     utils::{
-        deserialize_f64,
+        serialize_as_string,
+        datetime_to_millis,
+        millis_to_datetime,
+        deserialize_number,
         deserialize_string_to_u64,
     },
 };
@@ -13,13 +21,17 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/lending/history-order";
+const PATH: &str = "/v5/lending/history-order";
 
 impl BybitApi {
-    pub async fn get_order_records(&self, params: GetOrderRecordsParameters) -> Result<GetOrderRecordsResponse> {
+    pub async fn get_order_records(&self, params: GetOrderRecordsParameters) -> Result<GetOrderRecordsResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
 }
@@ -35,6 +47,12 @@ pub struct GetOrderRecordsParameters {
     order_type: Option<String>,
 }
 
+impl Default for GetOrderRecordsParameters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl GetOrderRecordsParameters {
     pub fn new() -> Self {
         Self {
@@ -62,11 +80,21 @@ impl GetOrderRecordsParameters {
         self
     }
 
+    pub fn with_start_datetime(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(datetime_to_millis(start_time));
+        self
+    }
+
     pub fn with_end_time(mut self, end_time: u64) -> Self {
         self.end_time = Some(end_time);
         self
     }
 
+    pub fn with_end_datetime(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(datetime_to_millis(end_time));
+        self
+    }
+
     pub fn with_limit(mut self, limit: i32) -> Self {
         self.limit = Some(limit);
         self
@@ -78,7 +106,7 @@ impl GetOrderRecordsParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetOrderRecordsResponse {
     ret_code: i32,
@@ -86,6 +114,8 @@ pub struct GetOrderRecordsResponse {
     result: OrderRecordsResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetOrderRecordsResponse {
     pub fn ret_code(&self) -> i32 {
@@ -127,12 +157,26 @@ impl GetOrderRecordsResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderRecordsResult {
-    list: Vec<OrderRecord>
+    list: Vec<OrderRecord>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl OrderRecordsResult {
     pub fn list(&self) -> &Vec<OrderRecord> {
@@ -142,22 +186,32 @@ impl OrderRecordsResult {
     pub fn set_list(&mut self, list: Vec<OrderRecord>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderRecord {
     coin: String,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     created_time: u64,
     order_id: String,
     order_type: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    quantity: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    quantity: Number,
     serial_no: String,
     status: String,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     updated_time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl OrderRecord {
@@ -177,6 +231,10 @@ impl OrderRecord {
         self.created_time = created_time;
     }
 
+    pub fn created_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.created_time)
+    }
+
     pub fn order_id(&self) -> &str {
         &self.order_id
     }
@@ -193,11 +251,11 @@ impl OrderRecord {
         self.order_type = order_type;
     }
 
-    pub fn quantity(&self) -> f64 {
+    pub fn quantity(&self) -> Number {
         self.quantity
     }
 
-    pub fn set_quantity(&mut self, quantity: f64) {
+    pub fn set_quantity(&mut self, quantity: Number) {
         self.quantity = quantity;
     }
 
@@ -224,4 +282,16 @@ impl OrderRecord {
     pub fn set_updated_time(&mut self, updated_time: u64) {
         self.updated_time = updated_time;
     }
+
+    pub fn updated_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.updated_time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
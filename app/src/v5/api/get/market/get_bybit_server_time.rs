@@ -1,15 +1,35 @@
+use chrono::{
+    DateTime,
+    TimeZone,
+    Utc,
+};
 use crate::{
     v5::api::{
         BybitApi,
         get::Get,
+        request::{
+            BybitApiRequest,
+            RequestType,
+        },
+    },
+    utils::{
+        serialize_as_string,
+        deserialize_string_to_u64,
+        millis_to_datetime,
     },
-    utils::deserialize_string_to_u64,
 };
-use serde::Deserialize;
-use serde_json::Value;
-use anyhow::Result;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/market/time";
+const PATH: &str = "/v5/market/time";
 
 impl BybitApi {
     /// Retrieves the server time from the Bybit API.
@@ -36,12 +56,18 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_bybit_server_time(&self) -> Result<GetBybitServerTimeResponse> {
+    pub async fn get_bybit_server_time(&self) -> Result<GetBybitServerTimeResponse, BybitError> {
         self.get(PATH, None::<()>, false).await
     }
+
+    // 往復時間を測るために使うので、再試行の待ち時間を含めないよう 1 回だけ送る
+    pub(crate) async fn get_bybit_server_time_once(&self) -> Result<GetBybitServerTimeResponse, BybitError> {
+        let body = self.send_api_request(PATH, None::<&()>, false, RequestType::Get).await?;
+        self.deserialize_response(PATH, &body)
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetBybitServerTimeResponse {
     ret_code: i32,
@@ -49,6 +75,8 @@ pub struct GetBybitServerTimeResponse {
     result: BybitServerTimeResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 
@@ -92,15 +120,29 @@ impl GetBybitServerTimeResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BybitServerTimeResult {
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     time_second: u64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     time_nano: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl BybitServerTimeResult {
     pub fn time_second(&self) -> u64 {
@@ -111,6 +153,11 @@ impl BybitServerTimeResult {
         self.time_second = time_second;
     }
 
+    // timeSecond は他のフィールドと違い秒単位
+    pub fn time_second_datetime(&self) -> DateTime<Utc> {
+        Utc.timestamp_opt(self.time_second as i64, 0).single().unwrap_or_default()
+    }
+
     pub fn time_nano(&self) -> u64 {
         self.time_nano
     }
@@ -118,4 +165,16 @@ impl BybitServerTimeResult {
     pub fn set_time_nano(&mut self, time_nano: u64) {
         self.time_nano = time_nano;
     }
+
+    pub fn time_nano_datetime(&self) -> DateTime<Utc> {
+        Utc.timestamp_nanos(self.time_nano as i64)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
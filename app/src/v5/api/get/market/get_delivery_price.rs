@@ -1,10 +1,21 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        OptionOnly,
+    },
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::Number,
     utils::{
-        deserialize_f64,
+        serialize_as_string,
+        millis_to_datetime,
+        deserialize_number,
         deserialize_string_to_u64,
     },
 };
@@ -13,10 +24,14 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/market/delivery-price";
+const PATH: &str = "/v5/market/delivery-price";
 
 impl BybitApi {
     /// Retrieves the delivery price based on the provided parameters.
@@ -50,16 +65,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_delivery_price(&self, params: GetDeliveryPriceParameters) -> Result<GetDeliveryPriceResponse> {
+    pub async fn get_delivery_price(&self, params: GetDeliveryPriceParameters) -> Result<GetDeliveryPriceResponse, BybitError> {
         self.get(PATH, Some(params), false).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetDeliveryPriceCategory {
-    Option,
-}
+pub type GetDeliveryPriceCategory = Category<OptionOnly>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -148,7 +159,7 @@ impl GetDeliveryPriceParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetDeliveryPriceResponse {
     ret_code: i32,
@@ -156,6 +167,8 @@ pub struct GetDeliveryPriceResponse {
     result: DeliveryPriceResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetDeliveryPriceResponse {
     pub fn ret_code(&self) -> i32 {
@@ -197,14 +210,28 @@ impl GetDeliveryPriceResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DeliveryPriceResult {
     category: String,
     next_page_cursor: String,
-    list: Vec<DeliveryPrice>
+    list: Vec<DeliveryPrice>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl DeliveryPriceResult {
     pub fn category(&self) -> &str {
@@ -230,16 +257,26 @@ impl DeliveryPriceResult {
     pub fn set_list(&mut self, list: Vec<DeliveryPrice>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DeliveryPrice {
     symbol: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    delivery_price: f64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    delivery_price: Number,
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     delivery_time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl DeliveryPrice {
     pub fn symbol(&self) -> &str {
@@ -250,11 +287,11 @@ impl DeliveryPrice {
         self.symbol = symbol;
     }
 
-    pub fn delivery_price(&self) -> f64 {
+    pub fn delivery_price(&self) -> Number {
         self.delivery_price
     }
 
-    pub fn set_delivery_price(&mut self, delivery_price: f64) {
+    pub fn set_delivery_price(&mut self, delivery_price: Number) {
         self.delivery_price = delivery_price;
     }
 
@@ -265,4 +302,16 @@ impl DeliveryPrice {
     pub fn set_delivery_time(&mut self, delivery_time: u64) {
         self.delivery_time = delivery_time;
     }
+
+    pub fn delivery_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.delivery_time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,10 +1,22 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        LinearInverse,
+    },
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::Number,
     utils::{
-        deserialize_f64,
+        serialize_as_string,
+        datetime_to_millis,
+        millis_to_datetime,
+        deserialize_number,
         deserialize_string_to_u64,
     },
 };
@@ -13,10 +25,14 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/market/funding/history";
+const PATH: &str = "/v5/market/funding/history";
 
 impl BybitApi {
     /// Retrieves the funding rate history from the Bybit API.
@@ -50,17 +66,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_funding_rate_history(&self, params: GetFundingRateHistoryParameters) -> Result<GetFundingRateHistoryResponse> {
+    pub async fn get_funding_rate_history(&self, params: GetFundingRateHistoryParameters) -> Result<GetFundingRateHistoryResponse, BybitError> {
         self.get(PATH, Some(params), false).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetFundingRateHistoryCategory {
-    Linear,
-    Inverse,
-}
+pub type GetFundingRateHistoryCategory = Category<LinearInverse>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -107,6 +118,20 @@ impl GetFundingRateHistoryParameters {
         self
     }
 
+    /// Sets the start time for the funding rate history.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_time` - The start time as a UTC date and time.
+    ///
+    /// # Returns
+    ///
+    /// The updated `GetFundingRateHistoryParameters` instance.
+    pub fn with_start_datetime(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(datetime_to_millis(start_time));
+        self
+    }
+
     /// Sets the end time for the funding rate history.
     ///
     /// # Arguments
@@ -121,6 +146,20 @@ impl GetFundingRateHistoryParameters {
         self
     }
 
+    /// Sets the end time for the funding rate history.
+    ///
+    /// # Arguments
+    ///
+    /// * `end_time` - The end time as a UTC date and time.
+    ///
+    /// # Returns
+    ///
+    /// The updated `GetFundingRateHistoryParameters` instance.
+    pub fn with_end_datetime(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(datetime_to_millis(end_time));
+        self
+    }
+
     /// Sets the limit for the number of funding rate history records to retrieve.
     ///
     /// # Arguments
@@ -136,7 +175,7 @@ impl GetFundingRateHistoryParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetFundingRateHistoryResponse {
     ret_code: i32,
@@ -144,6 +183,8 @@ pub struct GetFundingRateHistoryResponse {
     result: FundingRateHistoryResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetFundingRateHistoryResponse {
     pub fn ret_code(&self) -> i32 {
@@ -185,13 +226,27 @@ impl GetFundingRateHistoryResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FundingRateHistoryResult {
     category: String,
-    list: Vec<FundingRateHistory>
+    list: Vec<FundingRateHistory>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl FundingRateHistoryResult {
     pub fn category(&self) -> &str {
@@ -209,16 +264,26 @@ impl FundingRateHistoryResult {
     pub fn set_list(&mut self, list: Vec<FundingRateHistory>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FundingRateHistory {
     symbol: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    funding_rate: f64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    funding_rate: Number,
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     funding_rate_timestamp: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl FundingRateHistory {
     pub fn symbol(&self) -> &String {
@@ -229,11 +294,11 @@ impl FundingRateHistory {
         self.symbol = symbol;
     }
 
-    pub fn funding_rate(&self) -> f64 {
+    pub fn funding_rate(&self) -> Number {
         self.funding_rate
     }
 
-    pub fn set_funding_rate(&mut self, funding_rate: f64) {
+    pub fn set_funding_rate(&mut self, funding_rate: Number) {
         self.funding_rate = funding_rate;
     }
 
@@ -244,4 +309,16 @@ impl FundingRateHistory {
     pub fn set_funding_rate_timestamp(&mut self, funding_rate_timestamp: u64) {
         self.funding_rate_timestamp = funding_rate_timestamp;
     }
+
+    pub fn funding_rate_timestamp_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.funding_rate_timestamp)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
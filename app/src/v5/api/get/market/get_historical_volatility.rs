@@ -1,10 +1,22 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        OptionOnly,
+    },
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::Number,
     utils::{
-        deserialize_f64,
+        serialize_as_string,
+        datetime_to_millis,
+        millis_to_datetime,
+        deserialize_number,
         deserialize_string_to_u64,
     },
 };
@@ -13,9 +25,14 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/market/historical-volatility";
+const PATH: &str = "/v5/market/historical-volatility";
 
 impl BybitApi {
     /// Retrieves historical volatility data from the API.
@@ -57,16 +74,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_historical_volatility(&self, params: GetHistoricalVolatilityParameters) -> Result<GetHistoricalVolatilityResponse> {
+    pub async fn get_historical_volatility(&self, params: GetHistoricalVolatilityParameters) -> Result<GetHistoricalVolatilityResponse, BybitError> {
         self.get(PATH, Some(params), false).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetHistoricalVolatilityCategory {
-    Option,
-}
+pub type GetHistoricalVolatilityCategory = Category<OptionOnly>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -140,6 +153,20 @@ impl GetHistoricalVolatilityParameters {
         self
     }
 
+    /// Sets the start time for the historical volatility parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_time` - The start time as a UTC date and time.
+    ///
+    /// # Returns
+    ///
+    /// The updated `GetHistoricalVolatilityParameters` instance.
+    pub fn with_start_datetime(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(datetime_to_millis(start_time));
+        self
+    }
+
     /// Sets the end time for the historical volatility parameters.
     ///
     /// # Arguments
@@ -153,15 +180,31 @@ impl GetHistoricalVolatilityParameters {
         self.end_time = Some(end_time);
         self
     }
+
+    /// Sets the end time for the historical volatility parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `end_time` - The end time as a UTC date and time.
+    ///
+    /// # Returns
+    ///
+    /// The updated `GetHistoricalVolatilityParameters` instance.
+    pub fn with_end_datetime(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(datetime_to_millis(end_time));
+        self
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetHistoricalVolatilityResponse {
     ret_code: i32,
     ret_msg: String,
     category: String,
     result: Vec<HistoricalVolatility>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetHistoricalVolatilityResponse {
     pub fn ret_code(&self) -> i32 {
@@ -196,16 +239,26 @@ impl GetHistoricalVolatilityResponse {
         self.result = result;
     }
 
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct HistoricalVolatility {
     period: u64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    value: f64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    value: Number,
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl HistoricalVolatility {
     pub fn period(&self) -> u64 {
@@ -216,11 +269,11 @@ impl HistoricalVolatility {
         self.period = period;
     }
 
-    pub fn value(&self) -> f64 {
+    pub fn value(&self) -> Number {
         self.value
     }
 
-    pub fn set_value(&mut self, value: f64) {
+    pub fn set_value(&mut self, value: Number) {
         self.value = value;
     }
 
@@ -231,4 +284,16 @@ impl HistoricalVolatility {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
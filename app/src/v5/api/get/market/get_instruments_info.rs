@@ -1,18 +1,42 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use crate::v5::category::Category;
 pub mod inverse;
 pub mod linear;
 pub mod option;
 pub mod spot;
 
 use self::{
-    inverse::InverseInstrumentsInfoResult,
-    linear::LinearInstrumentsInfoResult,
-    spot::SpotInstrumentsInfoResult,
-    option::OptionInstrumentsInfoResult,
+    inverse::{
+        InverseInstrumentInfo,
+        InverseInstrumentsInfoResult,
+    },
+    linear::{
+        LinearInstrumentInfo,
+        LinearInstrumentsInfoResult,
+    },
+    spot::{
+        SpotInstrumentInfo,
+        SpotInstrumentsInfoResult,
+    },
+    option::{
+        OptionInstrumentInfo,
+        OptionInstrumentsInfoResult,
+    },
 };
 use crate::{
+    utils::{
+        millis_to_datetime,
+    },
     v5::api::{
         BybitApi,
         get::Get,
+        pagination::{
+            paginate,
+            Page,
+        },
     },
     constants::{
         CATEGORY_INVERSE,
@@ -21,14 +45,22 @@ use crate::{
         CATEGORY_SPOT,
     }
 };
-use anyhow::Result;
+use crate::error::BybitError;
 use serde::{
     Deserialize,
     Serialize,
 };
-use serde_json::Value;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use futures_util::{
+    FutureExt,
+    stream::BoxStream,
+};
 
-const PATH: &'static str = "/v5/market/instruments-info";
+const PATH: &str = "/v5/market/instruments-info";
 
 impl BybitApi {
     /// Retrieves information about instruments.
@@ -69,20 +101,60 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_instruments_info(&self, params: GetInstrumentsInfoParameters) -> Result<GetInstrumentsInfoResponse> {
+    pub async fn get_instruments_info(&self, params: GetInstrumentsInfoParameters) -> Result<GetInstrumentsInfoResponse, BybitError> {
         self.get(PATH, Some(params), false).await
     }
-}
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetInstrumentsInfoCategory {
-    Linear,
-    Option,
-    Spot,
-    Inverse,
+    /// Gets all instruments, following `next_page_cursor` until the last page.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters of the first request.
+    /// * `max_items` - The maximum number of instruments to yield, or `None` for all of them.
+    ///
+    /// # Returns
+    ///
+    /// A stream of instruments. A failed request is yielded as an error and ends the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rsbit::v5::api::{
+    ///     get::market::get_instruments_info::{
+    ///         GetInstrumentsInfoParameters,
+    ///         GetInstrumentsInfoCategory,
+    ///     },
+    ///     BybitApi,
+    /// };
+    /// use futures_util::StreamExt;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = BybitApi::new();
+    ///     let params = GetInstrumentsInfoParameters::new(GetInstrumentsInfoCategory::Option).with_limit(1000);
+    ///     let mut stream = api.get_instruments_info_stream(params, None);
+    ///     while let Some(item) = stream.next().await {
+    ///         match item {
+    ///             Ok(info) => {
+    ///                 // Handle the instrument
+    ///             },
+    ///             Err(err) => {
+    ///                 // Handle the error
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn get_instruments_info_stream(&self, params: GetInstrumentsInfoParameters, max_items: Option<usize>) -> BoxStream<'_, Result<InstrumentInfo, BybitError>> {
+        paginate(params, max_items, GetInstrumentsInfoParameters::with_cursor, move |params| {
+            async move {
+                Ok(self.get_instruments_info(params).await?.result.into_page())
+            }.boxed()
+        })
+    }
 }
 
+pub type GetInstrumentsInfoCategory = Category;
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetInstrumentsInfoParameters {
@@ -186,7 +258,7 @@ impl GetInstrumentsInfoParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetInstrumentsInfoResponse {
     ret_code: i32,
@@ -194,6 +266,8 @@ pub struct GetInstrumentsInfoResponse {
     result: InstrumentsInfoResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl GetInstrumentsInfoResponse {
@@ -236,9 +310,21 @@ impl GetInstrumentsInfoResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(tag = "category", rename_all = "camelCase")]
 pub enum InstrumentsInfoResult {
     Linear(LinearInstrumentsInfoResult),
@@ -284,5 +370,74 @@ impl InstrumentsInfoResult {
             InstrumentsInfoResult::Spot(_) => CATEGORY_SPOT,
         }
     }
+
+    pub fn next_page_cursor(&self) -> Option<&str> {
+        match self {
+            InstrumentsInfoResult::Linear(data) => Some(data.next_page_cursor()),
+            InstrumentsInfoResult::Option(data) => Some(data.next_page_cursor()),
+            InstrumentsInfoResult::Inverse(data) => Some(data.next_page_cursor()),
+            InstrumentsInfoResult::Spot(data) => data.next_page_cursor().as_deref(),
+        }
+    }
+
+    fn into_page(self) -> Page<InstrumentInfo> {
+        let cursor = self.next_page_cursor().map(str::to_string);
+        let list = match self {
+            InstrumentsInfoResult::Linear(data) => data.list().iter().cloned().map(InstrumentInfo::Linear).collect(),
+            InstrumentsInfoResult::Option(data) => data.list().iter().cloned().map(InstrumentInfo::Option).collect(),
+            InstrumentsInfoResult::Inverse(data) => data.list().iter().cloned().map(InstrumentInfo::Inverse).collect(),
+            InstrumentsInfoResult::Spot(data) => data.list().iter().cloned().map(InstrumentInfo::Spot).collect(),
+        };
+        (list, cursor)
+    }
+}
+
+/// One instrument of any category, as yielded by `get_instruments_info_stream`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum InstrumentInfo {
+    Linear(LinearInstrumentInfo),
+    Option(OptionInstrumentInfo),
+    Spot(SpotInstrumentInfo),
+    Inverse(InverseInstrumentInfo),
+}
+
+impl InstrumentInfo {
+    pub fn linear(&self) -> Option<&LinearInstrumentInfo> {
+        match self {
+            InstrumentInfo::Linear(info) => Some(info),
+            _ => None,
+        }
+    }
+
+    pub fn option(&self) -> Option<&OptionInstrumentInfo> {
+        match self {
+            InstrumentInfo::Option(info) => Some(info),
+            _ => None,
+        }
+    }
+
+    pub fn inverse(&self) -> Option<&InverseInstrumentInfo> {
+        match self {
+            InstrumentInfo::Inverse(info) => Some(info),
+            _ => None,
+        }
+    }
+
+    pub fn spot(&self) -> Option<&SpotInstrumentInfo> {
+        match self {
+            InstrumentInfo::Spot(info) => Some(info),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(&self) -> &str {
+        match self {
+            InstrumentInfo::Linear(info) => info.symbol(),
+            InstrumentInfo::Option(info) => info.symbol(),
+            InstrumentInfo::Inverse(info) => info.symbol(),
+            InstrumentInfo::Spot(info) => info.symbol(),
+        }
+    }
 }
 
@@ -1,14 +1,36 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use crate::utils::{
+    serialize_as_string,
+    str_millis_to_datetime,
+};
 use serde::{
     Deserialize,
     Serialize,
 };
-use crate::utils::deserialize_f64;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::utils::deserialize_number;
+use crate::v5::types::Number;
+use crate::v5::symbol::{
+    dated_futures,
+    next_future,
+    ParseSymbolError,
+    Symbol,
+};
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InverseInstrumentsInfoResult {
     list: Vec<InverseInstrumentInfo>,
     next_page_cursor: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl InverseInstrumentsInfoResult {
     pub fn list(&self) -> &Vec<InverseInstrumentInfo> {
@@ -26,9 +48,35 @@ impl InverseInstrumentsInfoResult {
     pub fn set_next_page_cursor(&mut self, next_page_cursor: String) {
         self.next_page_cursor = next_page_cursor;
     }
+
+    /// Gets the dated futures in the list, earliest expiry first. Perpetuals are left out.
+    pub fn dated_futures(&self) -> Vec<(Symbol, &InverseInstrumentInfo)> {
+        dated_futures(self.list.iter().map(|info| (info.symbol.as_str(), info)))
+    }
+
+    /// Finds the future to roll a position into.
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - The symbol held now. For a perpetual, the earliest future on the same coins is returned.
+    ///
+    /// # Returns
+    ///
+    /// The first future on the same base and quote coins expiring after `current`, if listed.
+    pub fn next_future(&self, current: &Symbol) -> Option<(Symbol, &InverseInstrumentInfo)> {
+        next_future(self.list.iter().map(|info| (info.symbol.as_str(), info)), current)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InverseInstrumentInfo {
     symbol: String,
@@ -46,6 +94,8 @@ pub struct InverseInstrumentInfo {
     unified_margin_trade: bool,
     funding_interval: i32,
     settle_coin: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl InverseInstrumentInfo {
     pub fn symbol(&self) -> &String {
@@ -56,6 +106,10 @@ impl InverseInstrumentInfo {
         self.symbol = symbol;
     }
 
+    pub fn parsed_symbol(&self) -> Result<Symbol, ParseSymbolError> {
+        self.symbol.parse()
+    }
+
     pub fn contract_type(&self) -> &String {
         &self.contract_type
     }
@@ -96,6 +150,10 @@ impl InverseInstrumentInfo {
         self.launch_time = launch_time;
     }
 
+    pub fn launch_time_datetime(&self) -> Option<DateTime<Utc>> {
+        str_millis_to_datetime(&self.launch_time)
+    }
+
     pub fn delivery_time(&self) -> &String {
         &self.delivery_time
     }
@@ -104,6 +162,10 @@ impl InverseInstrumentInfo {
         self.delivery_time = delivery_time;
     }
 
+    pub fn delivery_time_datetime(&self) -> Option<DateTime<Utc>> {
+        str_millis_to_datetime(&self.delivery_time)
+    }
+
     pub fn delivery_fee_rate(&self) -> &String {
         &self.delivery_fee_rate
     }
@@ -167,125 +229,163 @@ impl InverseInstrumentInfo {
     pub fn set_settle_coin(&mut self, settle_coin: String) {
         self.settle_coin = settle_coin;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InverseLeverageFilter {
-    #[serde(deserialize_with = "deserialize_f64")]
-    min_leverage: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    max_leverage: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    leverage_step: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    min_leverage: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    max_leverage: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    leverage_step: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl InverseLeverageFilter {
-    pub fn min_leverage(&self) -> f64 {
+    pub fn min_leverage(&self) -> Number {
         self.min_leverage
     }
 
-    pub fn set_min_leverage(&mut self, min_leverage: f64) {
+    pub fn set_min_leverage(&mut self, min_leverage: Number) {
         self.min_leverage = min_leverage;
     }
 
-    pub fn max_leverage(&self) -> f64 {
+    pub fn max_leverage(&self) -> Number {
         self.max_leverage
     }
 
-    pub fn set_max_leverage(&mut self, max_leverage: f64) {
+    pub fn set_max_leverage(&mut self, max_leverage: Number) {
         self.max_leverage = max_leverage;
     }
 
-    pub fn leverage_step(&self) -> f64 {
+    pub fn leverage_step(&self) -> Number {
         self.leverage_step
     }
 
-    pub fn set_leverage_step(&mut self, leverage_step: f64) {
+    pub fn set_leverage_step(&mut self, leverage_step: Number) {
         self.leverage_step = leverage_step;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InversePriceFilter {
-    #[serde(deserialize_with = "deserialize_f64")]
-    min_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    max_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    tick_size: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    min_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    max_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    tick_size: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl InversePriceFilter {
-    pub fn min_price(&self) -> f64 {
+    pub fn min_price(&self) -> Number {
         self.min_price
     }
 
-    pub fn set_min_price(&mut self, min_price: f64) {
+    pub fn set_min_price(&mut self, min_price: Number) {
         self.min_price = min_price;
     }
 
-    pub fn max_price(&self) -> f64 {
+    pub fn max_price(&self) -> Number {
         self.max_price
     }
 
-    pub fn set_max_price(&mut self, max_price: f64) {
+    pub fn set_max_price(&mut self, max_price: Number) {
         self.max_price = max_price;
     }
 
-    pub fn tick_size(&self) -> f64 {
+    pub fn tick_size(&self) -> Number {
         self.tick_size
     }
 
-    pub fn set_tick_size(&mut self, tick_size: f64) {
+    pub fn set_tick_size(&mut self, tick_size: Number) {
         self.tick_size = tick_size;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InverseLotSizeFilter {
-    #[serde(deserialize_with = "deserialize_f64")]
-    max_order_qty: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    min_order_qty: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    qty_step: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    post_only_max_order_qty: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    max_order_qty: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    min_order_qty: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    qty_step: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    post_only_max_order_qty: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl InverseLotSizeFilter {
-    pub fn max_order_qty(&self) -> f64 {
+    pub fn max_order_qty(&self) -> Number {
         self.max_order_qty
     }
 
-    pub fn set_max_order_qty(&mut self, max_order_qty: f64) {
+    pub fn set_max_order_qty(&mut self, max_order_qty: Number) {
         self.max_order_qty = max_order_qty;
     }
 
-    pub fn min_order_qty(&self) -> f64 {
+    pub fn min_order_qty(&self) -> Number {
         self.min_order_qty
     }
 
-    pub fn set_min_order_qty(&mut self, min_order_qty: f64) {
+    pub fn set_min_order_qty(&mut self, min_order_qty: Number) {
         self.min_order_qty = min_order_qty;
     }
 
-    pub fn qty_step(&self) -> f64 {
+    pub fn qty_step(&self) -> Number {
         self.qty_step
     }
 
-    pub fn set_qty_step(&mut self, qty_step: f64) {
+    pub fn set_qty_step(&mut self, qty_step: Number) {
         self.qty_step = qty_step;
     }
 
-    pub fn post_only_max_order_qty(&self) -> f64 {
+    pub fn post_only_max_order_qty(&self) -> Number {
         self.post_only_max_order_qty
     }
 
-    pub fn set_post_only_max_order_qty(&mut self, post_only_max_order_qty: f64) {
+    pub fn set_post_only_max_order_qty(&mut self, post_only_max_order_qty: Number) {
         self.post_only_max_order_qty = post_only_max_order_qty;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
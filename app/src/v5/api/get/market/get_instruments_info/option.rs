@@ -1,14 +1,39 @@
+use chrono::{
+    DateTime,
+    NaiveDate,
+    Utc,
+};
+use crate::utils::{
+    serialize_as_string,
+    str_millis_to_datetime,
+};
+use crate::v5::symbol::{
+    ParseSymbolError,
+    Symbol,
+};
 use serde::{
     Deserialize,
     Serialize,
 };
-use crate::utils::deserialize_f64;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::utils::deserialize_number;
+use crate::v5::types::Number;
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+};
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OptionInstrumentsInfoResult {
     list: Vec<OptionInstrumentInfo>,
     next_page_cursor: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl OptionInstrumentsInfoResult {
@@ -27,9 +52,41 @@ impl OptionInstrumentsInfoResult {
     pub fn set_next_page_cursor(&mut self, next_page_cursor: String) {
         self.next_page_cursor = next_page_cursor;
     }
+
+    /// Groups the options into a chain by expiry date, each expiry sorted by strike with calls before puts.
+    ///
+    /// Options whose symbol cannot be parsed are left out.
+    ///
+    /// # Returns
+    ///
+    /// The options of each expiry date, earliest expiry first.
+    pub fn option_chain(&self) -> BTreeMap<NaiveDate, Vec<(Symbol, &OptionInstrumentInfo)>> {
+        let mut chain: BTreeMap<NaiveDate, Vec<(Symbol, &OptionInstrumentInfo)>> = BTreeMap::new();
+        for info in &self.list {
+            let Ok(symbol) = info.parsed_symbol() else { continue };
+            let Some(expiry) = symbol.expiry() else { continue };
+            chain.entry(expiry).or_default().push((symbol, info));
+        }
+        for options in chain.values_mut() {
+            options.sort_by(|(a, _), (b, _)| {
+                a.strike().partial_cmp(&b.strike())
+                    .unwrap_or(Ordering::Equal)
+                    .then_with(|| a.option_type().cmp(&b.option_type()))
+            });
+        }
+        chain
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OptionInstrumentInfo {
     symbol: String,
@@ -43,6 +100,8 @@ pub struct OptionInstrumentInfo {
     delivery_fee_rate: String,
     price_filter: OptionPriceFilter,
     lot_size_filter: OptionLotSizeFilter,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl OptionInstrumentInfo {
     pub fn symbol(&self) -> &String {
@@ -53,6 +112,10 @@ impl OptionInstrumentInfo {
         self.symbol = symbol;
     }
 
+    pub fn parsed_symbol(&self) -> Result<Symbol, ParseSymbolError> {
+        self.symbol.parse()
+    }
+
     pub fn status(&self) -> &String {
         &self.status
     }
@@ -101,6 +164,10 @@ impl OptionInstrumentInfo {
         self.launch_time = launch_time;
     }
 
+    pub fn launch_time_datetime(&self) -> Option<DateTime<Utc>> {
+        str_millis_to_datetime(&self.launch_time)
+    }
+
     pub fn delivery_time(&self) -> &String {
         &self.delivery_time
     }
@@ -109,6 +176,10 @@ impl OptionInstrumentInfo {
         self.delivery_time = delivery_time;
     }
 
+    pub fn delivery_time_datetime(&self) -> Option<DateTime<Utc>> {
+        str_millis_to_datetime(&self.delivery_time)
+    }
+
     pub fn delivery_fee_rate(&self) -> &String {
         &self.delivery_fee_rate
     }
@@ -132,77 +203,105 @@ impl OptionInstrumentInfo {
     pub fn set_lot_size_filter(&mut self, lot_size_filter: OptionLotSizeFilter) {
         self.lot_size_filter = lot_size_filter;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OptionPriceFilter {
-    #[serde(deserialize_with = "deserialize_f64")]
-    min_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    max_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    tick_size: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    min_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    max_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    tick_size: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl OptionPriceFilter {
-    pub fn min_price(&self) -> f64 {
+    pub fn min_price(&self) -> Number {
         self.min_price
     }
 
-    pub fn set_min_price(&mut self, min_price: f64) {
+    pub fn set_min_price(&mut self, min_price: Number) {
         self.min_price = min_price;
     }
 
-    pub fn max_price(&self) -> f64 {
+    pub fn max_price(&self) -> Number {
         self.max_price
     }
 
-    pub fn set_max_price(&mut self, max_price: f64) {
+    pub fn set_max_price(&mut self, max_price: Number) {
         self.max_price = max_price;
     }
 
-    pub fn tick_size(&self) -> f64 {
+    pub fn tick_size(&self) -> Number {
         self.tick_size
     }
 
-    pub fn set_tick_size(&mut self, tick_size: f64) {
+    pub fn set_tick_size(&mut self, tick_size: Number) {
         self.tick_size = tick_size;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OptionLotSizeFilter {
-    #[serde(deserialize_with = "deserialize_f64")]
-    max_order_qty: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    min_order_qty: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    qty_step: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    max_order_qty: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    min_order_qty: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    qty_step: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl OptionLotSizeFilter {
-    pub fn max_order_qty(&self) -> f64 {
+    pub fn max_order_qty(&self) -> Number {
         self.max_order_qty
     }
 
-    pub fn set_max_order_qty(&mut self, max_order_qty: f64) {
+    pub fn set_max_order_qty(&mut self, max_order_qty: Number) {
         self.max_order_qty = max_order_qty;
     }
 
-    pub fn min_order_qty(&self) -> f64 {
+    pub fn min_order_qty(&self) -> Number {
         self.min_order_qty
     }
 
-    pub fn set_min_order_qty(&mut self, min_order_qty: f64) {
+    pub fn set_min_order_qty(&mut self, min_order_qty: Number) {
         self.min_order_qty = min_order_qty;
     }
 
-    pub fn qty_step(&self) -> f64 {
+    pub fn qty_step(&self) -> Number {
         self.qty_step
     }
 
-    pub fn set_qty_step(&mut self, qty_step: f64) {
+    pub fn set_qty_step(&mut self, qty_step: Number) {
         self.qty_step = qty_step;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
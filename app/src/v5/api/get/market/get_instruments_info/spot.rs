@@ -2,13 +2,24 @@ use serde::{
     Deserialize,
     Serialize,
 };
-use crate::utils::deserialize_f64;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::utils::{
+    deserialize_number,
+    serialize_as_string,
+};
+use crate::v5::types::Number;
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotInstrumentsInfoResult {
     list: Vec<SpotInstrumentInfo>,
     next_page_cursor: Option<String>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl SpotInstrumentsInfoResult {
     pub fn list(&self) -> &Vec<SpotInstrumentInfo> {
@@ -26,9 +37,17 @@ impl SpotInstrumentsInfoResult {
     pub fn set_next_page_cursor(&mut self, next_page_cursor: Option<String>) {
         self.next_page_cursor = next_page_cursor;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotInstrumentInfo {
     symbol: String,
@@ -39,6 +58,8 @@ pub struct SpotInstrumentInfo {
     margin_trading: String,
     price_filter: SpotPriceFilter,
     lot_size_filter: SpotLotSizeFilter,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl SpotInstrumentInfo {
     pub fn symbol(&self) -> &String {
@@ -104,87 +125,115 @@ impl SpotInstrumentInfo {
     pub fn set_lot_size_filter(&mut self, lot_size_filter: SpotLotSizeFilter) {
         self.lot_size_filter = lot_size_filter;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotPriceFilter {
-    #[serde(deserialize_with = "deserialize_f64")]
-    tick_size: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    tick_size: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl SpotPriceFilter {
-    pub fn tick_size(&self) -> f64 {
+    pub fn tick_size(&self) -> Number {
         self.tick_size
     }
 
-    pub fn set_tick_size(&mut self, tick_size: f64) {
+    pub fn set_tick_size(&mut self, tick_size: Number) {
         self.tick_size = tick_size;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotLotSizeFilter {
-    #[serde(deserialize_with = "deserialize_f64")]
-    base_precision: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    quote_precision: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    min_order_amt: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    max_order_amt: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    max_order_qty: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    min_order_qty: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    base_precision: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    quote_precision: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    min_order_amt: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    max_order_amt: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    max_order_qty: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    min_order_qty: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl SpotLotSizeFilter {
-    pub fn base_precision(&self) -> f64 {
+    pub fn base_precision(&self) -> Number {
         self.base_precision
     }
 
-    pub fn set_base_precision(&mut self, base_precision: f64) {
+    pub fn set_base_precision(&mut self, base_precision: Number) {
         self.base_precision = base_precision;
     }
 
-    pub fn quote_precision(&self) -> f64 {
+    pub fn quote_precision(&self) -> Number {
         self.quote_precision
     }
 
-    pub fn set_quote_precision(&mut self, quote_precision: f64) {
+    pub fn set_quote_precision(&mut self, quote_precision: Number) {
         self.quote_precision = quote_precision;
     }
 
-    pub fn min_order_amt(&self) -> f64 {
+    pub fn min_order_amt(&self) -> Number {
         self.min_order_amt
     }
 
-    pub fn set_min_order_amt(&mut self, min_order_amt: f64) {
+    pub fn set_min_order_amt(&mut self, min_order_amt: Number) {
         self.min_order_amt = min_order_amt;
     }
 
-    pub fn max_order_amt(&self) -> f64 {
+    pub fn max_order_amt(&self) -> Number {
         self.max_order_amt
     }
 
-    pub fn set_max_order_amt(&mut self, max_order_amt: f64) {
+    pub fn set_max_order_amt(&mut self, max_order_amt: Number) {
         self.max_order_amt = max_order_amt;
     }
 
-    pub fn max_order_qty(&self) -> f64 {
+    pub fn max_order_qty(&self) -> Number {
         self.max_order_qty
     }
 
-    pub fn set_max_order_qty(&mut self, max_order_qty: f64) {
+    pub fn set_max_order_qty(&mut self, max_order_qty: Number) {
         self.max_order_qty = max_order_qty;
     }
 
-    pub fn min_order_qty(&self) -> f64 {
+    pub fn min_order_qty(&self) -> Number {
         self.min_order_qty
     }
 
-    pub fn set_min_order_qty(&mut self, min_order_qty: f64) {
+    pub fn set_min_order_qty(&mut self, min_order_qty: Number) {
         self.min_order_qty = min_order_qty;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
@@ -1,10 +1,17 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::Number,
     utils::{
-        deserialize_f64,
+        serialize_as_string,
+        millis_to_datetime,
+        deserialize_number,
         deserialize_string_to_u64,
     },
 };
@@ -13,10 +20,14 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/market/insurance";
+const PATH: &str = "/v5/market/insurance";
 
 impl BybitApi {
     /// Retrieves insurance information.
@@ -47,7 +58,7 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_insurance(&self, params: GetInsuranceParameters) -> Result<GetInsuranceResponse> {
+    pub async fn get_insurance(&self, params: GetInsuranceParameters) -> Result<GetInsuranceResponse, BybitError> {
         self.get(PATH, Some(params), false).await
     }
 }
@@ -58,6 +69,12 @@ pub struct GetInsuranceParameters {
     coin: Option<String>
 }
 
+impl Default for GetInsuranceParameters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl GetInsuranceParameters {
     /// Creates a new instance of `GetInsuranceParameters`.
     pub fn new() -> Self {
@@ -81,7 +98,7 @@ impl GetInsuranceParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetInsuranceResponse {
     ret_code: i32,
@@ -89,6 +106,8 @@ pub struct GetInsuranceResponse {
     result: InsuranceResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetInsuranceResponse {
     pub fn ret_code(&self) -> i32 {
@@ -130,14 +149,28 @@ impl GetInsuranceResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InsuranceResult {
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     updated_time: u64,
-    list: Vec<Insurance>
+    list: Vec<Insurance>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl InsuranceResult {
     pub fn updated_time(&self) -> u64 {
@@ -147,6 +180,10 @@ impl InsuranceResult {
     pub fn set_updated_time(&mut self, updated_time: u64) {
         self.updated_time = updated_time;
     }
+
+    pub fn updated_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.updated_time)
+    }
     
     pub fn list(&self) -> &Vec<Insurance> {
         &self.list
@@ -155,16 +192,26 @@ impl InsuranceResult {
     pub fn set_list(&mut self, list: Vec<Insurance>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Insurance {
     coin: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    balance: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    value: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    balance: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    value: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl Insurance {
     pub fn coin(&self) -> &str {
@@ -175,19 +222,27 @@ impl Insurance {
         self.coin = coin;
     }
 
-    pub fn balance(&self) -> f64 {
+    pub fn balance(&self) -> Number {
         self.balance
     }
 
-    pub fn set_balance(&mut self, balance: f64) {
+    pub fn set_balance(&mut self, balance: Number) {
         self.balance = balance;
     }
 
-    pub fn value(&self) -> f64 {
+    pub fn value(&self) -> Number {
         self.value
     }
 
-    pub fn set_value(&mut self, value: f64) {
+    pub fn set_value(&mut self, value: Number) {
         self.value = value;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,22 +1,54 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        SpotLinearInverse,
+    },
     v5::api::{
         BybitApi,
         get::Get,
+        pagination::{
+            paginate_bars,
+            MAX_BARS,
+        },
+    },
+    v5::types::{
+        Interval,
+        Number,
     },
+    v5::ws::public::kline::PublicKlineData,
     utils::{
-        deserialize_f64,
+        datetime_to_millis,
+        millis_to_datetime,
+        deserialize_number,
         deserialize_string_to_u64,
     },
 };
 
+use futures_util::{
+    future::FutureExt,
+    stream::{
+        self,
+        BoxStream,
+        StreamExt,
+    },
+};
 use serde::{
     Serialize,
     Deserialize,
+    Serializer,
+};
+use serde_json::{
+    Map,
+    Value,
 };
-use serde_json::Value;
-use anyhow::Result;
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/market/kline";
+const PATH: &str = "/v5/market/kline";
 
 impl BybitApi {
     /// Retrieves the kline data from the API.
@@ -35,10 +67,11 @@ impl BybitApi {
     ///     },
     ///     BybitApi,
     /// };
+    /// use rsbit::v5::types::Interval;
     /// #[tokio::main]
     /// async fn main() {
     ///     let api = BybitApi::new();
-    ///     let params = GetKlineParameters::new(GetKlineCategory::Linear, "BTCUSDT".to_string(), "1".to_string());
+    ///     let params = GetKlineParameters::new(GetKlineCategory::Linear, "BTCUSDT".to_string(), Interval::Minute1);
     ///     let response = api.get_kline(params).await;
     ///     match response {
     ///         Ok(info) => {
@@ -50,25 +83,107 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_kline(&self, params: GetKlineParameters) -> Result<GetKlineResponse> {
+    pub async fn get_kline(&self, params: GetKlineParameters) -> Result<GetKlineResponse, BybitError> {
         self.get(PATH, Some(params), false).await
     }
-}
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetKlineCategory {
-    Linear,
-    Spot,
-    Inverse,
+    /// Downloads the klines between `from` and `to`, in pages of 1000 bars.
+    ///
+    /// Bars returned by two pages are yielded once. Each page is a `get_kline` request,
+    /// so the rate limiter set on this instance applies to every page.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The category of the kline data.
+    /// * `symbol` - The symbol of the trading pair.
+    /// * `interval` - The interval of the kline data.
+    /// * `from` - The start of the range, inclusive.
+    /// * `to` - The end of the range, exclusive.
+    ///
+    /// # Returns
+    ///
+    /// A stream of the bars opened in the range, oldest first. A failed request is yielded as an error and ends the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rsbit::v5::api::{
+    ///     get::market::get_kline::GetKlineCategory,
+    ///     BybitApi,
+    /// };
+    /// use rsbit::v5::types::Interval;
+    /// use chrono::{
+    ///     TimeZone,
+    ///     Utc,
+    /// };
+    /// use futures_util::StreamExt;
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let api = BybitApi::new();
+    ///     let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    ///     let to = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+    ///     let mut klines = api.download_klines(GetKlineCategory::Linear, "BTCUSDT".to_string(), Interval::Minute1, from, to);
+    ///     while let Some(kline) = klines.next().await {
+    ///         match kline {
+    ///             Ok(kline) => {
+    ///                 // Handle the kline
+    ///             },
+    ///             Err(err) => {
+    ///                 // Handle the error
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn download_klines(&self, category: GetKlineCategory, symbol: String, interval: Interval, from: DateTime<Utc>, to: DateTime<Utc>) -> BoxStream<'_, Result<Kline, BybitError>> {
+        paginate_bars(from, to, interval, Kline::timestamp, move |start, end| {
+            let params = GetKlineParameters::new(category, symbol.clone(), interval)
+                .with_start(start)
+                .with_end(end)
+                .with_limit(MAX_BARS);
+            async move {
+                Ok(self.get_kline(params).await?.result.list)
+            }.boxed()
+        })
+    }
+
+    /// Downloads the klines of several symbols between `from` and `to`, running up to `concurrency` symbols at once.
+    ///
+    /// Every page still goes through the rate limiter set on this instance, so `concurrency` only
+    /// bounds the requests in flight and the limiter keeps them within the rate limit budget.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The category of the kline data.
+    /// * `symbols` - The symbols of the trading pairs.
+    /// * `interval` - The interval of the kline data.
+    /// * `from` - The start of the range, inclusive.
+    /// * `to` - The end of the range, exclusive.
+    /// * `concurrency` - The number of symbols downloaded at once. `0` is taken as `1`.
+    ///
+    /// # Returns
+    ///
+    /// A stream of the symbol and bar pairs. The bars of one symbol are oldest first, while the
+    /// symbols are interleaved. A failed request ends the stream of that symbol only.
+    pub fn download_klines_concurrently(&self, category: GetKlineCategory, symbols: Vec<String>, interval: Interval, from: DateTime<Utc>, to: DateTime<Utc>, concurrency: usize) -> BoxStream<'_, (String, Result<Kline, BybitError>)> {
+        stream::iter(symbols)
+            .map(move |symbol| {
+                self.download_klines(category, symbol.clone(), interval, from, to)
+                    .map(move |kline| (symbol.clone(), kline))
+            })
+            .flatten_unordered(concurrency.max(1))
+            .boxed()
+    }
 }
 
+pub type GetKlineCategory = Category<SpotLinearInverse>;
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetKlineParameters {
     category: GetKlineCategory,
     symbol: String,
-    interval: String,
+    interval: Interval,
     start: Option<u64>,
     end: Option<u64>,
     limit: Option<u32>,
@@ -86,7 +201,7 @@ impl GetKlineParameters {
     /// # Returns
     ///
     /// A new instance of GetKlineParameters.
-    pub fn new(category: GetKlineCategory, symbol: String, interval: String) -> Self {
+    pub fn new(category: GetKlineCategory, symbol: String, interval: Interval) -> Self {
         Self {
             category,
             symbol,
@@ -111,6 +226,20 @@ impl GetKlineParameters {
         self
     }
 
+    /// Sets the start time for the kline data.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The start time as a UTC date and time.
+    ///
+    /// # Returns
+    ///
+    /// The modified GetKlineParameters instance.
+    pub fn with_start_datetime(mut self, start: DateTime<Utc>) -> Self {
+        self.start = Some(datetime_to_millis(start));
+        self
+    }
+
     /// Sets the end time for the kline data.
     ///
     /// # Arguments
@@ -125,6 +254,20 @@ impl GetKlineParameters {
         self
     }
 
+    /// Sets the end time for the kline data.
+    ///
+    /// # Arguments
+    ///
+    /// * `end` - The end time as a UTC date and time.
+    ///
+    /// # Returns
+    ///
+    /// The modified GetKlineParameters instance.
+    pub fn with_end_datetime(mut self, end: DateTime<Utc>) -> Self {
+        self.end = Some(datetime_to_millis(end));
+        self
+    }
+
     /// Sets the limit for the number of kline data to retrieve.
     ///
     /// # Arguments
@@ -140,7 +283,7 @@ impl GetKlineParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetKlineResponse {
     ret_code: i32,
@@ -148,6 +291,8 @@ pub struct GetKlineResponse {
     result: KlineResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetKlineResponse {
     pub fn ret_code(&self) -> i32 {
@@ -189,14 +334,28 @@ impl GetKlineResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct KlineResult {
     symbol: String,
     category: String,
-    list: Vec<Kline>
+    list: Vec<Kline>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl KlineResult {
     pub fn symbol(&self) -> &str {
@@ -222,26 +381,61 @@ impl KlineResult {
     pub fn set_list(&mut self, list: Vec<Kline>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct Kline {
     #[serde(rename = "0", deserialize_with = "deserialize_string_to_u64")]
     timestamp: u64,
-    #[serde(rename = "1", deserialize_with = "deserialize_f64")]
-    open: f64,
-    #[serde(rename = "2", deserialize_with = "deserialize_f64")]
-    high: f64,
-    #[serde(rename = "3", deserialize_with = "deserialize_f64")]
-    low: f64,
-    #[serde(rename = "4", deserialize_with = "deserialize_f64")]
-    close: f64,
-    #[serde(rename = "5", deserialize_with = "deserialize_f64")]
-    volume: f64,
-    #[serde(rename = "6", deserialize_with = "deserialize_f64")]
-    turnover: f64,
+    #[serde(rename = "1", deserialize_with = "deserialize_number")]
+    open: Number,
+    #[serde(rename = "2", deserialize_with = "deserialize_number")]
+    high: Number,
+    #[serde(rename = "3", deserialize_with = "deserialize_number")]
+    low: Number,
+    #[serde(rename = "4", deserialize_with = "deserialize_number")]
+    close: Number,
+    #[serde(rename = "5", deserialize_with = "deserialize_number")]
+    volume: Number,
+    #[serde(rename = "6", deserialize_with = "deserialize_number")]
+    turnover: Number,
 }
 impl Kline {
+    /// Creates a new instance of Kline.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - The open time of the bar in milliseconds.
+    /// * `open` - The open price.
+    /// * `high` - The highest price.
+    /// * `low` - The lowest price.
+    /// * `close` - The close price.
+    /// * `volume` - The trade volume.
+    /// * `turnover` - The turnover.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of Kline.
+    pub fn new(timestamp: u64, open: Number, high: Number, low: Number, close: Number, volume: Number, turnover: Number) -> Self {
+        Self {
+            timestamp,
+            open,
+            high,
+            low,
+            close,
+            volume,
+            turnover,
+        }
+    }
+
     pub fn timestamp(&self) -> u64 {
         self.timestamp
     }
@@ -250,51 +444,80 @@ impl Kline {
         self.timestamp = timestamp;
     }
 
-    pub fn open(&self) -> f64 {
+    pub fn timestamp_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.timestamp)
+    }
+
+    pub fn open(&self) -> Number {
         self.open
     }
 
-    pub fn set_open(&mut self, open: f64) {
+    pub fn set_open(&mut self, open: Number) {
         self.open = open;
     }
 
-    pub fn high(&self) -> f64 {
+    pub fn high(&self) -> Number {
         self.high
     }
 
-    pub fn set_high(&mut self, high: f64) {
+    pub fn set_high(&mut self, high: Number) {
         self.high = high;
     }
 
-    pub fn low(&self) -> f64 {
+    pub fn low(&self) -> Number {
         self.low
     }
 
-    pub fn set_low(&mut self, low: f64) {
+    pub fn set_low(&mut self, low: Number) {
         self.low = low;
     }
 
-    pub fn close(&self) -> f64 {
+    pub fn close(&self) -> Number {
         self.close
     }
 
-    pub fn set_close(&mut self, close: f64) {
+    pub fn set_close(&mut self, close: Number) {
         self.close = close;
     }
 
-    pub fn volume(&self) -> f64 {
+    pub fn volume(&self) -> Number {
         self.volume
     }
 
-    pub fn set_volume(&mut self, volume: f64) {
+    pub fn set_volume(&mut self, volume: Number) {
         self.volume = volume;
     }
 
-    pub fn turnover(&self) -> f64 {
+    pub fn turnover(&self) -> Number {
         self.turnover
     }
 
-    pub fn set_turnover(&mut self, turnover: f64) {
+    pub fn set_turnover(&mut self, turnover: Number) {
         self.turnover = turnover;
     }
-}
\ No newline at end of file
+}
+
+// WebSocket の足を REST と同じ形で扱えるようにする
+impl From<&PublicKlineData> for Kline {
+    fn from(data: &PublicKlineData) -> Self {
+        Kline::new(data.start(), data.open(), data.high(), data.low(), data.close(), data.volume(), data.turnover())
+    }
+}
+
+// Bybit と同じく文字列の配列として書き出す
+impl Serialize for Kline {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (
+            self.timestamp.to_string(),
+            self.open.to_string(),
+            self.high.to_string(),
+            self.low.to_string(),
+            self.close.to_string(),
+            self.volume.to_string(),
+            self.turnover.to_string(),
+        ).serialize(serializer)
+    }
+}
@@ -1,10 +1,21 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        LinearInverse,
+    },
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::Number,
     utils::{
-        deserialize_f64,
+        serialize_as_string,
+        millis_to_datetime,
+        deserialize_number,
         deserialize_string_to_u64,
     },
 };
@@ -13,10 +24,14 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/market/account-ratio";
+const PATH: &str = "/v5/market/account-ratio";
 
 impl BybitApi {
     /// Retrieves the long/short ratio for a specific market.
@@ -50,17 +65,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_long_short_ratio(&self, params: GetLongShortRatioParameters) -> Result<GetLongShortRatioResponse> {
+    pub async fn get_long_short_ratio(&self, params: GetLongShortRatioParameters) -> Result<GetLongShortRatioResponse, BybitError> {
         self.get(PATH, Some(params), false).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetLongShortRatioCategory {
-    Linear,
-    Inverse,
-}
+pub type GetLongShortRatioCategory = Category<LinearInverse>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -107,7 +117,7 @@ impl GetLongShortRatioParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetLongShortRatioResponse {
     ret_code: i32,
@@ -115,6 +125,8 @@ pub struct GetLongShortRatioResponse {
     result: LongShortRatioResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetLongShortRatioResponse {
     pub fn ret_code(&self) -> i32 {
@@ -156,12 +168,26 @@ impl GetLongShortRatioResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LongShortRatioResult {
-    list: Vec<LongShortRatio>
+    list: Vec<LongShortRatio>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl LongShortRatioResult {
     pub fn list(&self) -> &Vec<LongShortRatio> {
@@ -171,18 +197,28 @@ impl LongShortRatioResult {
     pub fn set_list(&mut self, list: Vec<LongShortRatio>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LongShortRatio {
     symbol: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    buy_ratio: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    sell_ratio: f64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    buy_ratio: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    sell_ratio: Number,
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     timestamp: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl LongShortRatio {
     pub fn symbol(&self) -> &str {
@@ -193,19 +229,19 @@ impl LongShortRatio {
         self.symbol = symbol;
     }
 
-    pub fn buy_ratio(&self) -> f64 {
+    pub fn buy_ratio(&self) -> Number {
         self.buy_ratio
     }
 
-    pub fn set_buy_ratio(&mut self, buy_ratio: f64) {
+    pub fn set_buy_ratio(&mut self, buy_ratio: Number) {
         self.buy_ratio = buy_ratio;
     }
 
-    pub fn sell_ratio(&self) -> f64 {
+    pub fn sell_ratio(&self) -> Number {
         self.sell_ratio
     }
 
-    pub fn set_sell_ratio(&mut self, sell_ratio: f64) {
+    pub fn set_sell_ratio(&mut self, sell_ratio: Number) {
         self.sell_ratio = sell_ratio;
     }
 
@@ -216,4 +252,16 @@ impl LongShortRatio {
     pub fn set_timestamp(&mut self, timestamp: u64) {
         self.timestamp = timestamp;
     }
+
+    pub fn timestamp_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.timestamp)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,10 +1,24 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        LinearInverse,
+    },
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::{
+        Interval,
+        Number,
+    },
     utils::{
-        deserialize_f64,
+        datetime_to_millis,
+        millis_to_datetime,
+        deserialize_number,
         deserialize_string_to_u64,
     },
 };
@@ -12,11 +26,16 @@ use crate::{
 use serde::{
     Serialize,
     Deserialize,
+    Serializer,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/market/mark-price-kline";
+const PATH: &str = "/v5/market/mark-price-kline";
 
 impl BybitApi {
     /// Retrieves the market's mark price kline.
@@ -38,10 +57,11 @@ impl BybitApi {
     ///     },
     ///     BybitApi,
     /// };
+    /// use rsbit::v5::types::Interval;
     /// #[tokio::main]
     /// async fn main() {
     ///     let api = BybitApi::new();
-    ///     let params = GetMarkPriceKlineParameters::new(GetMarkPriceKlineCategory::Linear, "BTCUSDT".to_string(), "1".to_string());
+    ///     let params = GetMarkPriceKlineParameters::new(GetMarkPriceKlineCategory::Linear, "BTCUSDT".to_string(), Interval::Minute1);
     ///     let response = api.get_mark_price_kline(params).await;
     ///     match response {
     ///         Ok(info) => {
@@ -53,24 +73,19 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_mark_price_kline(&self, params: GetMarkPriceKlineParameters) -> Result<GetMarkPriceKlineResponse> {
+    pub async fn get_mark_price_kline(&self, params: GetMarkPriceKlineParameters) -> Result<GetMarkPriceKlineResponse, BybitError> {
         self.get(PATH, Some(params), false).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetMarkPriceKlineCategory {
-    Linear,
-    Inverse,
-}
+pub type GetMarkPriceKlineCategory = Category<LinearInverse>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetMarkPriceKlineParameters {
     category: GetMarkPriceKlineCategory,
     symbol: String,
-    interval: String,
+    interval: Interval,
     start: Option<u64>,
     end: Option<u64>,
     limit: Option<u32>,
@@ -88,7 +103,7 @@ impl GetMarkPriceKlineParameters {
     /// # Returns
     ///
     /// A new instance of `GetMarkPriceKlineParameters`.
-    pub fn new(category: GetMarkPriceKlineCategory, symbol: String, interval: String) -> Self {
+    pub fn new(category: GetMarkPriceKlineCategory, symbol: String, interval: Interval) -> Self {
         Self {
             category,
             symbol,
@@ -113,6 +128,20 @@ impl GetMarkPriceKlineParameters {
         self
     }
 
+    /// Sets the start time for the mark price kline data.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The start time as a UTC date and time.
+    ///
+    /// # Returns
+    ///
+    /// The modified GetMarkPriceKlineParameters instance.
+    pub fn with_start_datetime(mut self, start: DateTime<Utc>) -> Self {
+        self.start = Some(datetime_to_millis(start));
+        self
+    }
+
     /// Sets the end time for the mark price kline data.
     ///
     /// # Arguments
@@ -127,6 +156,20 @@ impl GetMarkPriceKlineParameters {
         self
     }
 
+    /// Sets the end time for the mark price kline data.
+    ///
+    /// # Arguments
+    ///
+    /// * `end` - The end time as a UTC date and time.
+    ///
+    /// # Returns
+    ///
+    /// The modified GetMarkPriceKlineParameters instance.
+    pub fn with_end_datetime(mut self, end: DateTime<Utc>) -> Self {
+        self.end = Some(datetime_to_millis(end));
+        self
+    }
+
     /// Sets the limit for the number of mark price kline data to retrieve.
     ///
     /// # Arguments
@@ -142,7 +185,7 @@ impl GetMarkPriceKlineParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetMarkPriceKlineResponse {
     ret_code: i32,
@@ -150,6 +193,8 @@ pub struct GetMarkPriceKlineResponse {
     result: MarkPriceKlineResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetMarkPriceKlineResponse {
     pub fn ret_code(&self) -> i32 {
@@ -191,14 +236,28 @@ impl GetMarkPriceKlineResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MarkPriceKlineResult {
     symbol: String,
     category: String,
-    list: Vec<MarkPriceKline>
+    list: Vec<MarkPriceKline>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl MarkPriceKlineResult {
     pub fn symbol(&self) -> &str {
@@ -224,20 +283,28 @@ impl MarkPriceKlineResult {
     pub fn set_list(&mut self, list: Vec<MarkPriceKline>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct MarkPriceKline {
     #[serde(rename = "0", deserialize_with = "deserialize_string_to_u64")]
     timestamp: u64,
-    #[serde(rename = "1", deserialize_with = "deserialize_f64")]
-    open: f64,
-    #[serde(rename = "2", deserialize_with = "deserialize_f64")]
-    high: f64,
-    #[serde(rename = "3", deserialize_with = "deserialize_f64")]
-    low: f64,
-    #[serde(rename = "4", deserialize_with = "deserialize_f64")]
-    close: f64,
+    #[serde(rename = "1", deserialize_with = "deserialize_number")]
+    open: Number,
+    #[serde(rename = "2", deserialize_with = "deserialize_number")]
+    high: Number,
+    #[serde(rename = "3", deserialize_with = "deserialize_number")]
+    low: Number,
+    #[serde(rename = "4", deserialize_with = "deserialize_number")]
+    close: Number,
 }
 impl MarkPriceKline {
     pub fn timestamp(&self) -> u64 {
@@ -248,35 +315,55 @@ impl MarkPriceKline {
         self.timestamp = timestamp;
     }
 
-    pub fn open(&self) -> f64 {
+    pub fn timestamp_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.timestamp)
+    }
+
+    pub fn open(&self) -> Number {
         self.open
     }
 
-    pub fn set_open(&mut self, open: f64) {
+    pub fn set_open(&mut self, open: Number) {
         self.open = open;
     }
 
-    pub fn high(&self) -> f64 {
+    pub fn high(&self) -> Number {
         self.high
     }
 
-    pub fn set_high(&mut self, high: f64) {
+    pub fn set_high(&mut self, high: Number) {
         self.high = high;
     }
 
-    pub fn low(&self) -> f64 {
+    pub fn low(&self) -> Number {
         self.low
     }
 
-    pub fn set_low(&mut self, low: f64) {
+    pub fn set_low(&mut self, low: Number) {
         self.low = low;
     }
 
-    pub fn close(&self) -> f64 {
+    pub fn close(&self) -> Number {
         self.close
     }
 
-    pub fn set_close(&mut self, close: f64) {
+    pub fn set_close(&mut self, close: Number) {
         self.close = close;
     }
-}
\ No newline at end of file
+}
+
+// Bybit と同じく文字列の配列として書き出す
+impl Serialize for MarkPriceKline {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (
+            self.timestamp.to_string(),
+            self.open.to_string(),
+            self.high.to_string(),
+            self.low.to_string(),
+            self.close.to_string(),
+        ).serialize(serializer)
+    }
+}
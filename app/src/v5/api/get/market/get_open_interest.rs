@@ -1,10 +1,22 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        LinearInverse,
+    },
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::Number,
     utils::{
-        deserialize_f64,
+        serialize_as_string,
+        datetime_to_millis,
+        millis_to_datetime,
+        deserialize_number,
         deserialize_string_to_u64,
     },
 };
@@ -13,10 +25,14 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/market/open-interest";
+const PATH: &str = "/v5/market/open-interest";
 
 impl BybitApi {
     /// Retrieves the open interest data for the market.
@@ -50,17 +66,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_open_interest(&self, params: GetOpenInterestParameters) -> Result<GetOpenInterestResponse> {
+    pub async fn get_open_interest(&self, params: GetOpenInterestParameters) -> Result<GetOpenInterestResponse, BybitError> {
         self.get(PATH, Some(params), false).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetOpenInterestCategory {
-    Linear,
-    Inverse,
-}
+pub type GetOpenInterestCategory = Category<LinearInverse>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -112,6 +123,20 @@ impl GetOpenInterestParameters {
         self
     }
 
+    /// Sets the start time for the open interest query.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_time` - The start time as a UTC date and time.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GetOpenInterestParameters` instance.
+    pub fn with_start_datetime(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(datetime_to_millis(start_time));
+        self
+    }
+
     /// Sets the end time for the open interest query.
     ///
     /// # Arguments
@@ -126,6 +151,20 @@ impl GetOpenInterestParameters {
         self
     }
 
+    /// Sets the end time for the open interest query.
+    ///
+    /// # Arguments
+    ///
+    /// * `end_time` - The end time as a UTC date and time.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GetOpenInterestParameters` instance.
+    pub fn with_end_datetime(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(datetime_to_millis(end_time));
+        self
+    }
+
     /// Sets the limit for the open interest query.
     ///
     /// # Arguments
@@ -155,7 +194,7 @@ impl GetOpenInterestParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetOpenInterestResponse {
     ret_code: i32,
@@ -163,6 +202,8 @@ pub struct GetOpenInterestResponse {
     result: OpenInterestResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetOpenInterestResponse {
     pub fn ret_code(&self) -> i32 {
@@ -204,14 +245,28 @@ impl GetOpenInterestResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OpenInterestResult {
     category: String,
     symbol: String,
-    list: Vec<OpenInterest>
+    list: Vec<OpenInterest>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl OpenInterestResult {
     pub fn category(&self) -> &str {
@@ -237,22 +292,32 @@ impl OpenInterestResult {
     pub fn set_list(&mut self, list: Vec<OpenInterest>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OpenInterest {
-    #[serde(deserialize_with = "deserialize_f64")]
-    open_interest: f64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    open_interest: Number,
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     timestamp: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl OpenInterest {
-    pub fn open_interest(&self) -> f64 {
+    pub fn open_interest(&self) -> Number {
         self.open_interest
     }
 
-    pub fn set_open_interest(&mut self, open_interest: f64) {
+    pub fn set_open_interest(&mut self, open_interest: Number) {
         self.open_interest = open_interest;
     }
 
@@ -263,4 +328,16 @@ impl OpenInterest {
     pub fn set_timestamp(&mut self, timestamp: u64) {
         self.timestamp = timestamp;
     }
+
+    pub fn timestamp_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.timestamp)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
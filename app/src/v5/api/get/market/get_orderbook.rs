@@ -1,19 +1,33 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::Category,
     v5::api::{
         BybitApi,
         get::Get,
     },
-    utils::deserialize_f64,
+    v5::types::Number,
+    utils::{
+        deserialize_number,
+        millis_to_datetime,
+    },
 };
 
 use serde::{
     Serialize,
     Deserialize,
+    Serializer,
+};
+use serde_json::{
+    Map,
+    Value,
 };
-use serde_json::Value;
-use anyhow::Result;
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/market/orderbook";
+const PATH: &str = "/v5/market/orderbook";
 
 impl BybitApi {
     /// Retrieves the market's orderbook.
@@ -50,19 +64,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_orderbook(&self, params: GetOrderbookParameters) -> Result<GetOrderbookResponse> {
+    pub async fn get_orderbook(&self, params: GetOrderbookParameters) -> Result<GetOrderbookResponse, BybitError> {
         self.get(PATH, Some(params), false).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetOrderbookCategory {
-    Linear,
-    Spot,
-    Inverse,
-    Option,
-}
+pub type GetOrderbookCategory = Category;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -106,7 +113,7 @@ impl GetOrderbookParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetOrderbookResponse {
     ret_code: i32,
@@ -114,6 +121,8 @@ pub struct GetOrderbookResponse {
     result: OrderbookResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetOrderbookResponse {
     pub fn ret_code(&self) -> i32 {
@@ -155,9 +164,21 @@ impl GetOrderbookResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct OrderbookResult {
     #[serde(rename = "s")]
     symbol: String,
@@ -168,6 +189,8 @@ pub struct OrderbookResult {
     #[serde(rename = "u")]
     update_id: u64,
     ts: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl OrderbookResult {
     pub fn symbol(&self) -> &str {
@@ -209,29 +232,54 @@ impl OrderbookResult {
     pub fn set_ts(&mut self, ts: u64) {
         self.ts = ts;
     }
+
+    pub fn ts_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.ts)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct Order {
-    #[serde(rename = "0", deserialize_with = "deserialize_f64")]
-    price: f64,
-    #[serde(rename = "1", deserialize_with = "deserialize_f64")]
-    size: f64,
+    #[serde(rename = "0", deserialize_with = "deserialize_number")]
+    price: Number,
+    #[serde(rename = "1", deserialize_with = "deserialize_number")]
+    size: Number,
 }
 impl Order {
-    pub fn price(&self) -> f64 {
+    pub fn price(&self) -> Number {
         self.price
     }
 
-    pub fn set_price(&mut self, price: f64) {
+    pub fn set_price(&mut self, price: Number) {
         self.price = price;
     }
 
-    pub fn size(&self) -> f64 {
+    pub fn size(&self) -> Number {
         self.size
     }
 
-    pub fn set_size(&mut self, size: f64) {
+    pub fn set_size(&mut self, size: Number) {
         self.size = size;
     }
 }
+
+// Bybit と同じく文字列の配列として書き出す
+impl Serialize for Order {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (
+            self.price.to_string(),
+            self.size.to_string(),
+        ).serialize(serializer)
+    }
+}
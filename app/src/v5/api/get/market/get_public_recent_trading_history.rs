@@ -1,10 +1,21 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::Category,
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::{
+        Side,
+        Number,
+    },
     utils::{
-        deserialize_f64,
+        serialize_as_string,
+        millis_to_datetime,
+        deserialize_number,
         deserialize_string_to_u64,
     },
 };
@@ -13,10 +24,14 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/market/recent-trade";
+const PATH: &str = "/v5/market/recent-trade";
 
 impl BybitApi {
     /// Retrieves the recent trading history from the public API.
@@ -50,19 +65,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_public_recent_trading_history(&self, params: GetPublicRecentTradingHistoryParameters) -> Result<GetPublicRecentTradingHistoryResponse> {
+    pub async fn get_public_recent_trading_history(&self, params: GetPublicRecentTradingHistoryParameters) -> Result<GetPublicRecentTradingHistoryResponse, BybitError> {
         self.get(PATH, Some(params), false).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetPublicRecentTradingHistoryCategory {
-    Linear,
-    Inverse,
-    Spot,
-    Option,
-}
+pub type GetPublicRecentTradingHistoryCategory = Category;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -137,7 +145,7 @@ impl GetPublicRecentTradingHistoryParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetPublicRecentTradingHistoryResponse {
     ret_code: i32,
@@ -145,6 +153,8 @@ pub struct GetPublicRecentTradingHistoryResponse {
     result: PublicRecentTradingHistoryResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetPublicRecentTradingHistoryResponse {
     pub fn ret_code(&self) -> i32 {
@@ -186,13 +196,27 @@ impl GetPublicRecentTradingHistoryResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PublicRecentTradingHistoryResult {
     category: String,
-    list: Vec<PublicRecentTradingHistory>
+    list: Vec<PublicRecentTradingHistory>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl PublicRecentTradingHistoryResult {
     pub fn category(&self) -> &str {
@@ -210,21 +234,31 @@ impl PublicRecentTradingHistoryResult {
     pub fn set_list(&mut self, list: Vec<PublicRecentTradingHistory>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PublicRecentTradingHistory {
     exec_id: String,
     symbol: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    size: f64,
-    side: String,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    size: Number,
+    side: Side,
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     time: u64,
-    is_block_trade: bool
+    is_block_trade: bool,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl PublicRecentTradingHistory {
     pub fn exec_id(&self) -> &str {
@@ -243,27 +277,27 @@ impl PublicRecentTradingHistory {
         self.symbol = symbol;
     }
 
-    pub fn price(&self) -> f64 {
+    pub fn price(&self) -> Number {
         self.price
     }
 
-    pub fn set_price(&mut self, price: f64) {
+    pub fn set_price(&mut self, price: Number) {
         self.price = price;
     }
 
-    pub fn size(&self) -> f64 {
+    pub fn size(&self) -> Number {
         self.size
     }
 
-    pub fn set_size(&mut self, size: f64) {
+    pub fn set_size(&mut self, size: Number) {
         self.size = size;
     }
 
-    pub fn side(&self) -> &str {
+    pub fn side(&self) -> &Side {
         &self.side
     }
 
-    pub fn set_side(&mut self, side: String) {
+    pub fn set_side(&mut self, side: Side) {
         self.side = side;
     }
 
@@ -275,6 +309,10 @@ impl PublicRecentTradingHistory {
         self.time = time;
     }
 
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
     pub fn is_block_trade(&self) -> bool {
         self.is_block_trade
     }
@@ -283,4 +321,12 @@ impl PublicRecentTradingHistory {
         self.is_block_trade = is_block_trade;
     }
     
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
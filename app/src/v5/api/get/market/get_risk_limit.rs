@@ -1,19 +1,36 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        LinearInverse,
+    },
     v5::api::{
         BybitApi,
         get::Get,
     },
-    utils::deserialize_f64,
+    v5::types::Number,
+    utils::{
+        serialize_as_string,
+        deserialize_number,
+        millis_to_datetime,
+    },
 };
 
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/market/risk-limit";
+const PATH: &str = "/v5/market/risk-limit";
 
 impl BybitApi {
     /// Retrieves the risk limit for the market.
@@ -47,17 +64,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_risk_limit(&self, params: GetRiskLimitParameters) -> Result<GetRiskLimitResponse> {
+    pub async fn get_risk_limit(&self, params: GetRiskLimitParameters) -> Result<GetRiskLimitResponse, BybitError> {
         self.get(PATH, Some(params), false).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetRiskLimitCategory {
-    Linear,
-    Inverse,
-}
+pub type GetRiskLimitCategory = Category<LinearInverse>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -98,7 +110,7 @@ impl GetRiskLimitParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetRiskLimitResponse {
     ret_code: i32,
@@ -106,6 +118,8 @@ pub struct GetRiskLimitResponse {
     result: RiskLimitResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetRiskLimitResponse {
     pub fn ret_code(&self) -> i32 {
@@ -147,13 +161,27 @@ impl GetRiskLimitResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RiskLimitResult {
     category: String,
-    list: Vec<RiskLimit>
+    list: Vec<RiskLimit>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl RiskLimitResult {
     pub fn category(&self) -> &str {
@@ -171,22 +199,32 @@ impl RiskLimitResult {
     pub fn set_list(&mut self, list: Vec<RiskLimit>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RiskLimit {
     id: i64,
     symbol: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    risk_limit_value: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    maintenance_margin: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    initial_margin: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    risk_limit_value: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    maintenance_margin: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    initial_margin: Number,
     is_lowest_risk: i64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    max_leverage: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    max_leverage: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl RiskLimit {
     pub fn id(&self) -> i64 {
@@ -205,27 +243,27 @@ impl RiskLimit {
         self.symbol = symbol;
     }
 
-    pub fn risk_limit_value(&self) -> f64 {
+    pub fn risk_limit_value(&self) -> Number {
         self.risk_limit_value
     }
 
-    pub fn set_risk_limit_value(&mut self, risk_limit_value: f64) {
+    pub fn set_risk_limit_value(&mut self, risk_limit_value: Number) {
         self.risk_limit_value = risk_limit_value;
     }
 
-    pub fn maintenance_margin(&self) -> f64 {
+    pub fn maintenance_margin(&self) -> Number {
         self.maintenance_margin
     }
 
-    pub fn set_maintenance_margin(&mut self, maintenance_margin: f64) {
+    pub fn set_maintenance_margin(&mut self, maintenance_margin: Number) {
         self.maintenance_margin = maintenance_margin;
     }
 
-    pub fn initial_margin(&self) -> f64 {
+    pub fn initial_margin(&self) -> Number {
         self.initial_margin
     }
 
-    pub fn set_initial_margin(&mut self, initial_margin: f64) {
+    pub fn set_initial_margin(&mut self, initial_margin: Number) {
         self.initial_margin = initial_margin;
     }
 
@@ -237,11 +275,19 @@ impl RiskLimit {
         self.is_lowest_risk = is_lowest_risk;
     }
 
-    pub fn max_leverage(&self) -> f64 {
+    pub fn max_leverage(&self) -> Number {
         self.max_leverage
     }
 
-    pub fn set_max_leverage(&mut self, max_leverage: f64) {
+    pub fn set_max_leverage(&mut self, max_leverage: Number) {
         self.max_leverage = max_leverage;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,3 +1,8 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use crate::v5::category::Category;
 pub mod inverse;
 pub mod linear;
 pub mod option;
@@ -10,6 +15,9 @@ use self::{
     option::OptionTickersResult,
 };
 use crate::{
+    utils::{
+        millis_to_datetime,
+    },
     v5::api::{
         BybitApi,
         get::Get,
@@ -21,14 +29,18 @@ use crate::{
         CATEGORY_SPOT,
     }
 };
-use anyhow::Result;
+use crate::error::BybitError;
 use serde::{
     Deserialize,
     Serialize,
 };
-use serde_json::Value;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
 
-const PATH: &'static str = "/v5/market/tickers";
+const PATH: &str = "/v5/market/tickers";
 
 impl BybitApi {
     /// Retrieves tickers from the market.
@@ -66,19 +78,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_tickers(&self, params: GetTickersParameters) -> Result<GetTickersResponse> {
+    pub async fn get_tickers(&self, params: GetTickersParameters) -> Result<GetTickersResponse, BybitError> {
         self.get(PATH, Some(params), false).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetTickersCategory {
-    Linear,
-    Option,
-    Spot,
-    Inverse,
-}
+pub type GetTickersCategory = Category;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -151,7 +156,7 @@ impl GetTickersParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetTickersResponse {
     ret_code: i32,
@@ -159,6 +164,8 @@ pub struct GetTickersResponse {
     result: TickersResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl GetTickersResponse {
@@ -201,9 +208,21 @@ impl GetTickersResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(tag = "category", rename_all = "camelCase")]
 pub enum TickersResult {
     Linear(LinearTickersResult),
@@ -1,17 +1,32 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use serde::{
     Deserialize,
     Serialize,
 };
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
 use crate::utils::{
-    deserialize_f64,
-    deserialize_option_f64,
+    serialize_as_string,
+    serialize_option_as_string,
+    millis_to_datetime,
+    deserialize_number,
+    deserialize_option_number,
     deserialize_string_to_u64,
 };
+use crate::v5::types::Number;
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InverseTickersResult {
     list: Vec<InverseTickers>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl InverseTickersResult {
     pub fn list(&self) -> &Vec<InverseTickers> {
@@ -21,58 +36,68 @@ impl InverseTickersResult {
     pub fn set_list(&mut self, list: Vec<InverseTickers>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InverseTickers {
     symbol: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    last_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    index_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    mark_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    prev_price24h: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    price24h_pcnt: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    high_price24h: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    low_price24h: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    prev_price1h: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    open_interest: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    open_interest_value: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    turnover24h: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    volume24h: f64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    funding_rate: Option<f64>,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    last_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    index_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    mark_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    prev_price24h: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    price24h_pcnt: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    high_price24h: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    low_price24h: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    prev_price1h: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    open_interest: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    open_interest_value: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    turnover24h: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    volume24h: Number,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    funding_rate: Option<Number>,
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     next_funding_time: u64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    predicted_delivery_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    basis_rate: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    delivery_fee_rate: Option<f64>,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    predicted_delivery_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    basis_rate: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    delivery_fee_rate: Option<Number>,
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     delivery_time: u64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    ask1_size: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    bid1_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    ask1_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    bid1_size: f64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    basis: Option<f64>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    ask1_size: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    bid1_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    ask1_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    bid1_size: Number,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    basis: Option<Number>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl InverseTickers {
@@ -84,107 +109,107 @@ impl InverseTickers {
         self.symbol = symbol;
     }
 
-    pub fn last_price(&self) -> f64 {
+    pub fn last_price(&self) -> Number {
         self.last_price
     }
 
-    pub fn set_last_price(&mut self, last_price: f64) {
+    pub fn set_last_price(&mut self, last_price: Number) {
         self.last_price = last_price;
     }
 
-    pub fn index_price(&self) -> f64 {
+    pub fn index_price(&self) -> Number {
         self.index_price
     }
 
-    pub fn set_index_price(&mut self, index_price: f64) {
+    pub fn set_index_price(&mut self, index_price: Number) {
         self.index_price = index_price;
     }
 
-    pub fn mark_price(&self) -> f64 {
+    pub fn mark_price(&self) -> Number {
         self.mark_price
     }
 
-    pub fn set_mark_price(&mut self, mark_price: f64) {
+    pub fn set_mark_price(&mut self, mark_price: Number) {
         self.mark_price = mark_price;
     }
 
-    pub fn prev_price24h(&self) -> f64 {
+    pub fn prev_price24h(&self) -> Number {
         self.prev_price24h
     }
 
-    pub fn set_prev_price24h(&mut self, prev_price24h: f64) {
+    pub fn set_prev_price24h(&mut self, prev_price24h: Number) {
         self.prev_price24h = prev_price24h;
     }
 
-    pub fn price24h_pcnt(&self) -> f64 {
+    pub fn price24h_pcnt(&self) -> Number {
         self.price24h_pcnt
     }
 
-    pub fn set_price24h_pcnt(&mut self, price24h_pcnt: f64) {
+    pub fn set_price24h_pcnt(&mut self, price24h_pcnt: Number) {
         self.price24h_pcnt = price24h_pcnt;
     }
 
-    pub fn high_price24h(&self) -> f64 {
+    pub fn high_price24h(&self) -> Number {
         self.high_price24h
     }
 
-    pub fn set_high_price24h(&mut self, high_price24h: f64) {
+    pub fn set_high_price24h(&mut self, high_price24h: Number) {
         self.high_price24h = high_price24h;
     }
 
-    pub fn low_price24h(&self) -> f64 {
+    pub fn low_price24h(&self) -> Number {
         self.low_price24h
     }
 
-    pub fn set_low_price24h(&mut self, low_price24h: f64) {
+    pub fn set_low_price24h(&mut self, low_price24h: Number) {
         self.low_price24h = low_price24h;
     }
 
-    pub fn prev_price1h(&self) -> f64 {
+    pub fn prev_price1h(&self) -> Number {
         self.prev_price1h
     }
 
-    pub fn set_prev_price1h(&mut self, prev_price1h: f64) {
+    pub fn set_prev_price1h(&mut self, prev_price1h: Number) {
         self.prev_price1h = prev_price1h;
     }
 
-    pub fn open_interest(&self) -> f64 {
+    pub fn open_interest(&self) -> Number {
         self.open_interest
     }
 
-    pub fn set_open_interest(&mut self, open_interest: f64) {
+    pub fn set_open_interest(&mut self, open_interest: Number) {
         self.open_interest = open_interest;
     }
 
-    pub fn open_interest_value(&self) -> f64 {
+    pub fn open_interest_value(&self) -> Number {
         self.open_interest_value
     }
 
-    pub fn set_open_interest_value(&mut self, open_interest_value: f64) {
+    pub fn set_open_interest_value(&mut self, open_interest_value: Number) {
         self.open_interest_value = open_interest_value;
     }
 
-    pub fn turnover24h(&self) -> f64 {
+    pub fn turnover24h(&self) -> Number {
         self.turnover24h
     }
 
-    pub fn set_turnover24h(&mut self, turnover24h: f64) {
+    pub fn set_turnover24h(&mut self, turnover24h: Number) {
         self.turnover24h = turnover24h;
     }
 
-    pub fn volume24h(&self) -> f64 {
+    pub fn volume24h(&self) -> Number {
         self.volume24h
     }
 
-    pub fn set_volume24h(&mut self, volume24h: f64) {
+    pub fn set_volume24h(&mut self, volume24h: Number) {
         self.volume24h = volume24h;
     }
 
-    pub fn funding_rate(&self) -> Option<f64> {
+    pub fn funding_rate(&self) -> Option<Number> {
         self.funding_rate
     }
 
-    pub fn set_funding_rate(&mut self, funding_rate: f64) {
+    pub fn set_funding_rate(&mut self, funding_rate: Number) {
         self.funding_rate = Some(funding_rate);
     }
 
@@ -196,27 +221,31 @@ impl InverseTickers {
         self.next_funding_time = next_funding_time;
     }
 
-    pub fn predicted_delivery_price(&self) -> Option<f64> {
+    pub fn next_funding_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.next_funding_time)
+    }
+
+    pub fn predicted_delivery_price(&self) -> Option<Number> {
         self.predicted_delivery_price
     }
 
-    pub fn set_predicted_delivery_price(&mut self, predicted_delivery_price: f64) {
+    pub fn set_predicted_delivery_price(&mut self, predicted_delivery_price: Number) {
         self.predicted_delivery_price = Some(predicted_delivery_price);
     }
 
-    pub fn basis_rate(&self) -> Option<f64> {
+    pub fn basis_rate(&self) -> Option<Number> {
         self.basis_rate
     }
 
-    pub fn set_basis_rate(&mut self, basis_rate: f64) {
+    pub fn set_basis_rate(&mut self, basis_rate: Number) {
         self.basis_rate = Some(basis_rate);
     }
 
-    pub fn delivery_fee_rate(&self) -> Option<f64> {
+    pub fn delivery_fee_rate(&self) -> Option<Number> {
         self.delivery_fee_rate
     }
 
-    pub fn set_delivery_fee_rate(&mut self, delivery_fee_rate: f64) {
+    pub fn set_delivery_fee_rate(&mut self, delivery_fee_rate: Number) {
         self.delivery_fee_rate = Some(delivery_fee_rate);
     }
 
@@ -228,43 +257,55 @@ impl InverseTickers {
         self.delivery_time = delivery_time;
     }
 
-    pub fn ask1_size(&self) -> f64 {
+    pub fn delivery_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.delivery_time)
+    }
+
+    pub fn ask1_size(&self) -> Number {
         self.ask1_size
     }
 
-    pub fn set_ask1_size(&mut self, ask1_size: f64) {
+    pub fn set_ask1_size(&mut self, ask1_size: Number) {
         self.ask1_size = ask1_size;
     }
 
-    pub fn bid1_price(&self) -> f64 {
+    pub fn bid1_price(&self) -> Number {
         self.bid1_price
     }
 
-    pub fn set_bid1_price(&mut self, bid1_price: f64) {
+    pub fn set_bid1_price(&mut self, bid1_price: Number) {
         self.bid1_price = bid1_price;
     }
 
-    pub fn ask1_price(&self) -> f64 {
+    pub fn ask1_price(&self) -> Number {
         self.ask1_price
     }
 
-    pub fn set_ask1_price(&mut self, ask1_price: f64) {
+    pub fn set_ask1_price(&mut self, ask1_price: Number) {
         self.ask1_price = ask1_price;
     }
 
-    pub fn bid1_size(&self) -> f64 {
+    pub fn bid1_size(&self) -> Number {
         self.bid1_size
     }
 
-    pub fn set_bid1_size(&mut self, bid1_size: f64) {
+    pub fn set_bid1_size(&mut self, bid1_size: Number) {
         self.bid1_size = bid1_size;
     }
 
-    pub fn basis(&self) -> Option<f64> {
+    pub fn basis(&self) -> Option<Number> {
         self.basis
     }
 
-    pub fn set_basis(&mut self, basis: f64) {
+    pub fn set_basis(&mut self, basis: Number) {
         self.basis = Some(basis);
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -2,12 +2,27 @@ use serde::{
     Deserialize,
     Serialize,
 };
-use crate::utils::deserialize_f64;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::utils::{
+    deserialize_number,
+    serialize_as_string,
+};
+use crate::v5::types::Number;
+use crate::v5::symbol::{
+    ParseSymbolError,
+    Symbol,
+};
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OptionTickersResult {
     list: Vec<OptionTickers>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl OptionTickersResult {
     pub fn list(&self) -> &Vec<OptionTickers> {
@@ -17,60 +32,70 @@ impl OptionTickersResult {
     pub fn set_list(&mut self, list: Vec<OptionTickers>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OptionTickers {
     symbol: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    bid1_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    bid1_size: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    bid1_iv: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    ask1_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    ask1_size: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    ask1_iv: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    last_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    high_price24h: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    low_price24h: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    mark_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    index_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    mark_iv: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    underlying_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    open_interest: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    turnover24h: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    volume24h: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    total_volume: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    total_turnover: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    delta: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    gamma: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    vega: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    theta: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    predicted_delivery_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    change24h: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    bid1_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    bid1_size: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    bid1_iv: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    ask1_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    ask1_size: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    ask1_iv: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    last_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    high_price24h: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    low_price24h: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    mark_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    index_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    mark_iv: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    underlying_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    open_interest: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    turnover24h: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    volume24h: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    total_volume: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    total_turnover: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    delta: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    gamma: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    vega: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    theta: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    predicted_delivery_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    change24h: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl OptionTickers {
@@ -81,196 +106,208 @@ impl OptionTickers {
     pub fn set_symbol(&mut self, symbol: String) {
         self.symbol = symbol;
     }
+
+    pub fn parsed_symbol(&self) -> Result<Symbol, ParseSymbolError> {
+        self.symbol.parse()
+    }
     
-    pub fn bid1_price(&self) -> f64 {
+    pub fn bid1_price(&self) -> Number {
         self.bid1_price
     }
 
-    pub fn set_bid1_price(&mut self, bid1_price: f64) {
+    pub fn set_bid1_price(&mut self, bid1_price: Number) {
         self.bid1_price = bid1_price;
     }
 
-    pub fn bid1_size(&self) -> f64 {
+    pub fn bid1_size(&self) -> Number {
         self.bid1_size
     }
 
-    pub fn set_bid1_size(&mut self, bid1_size: f64) {
+    pub fn set_bid1_size(&mut self, bid1_size: Number) {
         self.bid1_size = bid1_size;
     }
 
-    pub fn bid1_iv(&self) -> f64 {
+    pub fn bid1_iv(&self) -> Number {
         self.bid1_iv
     }
 
-    pub fn set_bid1_iv(&mut self, bid1_iv: f64) {
+    pub fn set_bid1_iv(&mut self, bid1_iv: Number) {
         self.bid1_iv = bid1_iv;
     }
 
-    pub fn ask1_price(&self) -> f64 {
+    pub fn ask1_price(&self) -> Number {
         self.ask1_price
     }
 
-    pub fn set_ask1_price(&mut self, ask1_price: f64) {
+    pub fn set_ask1_price(&mut self, ask1_price: Number) {
         self.ask1_price = ask1_price;
     }
 
-    pub fn ask1_size(&self) -> f64 {
+    pub fn ask1_size(&self) -> Number {
         self.ask1_size
     }
 
-    pub fn set_ask1_size(&mut self, ask1_size: f64) {
+    pub fn set_ask1_size(&mut self, ask1_size: Number) {
         self.ask1_size = ask1_size;
     }
 
-    pub fn ask1_iv(&self) -> f64 {
+    pub fn ask1_iv(&self) -> Number {
         self.ask1_iv
     }
 
-    pub fn set_ask1_iv(&mut self, ask1_iv: f64) {
+    pub fn set_ask1_iv(&mut self, ask1_iv: Number) {
         self.ask1_iv = ask1_iv;
     }
 
-    pub fn last_price(&self) -> f64 {
+    pub fn last_price(&self) -> Number {
         self.last_price
     }
 
-    pub fn set_last_price(&mut self, last_price: f64) {
+    pub fn set_last_price(&mut self, last_price: Number) {
         self.last_price = last_price;
     }
 
-    pub fn high_price24h(&self) -> f64 {
+    pub fn high_price24h(&self) -> Number {
         self.high_price24h
     }
 
-    pub fn set_high_price24h(&mut self, high_price24h: f64) {
+    pub fn set_high_price24h(&mut self, high_price24h: Number) {
         self.high_price24h = high_price24h;
     }
 
-    pub fn low_price24h(&self) -> f64 {
+    pub fn low_price24h(&self) -> Number {
         self.low_price24h
     }
 
-    pub fn set_low_price24h(&mut self, low_price24h: f64) {
+    pub fn set_low_price24h(&mut self, low_price24h: Number) {
         self.low_price24h = low_price24h;
     }
 
-    pub fn mark_price(&self) -> f64 {
+    pub fn mark_price(&self) -> Number {
         self.mark_price
     }
 
-    pub fn set_mark_price(&mut self, mark_price: f64) {
+    pub fn set_mark_price(&mut self, mark_price: Number) {
         self.mark_price = mark_price;
     }
 
-    pub fn index_price(&self) -> f64 {
+    pub fn index_price(&self) -> Number {
         self.index_price
     }
 
-    pub fn set_index_price(&mut self, index_price: f64) {
+    pub fn set_index_price(&mut self, index_price: Number) {
         self.index_price = index_price;
     }
 
-    pub fn mark_iv(&self) -> f64 {
+    pub fn mark_iv(&self) -> Number {
         self.mark_iv
     }
 
-    pub fn set_mark_iv(&mut self, mark_iv: f64) {
+    pub fn set_mark_iv(&mut self, mark_iv: Number) {
         self.mark_iv = mark_iv;
     }
 
-    pub fn underlying_price(&self) -> f64 {
+    pub fn underlying_price(&self) -> Number {
         self.underlying_price
     }
 
-    pub fn set_underlying_price(&mut self, underlying_price: f64) {
+    pub fn set_underlying_price(&mut self, underlying_price: Number) {
         self.underlying_price = underlying_price;
     }
 
-    pub fn open_interest(&self) -> f64 {
+    pub fn open_interest(&self) -> Number {
         self.open_interest
     }
 
-    pub fn set_open_interest(&mut self, open_interest: f64) {
+    pub fn set_open_interest(&mut self, open_interest: Number) {
         self.open_interest = open_interest;
     }
 
-    pub fn turnover24h(&self) -> f64 {
+    pub fn turnover24h(&self) -> Number {
         self.turnover24h
     }
 
-    pub fn set_turnover24h(&mut self, turnover24h: f64) {
+    pub fn set_turnover24h(&mut self, turnover24h: Number) {
         self.turnover24h = turnover24h;
     }
 
-    pub fn volume24h(&self) -> f64 {
+    pub fn volume24h(&self) -> Number {
         self.volume24h
     }
 
-    pub fn set_volume24h(&mut self, volume24h: f64) {
+    pub fn set_volume24h(&mut self, volume24h: Number) {
         self.volume24h = volume24h;
     }
 
-    pub fn total_volume(&self) -> f64 {
+    pub fn total_volume(&self) -> Number {
         self.total_volume
     }
 
-    pub fn set_total_volume(&mut self, total_volume: f64) {
+    pub fn set_total_volume(&mut self, total_volume: Number) {
         self.total_volume = total_volume;
     }
 
-    pub fn total_turnover(&self) -> f64 {
+    pub fn total_turnover(&self) -> Number {
         self.total_turnover
     }
 
-    pub fn set_total_turnover(&mut self, total_turnover: f64) {
+    pub fn set_total_turnover(&mut self, total_turnover: Number) {
         self.total_turnover = total_turnover;
     }
 
-    pub fn delta(&self) -> f64 {
+    pub fn delta(&self) -> Number {
         self.delta
     }
 
-    pub fn set_delta(&mut self, delta: f64) {
+    pub fn set_delta(&mut self, delta: Number) {
         self.delta = delta;
     }
 
-    pub fn gamma(&self) -> f64 {
+    pub fn gamma(&self) -> Number {
         self.gamma
     }
 
-    pub fn set_gamma(&mut self, gamma: f64) {
+    pub fn set_gamma(&mut self, gamma: Number) {
         self.gamma = gamma;
     }
 
-    pub fn vega(&self) -> f64 {
+    pub fn vega(&self) -> Number {
         self.vega
     }
 
-    pub fn set_vega(&mut self, vega: f64) {
+    pub fn set_vega(&mut self, vega: Number) {
         self.vega = vega;
     }
 
-    pub fn theta(&self) -> f64 {
+    pub fn theta(&self) -> Number {
         self.theta
     }
 
-    pub fn set_theta(&mut self, theta: f64) {
+    pub fn set_theta(&mut self, theta: Number) {
         self.theta = theta;
     }
 
-    pub fn predicted_delivery_price(&self) -> f64 {
+    pub fn predicted_delivery_price(&self) -> Number {
         self.predicted_delivery_price
     }
 
-    pub fn set_predicted_delivery_price(&mut self, predicted_delivery_price: f64) {
+    pub fn set_predicted_delivery_price(&mut self, predicted_delivery_price: Number) {
         self.predicted_delivery_price = predicted_delivery_price;
     }
 
-    pub fn change24h(&self) -> f64 {
+    pub fn change24h(&self) -> Number {
         self.change24h
     }
 
-    pub fn set_change24h(&mut self, change24h: f64) {
+    pub fn set_change24h(&mut self, change24h: Number) {
         self.change24h = change24h;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -2,15 +2,25 @@ use serde::{
     Deserialize,
     Serialize,
 };
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
 use crate::utils::{
-    deserialize_f64,
-    deserialize_option_f64,
+    serialize_as_string,
+    serialize_option_as_string,
+    deserialize_number,
+    deserialize_option_number,
 };
+use crate::v5::types::Number;
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotTickersResult {
     list: Vec<SpotTickers>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl SpotTickersResult {
     pub fn list(&self) -> &Vec<SpotTickers> {
@@ -20,36 +30,46 @@ impl SpotTickersResult {
     pub fn set_list(&mut self, list: Vec<SpotTickers>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotTickers {
     symbol: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    bid1_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    bid1_size: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    ask1_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    ask1_size: Option<f64>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    last_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    prev_price24h: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    price24h_pcnt: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    high_price24h: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    low_price24h: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    turnover24h: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    volume24h: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    usd_index_price: f64,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    bid1_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    bid1_size: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    ask1_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    ask1_size: Option<Number>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    last_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    prev_price24h: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    price24h_pcnt: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    high_price24h: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    low_price24h: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    turnover24h: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    volume24h: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    usd_index_price: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl SpotTickers {
@@ -61,99 +81,107 @@ impl SpotTickers {
         self.symbol = symbol;
     }
 
-    pub fn bid1_price(&self) -> Option<f64> {
+    pub fn bid1_price(&self) -> Option<Number> {
         self.bid1_price
     }
 
-    pub fn set_bid1_price(&mut self, bid1_price: f64) {
+    pub fn set_bid1_price(&mut self, bid1_price: Number) {
         self.bid1_price = Some(bid1_price);
     }
 
-    pub fn bid1_size(&self) -> Option<f64> {
+    pub fn bid1_size(&self) -> Option<Number> {
         self.bid1_size
     }
 
-    pub fn set_bid1_size(&mut self, bid1_size: f64) {
+    pub fn set_bid1_size(&mut self, bid1_size: Number) {
         self.bid1_size = Some(bid1_size);
     }
 
-    pub fn ask1_price(&self) -> Option<f64> {
+    pub fn ask1_price(&self) -> Option<Number> {
         self.ask1_price
     }
 
-    pub fn set_ask1_price(&mut self, ask1_price: f64) {
+    pub fn set_ask1_price(&mut self, ask1_price: Number) {
         self.ask1_price = Some(ask1_price);
     }
 
-    pub fn ask1_size(&self) -> Option<f64> {
+    pub fn ask1_size(&self) -> Option<Number> {
         self.ask1_size
     }
 
-    pub fn set_ask1_size(&mut self, ask1_size: f64) {
+    pub fn set_ask1_size(&mut self, ask1_size: Number) {
         self.ask1_size = Some(ask1_size);
     }
 
-    pub fn last_price(&self) -> f64 {
+    pub fn last_price(&self) -> Number {
         self.last_price
     }
 
-    pub fn set_last_price(&mut self, last_price: f64) {
+    pub fn set_last_price(&mut self, last_price: Number) {
         self.last_price = last_price;
     }
 
-    pub fn prev_price24h(&self) -> f64 {
+    pub fn prev_price24h(&self) -> Number {
         self.prev_price24h
     }
 
-    pub fn set_prev_price24h(&mut self, prev_price24h: f64) {
+    pub fn set_prev_price24h(&mut self, prev_price24h: Number) {
         self.prev_price24h = prev_price24h;
     }
 
-    pub fn price24h_pcnt(&self) -> f64 {
+    pub fn price24h_pcnt(&self) -> Number {
         self.price24h_pcnt
     }
 
-    pub fn set_price24h_pcnt(&mut self, price24h_pcnt: f64) {
+    pub fn set_price24h_pcnt(&mut self, price24h_pcnt: Number) {
         self.price24h_pcnt = price24h_pcnt;
     }
 
-    pub fn high_price24h(&self) -> f64 {
+    pub fn high_price24h(&self) -> Number {
         self.high_price24h
     }
 
-    pub fn set_high_price24h(&mut self, high_price24h: f64) {
+    pub fn set_high_price24h(&mut self, high_price24h: Number) {
         self.high_price24h = high_price24h;
     }
 
-    pub fn low_price24h(&self) -> f64 {
+    pub fn low_price24h(&self) -> Number {
         self.low_price24h
     }
 
-    pub fn set_low_price24h(&mut self, low_price24h: f64) {
+    pub fn set_low_price24h(&mut self, low_price24h: Number) {
         self.low_price24h = low_price24h;
     }
 
-    pub fn turnover24h(&self) -> f64 {
+    pub fn turnover24h(&self) -> Number {
         self.turnover24h
     }
 
-    pub fn set_turnover24h(&mut self, turnover24h: f64) {
+    pub fn set_turnover24h(&mut self, turnover24h: Number) {
         self.turnover24h = turnover24h;
     }
 
-    pub fn volume24h(&self) -> f64 {
+    pub fn volume24h(&self) -> Number {
         self.volume24h
     }
 
-    pub fn set_volume24h(&mut self, volume24h: f64) {
+    pub fn set_volume24h(&mut self, volume24h: Number) {
         self.volume24h = volume24h;
     }
 
-    pub fn usd_index_price(&self) -> f64 {
+    pub fn usd_index_price(&self) -> Number {
         self.usd_index_price
     }
 
-    pub fn set_usd_index_price(&mut self, usd_index_price: f64) {
+    pub fn set_usd_index_price(&mut self, usd_index_price: Number) {
         self.usd_index_price = usd_index_price;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
@@ -1,10 +1,27 @@
+use chrono::{
+    DateTime,
+    Duration,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        LinearInverse,
+    },
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::{
+        Side,
+        OrderType,
+        Number,
+    },
     utils::{
-        deserialize_f64,
+        serialize_as_string,
+        datetime_to_millis,
+        millis_to_datetime,
+        deserialize_number,
         deserialize_string_to_u64,
     },
 };
@@ -13,10 +30,25 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
+use crate::v5::api::pagination::{
+    paginate,
+    paginate_time_range,
+    TimeRange,
+};
+use futures_util::{
+    FutureExt,
+    stream::BoxStream,
+};
 
-const PATH: &'static str = "/v5/position/closed-pnl";
+const PATH: &str = "/v5/position/closed-pnl";
+// startTime と endTime の間隔の上限
+const MAX_TIME_RANGE_DAYS: i64 = 7;
 
 impl BybitApi {
     /// Retrieves the closed pnl based on the provided parameters.
@@ -50,18 +82,61 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_closed_pnl(&self, params: GetClosedPnlParameters) -> Result<GetClosedPnlResponse> {
+    pub async fn get_closed_pnl(&self, params: GetClosedPnlParameters) -> Result<GetClosedPnlResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
-}
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetClosedPnlCategory {
-    Linear,
-    Inverse,
+    /// Gets all closed P&L records, following `next_page_cursor` until the last page.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters of the first request.
+    /// * `max_items` - The maximum number of closed P&L records to yield, or `None` for all of them.
+    ///
+    /// # Returns
+    ///
+    /// A stream of closed P&L records. A failed request is yielded as an error and ends the stream.
+    pub fn get_closed_pnl_stream(&self, params: GetClosedPnlParameters, max_items: Option<usize>) -> BoxStream<'_, Result<ClosedPnl, BybitError>> {
+        paginate(params, max_items, GetClosedPnlParameters::with_cursor, move |params| {
+            async move {
+                let result = self.get_closed_pnl(params).await?.result;
+                Ok((result.list, Some(result.next_page_cursor)))
+            }.boxed()
+        })
+    }
+
+    /// Gets the closed P&L records between `from` and `to`, split into the 7 day windows Bybit accepts.
+    ///
+    /// Each window is paged through with `next_page_cursor`. Closed P&L records returned by two windows are yielded once.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The filters of every request. `start_time`, `end_time` and `cursor` are overwritten.
+    /// * `from` - The start of the range, inclusive.
+    /// * `to` - The end of the range, exclusive.
+    ///
+    /// # Returns
+    ///
+    /// A stream of closed P&L records, oldest first. A failed request is yielded as an error and ends the stream.
+    pub fn get_closed_pnl_range_stream(&self, params: GetClosedPnlParameters, from: DateTime<Utc>, to: DateTime<Utc>) -> BoxStream<'_, Result<ClosedPnl, BybitError>> {
+        let range: TimeRange<GetClosedPnlParameters, ClosedPnl> = TimeRange {
+            with_window: |params, start, end| params.with_start_time(start).with_end_time(end),
+            with_cursor: GetClosedPnlParameters::with_cursor,
+            time: ClosedPnl::created_time,
+            // 1 つの注文に複数の記録が付くことがあるので作成時刻も含める
+            key: |closed_pnl| format!("{}:{}", closed_pnl.order_id, closed_pnl.created_time),
+        };
+        paginate_time_range(params, from, to, Duration::days(MAX_TIME_RANGE_DAYS), range, move |params| {
+            async move {
+                let result = self.get_closed_pnl(params).await?.result;
+                Ok((result.list, Some(result.next_page_cursor)))
+            }.boxed()
+        })
+    }
 }
 
+pub type GetClosedPnlCategory = Category<LinearInverse>;
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetClosedPnlParameters {
@@ -122,6 +197,20 @@ impl GetClosedPnlParameters {
         self
     }
 
+    /// Sets the start time for the execution parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `start time` - The start time to set.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GetExecutionParameters` instance.
+    pub fn with_start_datetime(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(datetime_to_millis(start_time));
+        self
+    }
+
     /// Sets the end time for the execution parameters.
     ///
     /// # Arguments
@@ -136,6 +225,20 @@ impl GetClosedPnlParameters {
         self
     }
 
+    /// Sets the end time for the execution parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `end time` - The end time to set.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GetExecutionParameters` instance.
+    pub fn with_end_datetime(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(datetime_to_millis(end_time));
+        self
+    }
+
     /// Sets the limit for the execution parameters.
     ///
     /// # Arguments
@@ -165,7 +268,7 @@ impl GetClosedPnlParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetClosedPnlResponse {
     ret_code: i32,
@@ -173,6 +276,8 @@ pub struct GetClosedPnlResponse {
     result: ClosedPnlResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetClosedPnlResponse {
     pub fn ret_code(&self) -> i32 {
@@ -214,14 +319,28 @@ impl GetClosedPnlResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ClosedPnlResult {
     category: String,
     next_page_cursor: String,
-    list: Vec<ClosedPnl>
+    list: Vec<ClosedPnl>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl ClosedPnlResult {
     pub fn category(&self) -> &str {
@@ -247,39 +366,49 @@ impl ClosedPnlResult {
     pub fn set_list(&mut self, list: Vec<ClosedPnl>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ClosedPnl {
     symbol: String,
-    order_type: String,
+    order_type: OrderType,
     leverage: String,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     updated_time: u64,
-    side: String,
+    side: Side,
     order_id: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    closed_pnl: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    avg_entry_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    qty: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    cum_entry_value: f64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    closed_pnl: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    avg_entry_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    qty: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    cum_entry_value: Number,
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     created_time: u64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    order_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    closed_size: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    avg_exit_price: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    order_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    closed_size: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    avg_exit_price: Number,
     exec_type: String,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     fill_count: u64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    cum_exit_value: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    cum_exit_value: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl ClosedPnl {
@@ -292,11 +421,11 @@ impl ClosedPnl {
         self.symbol = symbol;
     }
 
-    pub fn order_type(&self) -> &str {
+    pub fn order_type(&self) -> &OrderType {
         &self.order_type
     }
 
-    pub fn set_order_type(&mut self, order_type: String) {
+    pub fn set_order_type(&mut self, order_type: OrderType) {
         self.order_type = order_type;
     }
 
@@ -316,11 +445,15 @@ impl ClosedPnl {
         self.updated_time = updated_time;
     }
 
-    pub fn side(&self) -> &str {
+    pub fn updated_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.updated_time)
+    }
+
+    pub fn side(&self) -> &Side {
         &self.side
     }
 
-    pub fn set_side(&mut self, side: String) {
+    pub fn set_side(&mut self, side: Side) {
         self.side = side;
     }
 
@@ -332,35 +465,35 @@ impl ClosedPnl {
         self.order_id = order_id;
     }
 
-    pub fn closed_pnl(&self) -> f64 {
+    pub fn closed_pnl(&self) -> Number {
         self.closed_pnl
     }
 
-    pub fn set_closed_pnl(&mut self, closed_pnl: f64) {
+    pub fn set_closed_pnl(&mut self, closed_pnl: Number) {
         self.closed_pnl = closed_pnl;
     }
 
-    pub fn avg_entry_price(&self) -> f64 {
+    pub fn avg_entry_price(&self) -> Number {
         self.avg_entry_price
     }
 
-    pub fn set_avg_entry_price(&mut self, avg_entry_price: f64) {
+    pub fn set_avg_entry_price(&mut self, avg_entry_price: Number) {
         self.avg_entry_price = avg_entry_price;
     }
 
-    pub fn qty(&self) -> f64 {
+    pub fn qty(&self) -> Number {
         self.qty
     }
 
-    pub fn set_qty(&mut self, qty: f64) {
+    pub fn set_qty(&mut self, qty: Number) {
         self.qty = qty;
     }
 
-    pub fn cum_entry_value(&self) -> f64 {
+    pub fn cum_entry_value(&self) -> Number {
         self.cum_entry_value
     }
 
-    pub fn set_cum_entry_value(&mut self, cum_entry_value: f64) {
+    pub fn set_cum_entry_value(&mut self, cum_entry_value: Number) {
         self.cum_entry_value = cum_entry_value;
     }
 
@@ -372,27 +505,31 @@ impl ClosedPnl {
         self.created_time = created_time;
     }
 
-    pub fn order_price(&self) -> f64 {
+    pub fn created_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.created_time)
+    }
+
+    pub fn order_price(&self) -> Number {
         self.order_price
     }
 
-    pub fn set_order_price(&mut self, order_price: f64) {
+    pub fn set_order_price(&mut self, order_price: Number) {
         self.order_price = order_price;
     }
 
-    pub fn closed_size(&self) -> f64 {
+    pub fn closed_size(&self) -> Number {
         self.closed_size
     }
 
-    pub fn set_closed_size(&mut self, closed_size: f64) {
+    pub fn set_closed_size(&mut self, closed_size: Number) {
         self.closed_size = closed_size;
     }
 
-    pub fn avg_exit_price(&self) -> f64 {
+    pub fn avg_exit_price(&self) -> Number {
         self.avg_exit_price
     }
 
-    pub fn set_avg_exit_price(&mut self, avg_exit_price: f64) {
+    pub fn set_avg_exit_price(&mut self, avg_exit_price: Number) {
         self.avg_exit_price = avg_exit_price;
     }
 
@@ -412,12 +549,20 @@ impl ClosedPnl {
         self.fill_count = fill_count;
     }
 
-    pub fn cum_exit_value(&self) -> f64 {
+    pub fn cum_exit_value(&self) -> Number {
         self.cum_exit_value
     }
 
-    pub fn set_cum_exit_value(&mut self, cum_exit_value: f64) {
+    pub fn set_cum_exit_value(&mut self, cum_exit_value: Number) {
         self.cum_exit_value = cum_exit_value;
     }
 
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
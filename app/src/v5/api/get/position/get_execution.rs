@@ -1,12 +1,29 @@
+use chrono::{
+    DateTime,
+    Duration,
+    Utc,
+};
 use crate::{
+    v5::category::Category,
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::{
+        Side,
+        OrderType,
+        StopOrderType,
+        Number,
+    },
     utils::{
-        deserialize_f64,
+        serialize_as_string,
+        serialize_option_as_string,
+        datetime_to_millis,
+        millis_to_datetime,
+        deserialize_number,
         deserialize_string_to_u64,
-        deserialize_option_f64,
+        deserialize_option_number,
+        deserialize_option_enum,
     },
 };
 
@@ -14,10 +31,25 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
+use crate::v5::api::pagination::{
+    paginate,
+    paginate_time_range,
+    TimeRange,
+};
+use futures_util::{
+    FutureExt,
+    stream::BoxStream,
+};
 
-const PATH: &'static str = "/v5/execution/list";
+const PATH: &str = "/v5/execution/list";
+// startTime と endTime の間隔の上限
+const MAX_TIME_RANGE_DAYS: i64 = 7;
 
 impl BybitApi {
     /// Retrieves the execution based on the provided parameters.
@@ -51,20 +83,60 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_execution(&self, params: GetExecutionParameters) -> Result<GetExecutionResponse> {
+    pub async fn get_execution(&self, params: GetExecutionParameters) -> Result<GetExecutionResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
-}
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetExecutionCategory {
-    Linear,
-    Inverse,
-    Option,
-    Spot,
+    /// Gets all executions, following `next_page_cursor` until the last page.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters of the first request.
+    /// * `max_items` - The maximum number of executions to yield, or `None` for all of them.
+    ///
+    /// # Returns
+    ///
+    /// A stream of executions. A failed request is yielded as an error and ends the stream.
+    pub fn get_execution_stream(&self, params: GetExecutionParameters, max_items: Option<usize>) -> BoxStream<'_, Result<Execution, BybitError>> {
+        paginate(params, max_items, GetExecutionParameters::with_cursor, move |params| {
+            async move {
+                let result = self.get_execution(params).await?.result;
+                Ok((result.list, Some(result.next_page_cursor)))
+            }.boxed()
+        })
+    }
+
+    /// Gets the executions between `from` and `to`, split into the 7 day windows Bybit accepts.
+    ///
+    /// Each window is paged through with `next_page_cursor`. Executions returned by two windows are yielded once.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The filters of every request. `start_time`, `end_time` and `cursor` are overwritten.
+    /// * `from` - The start of the range, inclusive.
+    /// * `to` - The end of the range, exclusive.
+    ///
+    /// # Returns
+    ///
+    /// A stream of executions, oldest first. A failed request is yielded as an error and ends the stream.
+    pub fn get_execution_range_stream(&self, params: GetExecutionParameters, from: DateTime<Utc>, to: DateTime<Utc>) -> BoxStream<'_, Result<Execution, BybitError>> {
+        let range: TimeRange<GetExecutionParameters, Execution> = TimeRange {
+            with_window: |params, start, end| params.with_start_time(start).with_end_time(end),
+            with_cursor: GetExecutionParameters::with_cursor,
+            time: Execution::exec_time,
+            key: |execution| execution.exec_id.clone(),
+        };
+        paginate_time_range(params, from, to, Duration::days(MAX_TIME_RANGE_DAYS), range, move |params| {
+            async move {
+                let result = self.get_execution(params).await?.result;
+                Ok((result.list, Some(result.next_page_cursor)))
+            }.boxed()
+        })
+    }
 }
 
+pub type GetExecutionCategory = Category;
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetExecutionParameters {
@@ -175,6 +247,20 @@ impl GetExecutionParameters {
         self
     }
 
+    /// Sets the start time for the execution parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `start time` - The start time to set.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GetExecutionParameters` instance.
+    pub fn with_start_datetime(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(datetime_to_millis(start_time));
+        self
+    }
+
     /// Sets the end time for the execution parameters.
     ///
     /// # Arguments
@@ -189,6 +275,20 @@ impl GetExecutionParameters {
         self
     }
 
+    /// Sets the end time for the execution parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `end time` - The end time to set.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GetExecutionParameters` instance.
+    pub fn with_end_datetime(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(datetime_to_millis(end_time));
+        self
+    }
+
     /// Sets the exec type for the execution parameters.
     ///
     /// # Arguments
@@ -232,7 +332,7 @@ impl GetExecutionParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetExecutionResponse {
     ret_code: i32,
@@ -240,6 +340,8 @@ pub struct GetExecutionResponse {
     result: ExecutionResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetExecutionResponse {
     pub fn ret_code(&self) -> i32 {
@@ -281,14 +383,28 @@ impl GetExecutionResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ExecutionResult {
     category: String,
     next_page_cursor: String,
-    list: Vec<Execution>
+    list: Vec<Execution>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl ExecutionResult {
     pub fn category(&self) -> &str {
@@ -314,49 +430,60 @@ impl ExecutionResult {
     pub fn set_list(&mut self, list: Vec<Execution>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Execution {
     symbol: String,
-    order_type: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    underlying_price: Option<f64>,
+    order_type: OrderType,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    underlying_price: Option<Number>,
     order_link_id: Option<String>,
-    side: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    index_price: Option<f64>,
+    side: Side,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    index_price: Option<Number>,
     order_id: String,
-    stop_order_type: String,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    stop_order_type: Option<StopOrderType>,
     leaves_qty: String,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     exec_time: u64,
     is_maker: bool,
-    #[serde(deserialize_with = "deserialize_f64")]
-    exec_fee: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    fee_rate: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    exec_fee: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    fee_rate: Number,
     exec_id: String,
     trade_iv: Option<String>,
     block_trade_id: Option<String>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    mark_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    exec_price: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    mark_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    exec_price: Number,
     mark_iv: Option<String>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    order_qty: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    order_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    exec_value: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    order_qty: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    order_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    exec_value: Number,
     exec_type: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    exec_qty: f64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    closed_size: Option<f64>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    exec_qty: Number,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    closed_size: Option<Number>,
     seq: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl Execution {
@@ -368,19 +495,19 @@ impl Execution {
         self.symbol = symbol;
     }
 
-    pub fn order_type(&self) -> &str {
+    pub fn order_type(&self) -> &OrderType {
         &self.order_type
     }
 
-    pub fn set_order_type(&mut self, order_type: String) {
+    pub fn set_order_type(&mut self, order_type: OrderType) {
         self.order_type = order_type;
     }
 
-    pub fn underlying_price(&self) -> Option<f64> {
+    pub fn underlying_price(&self) -> Option<Number> {
         self.underlying_price
     }
 
-    pub fn set_underlying_price(&mut self, underlying_price: Option<f64>) {
+    pub fn set_underlying_price(&mut self, underlying_price: Option<Number>) {
         self.underlying_price = underlying_price;
     }
 
@@ -392,19 +519,19 @@ impl Execution {
         self.order_link_id = order_link_id;
     }
 
-    pub fn side(&self) -> &str {
+    pub fn side(&self) -> &Side {
         &self.side
     }
 
-    pub fn set_side(&mut self, side: String) {
+    pub fn set_side(&mut self, side: Side) {
         self.side = side;
     }
 
-    pub fn index_price(&self) -> Option<f64> {
+    pub fn index_price(&self) -> Option<Number> {
         self.index_price
     }
 
-    pub fn set_index_price(&mut self, index_price: Option<f64>) {
+    pub fn set_index_price(&mut self, index_price: Option<Number>) {
         self.index_price = index_price;
     }
 
@@ -416,12 +543,12 @@ impl Execution {
         self.order_id = order_id;
     }
 
-    pub fn stop_order_type(&self) -> &str {
-        &self.stop_order_type
+    pub fn stop_order_type(&self) -> Option<&StopOrderType> {
+        self.stop_order_type.as_ref()
     }
 
-    pub fn set_stop_order_type(&mut self, stop_order_type: String) {
-        self.stop_order_type = stop_order_type;
+    pub fn set_stop_order_type(&mut self, stop_order_type: StopOrderType) {
+        self.stop_order_type = Some(stop_order_type);
     }
 
     pub fn leaves_qty(&self) -> &str {
@@ -440,6 +567,10 @@ impl Execution {
         self.exec_time = exec_time;
     }
 
+    pub fn exec_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.exec_time)
+    }
+
     pub fn is_maker(&self) -> bool {
         self.is_maker
     }
@@ -448,19 +579,19 @@ impl Execution {
         self.is_maker = is_maker;
     }
 
-    pub fn exec_fee(&self) -> f64 {
+    pub fn exec_fee(&self) -> Number {
         self.exec_fee
     }
 
-    pub fn set_exec_fee(&mut self, exec_fee: f64) {
+    pub fn set_exec_fee(&mut self, exec_fee: Number) {
         self.exec_fee = exec_fee;
     }
 
-    pub fn fee_rate(&self) -> f64 {
+    pub fn fee_rate(&self) -> Number {
         self.fee_rate
     }
 
-    pub fn set_fee_rate(&mut self, fee_rate: f64) {
+    pub fn set_fee_rate(&mut self, fee_rate: Number) {
         self.fee_rate = fee_rate;
     }
 
@@ -488,19 +619,19 @@ impl Execution {
         self.block_trade_id = block_trade_id;
     }
 
-    pub fn mark_price(&self) -> f64 {
+    pub fn mark_price(&self) -> Number {
         self.mark_price
     }
 
-    pub fn set_mark_price(&mut self, mark_price: f64) {
+    pub fn set_mark_price(&mut self, mark_price: Number) {
         self.mark_price = mark_price;
     }
 
-    pub fn exec_price(&self) -> f64 {
+    pub fn exec_price(&self) -> Number {
         self.exec_price
     }
 
-    pub fn set_exec_price(&mut self, exec_price: f64) {
+    pub fn set_exec_price(&mut self, exec_price: Number) {
         self.exec_price = exec_price;
     }
 
@@ -512,27 +643,27 @@ impl Execution {
         self.mark_iv = mark_iv;
     }
 
-    pub fn order_qty(&self) -> f64 {
+    pub fn order_qty(&self) -> Number {
         self.order_qty
     }
 
-    pub fn set_order_qty(&mut self, order_qty: f64) {
+    pub fn set_order_qty(&mut self, order_qty: Number) {
         self.order_qty = order_qty;
     }
 
-    pub fn order_price(&self) -> f64 {
+    pub fn order_price(&self) -> Number {
         self.order_price
     }
 
-    pub fn set_order_price(&mut self, order_price: f64) {
+    pub fn set_order_price(&mut self, order_price: Number) {
         self.order_price = order_price;
     }
 
-    pub fn exec_value(&self) -> f64 {
+    pub fn exec_value(&self) -> Number {
         self.exec_value
     }
 
-    pub fn set_exec_value(&mut self, exec_value: f64) {
+    pub fn set_exec_value(&mut self, exec_value: Number) {
         self.exec_value = exec_value;
     }
 
@@ -544,19 +675,19 @@ impl Execution {
         self.exec_type = exec_type;
     }
 
-    pub fn exec_qty(&self) -> f64 {
+    pub fn exec_qty(&self) -> Number {
         self.exec_qty
     }
 
-    pub fn set_exec_qty(&mut self, exec_qty: f64) {
+    pub fn set_exec_qty(&mut self, exec_qty: Number) {
         self.exec_qty = exec_qty;
     }
 
-    pub fn closed_size(&self) -> Option<f64> {
+    pub fn closed_size(&self) -> Option<Number> {
         self.closed_size
     }
 
-    pub fn set_closed_size(&mut self, closed_size: Option<f64>) {
+    pub fn set_closed_size(&mut self, closed_size: Option<Number>) {
         self.closed_size = closed_size;
     }
 
@@ -568,4 +699,12 @@ impl Execution {
         self.seq = seq;
     }
 
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,12 +1,31 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        LinearInverseOption,
+    },
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::{
+        Side,
+        TpslMode,
+        PositionIdx,
+        Number,
+    },
     utils::{
-        deserialize_f64,
+        serialize_as_string,
+        serialize_option_as_string,
+        millis_to_datetime,
+        str_millis_to_datetime,
+        deserialize_number,
         deserialize_string_to_u64,
-        deserialize_option_f64,
+        deserialize_option_number,
+        deserialize_option_enum,
     },
 };
 
@@ -14,10 +33,14 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/position/list";
+const PATH: &str = "/v5/position/list";
 
 impl BybitApi {
     /// Retrieves the position info based on the provided parameters.
@@ -51,18 +74,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_position_info(&self, params: GetPositionInfoParameters) -> Result<GetPositionInfoResponse> {
+    pub async fn get_position_info(&self, params: GetPositionInfoParameters) -> Result<GetPositionInfoResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetPositionInfoCategory {
-    Linear,
-    Inverse,
-    Option,
-}
+pub type GetPositionInfoCategory = Category<LinearInverseOption>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -168,7 +185,7 @@ impl GetPositionInfoParameters {
 
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetPositionInfoResponse {
     ret_code: i32,
@@ -176,6 +193,8 @@ pub struct GetPositionInfoResponse {
     result: PositionInfoResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetPositionInfoResponse {
     pub fn ret_code(&self) -> i32 {
@@ -217,14 +236,28 @@ impl GetPositionInfoResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PositionInfoResult {
     category: String,
     next_page_cursor: String,
-    list: Vec<PositionInfo>
+    list: Vec<PositionInfo>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl PositionInfoResult {
     pub fn category(&self) -> &str {
@@ -250,61 +283,73 @@ impl PositionInfoResult {
     pub fn set_list(&mut self, list: Vec<PositionInfo>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PositionInfo {
-    position_idx: u8,
+    position_idx: PositionIdx,
     risk_id: u64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    risk_limit_value: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    risk_limit_value: Number,
     symbol: String,
-    side: Option<String>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    avg_price: f64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    position_value: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    side: Option<Side>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    avg_price: Number,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    position_value: Option<Number>,
     trade_mode: u8,
     auto_add_margin: u8,
     position_status: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    leverage: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    mark_price: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    leverage: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    mark_price: Number,
     liq_price: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    bust_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    position_m_m: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    position_i_m: f64,
-    tpsl_mode: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    take_profit: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    stop_loss: Option<f64>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    trailing_stop: f64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    unrealised_pnl: Option<f64>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    cum_realised_pnl: f64,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    bust_price: Option<Number>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    position_m_m: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    position_i_m: Number,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    tpsl_mode: Option<TpslMode>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    take_profit: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    stop_loss: Option<Number>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    trailing_stop: Number,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    unrealised_pnl: Option<Number>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    cum_realised_pnl: Number,
     seq: u64,
     is_reduce_only: bool,
     mmr_sys_updated_time: String,
     leverage_sys_updated_time: String,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     created_time: u64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     updated_time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl PositionInfo {
-    pub fn position_idx(&self) -> u8 {
+    pub fn position_idx(&self) -> PositionIdx {
         self.position_idx
     }
 
-    pub fn set_position_idx(&mut self, position_idx: u8) {
+    pub fn set_position_idx(&mut self, position_idx: PositionIdx) {
         self.position_idx = position_idx;
     }
 
@@ -316,11 +361,11 @@ impl PositionInfo {
         self.risk_id = risk_id;
     }
 
-    pub fn risk_limit_value(&self) -> f64 {
+    pub fn risk_limit_value(&self) -> Number {
         self.risk_limit_value
     }
 
-    pub fn set_risk_limit_value(&mut self, risk_limit_value: f64) {
+    pub fn set_risk_limit_value(&mut self, risk_limit_value: Number) {
         self.risk_limit_value = risk_limit_value;
     }
 
@@ -332,27 +377,27 @@ impl PositionInfo {
         self.symbol = symbol;
     }
 
-    pub fn side(&self) -> Option<&str> {
-        self.side.as_deref()
+    pub fn side(&self) -> Option<&Side> {
+        self.side.as_ref()
     }
 
-    pub fn set_side(&mut self, side: Option<String>) {
+    pub fn set_side(&mut self, side: Option<Side>) {
         self.side = side;
     }
 
-    pub fn avg_price(&self) -> f64 {
+    pub fn avg_price(&self) -> Number {
         self.avg_price
     }
 
-    pub fn set_avg_price(&mut self, avg_price: f64) {
+    pub fn set_avg_price(&mut self, avg_price: Number) {
         self.avg_price = avg_price;
     }
 
-    pub fn position_value(&self) -> Option<f64> {
+    pub fn position_value(&self) -> Option<Number> {
         self.position_value
     }
 
-    pub fn set_position_value(&mut self, position_value: f64) {
+    pub fn set_position_value(&mut self, position_value: Number) {
         self.position_value = Some(position_value);
     }
 
@@ -380,19 +425,19 @@ impl PositionInfo {
         self.position_status = position_status;
     }
 
-    pub fn leverage(&self) -> f64 {
+    pub fn leverage(&self) -> Number {
         self.leverage
     }
 
-    pub fn set_leverage(&mut self, leverage: f64) {
+    pub fn set_leverage(&mut self, leverage: Number) {
         self.leverage = leverage;
     }
 
-    pub fn mark_price(&self) -> f64 {
+    pub fn mark_price(&self) -> Number {
         self.mark_price
     }
 
-    pub fn set_mark_price(&mut self, mark_price: f64) {
+    pub fn set_mark_price(&mut self, mark_price: Number) {
         self.mark_price = mark_price;
     }
 
@@ -404,75 +449,75 @@ impl PositionInfo {
         self.liq_price = liq_price;
     }
 
-    pub fn bust_price(&self) -> Option<f64> {
+    pub fn bust_price(&self) -> Option<Number> {
         self.bust_price
     }
 
-    pub fn set_bust_price(&mut self, bust_price: f64) {
+    pub fn set_bust_price(&mut self, bust_price: Number) {
         self.bust_price = Some(bust_price);
     }
 
-    pub fn position_m_m(&self) -> f64 {
+    pub fn position_m_m(&self) -> Number {
         self.position_m_m
     }
 
-    pub fn set_position_m_m(&mut self, position_m_m: f64) {
+    pub fn set_position_m_m(&mut self, position_m_m: Number) {
         self.position_m_m = position_m_m;
     }
 
-    pub fn position_i_m(&self) -> f64 {
+    pub fn position_i_m(&self) -> Number {
         self.position_i_m
     }
 
-    pub fn set_position_i_m(&mut self, position_i_m: f64) {
+    pub fn set_position_i_m(&mut self, position_i_m: Number) {
         self.position_i_m = position_i_m;
     }
 
-    pub fn tpsl_mode(&self) -> &str {
-        &self.tpsl_mode
+    pub fn tpsl_mode(&self) -> Option<&TpslMode> {
+        self.tpsl_mode.as_ref()
     }
 
-    pub fn set_tpsl_mode(&mut self, tpsl_mode: String) {
-        self.tpsl_mode = tpsl_mode;
+    pub fn set_tpsl_mode(&mut self, tpsl_mode: TpslMode) {
+        self.tpsl_mode = Some(tpsl_mode);
     }
 
-    pub fn take_profit(&self) -> Option<f64> {
+    pub fn take_profit(&self) -> Option<Number> {
         self.take_profit
     }
 
-    pub fn set_take_profit(&mut self, take_profit: f64) {
+    pub fn set_take_profit(&mut self, take_profit: Number) {
         self.take_profit = Some(take_profit);
     }
 
-    pub fn stop_loss(&self) -> Option<f64> {
+    pub fn stop_loss(&self) -> Option<Number> {
         self.stop_loss
     }
 
-    pub fn set_stop_loss(&mut self, stop_loss: f64) {
+    pub fn set_stop_loss(&mut self, stop_loss: Number) {
         self.stop_loss = Some(stop_loss);
     }
 
-    pub fn trailing_stop(&self) -> f64 {
+    pub fn trailing_stop(&self) -> Number {
         self.trailing_stop
     }
 
-    pub fn set_trailing_stop(&mut self, trailing_stop: f64) {
+    pub fn set_trailing_stop(&mut self, trailing_stop: Number) {
         self.trailing_stop = trailing_stop;
     }
 
-    pub fn unrealised_pnl(&self) -> Option<f64> {
+    pub fn unrealised_pnl(&self) -> Option<Number> {
         self.unrealised_pnl
     }
 
-    pub fn set_unrealised_pnl(&mut self, unrealised_pnl: f64) {
+    pub fn set_unrealised_pnl(&mut self, unrealised_pnl: Number) {
         self.unrealised_pnl = Some(unrealised_pnl);
     }
 
-    pub fn cum_realised_pnl(&self) -> f64 {
+    pub fn cum_realised_pnl(&self) -> Number {
         self.cum_realised_pnl
     }
 
-    pub fn set_cum_realised_pnl(&mut self, cum_realised_pnl: f64) {
+    pub fn set_cum_realised_pnl(&mut self, cum_realised_pnl: Number) {
         self.cum_realised_pnl = cum_realised_pnl;
     }
 
@@ -500,6 +545,10 @@ impl PositionInfo {
         self.mmr_sys_updated_time = mmr_sys_updated_time;
     }
 
+    pub fn mmr_sys_updated_time_datetime(&self) -> Option<DateTime<Utc>> {
+        str_millis_to_datetime(&self.mmr_sys_updated_time)
+    }
+
     pub fn leverage_sys_updated_time(&self) -> &str {
         &self.leverage_sys_updated_time
     }
@@ -508,6 +557,10 @@ impl PositionInfo {
         self.leverage_sys_updated_time = leverage_sys_updated_time;
     }
 
+    pub fn leverage_sys_updated_time_datetime(&self) -> Option<DateTime<Utc>> {
+        str_millis_to_datetime(&self.leverage_sys_updated_time)
+    }
+
     pub fn created_time(&self) -> u64 {
         self.created_time
     }
@@ -516,6 +569,10 @@ impl PositionInfo {
         self.created_time = created_time;
     }
 
+    pub fn created_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.created_time)
+    }
+
     pub fn updated_time(&self) -> u64 {
         self.updated_time
     }
@@ -523,5 +580,17 @@ impl PositionInfo {
     pub fn set_updated_time(&mut self, updated_time: u64) {
         self.updated_time = updated_time;
     }
+
+    pub fn updated_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.updated_time)
+    }
     
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
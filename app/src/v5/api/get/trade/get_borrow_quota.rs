@@ -1,19 +1,39 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        SpotOnly,
+    },
     v5::api::{
         BybitApi,
         get::Get,
     },
-    utils::deserialize_f64,
+    v5::types::{
+        Side,
+        Number,
+    },
+    utils::{
+        serialize_as_string,
+        deserialize_number,
+        millis_to_datetime,
+    },
 };
 
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/order/spot-borrow-check";
+const PATH: &str = "/v5/order/spot-borrow-check";
 
 impl BybitApi {
     /// Retrieves the borrow quota information based on the provided parameters.
@@ -32,10 +52,11 @@ impl BybitApi {
     ///     },
     ///     BybitApi,
     /// };
+    /// use rsbit::v5::types::Side;
     /// #[tokio::main]
     /// async fn main() {
     ///     let api = BybitApi::new();
-    ///     let params = GetBorrowQuotaParameters::new(GetBorrowQuotaCategory::Spot, "BTCUSDT".to_string(), "Buy".to_string());
+    ///     let params = GetBorrowQuotaParameters::new(GetBorrowQuotaCategory::Spot, "BTCUSDT".to_string(), Side::Buy);
     ///     let response = api.get_borrow_quota(params).await;
     ///     match response {
     ///         Ok(info) => {
@@ -47,23 +68,19 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_borrow_quota(&self, params: GetBorrowQuotaParameters) -> Result<GetBorrowQuotaResponse> {
+    pub async fn get_borrow_quota(&self, params: GetBorrowQuotaParameters) -> Result<GetBorrowQuotaResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetBorrowQuotaCategory {
-    Spot,
-}
+pub type GetBorrowQuotaCategory = Category<SpotOnly>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetBorrowQuotaParameters {
     category: GetBorrowQuotaCategory,
     symbol: String,
-    side: String,
+    side: Side,
 }
 
 impl GetBorrowQuotaParameters {
@@ -78,7 +95,7 @@ impl GetBorrowQuotaParameters {
     /// # Returns
     ///
     /// A new instance of `GetBorrowQuotaParameters`.
-    pub fn new(category: GetBorrowQuotaCategory, symbol: String, side: String) -> Self {
+    pub fn new(category: GetBorrowQuotaCategory, symbol: String, side: Side) -> Self {
         Self {
             category,
             symbol,
@@ -88,7 +105,7 @@ impl GetBorrowQuotaParameters {
 
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetBorrowQuotaResponse {
     ret_code: i32,
@@ -96,6 +113,8 @@ pub struct GetBorrowQuotaResponse {
     result: BorrowQuotaResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetBorrowQuotaResponse {
     pub fn ret_code(&self) -> i32 {
@@ -137,22 +156,36 @@ impl GetBorrowQuotaResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BorrowQuotaResult {
     symbol: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    max_trade_qty: f64,
-    side: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    spot_max_trade_amount: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    max_trade_amount: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    max_trade_qty: Number,
+    side: Side,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    spot_max_trade_amount: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    max_trade_amount: Number,
     borrow_coin: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    spot_max_trade_qty: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    spot_max_trade_qty: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl BorrowQuotaResult {
     pub fn symbol(&self) -> &str {
@@ -163,35 +196,35 @@ impl BorrowQuotaResult {
         self.symbol = symbol;
     }
 
-    pub fn max_trade_qty(&self) -> f64 {
+    pub fn max_trade_qty(&self) -> Number {
         self.max_trade_qty
     }
 
-    pub fn set_max_trade_qty(&mut self, max_trade_qty: f64) {
+    pub fn set_max_trade_qty(&mut self, max_trade_qty: Number) {
         self.max_trade_qty = max_trade_qty;
     }
 
-    pub fn side(&self) -> &str {
+    pub fn side(&self) -> &Side {
         &self.side
     }
 
-    pub fn set_side(&mut self, side: String) {
+    pub fn set_side(&mut self, side: Side) {
         self.side = side;
     }
 
-    pub fn spot_max_trade_amount(&self) -> f64 {
+    pub fn spot_max_trade_amount(&self) -> Number {
         self.spot_max_trade_amount
     }
 
-    pub fn set_spot_max_trade_amount(&mut self, spot_max_trade_amount: f64) {
+    pub fn set_spot_max_trade_amount(&mut self, spot_max_trade_amount: Number) {
         self.spot_max_trade_amount = spot_max_trade_amount;
     }
 
-    pub fn max_trade_amount(&self) -> f64 {
+    pub fn max_trade_amount(&self) -> Number {
         self.max_trade_amount
     }
 
-    pub fn set_max_trade_amount(&mut self, max_trade_amount: f64) {
+    pub fn set_max_trade_amount(&mut self, max_trade_amount: Number) {
         self.max_trade_amount = max_trade_amount;
     }
 
@@ -203,12 +236,20 @@ impl BorrowQuotaResult {
         self.borrow_coin = borrow_coin;
     }
 
-    pub fn spot_max_trade_qty(&self) -> f64 {
+    pub fn spot_max_trade_qty(&self) -> Number {
         self.spot_max_trade_qty
     }
 
-    pub fn set_spot_max_trade_qty(&mut self, spot_max_trade_qty: f64) {
+    pub fn set_spot_max_trade_qty(&mut self, spot_max_trade_qty: Number) {
         self.spot_max_trade_qty = spot_max_trade_qty;
     }
 
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
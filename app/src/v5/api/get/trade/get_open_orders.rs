@@ -1,13 +1,34 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::Category,
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::{
+        Side,
+        OrderType,
+        TimeInForce,
+        TriggerBy,
+        OrderStatus,
+        StopOrderType,
+        CancelType,
+        TpslMode,
+        SmpType,
+        PositionIdx,
+        Number,
+    },
     utils::{
-        deserialize_f64,
-        deserialize_option_f64,
+        serialize_as_string,
+        millis_to_datetime,
+        deserialize_number,
+        deserialize_option_number,
         deserialize_string_to_u64,
         serialize_option_as_string,
+        deserialize_option_enum,
     },
 };
 
@@ -15,10 +36,19 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
+use crate::v5::api::pagination::paginate;
+use futures_util::{
+    FutureExt,
+    stream::BoxStream,
+};
 
-const PATH: &'static str = "/v5/order/realtime";
+const PATH: &str = "/v5/order/realtime";
 
 impl BybitApi {
     /// Retrieves the open order information based on the provided parameters.
@@ -52,20 +82,32 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_open_orders(&self, params: GetOpenOrdersParameters) -> Result<GetOpenOrdersResponse> {
+    pub async fn get_open_orders(&self, params: GetOpenOrdersParameters) -> Result<GetOpenOrdersResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
-}
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetOpenOrdersCategory {
-    Linear,
-    Inverse,
-    Option,
-    Spot,
+    /// Gets all open orders, following `next_page_cursor` until the last page.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters of the first request.
+    /// * `max_items` - The maximum number of open orders to yield, or `None` for all of them.
+    ///
+    /// # Returns
+    ///
+    /// A stream of open orders. A failed request is yielded as an error and ends the stream.
+    pub fn get_open_orders_stream(&self, params: GetOpenOrdersParameters, max_items: Option<usize>) -> BoxStream<'_, Result<OpenOrder, BybitError>> {
+        paginate(params, max_items, GetOpenOrdersParameters::with_cursor, move |params| {
+            async move {
+                let result = self.get_open_orders(params).await?.result;
+                Ok((result.list, Some(result.next_page_cursor)))
+            }.boxed()
+        })
+    }
 }
 
+pub type GetOpenOrdersCategory = Category;
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetOpenOrdersParameters {
@@ -235,7 +277,7 @@ impl GetOpenOrdersParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetOpenOrdersResponse {
     ret_code: i32,
@@ -243,6 +285,8 @@ pub struct GetOpenOrdersResponse {
     result: OpenOrdersResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetOpenOrdersResponse {
     pub fn ret_code(&self) -> i32 {
@@ -284,14 +328,28 @@ impl GetOpenOrdersResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OpenOrdersResult {
     category: String,
     next_page_cursor: String,
-    list: Vec<OpenOrder>
+    list: Vec<OpenOrder>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl OpenOrdersResult {
     pub fn category(&self) -> &str {
@@ -317,63 +375,78 @@ impl OpenOrdersResult {
     pub fn set_list(&mut self, list: Vec<OpenOrder>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OpenOrder {
     order_id: String,
     order_link_id: String,
     block_trade_id: String,
     symbol: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    qty: f64,
-    side: String,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    qty: Number,
+    side: Side,
     is_leverage: String,
-    position_idx: u64,
-    order_status: String,
-    cancel_type: String,
+    position_idx: PositionIdx,
+    order_status: OrderStatus,
+    cancel_type: CancelType,
     reject_reason: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    avg_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    leaves_qty: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    leaves_value: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    cum_exec_qty: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    cum_exec_fee: Option<f64>,
-    time_in_force: String,
-    order_type: String,
-    stop_order_type: String,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    avg_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    leaves_qty: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    leaves_value: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    cum_exec_qty: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    cum_exec_fee: Option<Number>,
+    time_in_force: TimeInForce,
+    order_type: OrderType,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    stop_order_type: Option<StopOrderType>,
     order_iv: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    trigger_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    take_profit: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    stop_loss: Option<f64>,
-    tp_trigger_by: String,
-    sl_trigger_by: String,
-    trigger_direction: f64,
-    trigger_by: String,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    trigger_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    take_profit: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    stop_loss: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    tp_trigger_by: Option<TriggerBy>,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    sl_trigger_by: Option<TriggerBy>,
+    trigger_direction: Number,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    trigger_by: Option<TriggerBy>,
     last_price_on_created: String,
     reduce_only: bool,
     close_on_trigger: bool,
-    smp_type: String,
+    smp_type: SmpType,
     smp_group: u64,
     smp_order_id: String,
-    tpsl_mode: String,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    tpsl_mode: Option<TpslMode>,
     tp_limit_price: String,
     sl_limit_price: String,
     place_type: String,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     created_time: u64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     updated_time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl OpenOrder {
     pub fn order_id(&self) -> &str {
@@ -408,27 +481,27 @@ impl OpenOrder {
         self.symbol = symbol;
     }
 
-    pub fn price(&self) -> f64 {
+    pub fn price(&self) -> Number {
         self.price
     }
 
-    pub fn set_price(&mut self, price: f64) {
+    pub fn set_price(&mut self, price: Number) {
         self.price = price;
     }
 
-    pub fn qty(&self) -> f64 {
+    pub fn qty(&self) -> Number {
         self.qty
     }
 
-    pub fn set_qty(&mut self, qty: f64) {
+    pub fn set_qty(&mut self, qty: Number) {
         self.qty = qty;
     }
 
-    pub fn side(&self) -> &str {
+    pub fn side(&self) -> &Side {
         &self.side
     }
 
-    pub fn set_side(&mut self, side: String) {
+    pub fn set_side(&mut self, side: Side) {
         self.side = side;
     }
 
@@ -440,27 +513,27 @@ impl OpenOrder {
         self.is_leverage = is_leverage;
     }
 
-    pub fn position_idx(&self) -> u64 {
+    pub fn position_idx(&self) -> PositionIdx {
         self.position_idx
     }
 
-    pub fn set_position_idx(&mut self, position_idx: u64) {
+    pub fn set_position_idx(&mut self, position_idx: PositionIdx) {
         self.position_idx = position_idx;
     }
 
-    pub fn order_status(&self) -> &str {
+    pub fn order_status(&self) -> &OrderStatus {
         &self.order_status
     }
 
-    pub fn set_order_status(&mut self, order_status: String) {
+    pub fn set_order_status(&mut self, order_status: OrderStatus) {
         self.order_status = order_status;
     }
 
-    pub fn cancel_type(&self) -> &str {
+    pub fn cancel_type(&self) -> &CancelType {
         &self.cancel_type
     }
 
-    pub fn set_cancel_type(&mut self, cancel_type: String) {
+    pub fn set_cancel_type(&mut self, cancel_type: CancelType) {
         self.cancel_type = cancel_type;
     }
 
@@ -472,68 +545,68 @@ impl OpenOrder {
         self.reject_reason = reject_reason;
     }
 
-    pub fn avg_price(&self) -> Option<f64> {
+    pub fn avg_price(&self) -> Option<Number> {
         self.avg_price
     }
 
-    pub fn set_avg_price(&mut self, avg_price: f64) {
+    pub fn set_avg_price(&mut self, avg_price: Number) {
         self.avg_price = Some(avg_price);
     }
 
-    pub fn leaves_qty(&self) -> Option<f64> {
+    pub fn leaves_qty(&self) -> Option<Number> {
         self.leaves_qty
     }
 
-    pub fn set_leaves_qty(&mut self, leaves_qty: f64) {
+    pub fn set_leaves_qty(&mut self, leaves_qty: Number) {
         self.leaves_qty = Some(leaves_qty);
     }
 
-    pub fn leaves_value(&self) -> Option<f64> {
+    pub fn leaves_value(&self) -> Option<Number> {
         self.leaves_value
     }
 
-    pub fn set_leaves_value(&mut self, leaves_value: f64) {
+    pub fn set_leaves_value(&mut self, leaves_value: Number) {
         self.leaves_value = Some(leaves_value);
     }
 
-    pub fn cum_exec_qty(&self) -> Option<f64> {
+    pub fn cum_exec_qty(&self) -> Option<Number> {
         self.cum_exec_qty
     }
 
-    pub fn set_cum_exec_qty(&mut self, cum_exec_qty: f64) {
+    pub fn set_cum_exec_qty(&mut self, cum_exec_qty: Number) {
         self.cum_exec_qty = Some(cum_exec_qty);
     }
 
-    pub fn cum_exec_fee(&self) -> Option<f64> {
+    pub fn cum_exec_fee(&self) -> Option<Number> {
         self.cum_exec_fee
     }
 
-    pub fn set_cum_exec_fee(&mut self, cum_exec_fee: f64) {
+    pub fn set_cum_exec_fee(&mut self, cum_exec_fee: Number) {
         self.cum_exec_fee = Some(cum_exec_fee);
     }
 
-    pub fn time_in_force(&self) -> &str {
+    pub fn time_in_force(&self) -> &TimeInForce {
         &self.time_in_force
     }
 
-    pub fn set_time_in_force(&mut self, time_in_force: String) {
+    pub fn set_time_in_force(&mut self, time_in_force: TimeInForce) {
         self.time_in_force = time_in_force;
     }
 
-    pub fn order_type(&self) -> &str {
+    pub fn order_type(&self) -> &OrderType {
         &self.order_type
     }
 
-    pub fn set_order_type(&mut self, order_type: String) {
+    pub fn set_order_type(&mut self, order_type: OrderType) {
         self.order_type = order_type;
     }
 
-    pub fn stop_order_type(&self) -> &str {
-        &self.stop_order_type
+    pub fn stop_order_type(&self) -> Option<&StopOrderType> {
+        self.stop_order_type.as_ref()
     }
 
-    pub fn set_stop_order_type(&mut self, stop_order_type: String) {
-        self.stop_order_type = stop_order_type;
+    pub fn set_stop_order_type(&mut self, stop_order_type: StopOrderType) {
+        self.stop_order_type = Some(stop_order_type);
     }
 
     pub fn order_iv(&self) -> &str {
@@ -544,60 +617,60 @@ impl OpenOrder {
         self.order_iv = order_iv;
     }
 
-    pub fn trigger_price(&self) -> Option<f64> {
+    pub fn trigger_price(&self) -> Option<Number> {
         self.trigger_price
     }
 
-    pub fn set_trigger_price(&mut self, trigger_price: f64) {
+    pub fn set_trigger_price(&mut self, trigger_price: Number) {
         self.trigger_price = Some(trigger_price);
     }
 
-    pub fn take_profit(&self) -> Option<f64> {
+    pub fn take_profit(&self) -> Option<Number> {
         self.take_profit
     }
 
-    pub fn set_take_profit(&mut self, take_profit: f64) {
+    pub fn set_take_profit(&mut self, take_profit: Number) {
         self.take_profit = Some(take_profit);
     }
 
-    pub fn stop_loss(&self) -> Option<f64> {
+    pub fn stop_loss(&self) -> Option<Number> {
         self.stop_loss
     }
 
-    pub fn set_stop_loss(&mut self, stop_loss: f64) {
+    pub fn set_stop_loss(&mut self, stop_loss: Number) {
         self.stop_loss = Some(stop_loss);
     }
 
-    pub fn tp_trigger_by(&self) -> &str {
-        &self.tp_trigger_by
+    pub fn tp_trigger_by(&self) -> Option<&TriggerBy> {
+        self.tp_trigger_by.as_ref()
     }
 
-    pub fn set_tp_trigger_by(&mut self, tp_trigger_by: String) {
-        self.tp_trigger_by = tp_trigger_by;
+    pub fn set_tp_trigger_by(&mut self, tp_trigger_by: TriggerBy) {
+        self.tp_trigger_by = Some(tp_trigger_by);
     }
 
-    pub fn sl_trigger_by(&self) -> &str {
-        &self.sl_trigger_by
+    pub fn sl_trigger_by(&self) -> Option<&TriggerBy> {
+        self.sl_trigger_by.as_ref()
     }
 
-    pub fn set_sl_trigger_by(&mut self, sl_trigger_by: String) {
-        self.sl_trigger_by = sl_trigger_by;
+    pub fn set_sl_trigger_by(&mut self, sl_trigger_by: TriggerBy) {
+        self.sl_trigger_by = Some(sl_trigger_by);
     }
 
-    pub fn trigger_direction(&self) -> f64 {
+    pub fn trigger_direction(&self) -> Number {
         self.trigger_direction
     }
 
-    pub fn set_trigger_direction(&mut self, trigger_direction: f64) {
+    pub fn set_trigger_direction(&mut self, trigger_direction: Number) {
         self.trigger_direction = trigger_direction;
     }
 
-    pub fn trigger_by(&self) -> &str {
-        &self.trigger_by
+    pub fn trigger_by(&self) -> Option<&TriggerBy> {
+        self.trigger_by.as_ref()
     }
 
-    pub fn set_trigger_by(&mut self, trigger_by: String) {
-        self.trigger_by = trigger_by;
+    pub fn set_trigger_by(&mut self, trigger_by: TriggerBy) {
+        self.trigger_by = Some(trigger_by);
     }
 
     pub fn last_price_on_created(&self) -> &str {
@@ -624,11 +697,11 @@ impl OpenOrder {
         self.close_on_trigger = close_on_trigger;
     }
 
-    pub fn smp_type(&self) -> &str {
+    pub fn smp_type(&self) -> &SmpType {
         &self.smp_type
     }
 
-    pub fn set_smp_type(&mut self, smp_type: String) {
+    pub fn set_smp_type(&mut self, smp_type: SmpType) {
         self.smp_type = smp_type;
     }
 
@@ -648,12 +721,12 @@ impl OpenOrder {
         self.smp_order_id = smp_order_id;
     }
 
-    pub fn tpsl_mode(&self) -> &str {
-        &self.tpsl_mode
+    pub fn tpsl_mode(&self) -> Option<&TpslMode> {
+        self.tpsl_mode.as_ref()
     }
 
-    pub fn set_tpsl_mode(&mut self, tpsl_mode: String) {
-        self.tpsl_mode = tpsl_mode;
+    pub fn set_tpsl_mode(&mut self, tpsl_mode: TpslMode) {
+        self.tpsl_mode = Some(tpsl_mode);
     }
 
     pub fn tp_limit_price(&self) -> &str {
@@ -688,6 +761,10 @@ impl OpenOrder {
         self.created_time = created_time;
     }
 
+    pub fn created_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.created_time)
+    }
+
     pub fn updated_time(&self) -> u64 {
         self.updated_time
     }
@@ -695,4 +772,16 @@ impl OpenOrder {
     pub fn set_updated_time(&mut self, updated_time: u64) {
         self.updated_time = updated_time;
     }
+
+    pub fn updated_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.updated_time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,12 +1,36 @@
+use chrono::{
+    DateTime,
+    Duration,
+    Utc,
+};
 use crate::{
+    v5::category::Category,
     v5::api::{
         BybitApi,
         get::Get,
     },
+    v5::types::{
+        Side,
+        OrderType,
+        TimeInForce,
+        TriggerBy,
+        OrderStatus,
+        StopOrderType,
+        CancelType,
+        TpslMode,
+        SmpType,
+        PositionIdx,
+        Number,
+    },
     utils::{
-        deserialize_f64,
-        deserialize_option_f64,
+        serialize_as_string,
+        serialize_option_as_string,
+        datetime_to_millis,
+        millis_to_datetime,
+        deserialize_number,
+        deserialize_option_number,
         deserialize_string_to_u64,
+        deserialize_option_enum,
     },
 };
 
@@ -14,10 +38,25 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
+use crate::v5::api::pagination::{
+    paginate,
+    paginate_time_range,
+    TimeRange,
+};
+use futures_util::{
+    FutureExt,
+    stream::BoxStream,
+};
 
-const PATH: &'static str = "/v5/order/history";
+const PATH: &str = "/v5/order/history";
+// startTime と endTime の間隔の上限
+const MAX_TIME_RANGE_DAYS: i64 = 7;
 
 impl BybitApi {
     /// Retrieves the order history for the specified parameters.
@@ -55,20 +94,60 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn get_order_history(&self, params: GetOrderHistoryParameters) -> Result<GetOrderHistoryResponse> {
+    pub async fn get_order_history(&self, params: GetOrderHistoryParameters) -> Result<GetOrderHistoryResponse, BybitError> {
         self.get(PATH, Some(params), true).await
     }
-}
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum GetOrderHistoryCategory {
-    Linear,
-    Inverse,
-    Option,
-    Spot,
+    /// Gets all orders, following `next_page_cursor` until the last page.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters of the first request.
+    /// * `max_items` - The maximum number of orders to yield, or `None` for all of them.
+    ///
+    /// # Returns
+    ///
+    /// A stream of orders. A failed request is yielded as an error and ends the stream.
+    pub fn get_order_history_stream(&self, params: GetOrderHistoryParameters, max_items: Option<usize>) -> BoxStream<'_, Result<OrderHistory, BybitError>> {
+        paginate(params, max_items, GetOrderHistoryParameters::with_cursor, move |params| {
+            async move {
+                let result = self.get_order_history(params).await?.result;
+                Ok((result.list, Some(result.next_page_cursor)))
+            }.boxed()
+        })
+    }
+
+    /// Gets the orders between `from` and `to`, split into the 7 day windows Bybit accepts.
+    ///
+    /// Each window is paged through with `next_page_cursor`. Orders returned by two windows are yielded once.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The filters of every request. `start_time`, `end_time` and `cursor` are overwritten.
+    /// * `from` - The start of the range, inclusive.
+    /// * `to` - The end of the range, exclusive.
+    ///
+    /// # Returns
+    ///
+    /// A stream of orders, oldest first. A failed request is yielded as an error and ends the stream.
+    pub fn get_order_history_range_stream(&self, params: GetOrderHistoryParameters, from: DateTime<Utc>, to: DateTime<Utc>) -> BoxStream<'_, Result<OrderHistory, BybitError>> {
+        let range: TimeRange<GetOrderHistoryParameters, OrderHistory> = TimeRange {
+            with_window: |params, start, end| params.with_start_time(start).with_end_time(end),
+            with_cursor: GetOrderHistoryParameters::with_cursor,
+            time: OrderHistory::created_time,
+            key: |order| order.order_id.clone(),
+        };
+        paginate_time_range(params, from, to, Duration::days(MAX_TIME_RANGE_DAYS), range, move |params| {
+            async move {
+                let result = self.get_order_history(params).await?.result;
+                Ok((result.list, Some(result.next_page_cursor)))
+            }.boxed()
+        })
+    }
 }
 
+pub type GetOrderHistoryCategory = Category;
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetOrderHistoryParameters {
@@ -79,7 +158,7 @@ pub struct GetOrderHistoryParameters {
     order_id: Option<String>,
     order_link_id: Option<String>,
     order_filter: Option<String>,
-    order_status: Option<String>,
+    order_status: Option<OrderStatus>,
     start_time: Option<u64>,
     end_time: Option<u64>,
     limit: Option<u32>,
@@ -206,7 +285,7 @@ impl GetOrderHistoryParameters {
     /// # Returns
     ///
     /// The modified `GetOrderHistoryParameters` instance.
-    pub fn with_order_status(mut self, order_status: String) -> Self {
+    pub fn with_order_status(mut self, order_status: OrderStatus) -> Self {
         self.order_status = Some(order_status);
         self
     }
@@ -225,6 +304,20 @@ impl GetOrderHistoryParameters {
         self
     }
 
+    /// Sets the start time for the order history parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_time` - The start time as a UTC date and time.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GetOrderHistoryParameters` instance.
+    pub fn with_start_datetime(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(datetime_to_millis(start_time));
+        self
+    }
+
     /// Sets the end time for the order history parameters.
     ///
     /// # Arguments
@@ -239,6 +332,20 @@ impl GetOrderHistoryParameters {
         self
     }
 
+    /// Sets the end time for the order history parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `end_time` - The end time as a UTC date and time.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GetOrderHistoryParameters` instance.
+    pub fn with_end_datetime(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = Some(datetime_to_millis(end_time));
+        self
+    }
+
     /// Sets the limit for the order history parameters.
     ///
     /// # Arguments
@@ -253,9 +360,22 @@ impl GetOrderHistoryParameters {
         self
     }
 
+    /// Sets the cursor for the order history parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - The cursor to set.
+    ///
+    /// # Returns
+    ///
+    /// The modified `GetOrderHistoryParameters` instance.
+    pub fn with_cursor(mut self, cursor: String) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetOrderHistoryResponse {
     ret_code: i32,
@@ -263,6 +383,8 @@ pub struct GetOrderHistoryResponse {
     result: OrderHistoryResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl GetOrderHistoryResponse {
     pub fn ret_code(&self) -> i32 {
@@ -304,14 +426,28 @@ impl GetOrderHistoryResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderHistoryResult {
     category: String,
     next_page_cursor: String,
-    list: Vec<OrderHistory>
+    list: Vec<OrderHistory>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl OrderHistoryResult {
     pub fn category(&self) -> &str {
@@ -337,63 +473,78 @@ impl OrderHistoryResult {
     pub fn set_list(&mut self, list: Vec<OrderHistory>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderHistory {
     order_id: String,
     order_link_id: String,
     block_trade_id: String,
     symbol: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    qty: f64,
-    side: String,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    qty: Number,
+    side: Side,
     is_leverage: String,
-    position_idx: u64,
-    order_status: String,
-    cancel_type: String,
+    position_idx: PositionIdx,
+    order_status: OrderStatus,
+    cancel_type: CancelType,
     reject_reason: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    avg_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    leaves_qty: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    leaves_value: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    cum_exec_qty: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    cum_exec_fee: Option<f64>,
-    time_in_force: String,
-    order_type: String,
-    stop_order_type: String,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    avg_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    leaves_qty: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    leaves_value: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    cum_exec_qty: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    cum_exec_fee: Option<Number>,
+    time_in_force: TimeInForce,
+    order_type: OrderType,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    stop_order_type: Option<StopOrderType>,
     order_iv: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    trigger_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    take_profit: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    stop_loss: Option<f64>,
-    tp_trigger_by: String,
-    sl_trigger_by: String,
-    trigger_direction: f64,
-    trigger_by: String,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    trigger_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    take_profit: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    stop_loss: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    tp_trigger_by: Option<TriggerBy>,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    sl_trigger_by: Option<TriggerBy>,
+    trigger_direction: Number,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    trigger_by: Option<TriggerBy>,
     last_price_on_created: String,
     reduce_only: bool,
     close_on_trigger: bool,
-    smp_type: String,
+    smp_type: SmpType,
     smp_group: u64,
     smp_order_id: String,
-    tpsl_mode: String,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    tpsl_mode: Option<TpslMode>,
     tp_limit_price: String,
     sl_limit_price: String,
     place_type: String,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     created_time: u64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     updated_time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl OrderHistory {
     pub fn order_id(&self) -> &str {
@@ -428,27 +579,27 @@ impl OrderHistory {
         self.symbol = symbol;
     }
 
-    pub fn price(&self) -> f64 {
+    pub fn price(&self) -> Number {
         self.price
     }
 
-    pub fn set_price(&mut self, price: f64) {
+    pub fn set_price(&mut self, price: Number) {
         self.price = price;
     }
 
-    pub fn qty(&self) -> f64 {
+    pub fn qty(&self) -> Number {
         self.qty
     }
 
-    pub fn set_qty(&mut self, qty: f64) {
+    pub fn set_qty(&mut self, qty: Number) {
         self.qty = qty;
     }
 
-    pub fn side(&self) -> &str {
+    pub fn side(&self) -> &Side {
         &self.side
     }
 
-    pub fn set_side(&mut self, side: String) {
+    pub fn set_side(&mut self, side: Side) {
         self.side = side;
     }
 
@@ -460,27 +611,27 @@ impl OrderHistory {
         self.is_leverage = is_leverage;
     }
 
-    pub fn position_idx(&self) -> u64 {
+    pub fn position_idx(&self) -> PositionIdx {
         self.position_idx
     }
 
-    pub fn set_position_idx(&mut self, position_idx: u64) {
+    pub fn set_position_idx(&mut self, position_idx: PositionIdx) {
         self.position_idx = position_idx;
     }
 
-    pub fn order_status(&self) -> &str {
+    pub fn order_status(&self) -> &OrderStatus {
         &self.order_status
     }
 
-    pub fn set_order_status(&mut self, order_status: String) {
+    pub fn set_order_status(&mut self, order_status: OrderStatus) {
         self.order_status = order_status;
     }
 
-    pub fn cancel_type(&self) -> &str {
+    pub fn cancel_type(&self) -> &CancelType {
         &self.cancel_type
     }
 
-    pub fn set_cancel_type(&mut self, cancel_type: String) {
+    pub fn set_cancel_type(&mut self, cancel_type: CancelType) {
         self.cancel_type = cancel_type;
     }
 
@@ -492,68 +643,68 @@ impl OrderHistory {
         self.reject_reason = reject_reason;
     }
 
-    pub fn avg_price(&self) -> Option<f64> {
+    pub fn avg_price(&self) -> Option<Number> {
         self.avg_price
     }
 
-    pub fn set_avg_price(&mut self, avg_price: f64) {
+    pub fn set_avg_price(&mut self, avg_price: Number) {
         self.avg_price = Some(avg_price);
     }
 
-    pub fn leaves_qty(&self) -> Option<f64> {
+    pub fn leaves_qty(&self) -> Option<Number> {
         self.leaves_qty
     }
 
-    pub fn set_leaves_qty(&mut self, leaves_qty: f64) {
+    pub fn set_leaves_qty(&mut self, leaves_qty: Number) {
         self.leaves_qty = Some(leaves_qty);
     }
 
-    pub fn leaves_value(&self) -> Option<f64> {
+    pub fn leaves_value(&self) -> Option<Number> {
         self.leaves_value
     }
 
-    pub fn set_leaves_value(&mut self, leaves_value: f64) {
+    pub fn set_leaves_value(&mut self, leaves_value: Number) {
         self.leaves_value = Some(leaves_value);
     }
 
-    pub fn cum_exec_qty(&self) -> Option<f64> {
+    pub fn cum_exec_qty(&self) -> Option<Number> {
         self.cum_exec_qty
     }
 
-    pub fn set_cum_exec_qty(&mut self, cum_exec_qty: f64) {
+    pub fn set_cum_exec_qty(&mut self, cum_exec_qty: Number) {
         self.cum_exec_qty = Some(cum_exec_qty);
     }
 
-    pub fn cum_exec_fee(&self) -> Option<f64> {
+    pub fn cum_exec_fee(&self) -> Option<Number> {
         self.cum_exec_fee
     }
 
-    pub fn set_cum_exec_fee(&mut self, cum_exec_fee: f64) {
+    pub fn set_cum_exec_fee(&mut self, cum_exec_fee: Number) {
         self.cum_exec_fee = Some(cum_exec_fee);
     }
 
-    pub fn time_in_force(&self) -> &str {
+    pub fn time_in_force(&self) -> &TimeInForce {
         &self.time_in_force
     }
 
-    pub fn set_time_in_force(&mut self, time_in_force: String) {
+    pub fn set_time_in_force(&mut self, time_in_force: TimeInForce) {
         self.time_in_force = time_in_force;
     }
 
-    pub fn order_type(&self) -> &str {
+    pub fn order_type(&self) -> &OrderType {
         &self.order_type
     }
 
-    pub fn set_order_type(&mut self, order_type: String) {
+    pub fn set_order_type(&mut self, order_type: OrderType) {
         self.order_type = order_type;
     }
 
-    pub fn stop_order_type(&self) -> &str {
-        &self.stop_order_type
+    pub fn stop_order_type(&self) -> Option<&StopOrderType> {
+        self.stop_order_type.as_ref()
     }
 
-    pub fn set_stop_order_type(&mut self, stop_order_type: String) {
-        self.stop_order_type = stop_order_type;
+    pub fn set_stop_order_type(&mut self, stop_order_type: StopOrderType) {
+        self.stop_order_type = Some(stop_order_type);
     }
 
     pub fn order_iv(&self) -> &str {
@@ -564,60 +715,60 @@ impl OrderHistory {
         self.order_iv = order_iv;
     }
 
-    pub fn trigger_price(&self) -> Option<f64> {
+    pub fn trigger_price(&self) -> Option<Number> {
         self.trigger_price
     }
 
-    pub fn set_trigger_price(&mut self, trigger_price: f64) {
+    pub fn set_trigger_price(&mut self, trigger_price: Number) {
         self.trigger_price = Some(trigger_price);
     }
 
-    pub fn take_profit(&self) -> Option<f64> {
+    pub fn take_profit(&self) -> Option<Number> {
         self.take_profit
     }
 
-    pub fn set_take_profit(&mut self, take_profit: f64) {
+    pub fn set_take_profit(&mut self, take_profit: Number) {
         self.take_profit = Some(take_profit);
     }
 
-    pub fn stop_loss(&self) -> Option<f64> {
+    pub fn stop_loss(&self) -> Option<Number> {
         self.stop_loss
     }
 
-    pub fn set_stop_loss(&mut self, stop_loss: f64) {
+    pub fn set_stop_loss(&mut self, stop_loss: Number) {
         self.stop_loss = Some(stop_loss);
     }
 
-    pub fn tp_trigger_by(&self) -> &str {
-        &self.tp_trigger_by
+    pub fn tp_trigger_by(&self) -> Option<&TriggerBy> {
+        self.tp_trigger_by.as_ref()
     }
 
-    pub fn set_tp_trigger_by(&mut self, tp_trigger_by: String) {
-        self.tp_trigger_by = tp_trigger_by;
+    pub fn set_tp_trigger_by(&mut self, tp_trigger_by: TriggerBy) {
+        self.tp_trigger_by = Some(tp_trigger_by);
     }
 
-    pub fn sl_trigger_by(&self) -> &str {
-        &self.sl_trigger_by
+    pub fn sl_trigger_by(&self) -> Option<&TriggerBy> {
+        self.sl_trigger_by.as_ref()
     }
 
-    pub fn set_sl_trigger_by(&mut self, sl_trigger_by: String) {
-        self.sl_trigger_by = sl_trigger_by;
+    pub fn set_sl_trigger_by(&mut self, sl_trigger_by: TriggerBy) {
+        self.sl_trigger_by = Some(sl_trigger_by);
     }
 
-    pub fn trigger_direction(&self) -> f64 {
+    pub fn trigger_direction(&self) -> Number {
         self.trigger_direction
     }
 
-    pub fn set_trigger_direction(&mut self, trigger_direction: f64) {
+    pub fn set_trigger_direction(&mut self, trigger_direction: Number) {
         self.trigger_direction = trigger_direction;
     }
 
-    pub fn trigger_by(&self) -> &str {
-        &self.trigger_by
+    pub fn trigger_by(&self) -> Option<&TriggerBy> {
+        self.trigger_by.as_ref()
     }
 
-    pub fn set_trigger_by(&mut self, trigger_by: String) {
-        self.trigger_by = trigger_by;
+    pub fn set_trigger_by(&mut self, trigger_by: TriggerBy) {
+        self.trigger_by = Some(trigger_by);
     }
 
     pub fn last_price_on_created(&self) -> &str {
@@ -644,11 +795,11 @@ impl OrderHistory {
         self.close_on_trigger = close_on_trigger;
     }
 
-    pub fn smp_type(&self) -> &str {
+    pub fn smp_type(&self) -> &SmpType {
         &self.smp_type
     }
 
-    pub fn set_smp_type(&mut self, smp_type: String) {
+    pub fn set_smp_type(&mut self, smp_type: SmpType) {
         self.smp_type = smp_type;
     }
 
@@ -668,12 +819,12 @@ impl OrderHistory {
         self.smp_order_id = smp_order_id;
     }
 
-    pub fn tpsl_mode(&self) -> &str {
-        &self.tpsl_mode
+    pub fn tpsl_mode(&self) -> Option<&TpslMode> {
+        self.tpsl_mode.as_ref()
     }
 
-    pub fn set_tpsl_mode(&mut self, tpsl_mode: String) {
-        self.tpsl_mode = tpsl_mode;
+    pub fn set_tpsl_mode(&mut self, tpsl_mode: TpslMode) {
+        self.tpsl_mode = Some(tpsl_mode);
     }
 
     pub fn tp_limit_price(&self) -> &str {
@@ -708,6 +859,10 @@ impl OrderHistory {
         self.created_time = created_time;
     }
 
+    pub fn created_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.created_time)
+    }
+
     pub fn updated_time(&self) -> u64 {
         self.updated_time
     }
@@ -715,4 +870,16 @@ impl OrderHistory {
     pub fn set_updated_time(&mut self, updated_time: u64) {
         self.updated_time = updated_time;
     }
+
+    pub fn updated_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.updated_time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -0,0 +1,182 @@
+use crate::{
+    constants::TRACE_ID_HEADER,
+    error::BybitError,
+    v5::api::{
+        BybitApi,
+        rate_limit::RateLimitStatus,
+    },
+};
+use reqwest::header::HeaderMap;
+use std::{
+    future::Future,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+
+impl BybitApi {
+    /// Calls an endpoint and returns its response together with the transport metadata.
+    ///
+    /// The closure gets a clone of this instance that records the metadata of the last
+    /// successful HTTP exchange, so any endpoint method can be wrapped:
+    ///
+    /// ```no_run
+    /// # use rsbit::v5::api::BybitApi;
+    /// # async fn example(api: BybitApi) -> Result<(), rsbit::error::BybitError> {
+    /// let response = api.with_meta(|api| async move { api.get_bybit_server_time().await }).await?;
+    /// println!("{:?} {:?}", response.meta().trace_id(), response.meta().latency());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A closure calling one endpoint method on the given `BybitApi`.
+    ///
+    /// # Returns
+    ///
+    /// The typed response wrapped in a `Response` with its metadata.
+    pub async fn with_meta<T, F, Fut>(&self, request: F) -> Result<Response<T>, BybitError>
+    where
+        F: FnOnce(BybitApi) -> Fut,
+        Fut: Future<Output = Result<T, BybitError>>,
+    {
+        let sink = Arc::new(Mutex::new(None));
+        let mut api = self.clone();
+        api.meta_sink = Some(sink.clone());
+
+        let result = request(api).await?;
+        let meta = sink
+            .lock()
+            .expect("response meta lock poisoned")
+            .take()
+            .unwrap_or_default();
+        Ok(Response::new(result, meta))
+    }
+
+    pub(crate) fn record_meta(&self, meta: ResponseMeta) {
+        if let Some(sink) = &self.meta_sink {
+            *sink.lock().expect("response meta lock poisoned") = Some(meta);
+        }
+    }
+}
+
+pub(crate) type MetaSink = Arc<Mutex<Option<ResponseMeta>>>;
+
+/// A typed response together with the metadata of the HTTP exchange that produced it.
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    result: T,
+    meta: ResponseMeta,
+}
+
+impl<T> Response<T> {
+    /// Creates a new instance of `Response`.
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - The typed response.
+    /// * `meta` - The metadata of the HTTP exchange.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `Response`.
+    pub fn new(result: T, meta: ResponseMeta) -> Self {
+        Self {
+            result,
+            meta,
+        }
+    }
+
+    /// Gets the typed response.
+    pub fn result(&self) -> &T {
+        &self.result
+    }
+
+    /// Gets the metadata of the HTTP exchange.
+    pub fn meta(&self) -> &ResponseMeta {
+        &self.meta
+    }
+
+    /// Consumes the wrapper and returns the typed response, dropping the metadata.
+    pub fn into_result(self) -> T {
+        self.result
+    }
+
+    /// Consumes the wrapper and returns the typed response and the metadata.
+    pub fn into_parts(self) -> (T, ResponseMeta) {
+        (self.result, self.meta)
+    }
+}
+
+/// The metadata of an HTTP exchange with Bybit.
+#[derive(Debug, Clone, Default)]
+pub struct ResponseMeta {
+    endpoint: String,
+    status: u16,
+    rate_limit_status: Option<RateLimitStatus>,
+    trace_id: Option<String>,
+    latency: Duration,
+    raw: String,
+}
+
+impl ResponseMeta {
+    /// Creates a new instance of `ResponseMeta` from the response headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - The path of the request.
+    /// * `status` - The HTTP status code.
+    /// * `headers` - The response headers.
+    /// * `latency` - The time from sending the request to receiving the whole body.
+    /// * `raw` - The raw JSON body.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `ResponseMeta`.
+    pub fn new(endpoint: &str, status: u16, headers: &HeaderMap, latency: Duration, raw: String) -> Self {
+        Self {
+            endpoint: endpoint.to_string(),
+            status,
+            rate_limit_status: RateLimitStatus::from_headers(headers),
+            trace_id: headers
+                .get(TRACE_ID_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.to_string()),
+            latency,
+            raw,
+        }
+    }
+
+    /// Gets the path of the request, e.g. `/v5/market/time`.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Gets the HTTP status code.
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// Gets the rate limit status from the `X-Bapi-Limit*` headers, if Bybit sent them.
+    pub fn rate_limit_status(&self) -> Option<&RateLimitStatus> {
+        self.rate_limit_status.as_ref()
+    }
+
+    /// Gets the `Traceid` header, which Bybit support asks for when investigating a request.
+    pub fn trace_id(&self) -> Option<&str> {
+        self.trace_id.as_deref()
+    }
+
+    /// Gets the round-trip time, from sending the request to receiving the whole body.
+    pub fn latency(&self) -> Duration {
+        self.latency
+    }
+
+    /// Gets the raw JSON body.
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{
+    error::BybitError,
+    utils::datetime_to_millis,
+    v5::types::Interval,
+};
+use chrono::{
+    DateTime,
+    Duration,
+    Months,
+    Utc,
+};
+use futures_util::{
+    future::BoxFuture,
+    stream::{
+        self,
+        BoxStream,
+        StreamExt,
+        TryStreamExt,
+    },
+};
+use std::collections::HashSet;
+
+/// The most bars the kline endpoints return for one request.
+pub(crate) const MAX_BARS: u32 = 1000;
+
+/// One page of a list endpoint: the items and the cursor of the next page.
+pub(crate) type Page<T> = (Vec<T>, Option<String>);
+
+/// Follows `nextPageCursor` until it is empty and yields the items of every page.
+///
+/// # Arguments
+///
+/// * `params` - The parameters of the first request.
+/// * `max_items` - Stops after this many items. No more pages are requested once it is reached.
+/// * `with_cursor` - Sets the cursor on the parameters for the next request.
+/// * `fetch` - Requests one page.
+///
+/// # Returns
+///
+/// A stream of items. A failed request is yielded as an error and ends the stream.
+pub(crate) fn paginate<'a, P, T>(
+    params: P,
+    max_items: Option<usize>,
+    with_cursor: fn(P, String) -> P,
+    fetch: impl Fn(P) -> BoxFuture<'a, Result<Page<T>, BybitError>> + Send + 'a,
+) -> BoxStream<'a, Result<T, BybitError>>
+where
+    P: Clone + Send + 'a,
+    T: Send + 'a,
+{
+    stream::unfold((Some(params), fetch), move |(params, fetch)| async move {
+        let params = params?;
+        match fetch(params.clone()).await {
+            Ok((items, cursor)) => {
+                // 空のページやカーソルが返ってきたら終了
+                let next = cursor
+                    .filter(|cursor| !cursor.is_empty() && !items.is_empty())
+                    .map(|cursor| with_cursor(params, cursor));
+                let items: Vec<Result<T, BybitError>> = items.into_iter().map(Ok).collect();
+                Some((items, (next, fetch)))
+            },
+            Err(err) => Some((vec![Err(err)], (None, fetch))),
+        }
+    })
+        .flat_map(stream::iter)
+        .take(max_items.unwrap_or(usize::MAX))
+        .boxed()
+}
+
+/// Splits `[from, to)` into consecutive windows no longer than `max_span`.
+///
+/// # Arguments
+///
+/// * `from` - The start of the range, inclusive.
+/// * `to` - The end of the range, exclusive.
+/// * `max_span` - The longest span Bybit accepts between `startTime` and `endTime`.
+///
+/// # Returns
+///
+/// The windows in time order, or nothing if `from` is not before `to`.
+pub(crate) fn split_time_range(from: DateTime<Utc>, to: DateTime<Utc>, max_span: Duration) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let mut windows = Vec::new();
+    let mut start = from;
+    while start < to {
+        let end = (start + max_span).min(to);
+        windows.push((start, end));
+        start = end;
+    }
+    windows
+}
+
+/// The endpoint specific parts of `paginate_time_range`.
+pub(crate) struct TimeRange<P, T> {
+    /// Sets `startTime` and `endTime` (both inclusive, in milliseconds) on the parameters.
+    pub with_window: fn(P, u64, u64) -> P,
+    /// Sets the cursor on the parameters for the next page.
+    pub with_cursor: fn(P, String) -> P,
+    /// Gets the timestamp the endpoint filters and sorts by.
+    pub time: fn(&T) -> u64,
+    /// Gets a value identifying the item, to drop items returned by two windows.
+    pub key: fn(&T) -> String,
+}
+
+/// Queries `[from, to)` window by window with cursor paging and yields the items oldest first.
+///
+/// # Arguments
+///
+/// * `params` - The parameters of every request. `startTime`, `endTime` and the cursor are overwritten.
+/// * `from` - The start of the range, inclusive.
+/// * `to` - The end of the range, exclusive.
+/// * `max_span` - The longest span Bybit accepts for the endpoint.
+/// * `range` - How to set the window and read the items.
+/// * `fetch` - Requests one page.
+///
+/// # Returns
+///
+/// A stream of items in time order. A failed request is yielded as an error and ends the stream.
+pub(crate) fn paginate_time_range<'a, P, T, F>(
+    params: P,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    max_span: Duration,
+    range: TimeRange<P, T>,
+    fetch: F,
+) -> BoxStream<'a, Result<T, BybitError>>
+where
+    P: Clone + Send + 'a,
+    T: Send + 'a,
+    F: Fn(P) -> BoxFuture<'a, Result<Page<T>, BybitError>> + Clone + Send + 'a,
+{
+    let windows = split_time_range(from, to, max_span).into_iter();
+    stream::unfold((windows, HashSet::new(), range, fetch), move |(mut windows, seen, range, fetch)| {
+        let params = params.clone();
+        async move {
+            let (start, end) = windows.next()?;
+            // endTime は両端を含むので 1ms 手前までにする
+            let params = (range.with_window)(params, datetime_to_millis(start), datetime_to_millis(end).saturating_sub(1));
+            let items = paginate(params, None, range.with_cursor, fetch.clone())
+                .try_collect::<Vec<T>>()
+                .await;
+            match items {
+                Ok(mut items) => {
+                    // Bybit は新しい順に返すので古い順に並べ替える
+                    items.sort_by_key(|item| (range.time)(item));
+                    // 境界で前の窓と重複したものとページ間で重複したものを除く
+                    let mut keys = HashSet::new();
+                    let items: Vec<Result<T, BybitError>> = items.into_iter()
+                        .filter(|item| {
+                            let key = (range.key)(item);
+                            !seen.contains(&key) && keys.insert(key)
+                        })
+                        .map(Ok)
+                        .collect();
+                    Some((items, (windows, keys, range, fetch)))
+                },
+                Err(err) => Some((vec![Err(err)], (Vec::new().into_iter(), HashSet::new(), range, fetch))),
+            }
+        }
+    })
+        .flat_map(stream::iter)
+        .boxed()
+}
+
+/// Walks `[from, to)` forwards in windows of `MAX_BARS` bars and yields the bars oldest first.
+///
+/// # Arguments
+///
+/// * `from` - The start of the range, inclusive.
+/// * `to` - The end of the range, exclusive.
+/// * `interval` - The interval of the bars.
+/// * `time` - Gets the open time of a bar.
+/// * `fetch` - Requests the bars opened between a start and an end, both inclusive, in milliseconds.
+///
+/// # Returns
+///
+/// A stream of bars opened in `[from, to)`, oldest first and without duplicates.
+/// A failed request is yielded as an error and ends the stream.
+pub(crate) fn paginate_bars<'a, T, F>(
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    interval: Interval,
+    time: fn(&T) -> u64,
+    fetch: F,
+) -> BoxStream<'a, Result<T, BybitError>>
+where
+    T: Send + 'a,
+    F: Fn(u64, u64) -> BoxFuture<'a, Result<Vec<T>, BybitError>> + Send + 'a,
+{
+    let (from_millis, to_millis) = (datetime_to_millis(from), datetime_to_millis(to));
+    let start = Some(from).filter(|from| *from < to);
+    stream::unfold((start, None, fetch), move |(start, last, fetch)| async move {
+        let start = start?;
+        let end = match interval.duration() {
+            Some(duration) => start.checked_add_signed(duration * MAX_BARS as i32),
+            None => start.checked_add_months(Months::new(MAX_BARS)),
+        }.map_or(to, |end| end.min(to));
+        // end は両端を含むので 1ms 手前までにする
+        match fetch(datetime_to_millis(start), datetime_to_millis(end).saturating_sub(1)).await {
+            Ok(mut bars) => {
+                // Bybit は新しい順に返すので古い順に並べ替える
+                bars.sort_by_key(time);
+                // 範囲外のものと前の窓で返したものを除く
+                let mut last: Option<u64> = last;
+                let bars: Vec<Result<T, BybitError>> = bars.into_iter()
+                    .filter(|bar| {
+                        let time = time(bar);
+                        let keep = time >= from_millis && time < to_millis && last.is_none_or(|last| time > last);
+                        if keep {
+                            last = Some(time);
+                        }
+                        keep
+                    })
+                    .map(Ok)
+                    .collect();
+                let next = Some(end).filter(|end| *end < to);
+                Some((bars, (next, last, fetch)))
+            },
+            Err(err) => Some((vec![Err(err)], (None, last, fetch))),
+        }
+    })
+        .flat_map(stream::iter)
+        .boxed()
+}
@@ -10,7 +10,7 @@ use crate::v5::api::{
     },
     BybitApi,
 };
-use anyhow::Result;
+use crate::error::BybitError;
 use async_trait::async_trait;
 use serde::{
     Serialize,
@@ -19,13 +19,21 @@ use serde::{
 
 #[async_trait]
 trait Post: BybitApiRequest {
-    async fn post<T: Serialize + Send, P: DeserializeOwned>(&self, endpoint: &str, params: Option<T>) -> Result<P>;
+    async fn post<T: Serialize + Send + Sync, P: DeserializeOwned + Send>(&self, endpoint: &str, params: Option<T>) -> Result<P, BybitError>;
+    async fn post_with_retry<T: Serialize + Send + Sync, P: DeserializeOwned + Send>(&self, endpoint: &str, params: Option<T>, retryable: bool) -> Result<P, BybitError>;
 }
 
 #[async_trait]
 impl Post for BybitApi {
-    async fn post<T: Serialize + Send, P: DeserializeOwned>(&self, endpoint: &str, params: Option<T>) -> Result<P> {
-        let body = self.send_api_request(endpoint, params, true, RequestType::Post).await?;
-        self.deserialize_response(&body)
+    async fn post<T: Serialize + Send + Sync, P: DeserializeOwned + Send>(&self, endpoint: &str, params: Option<T>) -> Result<P, BybitError> {
+        self.post_with_retry(endpoint, params, false).await
+    }
+
+    // Only pass `retryable` when sending the request twice cannot have a different effect than sending it once.
+    async fn post_with_retry<T: Serialize + Send + Sync, P: DeserializeOwned + Send>(&self, endpoint: &str, params: Option<T>, retryable: bool) -> Result<P, BybitError> {
+        self.retry(retryable, || async {
+            let body = self.send_api_request(endpoint, params.as_ref(), true, RequestType::Post).await?;
+            self.deserialize_response(endpoint, &body)
+        }).await
     }
 }
\ No newline at end of file
@@ -1,3 +1,10 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use crate::utils::{
+    millis_to_datetime,
+};
 use crate::v5::api::{
     BybitApi,
     post::Post,
@@ -7,13 +14,17 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/account/set-collateral-switch-batch";
+const PATH: &str = "/v5/account/set-collateral-switch-batch";
 
 impl BybitApi {
-    pub async fn batch_set_collateral_coin(&self, params: BatchSetCollateralCoinParameters) -> Result<BatchSetCollateralCoinResponse> {
+    pub async fn batch_set_collateral_coin(&self, params: BatchSetCollateralCoinParameters) -> Result<BatchSetCollateralCoinResponse, BybitError> {
         self.post(PATH, Some(params)).await
     }
 }
@@ -48,7 +59,7 @@ impl BatchSetCollateralCoinRequestParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchSetCollateralCoinResponse {
     ret_code: i32,
@@ -56,6 +67,8 @@ pub struct BatchSetCollateralCoinResponse {
     result: BatchSetCollateralCoinResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl BatchSetCollateralCoinResponse {
     pub fn ret_code(&self) -> i32 {
@@ -97,12 +110,26 @@ impl BatchSetCollateralCoinResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchSetCollateralCoinResult {
-    list: Vec<BatchSetCollateralCoin>
+    list: Vec<BatchSetCollateralCoin>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl BatchSetCollateralCoinResult {
@@ -113,13 +140,23 @@ impl BatchSetCollateralCoinResult {
     pub fn set_list(&mut self, list: Vec<BatchSetCollateralCoin>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchSetCollateralCoin {
     coin: String,
     collateral_switch: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl BatchSetCollateralCoin {
@@ -138,4 +175,12 @@ impl BatchSetCollateralCoin {
     pub fn set_collateral_switch(&mut self, collateral_switch: String) {
         self.collateral_switch = collateral_switch;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
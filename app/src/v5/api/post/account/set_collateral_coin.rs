@@ -1,3 +1,10 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use crate::utils::{
+    millis_to_datetime,
+};
 use crate::v5::api::{
     BybitApi,
     post::Post,
@@ -7,13 +14,17 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/account/set-collateral-switch";
+const PATH: &str = "/v5/account/set-collateral-switch";
 
 impl BybitApi {
-    pub async fn set_collateral_coin(&self, params: SetCollateralCoinParameters) -> Result<SetCollateralCoinResponse> {
+    pub async fn set_collateral_coin(&self, params: SetCollateralCoinParameters) -> Result<SetCollateralCoinResponse, BybitError> {
         self.post(PATH, Some(params)).await
     }
 }
@@ -34,7 +45,7 @@ impl SetCollateralCoinParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SetCollateralCoinResponse {
     ret_code: i32,
@@ -42,6 +53,8 @@ pub struct SetCollateralCoinResponse {
     result: Value,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl SetCollateralCoinResponse {
     pub fn ret_code(&self) -> i32 {
@@ -83,4 +96,16 @@ impl SetCollateralCoinResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
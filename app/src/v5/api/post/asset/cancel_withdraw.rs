@@ -1,3 +1,10 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use crate::utils::{
+    millis_to_datetime,
+};
 use crate::v5::api::{
     BybitApi,
     post::Post,
@@ -7,13 +14,17 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/asset/withdraw/cancel";
+const PATH: &str = "/v5/asset/withdraw/cancel";
 
 impl BybitApi {
-    pub async fn cancel_withdraw(&self, params: CancelWithdrawParameters) -> Result<CancelWithdrawResponse> {
+    pub async fn cancel_withdraw(&self, params: CancelWithdrawParameters) -> Result<CancelWithdrawResponse, BybitError> {
         self.post(PATH, Some(params)).await
     }
 }
@@ -32,7 +43,7 @@ impl CancelWithdrawParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelWithdrawResponse {
     ret_code: i32,
@@ -40,6 +51,8 @@ pub struct CancelWithdrawResponse {
     result: CancelWithdrawResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl CancelWithdrawResponse {
     pub fn ret_code(&self) -> i32 {
@@ -81,11 +94,25 @@ impl CancelWithdrawResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct CancelWithdrawResult {
     status: u8,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl CancelWithdrawResult {
@@ -96,4 +123,12 @@ impl CancelWithdrawResult {
     pub fn set_status(&mut self, status: u8) {
         self.status = status;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
@@ -1,22 +1,34 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
     v5::api::{
         BybitApi,
         post::Post,
     },
-    utils::serialize_as_string,
+    v5::types::Number,
+    utils::{
+        serialize_as_string,
+        millis_to_datetime,
+    },
 };
 
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/asset/withdraw/create";
+const PATH: &str = "/v5/asset/withdraw/create";
 
 impl BybitApi {
-    pub async fn withdraw(&self, params: WithdrawParameters) -> Result<WithdrawResponse> {
+    pub async fn withdraw(&self, params: WithdrawParameters) -> Result<WithdrawResponse, BybitError> {
         self.post(PATH, Some(params)).await
     }
 }
@@ -29,7 +41,7 @@ pub struct WithdrawParameters {
     address: String,
     tag: Option<String>,
     #[serde(serialize_with = "serialize_as_string")]
-    amount: f64,
+    amount: Number,
     timestamp: u64,
     force_chain: Option<u8>,
     account_type: String,
@@ -38,7 +50,7 @@ pub struct WithdrawParameters {
 }
 
 impl WithdrawParameters {
-    pub fn new(coin: String, address: String, amount: f64, timestamp: u64, account_type: String) -> Self {
+    pub fn new(coin: String, address: String, amount: Number, timestamp: u64, account_type: String) -> Self {
         Self {
             coin,
             chain: None,
@@ -79,7 +91,7 @@ impl WithdrawParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WithdrawResponse {
     ret_code: i32,
@@ -87,6 +99,8 @@ pub struct WithdrawResponse {
     result: WithdrawResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl WithdrawResponse {
     pub fn ret_code(&self) -> i32 {
@@ -128,11 +142,25 @@ impl WithdrawResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct WithdrawResult {
     id: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl WithdrawResult {
@@ -143,4 +171,12 @@ impl WithdrawResult {
     pub fn set_id(&mut self, id: String) {
         self.id = id;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
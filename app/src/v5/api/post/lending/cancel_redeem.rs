@@ -1,22 +1,34 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
     v5::api::{
         BybitApi,
         post::Post,
     },
-    utils::deserialize_string_to_u64,
+    utils::{
+        serialize_as_string,
+        deserialize_string_to_u64,
+        millis_to_datetime,
+    },
 };
 
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/lending/redeem-cancel";
+const PATH: &str = "/v5/lending/redeem-cancel";
 
 impl BybitApi {
-    pub async fn cancel_redeem(&self, params: CancelRedeemParameters) -> Result<CancelRedeemResponse> {
+    pub async fn cancel_redeem(&self, params: CancelRedeemParameters) -> Result<CancelRedeemResponse, BybitError> {
         self.post(PATH, Some(params)).await
     }
 }
@@ -29,6 +41,12 @@ pub struct CancelRedeemParameters {
     serial_no: Option<String>
 }
 
+impl Default for CancelRedeemParameters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CancelRedeemParameters {
     pub fn new() -> Self {
         Self {
@@ -54,7 +72,7 @@ impl CancelRedeemParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelRedeemResponse {
     ret_code: i32,
@@ -62,6 +80,8 @@ pub struct CancelRedeemResponse {
     result: CancelRedeemResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl CancelRedeemResponse {
     pub fn ret_code(&self) -> i32 {
@@ -103,15 +123,29 @@ impl CancelRedeemResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelRedeemResult {
     order_id: String,
     serial_no: String,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     updated_time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl CancelRedeemResult {
@@ -138,4 +172,16 @@ impl CancelRedeemResult {
     pub fn set_updated_time(&mut self, updated_time: u64) {
         self.updated_time = updated_time;
     }
+
+    pub fn updated_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.updated_time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
@@ -1,12 +1,18 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
     v5::api::{
         BybitApi,
         post::Post,
     },
+    v5::types::Number,
     utils::{
+        millis_to_datetime,
         serialize_as_string,
         deserialize_string_to_u64,
-        deserialize_f64,
+        deserialize_number,
     },
 };
 
@@ -14,13 +20,17 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/lending/redeem";
+const PATH: &str = "/v5/lending/redeem";
 
 impl BybitApi {
-    pub async fn redeem_funds(&self, params: RedeemFundsParameters) -> Result<RedeemFundsResponse> {
+    pub async fn redeem_funds(&self, params: RedeemFundsParameters) -> Result<RedeemFundsResponse, BybitError> {
         self.post(PATH, Some(params)).await
     }
 }
@@ -30,12 +40,12 @@ impl BybitApi {
 pub struct RedeemFundsParameters {
     coin: String,
     #[serde(serialize_with = "serialize_as_string")]
-    quantity: f64,
+    quantity: Number,
     serial_no: Option<String>
 }
 
 impl RedeemFundsParameters {
-    pub fn new(coin: String, quantity: f64) -> Self {
+    pub fn new(coin: String, quantity: Number) -> Self {
         Self {
             coin,
             quantity,
@@ -49,7 +59,7 @@ impl RedeemFundsParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RedeemFundsResponse {
     ret_code: i32,
@@ -57,6 +67,8 @@ pub struct RedeemFundsResponse {
     result: RedeemFundsResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl RedeemFundsResponse {
     pub fn ret_code(&self) -> i32 {
@@ -98,21 +110,35 @@ impl RedeemFundsResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RedeemFundsResult {
     coin: String,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     created_time: u64,
     order_id: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    principal_qty: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    principal_qty: Number,
     serial_no: String,
     status: String,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     updated_time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl RedeemFundsResult {
@@ -132,6 +158,10 @@ impl RedeemFundsResult {
         self.created_time = created_time;
     }
 
+    pub fn created_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.created_time)
+    }
+
     pub fn order_id(&self) -> &str {
         &self.order_id
     }
@@ -140,11 +170,11 @@ impl RedeemFundsResult {
         self.order_id = order_id;
     }
 
-    pub fn principal_qty(&self) -> f64 {
+    pub fn principal_qty(&self) -> Number {
         self.principal_qty
     }
 
-    pub fn set_principal_qty(&mut self, principal_qty: f64) {
+    pub fn set_principal_qty(&mut self, principal_qty: Number) {
         self.principal_qty = principal_qty;
     }
 
@@ -172,4 +202,16 @@ impl RedeemFundsResult {
         self.updated_time = updated_time;
     }
 
+    pub fn updated_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.updated_time)
+    }
+
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
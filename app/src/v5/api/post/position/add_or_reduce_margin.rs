@@ -1,12 +1,29 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        LinearInverse,
+    },
     v5::api::{
         BybitApi,
         post::Post,
     },
+    v5::types::{
+        Side,
+        PositionIdx,
+        Number,
+    },
     utils::{
-        deserialize_f64,
+        serialize_as_string,
+        serialize_option_as_string,
+        millis_to_datetime,
+        deserialize_number,
         deserialize_string_to_u64,
-        deserialize_option_f64,
+        deserialize_option_number,
+        deserialize_option_enum,
     },
 };
 
@@ -14,10 +31,14 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/position/add-margin";
+const PATH: &str = "/v5/position/add-margin";
 
 impl BybitApi {
     /// add or reduce margin.
@@ -55,17 +76,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn add_or_reduce_margin(&self, params: AddOrReduceMarginParameters) -> Result<AddOrReduceMarginResponse> {
+    pub async fn add_or_reduce_margin(&self, params: AddOrReduceMarginParameters) -> Result<AddOrReduceMarginResponse, BybitError> {
         self.post(PATH, Some(params)).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum AddOrReduceMarginCategory {
-    Linear,
-    Inverse,
-}
+pub type AddOrReduceMarginCategory = Category<LinearInverse>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -73,7 +89,7 @@ pub struct AddOrReduceMarginParameters {
     category: AddOrReduceMarginCategory,
     symbol: String,
     margin: String,
-    position_idx: Option<u8>,
+    position_idx: Option<PositionIdx>,
 }
 
 impl AddOrReduceMarginParameters {
@@ -106,13 +122,13 @@ impl AddOrReduceMarginParameters {
     /// # Returns
     ///
     /// The modified `AddOrReduceMarginParameters` instance.
-    pub fn with_position_idx(mut self, position_idx: u8) -> Self {
+    pub fn with_position_idx(mut self, position_idx: PositionIdx) -> Self {
         self.position_idx = Some(position_idx);
         self
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AddOrReduceMarginResponse {
     ret_code: i32,
@@ -120,6 +136,8 @@ pub struct AddOrReduceMarginResponse {
     result: AddOrReduceMarginResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl AddOrReduceMarginResponse {
     pub fn ret_code(&self) -> i32 {
@@ -161,49 +179,64 @@ impl AddOrReduceMarginResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AddOrReduceMarginResult {
     category: String,
     symbol: String,
-    position_idx: u8,
+    position_idx: PositionIdx,
     risk_id: u64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    risk_limit_value: f64,
-    side: Option<String>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    avg_price: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    risk_limit_value: Number,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    side: Option<Side>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    avg_price: Number,
     liq_price: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    bust_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    mark_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    position_value: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    leverage: f64,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    bust_price: Option<Number>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    mark_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    position_value: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    leverage: Number,
     auto_add_margin: u8,
     position_status: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    position_m_m: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    position_i_m: f64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    take_profit: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    stop_loss: Option<f64>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    trailing_stop: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    unrealised_pnl: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    cum_realised_pnl: f64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    position_m_m: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    position_i_m: Number,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    take_profit: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    stop_loss: Option<Number>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    trailing_stop: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    unrealised_pnl: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    cum_realised_pnl: Number,
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     created_time: u64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     updated_time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl AddOrReduceMarginResult {
@@ -223,11 +256,11 @@ impl AddOrReduceMarginResult {
         self.symbol = symbol;
     }
 
-    pub fn position_idx(&self) -> u8 {
+    pub fn position_idx(&self) -> PositionIdx {
         self.position_idx
     }
 
-    pub fn set_position_idx(&mut self, position_idx: u8) {
+    pub fn set_position_idx(&mut self, position_idx: PositionIdx) {
         self.position_idx = position_idx;
     }
 
@@ -239,27 +272,27 @@ impl AddOrReduceMarginResult {
         self.risk_id = risk_id;
     }
 
-    pub fn risk_limit_value(&self) -> f64 {
+    pub fn risk_limit_value(&self) -> Number {
         self.risk_limit_value
     }
 
-    pub fn set_risk_limit_value(&mut self, risk_limit_value: f64) {
+    pub fn set_risk_limit_value(&mut self, risk_limit_value: Number) {
         self.risk_limit_value = risk_limit_value;
     }
 
-    pub fn side(&self) -> &Option<String> {
+    pub fn side(&self) -> &Option<Side> {
         &self.side
     }
 
-    pub fn set_side(&mut self, side: Option<String>) {
+    pub fn set_side(&mut self, side: Option<Side>) {
         self.side = side;
     }
 
-    pub fn avg_price(&self) -> f64 {
+    pub fn avg_price(&self) -> Number {
         self.avg_price
     }
 
-    pub fn set_avg_price(&mut self, avg_price: f64) {
+    pub fn set_avg_price(&mut self, avg_price: Number) {
         self.avg_price = avg_price;
     }
 
@@ -271,35 +304,35 @@ impl AddOrReduceMarginResult {
         self.liq_price = liq_price;
     }
 
-    pub fn bust_price(&self) -> &Option<f64> {
+    pub fn bust_price(&self) -> &Option<Number> {
         &self.bust_price
     }
 
-    pub fn set_bust_price(&mut self, bust_price: Option<f64>) {
+    pub fn set_bust_price(&mut self, bust_price: Option<Number>) {
         self.bust_price = bust_price;
     }
 
-    pub fn mark_price(&self) -> f64 {
+    pub fn mark_price(&self) -> Number {
         self.mark_price
     }
 
-    pub fn set_mark_price(&mut self, mark_price: f64) {
+    pub fn set_mark_price(&mut self, mark_price: Number) {
         self.mark_price = mark_price;
     }
 
-    pub fn position_value(&self) -> f64 {
+    pub fn position_value(&self) -> Number {
         self.position_value
     }
 
-    pub fn set_position_value(&mut self, position_value: f64) {
+    pub fn set_position_value(&mut self, position_value: Number) {
         self.position_value = position_value;
     }
 
-    pub fn leverage(&self) -> f64 {
+    pub fn leverage(&self) -> Number {
         self.leverage
     }
 
-    pub fn set_leverage(&mut self, leverage: f64) {
+    pub fn set_leverage(&mut self, leverage: Number) {
         self.leverage = leverage;
     }
 
@@ -319,59 +352,59 @@ impl AddOrReduceMarginResult {
         self.position_status = position_status;
     }
 
-    pub fn position_m_m(&self) -> f64 {
+    pub fn position_m_m(&self) -> Number {
         self.position_m_m
     }
 
-    pub fn set_position_m_m(&mut self, position_m_m: f64) {
+    pub fn set_position_m_m(&mut self, position_m_m: Number) {
         self.position_m_m = position_m_m;
     }
 
-    pub fn position_i_m(&self) -> f64 {
+    pub fn position_i_m(&self) -> Number {
         self.position_i_m
     }
 
-    pub fn set_position_i_m(&mut self, position_i_m: f64) {
+    pub fn set_position_i_m(&mut self, position_i_m: Number) {
         self.position_i_m = position_i_m;
     }
 
-    pub fn take_profit(&self) -> &Option<f64> {
+    pub fn take_profit(&self) -> &Option<Number> {
         &self.take_profit
     }
 
-    pub fn set_take_profit(&mut self, take_profit: Option<f64>) {
+    pub fn set_take_profit(&mut self, take_profit: Option<Number>) {
         self.take_profit = take_profit;
     }
 
-    pub fn stop_loss(&self) -> &Option<f64> {
+    pub fn stop_loss(&self) -> &Option<Number> {
         &self.stop_loss
     }
 
-    pub fn set_stop_loss(&mut self, stop_loss: Option<f64>) {
+    pub fn set_stop_loss(&mut self, stop_loss: Option<Number>) {
         self.stop_loss = stop_loss;
     }
 
-    pub fn trailing_stop(&self) -> f64 {
+    pub fn trailing_stop(&self) -> Number {
         self.trailing_stop
     }
 
-    pub fn set_trailing_stop(&mut self, trailing_stop: f64) {
+    pub fn set_trailing_stop(&mut self, trailing_stop: Number) {
         self.trailing_stop = trailing_stop;
     }
 
-    pub fn unrealised_pnl(&self) -> f64 {
+    pub fn unrealised_pnl(&self) -> Number {
         self.unrealised_pnl
     }
 
-    pub fn set_unrealised_pnl(&mut self, unrealised_pnl: f64) {
+    pub fn set_unrealised_pnl(&mut self, unrealised_pnl: Number) {
         self.unrealised_pnl = unrealised_pnl;
     }
 
-    pub fn cum_realised_pnl(&self) -> f64 {
+    pub fn cum_realised_pnl(&self) -> Number {
         self.cum_realised_pnl
     }
 
-    pub fn set_cum_realised_pnl(&mut self, cum_realised_pnl: f64) {
+    pub fn set_cum_realised_pnl(&mut self, cum_realised_pnl: Number) {
         self.cum_realised_pnl = cum_realised_pnl;
     }
 
@@ -383,6 +416,10 @@ impl AddOrReduceMarginResult {
         self.created_time = created_time;
     }
 
+    pub fn created_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.created_time)
+    }
+
     pub fn updated_time(&self) -> u64 {
         self.updated_time
     }
@@ -391,4 +428,16 @@ impl AddOrReduceMarginResult {
         self.updated_time = updated_time;
     }
 
+    pub fn updated_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.updated_time)
+    }
+
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,3 +1,14 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use crate::utils::{
+    millis_to_datetime,
+};
+use crate::v5::category::{
+    Category,
+    LinearInverse,
+};
 use crate::v5::api::{
     BybitApi,
     post::Post,
@@ -7,23 +18,22 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/position/confirm-pending-mmr";
+const PATH: &str = "/v5/position/confirm-pending-mmr";
 
 impl BybitApi {
-    pub async fn confirm_new_risk_limit(&self, params: ConfirmNewRiskLimitParameters) -> Result<ConfirmNewRiskLimitResponse> {
+    pub async fn confirm_new_risk_limit(&self, params: ConfirmNewRiskLimitParameters) -> Result<ConfirmNewRiskLimitResponse, BybitError> {
         self.post(PATH, Some(params)).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum ConfirmNewRiskLimitCategory {
-    Linear,
-    Inverse,
-}
+pub type ConfirmNewRiskLimitCategory = Category<LinearInverse>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -41,7 +51,7 @@ impl ConfirmNewRiskLimitParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ConfirmNewRiskLimitResponse {
     ret_code: i32,
@@ -49,6 +59,8 @@ pub struct ConfirmNewRiskLimitResponse {
     result: Value,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl ConfirmNewRiskLimitResponse {
     pub fn ret_code(&self) -> i32 {
@@ -90,4 +102,16 @@ impl ConfirmNewRiskLimitResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,16 +1,34 @@
-use crate::v5::api::{
-    BybitApi,
-    post::Post,
+use chrono::{
+    DateTime,
+    Utc,
+};
+use crate::{
+    utils::{
+        millis_to_datetime,
+    },
+    v5::category::{
+        Category,
+        LinearInverse,
+    },
+    v5::api::{
+        BybitApi,
+        post::Post,
+    },
+    v5::types::PositionIdx,
 };
 
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/position/set-auto-add-margin";
+const PATH: &str = "/v5/position/set-auto-add-margin";
 
 impl BybitApi {
     /// set auto_add_margin.
@@ -48,17 +66,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn set_auto_add_margin(&self, params: SetAutoAddMarginParameters) -> Result<SetAutoAddMarginResponse> {
+    pub async fn set_auto_add_margin(&self, params: SetAutoAddMarginParameters) -> Result<SetAutoAddMarginResponse, BybitError> {
         self.post(PATH, Some(params)).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum SetAutoAddMarginCategory {
-    Linear,
-    Inverse,
-}
+pub type SetAutoAddMarginCategory = Category<LinearInverse>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -66,7 +79,7 @@ pub struct SetAutoAddMarginParameters {
     category: SetAutoAddMarginCategory,
     symbol: String,
     auto_add_margin: u8,
-    position_idx: Option<u8>,
+    position_idx: Option<PositionIdx>,
 }
 
 impl SetAutoAddMarginParameters {
@@ -99,13 +112,13 @@ impl SetAutoAddMarginParameters {
     /// # Returns
     ///
     /// The modified `SwitchPositionModeParameters` instance.
-    pub fn with_position_idx(mut self, position_idx: u8) -> Self {
+    pub fn with_position_idx(mut self, position_idx: PositionIdx) -> Self {
         self.position_idx = Some(position_idx);
         self
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SetAutoAddMarginResponse {
     ret_code: i32,
@@ -113,6 +126,8 @@ pub struct SetAutoAddMarginResponse {
     result: Value,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl SetAutoAddMarginResponse {
     pub fn ret_code(&self) -> i32 {
@@ -154,4 +169,16 @@ impl SetAutoAddMarginResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,19 +1,35 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        LinearInverse,
+    },
     v5::api::{
         BybitApi,
         post::Post,
     },
-    utils::serialize_as_string,
+    v5::types::Number,
+    utils::{
+        serialize_as_string,
+        millis_to_datetime,
+    },
 };
 
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/position/set-leverage";
+const PATH: &str = "/v5/position/set-leverage";
 
 impl BybitApi {
     /// set leverage.
@@ -38,8 +54,8 @@ impl BybitApi {
     ///     let params = SetLeverageParameters::new(
     ///         SetLeverageCategory::Linear,
     ///         "BTCUSDT".to_string(),
-    ///         10.0,
-    ///         10.0,
+    ///         "10".parse().unwrap(),
+    ///         "10".parse().unwrap(),
     ///     );
     ///     let response = api.set_leverage(params).await;
     ///     match response {
@@ -52,17 +68,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn set_leverage(&self, params: SetLeverageParameters) -> Result<SetLeverageResponse> {
+    pub async fn set_leverage(&self, params: SetLeverageParameters) -> Result<SetLeverageResponse, BybitError> {
         self.post(PATH, Some(params)).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum SetLeverageCategory {
-    Linear,
-    Inverse,
-}
+pub type SetLeverageCategory = Category<LinearInverse>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -70,9 +81,9 @@ pub struct SetLeverageParameters {
     category: SetLeverageCategory,
     symbol: String,
     #[serde(serialize_with = "serialize_as_string")]
-    buy_leverage: f64,
+    buy_leverage: Number,
     #[serde(serialize_with = "serialize_as_string")]
-    sell_leverage: f64,
+    sell_leverage: Number,
 }
 
 impl SetLeverageParameters {
@@ -88,7 +99,7 @@ impl SetLeverageParameters {
     /// # Returns
     ///
     /// A new instance of `SetLeverageParameters`.
-    pub fn new(category: SetLeverageCategory, symbol: String, buy_leverage: f64, sell_leverage: f64) -> Self {
+    pub fn new(category: SetLeverageCategory, symbol: String, buy_leverage: Number, sell_leverage: Number) -> Self {
         Self {
             category,
             symbol,
@@ -98,7 +109,7 @@ impl SetLeverageParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SetLeverageResponse {
     ret_code: i32,
@@ -106,6 +117,8 @@ pub struct SetLeverageResponse {
     result: Value,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl SetLeverageResponse {
     pub fn ret_code(&self) -> i32 {
@@ -147,4 +160,16 @@ impl SetLeverageResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,19 +1,39 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        LinearInverse,
+    },
     v5::api::{
         BybitApi,
         post::Post,
     },
-    utils::deserialize_f64,
+    v5::types::{
+        PositionIdx,
+        Number,
+    },
+    utils::{
+        serialize_as_string,
+        deserialize_number,
+        millis_to_datetime,
+    },
 };
 
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/position/set-risk-limit";
+const PATH: &str = "/v5/position/set-risk-limit";
 
 impl BybitApi {
     /// set risk limit.
@@ -51,17 +71,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn set_risk_limit(&self, params: SetRiskLimitParameters) -> Result<SetRiskLimitResponse> {
+    pub async fn set_risk_limit(&self, params: SetRiskLimitParameters) -> Result<SetRiskLimitResponse, BybitError> {
         self.post(PATH, Some(params)).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum SetRiskLimitCategory {
-    Linear,
-    Inverse,
-}
+pub type SetRiskLimitCategory = Category<LinearInverse>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -69,7 +84,7 @@ pub struct SetRiskLimitParameters {
     category: SetRiskLimitCategory,
     symbol: String,
     risk_id: u64,
-    position_idx: Option<u8>
+    position_idx: Option<PositionIdx>
 }
 
 impl SetRiskLimitParameters {
@@ -103,14 +118,14 @@ impl SetRiskLimitParameters {
     /// # Returns
     ///
     /// The modified `SetRiskLimitParameters` instance.
-    pub fn with_position_idx(mut self, position_idx: u8) -> Self {
+    pub fn with_position_idx(mut self, position_idx: PositionIdx) -> Self {
         self.position_idx = Some(position_idx);
         self
     }
 
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SetRiskLimitResponse {
     ret_code: i32,
@@ -118,6 +133,8 @@ pub struct SetRiskLimitResponse {
     result: SetRiskLimitResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl SetRiskLimitResponse {
     pub fn ret_code(&self) -> i32 {
@@ -159,15 +176,29 @@ impl SetRiskLimitResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SetRiskLimitResult {
     risk_id: u64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    risk_limit_value: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    risk_limit_value: Number,
     category: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl SetRiskLimitResult {
@@ -179,11 +210,11 @@ impl SetRiskLimitResult {
         self.risk_id = risk_id;
     }
 
-    pub fn risk_limit_value(&self) -> f64 {
+    pub fn risk_limit_value(&self) -> Number {
         self.risk_limit_value
     }
 
-    pub fn set_risk_limit_value(&mut self, risk_limit_value: f64) {
+    pub fn set_risk_limit_value(&mut self, risk_limit_value: Number) {
         self.risk_limit_value = risk_limit_value;
     }
 
@@ -194,4 +225,12 @@ impl SetRiskLimitResult {
     pub fn set_category(&mut self, category: String) {
         self.category = category;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,3 +1,14 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use crate::utils::{
+    millis_to_datetime,
+};
+use crate::v5::category::{
+    Category,
+    LinearInverse,
+};
 use crate::v5::api::{
     BybitApi,
     post::Post,
@@ -6,10 +17,14 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/position/set-tpsl-mode";
+const PATH: &str = "/v5/position/set-tpsl-mode";
 
 impl BybitApi {
     /// set tpsl mode.
@@ -47,17 +62,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn set_tpsl_mode(&self, params: SetTpslModeParameters) -> Result<SetTpslModeResponse> {
+    pub async fn set_tpsl_mode(&self, params: SetTpslModeParameters) -> Result<SetTpslModeResponse, BybitError> {
         self.post(PATH, Some(params)).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum SetTpslModeCategory {
-    Linear,
-    Inverse,
-}
+pub type SetTpslModeCategory = Category<LinearInverse>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -88,7 +98,7 @@ impl SetTpslModeParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SetTpslModeResponse {
     ret_code: i32,
@@ -96,6 +106,8 @@ pub struct SetTpslModeResponse {
     result: SetTpslModeResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl SetTpslModeResponse {
     pub fn ret_code(&self) -> i32 {
@@ -137,12 +149,26 @@ impl SetTpslModeResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SetTpslModeResult {
     tp_sl_mode: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl SetTpslModeResult {
@@ -153,4 +179,12 @@ impl SetTpslModeResult {
     pub fn set_tp_sl_mode(&mut self, tp_sl_mode: String) {
         self.tp_sl_mode = tp_sl_mode;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,18 +1,40 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        LinearInverse,
+    },
     v5::api::{
         BybitApi,
         post::Post,
     },
-    utils::serialize_option_as_string,
+    v5::types::{
+        OrderType,
+        TriggerBy,
+        TpslMode,
+        PositionIdx,
+        Number,
+    },
+    utils::{
+        serialize_option_as_string,
+        millis_to_datetime,
+    },
 };
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/position/trading-stop";
+const PATH: &str = "/v5/position/trading-stop";
 
 impl BybitApi {
     /// set trading stop.
@@ -31,14 +53,15 @@ impl BybitApi {
     ///     },
     ///     BybitApi,
     /// };
+    /// use rsbit::v5::types::PositionIdx;
     /// #[tokio::main]
     /// async fn main() {
     ///     let api = BybitApi::new();
     ///     let params = SetTradingStopParameters::new(
     ///         SetTradingStopCategory::Linear,
     ///         "BTCUSDT".to_string(),
-    ///         1,
-    ///     ).with_take_profit(50000.0);
+    ///         PositionIdx::HedgeBuy,
+    ///     ).with_take_profit("50000".parse().unwrap());
     ///     let response = api.set_trading_stop(params).await;
     ///     match response {
     ///         Ok(info) => {
@@ -50,17 +73,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn set_trading_stop(&self, params: SetTradingStopParameters) -> Result<SetTradingStopResponse> {
+    pub async fn set_trading_stop(&self, params: SetTradingStopParameters) -> Result<SetTradingStopResponse, BybitError> {
         self.post(PATH, Some(params)).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum SetTradingStopCategory {
-    Linear,
-    Inverse,
-}
+pub type SetTradingStopCategory = Category<LinearInverse>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -68,27 +86,27 @@ pub struct SetTradingStopParameters {
     category: SetTradingStopCategory,
     symbol: String,
     #[serde(serialize_with = "serialize_option_as_string")]
-    take_profit: Option<f64>,
+    take_profit: Option<Number>,
     #[serde(serialize_with = "serialize_option_as_string")]
-    stop_loss: Option<f64>,
+    stop_loss: Option<Number>,
     #[serde(serialize_with = "serialize_option_as_string")]
-    trailing_stop: Option<f64>,
-    tp_trigger_by: Option<String>,
-    sl_trigger_by: Option<String>,
+    trailing_stop: Option<Number>,
+    tp_trigger_by: Option<TriggerBy>,
+    sl_trigger_by: Option<TriggerBy>,
     #[serde(serialize_with = "serialize_option_as_string")]
-    active_price: Option<f64>,
-    tpsl_mode: Option<String>,
+    active_price: Option<Number>,
+    tpsl_mode: Option<TpslMode>,
     #[serde(serialize_with = "serialize_option_as_string")]
-    tp_size: Option<f64>,
+    tp_size: Option<Number>,
     #[serde(serialize_with = "serialize_option_as_string")]
-    sl_size: Option<f64>,
+    sl_size: Option<Number>,
     #[serde(serialize_with = "serialize_option_as_string")]
-    tp_limit_price: Option<f64>,
+    tp_limit_price: Option<Number>,
     #[serde(serialize_with = "serialize_option_as_string")]
-    sl_limit_price: Option<f64>,
-    tp_order_type: Option<String>,
-    sl_order_type: Option<String>,
-    position_idx: u8,
+    sl_limit_price: Option<Number>,
+    tp_order_type: Option<OrderType>,
+    sl_order_type: Option<OrderType>,
+    position_idx: PositionIdx,
 }
 
 impl SetTradingStopParameters {
@@ -103,7 +121,7 @@ impl SetTradingStopParameters {
     /// # Returns
     ///
     /// A new instance of `SetTradingStopParameters`.
-    pub fn new(category: SetTradingStopCategory, symbol: String, position_idx: u8) -> Self {
+    pub fn new(category: SetTradingStopCategory, symbol: String, position_idx: PositionIdx) -> Self {
         Self {
             category,
             symbol,
@@ -133,7 +151,7 @@ impl SetTradingStopParameters {
     /// # Returns
     ///
     /// The modified `SetTradingStopParameters` instance.
-    pub fn with_take_profit(mut self, take_profit: f64) -> Self {
+    pub fn with_take_profit(mut self, take_profit: Number) -> Self {
         self.take_profit = Some(take_profit);
         self
     }
@@ -147,7 +165,7 @@ impl SetTradingStopParameters {
     /// # Returns
     ///
     /// The modified `SetTradingStopParameters` instance.
-    pub fn with_stop_loss(mut self, stop_loss: f64) -> Self {
+    pub fn with_stop_loss(mut self, stop_loss: Number) -> Self {
         self.stop_loss = Some(stop_loss);
         self
     }
@@ -161,7 +179,7 @@ impl SetTradingStopParameters {
     /// # Returns
     ///
     /// The modified `SetTradingStopParameters` instance.
-    pub fn with_trailing_stop(mut self, trailing_stop: f64) -> Self {
+    pub fn with_trailing_stop(mut self, trailing_stop: Number) -> Self {
         self.trailing_stop = Some(trailing_stop);
         self
     }
@@ -175,7 +193,7 @@ impl SetTradingStopParameters {
     /// # Returns
     ///
     /// The modified `SetTradingStopParameters` instance.
-    pub fn with_tp_trigger_by(mut self, tp_trigger_by: String) -> Self {
+    pub fn with_tp_trigger_by(mut self, tp_trigger_by: TriggerBy) -> Self {
         self.tp_trigger_by = Some(tp_trigger_by);
         self
     }
@@ -189,7 +207,7 @@ impl SetTradingStopParameters {
     /// # Returns
     ///
     /// The modified `SetTradingStopParameters` instance.
-    pub fn with_sl_trigger_by(mut self, sl_trigger_by: String) -> Self {
+    pub fn with_sl_trigger_by(mut self, sl_trigger_by: TriggerBy) -> Self {
         self.sl_trigger_by = Some(sl_trigger_by);
         self
     }
@@ -203,7 +221,7 @@ impl SetTradingStopParameters {
     /// # Returns
     ///
     /// The modified `SetTradingStopParameters` instance.
-    pub fn with_active_price(mut self, active_price: f64) -> Self {
+    pub fn with_active_price(mut self, active_price: Number) -> Self {
         self.active_price = Some(active_price);
         self
     }
@@ -217,7 +235,7 @@ impl SetTradingStopParameters {
     /// # Returns
     ///
     /// The modified `SetTradingStopParameters` instance.
-    pub fn with_tpsl_mode(mut self, tpsl_mode: String) -> Self {
+    pub fn with_tpsl_mode(mut self, tpsl_mode: TpslMode) -> Self {
         self.tpsl_mode = Some(tpsl_mode);
         self
     }
@@ -231,7 +249,7 @@ impl SetTradingStopParameters {
     /// # Returns
     ///
     /// The modified `SetTradingStopParameters` instance.
-    pub fn with_tp_size(mut self, tp_size: f64) -> Self {
+    pub fn with_tp_size(mut self, tp_size: Number) -> Self {
         self.tp_size = Some(tp_size);
         self
     }
@@ -245,7 +263,7 @@ impl SetTradingStopParameters {
     /// # Returns
     ///
     /// The modified `SetTradingStopParameters` instance.
-    pub fn with_sl_size(mut self, sl_size: f64) -> Self {
+    pub fn with_sl_size(mut self, sl_size: Number) -> Self {
         self.sl_size = Some(sl_size);
         self
     }
@@ -259,7 +277,7 @@ impl SetTradingStopParameters {
     /// # Returns
     ///
     /// The modified `SetTradingStopParameters` instance.
-    pub fn with_tp_limit_price(mut self, tp_limit_price: f64) -> Self {
+    pub fn with_tp_limit_price(mut self, tp_limit_price: Number) -> Self {
         self.tp_limit_price = Some(tp_limit_price);
         self
     }
@@ -273,7 +291,7 @@ impl SetTradingStopParameters {
     /// # Returns
     ///
     /// The modified `SetTradingStopParameters` instance.
-    pub fn with_sl_limit_price(mut self, sl_limit_price: f64) -> Self {
+    pub fn with_sl_limit_price(mut self, sl_limit_price: Number) -> Self {
         self.sl_limit_price = Some(sl_limit_price);
         self
     }
@@ -287,7 +305,7 @@ impl SetTradingStopParameters {
     /// # Returns
     ///
     /// The modified `SetTradingStopParameters` instance.
-    pub fn with_tp_order_type(mut self, tp_order_type: String) -> Self {
+    pub fn with_tp_order_type(mut self, tp_order_type: OrderType) -> Self {
         self.tp_order_type = Some(tp_order_type);
         self
     }
@@ -301,7 +319,7 @@ impl SetTradingStopParameters {
     /// # Returns
     ///
     /// The modified `SetTradingStopParameters` instance.
-    pub fn with_sl_order_type(mut self, sl_order_type: String) -> Self {
+    pub fn with_sl_order_type(mut self, sl_order_type: OrderType) -> Self {
         self.sl_order_type = Some(sl_order_type);
         self
     }
@@ -315,13 +333,13 @@ impl SetTradingStopParameters {
     /// # Returns
     ///
     /// The modified `SetTradingStopParameters` instance.
-    pub fn with_position_idx(mut self, position_idx: u8) -> Self {
+    pub fn with_position_idx(mut self, position_idx: PositionIdx) -> Self {
         self.position_idx = position_idx;
         self
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SetTradingStopResponse {
     ret_code: i32,
@@ -329,6 +347,8 @@ pub struct SetTradingStopResponse {
     result: Value,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl SetTradingStopResponse {
     pub fn ret_code(&self) -> i32 {
@@ -370,4 +390,16 @@ impl SetTradingStopResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
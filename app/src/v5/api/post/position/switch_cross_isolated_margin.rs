@@ -1,19 +1,35 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        LinearInverse,
+    },
     v5::api::{
         BybitApi,
         post::Post,
     },
-    utils::serialize_as_string,
+    v5::types::Number,
+    utils::{
+        serialize_as_string,
+        millis_to_datetime,
+    },
 };
 
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/position/switch-isolated";
+const PATH: &str = "/v5/position/switch-isolated";
 
 impl BybitApi {
     /// switch cross isolated margin.
@@ -39,8 +55,8 @@ impl BybitApi {
     ///         SwitchCrossIsolatedMarginCategory::Linear,
     ///         "BTCUSDT".to_string(),
     ///         0,
-    ///         10.0,
-    ///         10.0,
+    ///         "10".parse().unwrap(),
+    ///         "10".parse().unwrap(),
     ///     );
     ///     let response = api.switch_cross_isolated_margin(params).await;
     ///     match response {
@@ -53,17 +69,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn switch_cross_isolated_margin(&self, params: SwitchCrossIsolatedMarginParameters) -> Result<SwitchCrossIsolatedMarginResponse> {
+    pub async fn switch_cross_isolated_margin(&self, params: SwitchCrossIsolatedMarginParameters) -> Result<SwitchCrossIsolatedMarginResponse, BybitError> {
         self.post(PATH, Some(params)).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum SwitchCrossIsolatedMarginCategory {
-    Linear,
-    Inverse,
-}
+pub type SwitchCrossIsolatedMarginCategory = Category<LinearInverse>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -73,9 +84,9 @@ pub struct SwitchCrossIsolatedMarginParameters {
     #[serde(serialize_with = "serialize_as_string")]
     trade_mode: u8,
     #[serde(serialize_with = "serialize_as_string")]
-    buy_leverage: f64,
+    buy_leverage: Number,
     #[serde(serialize_with = "serialize_as_string")]
-    sell_leverage: f64,
+    sell_leverage: Number,
 }
 
 impl SwitchCrossIsolatedMarginParameters {
@@ -92,7 +103,7 @@ impl SwitchCrossIsolatedMarginParameters {
     /// # Returns
     ///
     /// A new instance of `SwitchCrossIsolatedMarginParameters`.
-    pub fn new(category: SwitchCrossIsolatedMarginCategory, symbol: String, trade_mode: u8, buy_leverage: f64, sell_leverage: f64) -> Self {
+    pub fn new(category: SwitchCrossIsolatedMarginCategory, symbol: String, trade_mode: u8, buy_leverage: Number, sell_leverage: Number) -> Self {
         Self {
             category,
             symbol,
@@ -103,7 +114,7 @@ impl SwitchCrossIsolatedMarginParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SwitchCrossIsolatedMarginResponse {
     ret_code: i32,
@@ -111,6 +122,8 @@ pub struct SwitchCrossIsolatedMarginResponse {
     result: Value,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl SwitchCrossIsolatedMarginResponse {
     pub fn ret_code(&self) -> i32 {
@@ -152,4 +165,16 @@ impl SwitchCrossIsolatedMarginResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,19 +1,34 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        LinearInverse,
+    },
     v5::api::{
         BybitApi,
         post::Post,
     },
-    utils::serialize_as_string,
+    utils::{
+        serialize_as_string,
+        millis_to_datetime,
+    },
 };
 
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/position/switch-mode";
+const PATH: &str = "/v5/position/switch-mode";
 
 impl BybitApi {
     /// switch position mode.
@@ -50,17 +65,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn switch_position_mode(&self, params: SwitchPositionModeParameters) -> Result<SwitchPositionModeResponse> {
+    pub async fn switch_position_mode(&self, params: SwitchPositionModeParameters) -> Result<SwitchPositionModeResponse, BybitError> {
         self.post(PATH, Some(params)).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum SwitchPositionModeCategory {
-    Linear,
-    Inverse,
-}
+pub type SwitchPositionModeCategory = Category<LinearInverse>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -124,7 +134,7 @@ impl SwitchPositionModeParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SwitchPositionModeResponse {
     ret_code: i32,
@@ -132,6 +142,8 @@ pub struct SwitchPositionModeResponse {
     result: Value,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl SwitchPositionModeResponse {
     pub fn ret_code(&self) -> i32 {
@@ -173,4 +185,16 @@ impl SwitchPositionModeResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -5,4 +5,6 @@ pub mod cancel_all_order;
 pub mod batch_place_order;
 pub mod batch_amend_order;
 pub mod batch_cancel_order;
-pub mod set_dcp;
\ No newline at end of file
+pub mod set_dcp;
+pub mod validation;
+pub mod batch;
@@ -1,19 +1,42 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::Category,
     v5::api::{
         BybitApi,
         post::Post,
     },
-    utils::serialize_option_as_string,
+    v5::api::post::trade::validation::{
+        OrderValidationError,
+        OrderValidationIssue,
+        limit_tpsl_issue,
+        is_blank,
+    },
+    v5::types::{
+        TriggerBy,
+        TpslMode,
+        Number,
+    },
+    utils::{
+        serialize_option_as_string,
+        millis_to_datetime,
+    },
 };
 
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/order/amend";
+const PATH: &str = "/v5/order/amend";
 
 impl BybitApi {
     /// Amend an order.
@@ -42,16 +65,20 @@ impl BybitApi {
     ///     },
     ///     BybitApi,
     /// };
+    /// use rsbit::v5::types::{
+    ///     Side,
+    ///     OrderType,
+    /// };
     /// #[tokio::main]
     /// async fn main() {
     ///     let api = BybitApi::new();
     ///     let params = PlaceOrderParameters::new(
     ///         PlaceOrderCategory::Linear,
     ///         "BTCUSDT".to_string(),
-    ///         "Buy".to_string(),
-    ///         "Limit".to_string(),
-    ///         0.01,
-    ///     ).with_price(30000.0);
+    ///         Side::Buy,
+    ///         OrderType::Limit,
+    ///         "0.01".parse().unwrap(),
+    ///     ).with_price("30000".parse().unwrap());
     ///     let response = api.place_order(params).await;
     ///     match response {
     ///         Ok(info) => {
@@ -73,19 +100,17 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn amend_order(&self, params: AmendOrderParameters) -> Result<AmendOrderResponse> {
-        self.post(PATH, Some(params)).await
+    pub async fn amend_order(&self, params: AmendOrderParameters) -> Result<AmendOrderResponse, BybitError> {
+        if self.order_validation() {
+            params.validate()?;
+        }
+        // orderLinkId が空だと Bybit が重複を判定できないので再試行しない
+        let retryable = !is_blank(params.order_link_id.as_deref());
+        self.post_with_retry(PATH, Some(params), retryable).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum AmendOrderCategory {
-    Spot,
-    Linear,
-    Inverse,
-    Option,
-}
+pub type AmendOrderCategory = Category;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -96,14 +121,14 @@ pub struct AmendOrderParameters {
     order_link_id: Option<String>,
     order_iv: Option<String>,
     #[serde(serialize_with = "serialize_option_as_string")]
-    qty: Option<f64>,
+    qty: Option<Number>,
     #[serde(serialize_with = "serialize_option_as_string")]
-    price: Option<f64>,
-    tpsl_mode: Option<String>,
+    price: Option<Number>,
+    tpsl_mode: Option<TpslMode>,
     take_profit: Option<String>,
     stop_loss: Option<String>,
-    tp_trigger_by: Option<String>,
-    sl_trigger_by: Option<String>,
+    tp_trigger_by: Option<TriggerBy>,
+    sl_trigger_by: Option<TriggerBy>,
     tp_limit_price: Option<String>,
     sl_limit_price: Option<String>,
 }
@@ -189,7 +214,7 @@ impl AmendOrderParameters {
     /// # Returns
     ///
     /// The modified `AmendOrderParameters` instance.
-    pub fn with_qty(mut self, qty: f64) -> Self {
+    pub fn with_qty(mut self, qty: Number) -> Self {
         self.qty = Some(qty);
         self
     }
@@ -203,7 +228,7 @@ impl AmendOrderParameters {
     /// # Returns
     ///
     /// The modified `AmendOrderParameters` instance.
-    pub fn with_price(mut self, price: f64) -> Self {
+    pub fn with_price(mut self, price: Number) -> Self {
         self.price = Some(price);
         self
     }
@@ -217,7 +242,7 @@ impl AmendOrderParameters {
     /// # Returns
     ///
     /// The modified `AmendOrderParameters` instance.
-    pub fn with_tpsl_mode(mut self, tpsl_mode: String) -> Self {
+    pub fn with_tpsl_mode(mut self, tpsl_mode: TpslMode) -> Self {
         self.tpsl_mode = Some(tpsl_mode);
         self
     }
@@ -259,7 +284,7 @@ impl AmendOrderParameters {
     /// # Returns
     ///
     /// The modified `AmendOrderParameters` instance.
-    pub fn with_tp_trigger_by(mut self, tp_trigger_by: String) -> Self {
+    pub fn with_tp_trigger_by(mut self, tp_trigger_by: TriggerBy) -> Self {
         self.tp_trigger_by = Some(tp_trigger_by);
         self
     }
@@ -273,7 +298,7 @@ impl AmendOrderParameters {
     /// # Returns
     ///
     /// The modified `AmendOrderParameters` instance.
-    pub fn with_sl_trigger_by(mut self, sl_trigger_by: String) -> Self {
+    pub fn with_sl_trigger_by(mut self, sl_trigger_by: TriggerBy) -> Self {
         self.sl_trigger_by = Some(sl_trigger_by);
         self
     }
@@ -306,9 +331,33 @@ impl AmendOrderParameters {
         self
     }
 
+    /// Checks the parameters for combinations Bybit would reject.
+    ///
+    /// `BybitApi::amend_order` calls this before sending unless it was turned off with
+    /// `BybitApi::with_order_validation(false)`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())`, or an error listing every problem found.
+    pub fn validate(&self) -> Result<(), OrderValidationError> {
+        let mut issues = Vec::new();
+        if is_blank(self.order_id.as_deref()) && is_blank(self.order_link_id.as_deref()) {
+            issues.push(OrderValidationIssue::MissingOrderId);
+        }
+        if self.qty.is_some_and(|qty| qty <= Number::default()) {
+            issues.push(OrderValidationIssue::NonPositiveQty);
+        }
+        if self.price.is_some_and(|price| price <= Number::default()) {
+            issues.push(OrderValidationIssue::NonPositivePrice);
+        }
+        if let Some(issue) = limit_tpsl_issue(self.tpsl_mode.as_ref(), self.tp_limit_price.as_deref(), self.sl_limit_price.as_deref()) {
+            issues.push(issue);
+        }
+        OrderValidationError::check(issues)
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AmendOrderResponse {
     ret_code: i32,
@@ -316,6 +365,8 @@ pub struct AmendOrderResponse {
     result: AmendOrderResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl AmendOrderResponse {
     pub fn ret_code(&self) -> i32 {
@@ -357,13 +408,27 @@ impl AmendOrderResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AmendOrderResult {
     order_id: String,
     order_link_id: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl AmendOrderResult {
     pub fn order_id(&self) -> &str {
@@ -381,4 +446,12 @@ impl AmendOrderResult {
     pub fn set_order_link_id(&mut self, order_link_id: String) {
         self.order_link_id = order_link_id;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
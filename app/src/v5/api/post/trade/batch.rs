@@ -0,0 +1,39 @@
+use crate::error::BybitItemError;
+use serde::Deserialize;
+use serde_json::Value;
+
+// retExtInfo.list の各要素。result.list と同じ順番で並ぶ
+#[derive(Deserialize)]
+struct ItemStatus {
+    code: i32,
+    msg: String,
+}
+
+/// Pairs the items of a batch response with their status in `retExtInfo`.
+///
+/// Bybit lists failed requests in `result.list` as well, with empty ids, so the position in the
+/// list is the position of the request in the batch. Items without a status are taken as successful.
+///
+/// # Arguments
+///
+/// * `list` - The items in `result.list`.
+/// * `ret_ext_info` - The `retExtInfo` of the response.
+///
+/// # Returns
+///
+/// One result per request, in request order.
+pub(crate) fn zip_items<'a, T>(list: &'a [T], ret_ext_info: &Value) -> Vec<Result<&'a T, BybitItemError>> {
+    let statuses: Vec<ItemStatus> = ret_ext_info.get("list")
+        .and_then(|list| Vec::<ItemStatus>::deserialize(list).ok())
+        .unwrap_or_default();
+    let len = list.len().max(statuses.len());
+    (0..len)
+        .map(|index| match (list.get(index), statuses.get(index)) {
+            (Some(item), None) => Ok(item),
+            (Some(item), Some(status)) if status.code == 0 => Ok(item),
+            (_, Some(status)) => Err(BybitItemError::new(index, status.code, status.msg.clone())),
+            // statuses より長い場合は list 側で必ず Some になる
+            (None, None) => unreachable!(),
+        })
+        .collect()
+}
@@ -1,19 +1,44 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        LinearOption,
+    },
     v5::api::{
         BybitApi,
         post::Post,
     },
-    utils::serialize_option_as_string,
+    v5::api::post::trade::validation::is_blank,
+    v5::types::{
+        TriggerBy,
+        TpslMode,
+        Number,
+    },
+    utils::{
+        serialize_option_as_string,
+        millis_to_datetime,
+    },
 };
 
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::{
+    BybitError,
+    BybitItemError,
+};
+use crate::v5::api::post::trade::batch::zip_items;
 
-const PATH: &'static str = "/v5/order/amend-batch";
+const PATH: &str = "/v5/order/amend-batch";
 
 impl BybitApi {
     /// batch amend orders.
@@ -40,14 +65,18 @@ impl BybitApi {
     ///     },
     ///     BybitApi,
     /// };
+    /// use rsbit::v5::types::{
+    ///     Side,
+    ///     OrderType,
+    /// };
     /// #[tokio::main]
     /// async fn main() {
     ///     let api = BybitApi::new();
     ///     let params = BatchPlaceOrderParameters::new(
     ///         BatchPlaceOrderCategory::Linear,
     ///         vec![
-    ///             BatchPlaceOrderRequestParameters::new("BTCUSDT".to_string(), "Buy".to_string(), "Limit".to_string(), 0.01).with_price(30000.0),
-    ///             BatchPlaceOrderRequestParameters::new("BTCUSDT".to_string(), "Buy".to_string(), "Limit".to_string(), 0.01).with_price(30000.0),
+    ///             BatchPlaceOrderRequestParameters::new("BTCUSDT".to_string(), Side::Buy, OrderType::Limit, "0.01".parse().unwrap()).with_price("30000".parse().unwrap()),
+    ///             BatchPlaceOrderRequestParameters::new("BTCUSDT".to_string(), Side::Buy, OrderType::Limit, "0.01".parse().unwrap()).with_price("30000".parse().unwrap()),
     ///         ],
     ///     );
     ///     let response = api.batch_place_order(params).await;
@@ -56,8 +85,8 @@ impl BybitApi {
     ///             let params = BatchAmendOrderParameters::new(
     ///                 BatchAmendOrderCategory::Linear,
     ///                 vec![
-    ///                     BatchAmendOrderRequestParameters::new("BTCUSDT".to_string()).with_order_id(info.result().list()[0].order_id().to_string()).with_qty(0.02),
-    ///                     BatchAmendOrderRequestParameters::new("BTCUSDT".to_string()).with_order_id(info.result().list()[1].order_id().to_string()).with_qty(0.02),
+    ///                     BatchAmendOrderRequestParameters::new("BTCUSDT".to_string()).with_order_id(info.result().list()[0].order_id().to_string()).with_qty("0.02".parse().unwrap()),
+    ///                     BatchAmendOrderRequestParameters::new("BTCUSDT".to_string()).with_order_id(info.result().list()[1].order_id().to_string()).with_qty("0.02".parse().unwrap()),
     ///                 ],
     ///             );
     ///             let response = api.batch_amend_order(params).await;
@@ -75,17 +104,14 @@ impl BybitApi {
     ///         }
     ///     }
     /// }
-    pub async fn batch_amend_order(&self, params: BatchAmendOrderParameters) -> Result<BatchAmendOrderResponse> {
-        self.post(PATH, Some(params)).await
+    pub async fn batch_amend_order(&self, params: BatchAmendOrderParameters) -> Result<BatchAmendOrderResponse, BybitError> {
+        // orderLinkId が空だと Bybit が重複を判定できないので再試行しない
+        let retryable = params.request.iter().all(|request| !is_blank(request.order_link_id.as_deref()));
+        self.post_with_retry(PATH, Some(params), retryable).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum BatchAmendOrderCategory {
-    Linear,
-    Option,
-}
+pub type BatchAmendOrderCategory = Category<LinearOption>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -110,18 +136,18 @@ pub struct BatchAmendOrderRequestParameters {
     order_id: Option<String>,
     order_link_id: Option<String>,
     #[serde(serialize_with = "serialize_option_as_string")]
-    order_iv: Option<f64>,
+    order_iv: Option<Number>,
     trigger_price: Option<String>,
     #[serde(serialize_with = "serialize_option_as_string")]
-    qty: Option<f64>,
+    qty: Option<Number>,
     #[serde(serialize_with = "serialize_option_as_string")]
-    price: Option<f64>,
-    tpsl_mode: Option<String>,
+    price: Option<Number>,
+    tpsl_mode: Option<TpslMode>,
     take_profit: Option<String>,
     stop_loss: Option<String>,
-    tp_trigger_by: Option<String>,
-    sl_trigger_by: Option<String>,
-    trigger_by: Option<String>,
+    tp_trigger_by: Option<TriggerBy>,
+    sl_trigger_by: Option<TriggerBy>,
+    trigger_by: Option<TriggerBy>,
     tp_limit_price: Option<String>,
     sl_limit_price: Option<String>,
 }
@@ -194,7 +220,7 @@ impl BatchAmendOrderRequestParameters {
     /// # Returns
     ///
     /// The modified `BatchAmendOrderParameters` instance.
-    pub fn with_order_iv(mut self, order_iv: f64) -> Self {
+    pub fn with_order_iv(mut self, order_iv: Number) -> Self {
         self.order_iv = Some(order_iv);
         self
     }
@@ -222,7 +248,7 @@ impl BatchAmendOrderRequestParameters {
     /// # Returns
     ///
     /// The modified `BatchAmendOrderParameters` instance.
-    pub fn with_qty(mut self, qty: f64) -> Self {
+    pub fn with_qty(mut self, qty: Number) -> Self {
         self.qty = Some(qty);
         self
     }
@@ -236,7 +262,7 @@ impl BatchAmendOrderRequestParameters {
     /// # Returns
     ///
     /// The modified `BatchAmendOrderParameters` instance.
-    pub fn with_price(mut self, price: f64) -> Self {
+    pub fn with_price(mut self, price: Number) -> Self {
         self.price = Some(price);
         self
     }
@@ -250,7 +276,7 @@ impl BatchAmendOrderRequestParameters {
     /// # Returns
     ///
     /// The modified `BatchAmendOrderParameters` instance.
-    pub fn with_tpsl_mode(mut self, tpsl_mode: String) -> Self {
+    pub fn with_tpsl_mode(mut self, tpsl_mode: TpslMode) -> Self {
         self.tpsl_mode = Some(tpsl_mode);
         self
     }
@@ -292,7 +318,7 @@ impl BatchAmendOrderRequestParameters {
     /// # Returns
     ///
     /// The modified `BatchAmendOrderParameters` instance.
-    pub fn with_tp_trigger_by(mut self, tp_trigger_by: String) -> Self {
+    pub fn with_tp_trigger_by(mut self, tp_trigger_by: TriggerBy) -> Self {
         self.tp_trigger_by = Some(tp_trigger_by);
         self
     }
@@ -306,7 +332,7 @@ impl BatchAmendOrderRequestParameters {
     /// # Returns
     ///
     /// The modified `BatchAmendOrderParameters` instance.
-    pub fn with_sl_trigger_by(mut self, sl_trigger_by: String) -> Self {
+    pub fn with_sl_trigger_by(mut self, sl_trigger_by: TriggerBy) -> Self {
         self.sl_trigger_by = Some(sl_trigger_by);
         self
     }
@@ -320,7 +346,7 @@ impl BatchAmendOrderRequestParameters {
     /// # Returns
     ///
     /// The modified `BatchAmendOrderParameters` instance.
-    pub fn with_trigger_by(mut self, trigger_by: String) -> Self {
+    pub fn with_trigger_by(mut self, trigger_by: TriggerBy) -> Self {
         self.trigger_by = Some(trigger_by);
         self
     }
@@ -355,7 +381,7 @@ impl BatchAmendOrderRequestParameters {
 
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchAmendOrderResponse {
     ret_code: i32,
@@ -363,6 +389,8 @@ pub struct BatchAmendOrderResponse {
     result: BatchAmendOrderResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl BatchAmendOrderResponse {
     pub fn ret_code(&self) -> i32 {
@@ -397,6 +425,14 @@ impl BatchAmendOrderResponse {
         self.ret_ext_info = ret_ext_info;
     }
 
+    /// Gets the outcome of each request in the batch, in request order.
+    ///
+    /// `result.list` holds the amended orders and `retExtInfo.list` the status of each request.
+    /// A request with a non-zero `code` gives a `BybitItemError`.
+    pub fn items(&self) -> Vec<Result<&BatchAmendOrder, BybitItemError>> {
+        zip_items(&self.result.list, &self.ret_ext_info)
+    }
+
     pub fn time(&self) -> u64 {
         self.time
     }
@@ -404,12 +440,26 @@ impl BatchAmendOrderResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchAmendOrderResult {
     list: Vec<BatchAmendOrder>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl BatchAmendOrderResult {
     pub fn list(&self) -> &Vec<BatchAmendOrder> {
@@ -419,15 +469,25 @@ impl BatchAmendOrderResult {
     pub fn set_list(&mut self, list: Vec<BatchAmendOrder>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchAmendOrder {
     category: String,
     symbol: String,
     order_id: String,
     order_link_id: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl BatchAmendOrder {
@@ -463,4 +523,12 @@ impl BatchAmendOrder {
         self.order_link_id = order_link_id;
     }
 
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
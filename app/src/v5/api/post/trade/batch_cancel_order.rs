@@ -1,3 +1,14 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use crate::utils::{
+    millis_to_datetime,
+};
+use crate::v5::category::{
+    Category,
+    LinearOption,
+};
 use crate::v5::api::{
     BybitApi,
     post::Post,
@@ -7,10 +18,19 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::{
+    BybitError,
+    BybitItemError,
+};
+use crate::v5::api::post::trade::batch::zip_items;
+use crate::v5::api::post::trade::validation::is_blank;
 
-const PATH: &'static str = "/v5/order/cancel-batch";
+const PATH: &str = "/v5/order/cancel-batch";
 
 impl BybitApi {
     /// batch cancel orders.
@@ -37,14 +57,18 @@ impl BybitApi {
     ///     },
     ///     BybitApi,
     /// };
+    /// use rsbit::v5::types::{
+    ///     Side,
+    ///     OrderType,
+    /// };
     /// #[tokio::main]
     /// async fn main() {
     ///     let api = BybitApi::new();
     ///     let params = BatchPlaceOrderParameters::new(
     ///         BatchPlaceOrderCategory::Linear,
     ///         vec![
-    ///             BatchPlaceOrderRequestParameters::new("BTCUSDT".to_string(), "Buy".to_string(), "Limit".to_string(), 0.01).with_price(30000.0),
-    ///             BatchPlaceOrderRequestParameters::new("BTCUSDT".to_string(), "Buy".to_string(), "Limit".to_string(), 0.01).with_price(30000.0),
+    ///             BatchPlaceOrderRequestParameters::new("BTCUSDT".to_string(), Side::Buy, OrderType::Limit, "0.01".parse().unwrap()).with_price("30000".parse().unwrap()),
+    ///             BatchPlaceOrderRequestParameters::new("BTCUSDT".to_string(), Side::Buy, OrderType::Limit, "0.01".parse().unwrap()).with_price("30000".parse().unwrap()),
     ///         ],
     ///     );
     ///     let response = api.batch_place_order(params).await;
@@ -73,17 +97,14 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn batch_cancel_order(&self, params: BatchCancelOrderParameters) -> Result<BatchCancelOrderResponse> {
-        self.post(PATH, Some(params)).await
+    pub async fn batch_cancel_order(&self, params: BatchCancelOrderParameters) -> Result<BatchCancelOrderResponse, BybitError> {
+        // orderLinkId が空だと Bybit が重複を判定できないので再試行しない
+        let retryable = params.request.iter().all(|request| !is_blank(request.order_link_id.as_deref()));
+        self.post_with_retry(PATH, Some(params), retryable).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum BatchCancelOrderCategory {
-    Linear,
-    Option,
-}
+pub type BatchCancelOrderCategory = Category<LinearOption>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -147,7 +168,7 @@ impl BatchCancelOrderRequestParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchCancelOrderResponse {
     ret_code: i32,
@@ -155,6 +176,8 @@ pub struct BatchCancelOrderResponse {
     result: BatchCancelOrderResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl BatchCancelOrderResponse {
     pub fn ret_code(&self) -> i32 {
@@ -189,6 +212,14 @@ impl BatchCancelOrderResponse {
         self.ret_ext_info = ret_ext_info;
     }
 
+    /// Gets the outcome of each request in the batch, in request order.
+    ///
+    /// `result.list` holds the cancelled orders and `retExtInfo.list` the status of each request.
+    /// A request with a non-zero `code` gives a `BybitItemError`.
+    pub fn items(&self) -> Vec<Result<&BatchCancelOrder, BybitItemError>> {
+        zip_items(&self.result.list, &self.ret_ext_info)
+    }
+
     pub fn time(&self) -> u64 {
         self.time
     }
@@ -196,12 +227,26 @@ impl BatchCancelOrderResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchCancelOrderResult {
     list: Vec<BatchCancelOrder>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl BatchCancelOrderResult {
     pub fn list(&self) -> &Vec<BatchCancelOrder> {
@@ -211,15 +256,25 @@ impl BatchCancelOrderResult {
     pub fn set_list(&mut self, list: Vec<BatchCancelOrder>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchCancelOrder {
     category: String,
     symbol: String,
     order_id: String,
     order_link_id: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl BatchCancelOrder {
@@ -255,4 +310,12 @@ impl BatchCancelOrder {
         self.order_link_id = order_link_id;
     }
 
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
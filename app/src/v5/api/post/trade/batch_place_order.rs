@@ -1,12 +1,38 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        LinearOption,
+    },
     v5::api::{
         BybitApi,
         post::Post,
     },
+    v5::api::post::trade::validation::{
+        NewOrder,
+        OrderValidationError,
+        OrderValidationIssue,
+        is_blank,
+    },
+    v5::types::{
+        Side,
+        OrderType,
+        TimeInForce,
+        TriggerBy,
+        TpslMode,
+        SmpType,
+        PositionIdx,
+        Number,
+    },
     utils::{
+        millis_to_datetime,
+        option_millis_to_datetime,
         serialize_as_string,
         serialize_option_as_string,
-        deserialize_string_to_u64
+        deserialize_string_to_option_u64,
     },
 };
 
@@ -14,10 +40,18 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::{
+    BybitError,
+    BybitItemError,
+};
+use crate::v5::api::post::trade::batch::zip_items;
 
-const PATH: &'static str = "/v5/order/create-batch";
+const PATH: &str = "/v5/order/create-batch";
 
 impl BybitApi {
     /// batch place orders.
@@ -37,15 +71,19 @@ impl BybitApi {
     ///     },
     ///     BybitApi,
     /// };
+    /// use rsbit::v5::types::{
+    ///     Side,
+    ///     OrderType,
+    /// };
     /// #[tokio::main]
     /// async fn main() {
     ///     let api = BybitApi::new();
     ///     let request = vec![
     ///         BatchPlaceOrderRequestParameters::new(
     ///             "BTCUSDT".to_string(),
-    ///             "Buy".to_string(),
-    ///             "Market".to_string(),
-    ///             0.01,
+    ///             Side::Buy,
+    ///             OrderType::Market,
+    ///             "0.01".parse().unwrap(),
     ///         ),
     ///     ];
     ///     let params = BatchPlaceOrderParameters::new(
@@ -63,17 +101,17 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn batch_place_order(&self, params: BatchPlaceOrderParameters) -> Result<BatchPlaceOrderResponse> {
-        self.post(PATH, Some(params)).await
+    pub async fn batch_place_order(&self, params: BatchPlaceOrderParameters) -> Result<BatchPlaceOrderResponse, BybitError> {
+        if self.order_validation() {
+            params.validate()?;
+        }
+        // orderLinkId が空だと Bybit が重複を判定できないので再試行しない
+        let retryable = params.request.iter().all(|request| !is_blank(request.order_link_id.as_deref()));
+        self.post_with_retry(PATH, Some(params), retryable).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum BatchPlaceOrderCategory {
-    Linear,
-    Option,
-}
+pub type BatchPlaceOrderCategory = Category<LinearOption>;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -99,43 +137,68 @@ impl BatchPlaceOrderParameters {
             request,
         }
     }
+
+    /// Checks every request for combinations Bybit would reject.
+    ///
+    /// `BybitApi::batch_place_order` calls this before sending unless it was turned off with
+    /// `BybitApi::with_order_validation(false)`. Problems with a request are reported as
+    /// `OrderValidationIssue::Request` with its position in the batch.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())`, or an error listing every problem found.
+    pub fn validate(&self) -> Result<(), OrderValidationError> {
+        if self.request.is_empty() {
+            return OrderValidationError::check(vec![OrderValidationIssue::EmptyBatch]);
+        }
+        let issues = self.request.iter()
+            .enumerate()
+            .flat_map(|(index, request)| {
+                request.issues(self.category).into_iter().map(move |issue| OrderValidationIssue::Request {
+                    index,
+                    issue: Box::new(issue),
+                })
+            })
+            .collect();
+        OrderValidationError::check(issues)
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchPlaceOrderRequestParameters {
     symbol: String,
-    side: String,
-    order_type: String,
+    side: Side,
+    order_type: OrderType,
     #[serde(serialize_with = "serialize_as_string")]
-    qty: f64,
+    qty: Number,
     #[serde(serialize_with = "serialize_option_as_string")]
-    price: Option<f64>,
+    price: Option<Number>,
     #[serde(serialize_with = "serialize_option_as_string")]
     trigger_direction: Option<u8>,
     trigger_price: Option<String>,
-    trigger_by: Option<String>,
+    trigger_by: Option<TriggerBy>,
     order_iv: Option<String>,
-    time_in_force: Option<String>,
+    time_in_force: Option<TimeInForce>,
     #[serde(serialize_with = "serialize_option_as_string")]
-    position_idx: Option<u8>,
+    position_idx: Option<PositionIdx>,
     order_link_id: Option<String>,
     take_profit: Option<String>,
     stop_loss: Option<String>,
-    tp_trigger_by: Option<String>,
-    sl_trigger_by: Option<String>,
+    tp_trigger_by: Option<TriggerBy>,
+    sl_trigger_by: Option<TriggerBy>,
     #[serde(serialize_with = "serialize_option_as_string")]
     reduce_only: Option<bool>,
     #[serde(serialize_with = "serialize_option_as_string")]
     close_on_trigger: Option<bool>,
-    smp_type: Option<String>,
+    smp_type: Option<SmpType>,
     #[serde(serialize_with = "serialize_option_as_string")]
     mmp: Option<bool>,
-    tpsl_mode: Option<String>,
+    tpsl_mode: Option<TpslMode>,
     tp_limit_price: Option<String>,
     sl_limit_price: Option<String>,
-    tp_order_type: Option<String>,
-    sl_order_type: Option<String>,
+    tp_order_type: Option<OrderType>,
+    sl_order_type: Option<OrderType>,
 }
 
 impl BatchPlaceOrderRequestParameters {
@@ -151,7 +214,7 @@ impl BatchPlaceOrderRequestParameters {
     /// # Returns
     ///
     /// A new instance of `BatchPlaceOrderRequestParameters`.
-    pub fn new(symbol: String, side: String, order_type: String, qty: f64) -> Self {
+    pub fn new(symbol: String, side: Side, order_type: OrderType, qty: Number) -> Self {
         Self {
             symbol,
             side,
@@ -190,7 +253,7 @@ impl BatchPlaceOrderRequestParameters {
     /// # Returns
     ///
     /// The modified `BatchPlaceOrderParameters` instance.
-    pub fn with_price(mut self, price: f64) -> Self {
+    pub fn with_price(mut self, price: Number) -> Self {
         self.price = Some(price);
         self
     }
@@ -232,7 +295,7 @@ impl BatchPlaceOrderRequestParameters {
     /// # Returns
     ///
     /// The modified `BatchPlaceOrderParameters` instance.
-    pub fn with_trigger_by(mut self, trigger_by: String) -> Self {
+    pub fn with_trigger_by(mut self, trigger_by: TriggerBy) -> Self {
         self.trigger_by = Some(trigger_by);
         self
     }
@@ -260,7 +323,7 @@ impl BatchPlaceOrderRequestParameters {
     /// # Returns
     ///
     /// The modified `BatchPlaceOrderParameters` instance.
-    pub fn with_time_in_force(mut self, time_in_force: String) -> Self {
+    pub fn with_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
         self.time_in_force = Some(time_in_force);
         self
     }
@@ -274,7 +337,7 @@ impl BatchPlaceOrderRequestParameters {
     /// # Returns
     ///
     /// The modified `BatchPlaceOrderParameters` instance.
-    pub fn with_position_idx(mut self, position_idx: u8) -> Self {
+    pub fn with_position_idx(mut self, position_idx: PositionIdx) -> Self {
         self.position_idx = Some(position_idx);
         self
     }
@@ -330,7 +393,7 @@ impl BatchPlaceOrderRequestParameters {
     /// # Returns
     ///
     /// The modified `BatchPlaceOrderParameters` instance.
-    pub fn with_tp_trigger_by(mut self, tp_trigger_by: String) -> Self {
+    pub fn with_tp_trigger_by(mut self, tp_trigger_by: TriggerBy) -> Self {
         self.tp_trigger_by = Some(tp_trigger_by);
         self
     }
@@ -344,7 +407,7 @@ impl BatchPlaceOrderRequestParameters {
     /// # Returns
     ///
     /// The modified `BatchPlaceOrderParameters` instance.
-    pub fn with_sl_trigger_by(mut self, sl_trigger_by: String) -> Self {
+    pub fn with_sl_trigger_by(mut self, sl_trigger_by: TriggerBy) -> Self {
         self.sl_trigger_by = Some(sl_trigger_by);
         self
     }
@@ -386,7 +449,7 @@ impl BatchPlaceOrderRequestParameters {
     /// # Returns
     ///
     /// The modified `BatchPlaceOrderParameters` instance.
-    pub fn with_smp_type(mut self, smp_type: String) -> Self {
+    pub fn with_smp_type(mut self, smp_type: SmpType) -> Self {
         self.smp_type = Some(smp_type);
         self
     }
@@ -414,7 +477,7 @@ impl BatchPlaceOrderRequestParameters {
     /// # Returns
     ///
     /// The modified `BatchPlaceOrderParameters` instance.
-    pub fn with_tpsl_mode(mut self, tpsl_mode: String) -> Self {
+    pub fn with_tpsl_mode(mut self, tpsl_mode: TpslMode) -> Self {
         self.tpsl_mode = Some(tpsl_mode);
         self
     }
@@ -456,7 +519,7 @@ impl BatchPlaceOrderRequestParameters {
     /// # Returns
     ///
     /// The modified `BatchPlaceOrderParameters` instance.
-    pub fn with_tp_order_type(mut self, tp_order_type: String) -> Self {
+    pub fn with_tp_order_type(mut self, tp_order_type: OrderType) -> Self {
         self.tp_order_type = Some(tp_order_type);
         self
     }
@@ -470,13 +533,42 @@ impl BatchPlaceOrderRequestParameters {
     /// # Returns
     ///
     /// The modified `BatchPlaceOrderParameters` instance.
-    pub fn with_sl_order_type(mut self, sl_order_type: String) -> Self {
+    pub fn with_sl_order_type(mut self, sl_order_type: OrderType) -> Self {
         self.sl_order_type = Some(sl_order_type);
         self
     }
+
+    /// Checks the request for combinations Bybit would reject.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The category of the batch the request belongs to.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())`, or an error listing every problem found.
+    pub fn validate(&self, category: BatchPlaceOrderCategory) -> Result<(), OrderValidationError> {
+        OrderValidationError::check(self.issues(category))
+    }
+
+    fn issues(&self, category: BatchPlaceOrderCategory) -> Vec<OrderValidationIssue> {
+        NewOrder {
+            category: category.kind(),
+            order_type: &self.order_type,
+            qty: self.qty,
+            price: self.price,
+            trigger_direction: self.trigger_direction,
+            trigger_price: self.trigger_price.as_deref(),
+            order_link_id: self.order_link_id.as_deref(),
+            position_idx: self.position_idx.as_ref(),
+            tpsl_mode: self.tpsl_mode.as_ref(),
+            tp_limit_price: self.tp_limit_price.as_deref(),
+            sl_limit_price: self.sl_limit_price.as_deref(),
+        }.issues()
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchPlaceOrderResponse {
     ret_code: i32,
@@ -484,6 +576,8 @@ pub struct BatchPlaceOrderResponse {
     result: BatchPlaceOrderResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl BatchPlaceOrderResponse {
     pub fn ret_code(&self) -> i32 {
@@ -518,6 +612,14 @@ impl BatchPlaceOrderResponse {
         self.ret_ext_info = ret_ext_info;
     }
 
+    /// Gets the outcome of each request in the batch, in request order.
+    ///
+    /// `result.list` holds the placed orders and `retExtInfo.list` the status of each request.
+    /// A request with a non-zero `code` gives a `BybitItemError`.
+    pub fn items(&self) -> Vec<Result<&BatchPlaceOrder, BybitItemError>> {
+        zip_items(&self.result.list, &self.ret_ext_info)
+    }
+
     pub fn time(&self) -> u64 {
         self.time
     }
@@ -525,12 +627,26 @@ impl BatchPlaceOrderResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchPlaceOrderResult {
     list: Vec<BatchPlaceOrder>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl BatchPlaceOrderResult {
     pub fn list(&self) -> &Vec<BatchPlaceOrder> {
@@ -540,17 +656,28 @@ impl BatchPlaceOrderResult {
     pub fn set_list(&mut self, list: Vec<BatchPlaceOrder>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchPlaceOrder {
     category: String,
     symbol: String,
     order_id: String,
     order_link_id: String,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
-    create_at: u64,
+    // 失敗したリクエストは空文字で返ってくる
+    #[serde(default, deserialize_with = "deserialize_string_to_option_u64", serialize_with = "serialize_option_as_string")]
+    create_at: Option<u64>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl BatchPlaceOrder {
@@ -586,11 +713,23 @@ impl BatchPlaceOrder {
         self.order_link_id = order_link_id;
     }
 
-    pub fn create_at(&self) -> u64 {
+    pub fn create_at(&self) -> Option<u64> {
         self.create_at
     }
 
     pub fn set_create_at(&mut self, create_at: u64) {
-        self.create_at = create_at;
+        self.create_at = Some(create_at);
+    }
+
+    pub fn create_at_datetime(&self) -> Option<DateTime<Utc>> {
+        option_millis_to_datetime(self.create_at)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
     }
 }
\ No newline at end of file
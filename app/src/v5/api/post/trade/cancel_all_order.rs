@@ -1,16 +1,31 @@
-use crate::v5::api::{
-    BybitApi,
-    post::Post,
+use chrono::{
+    DateTime,
+    Utc,
+};
+use crate::{
+    utils::{
+        millis_to_datetime,
+    },
+    v5::category::Category,
+    v5::api::{
+        BybitApi,
+        post::Post,
+    },
+    v5::types::StopOrderType,
 };
 
 use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/order/cancel-all";
+const PATH: &str = "/v5/order/cancel-all";
 
 impl BybitApi {
     /// Cancels all open orders.
@@ -35,16 +50,20 @@ impl BybitApi {
     ///     },
     ///     BybitApi,
     /// };
+    /// use rsbit::v5::types::{
+    ///     Side,
+    ///     OrderType,
+    /// };
     /// #[tokio::main]
     /// async fn main() {
     ///     let api = BybitApi::new();
     ///     let params = PlaceOrderParameters::new(
     ///         PlaceOrderCategory::Linear,
     ///         "BTCUSDT".to_string(),
-    ///         "Buy".to_string(),
-    ///         "Limit".to_string(),
-    ///         0.01,
-    ///     ).with_price(30000.0);
+    ///         Side::Buy,
+    ///         OrderType::Limit,
+    ///         "0.01".parse().unwrap(),
+    ///     ).with_price("30000".parse().unwrap());
     ///     let response = api.place_order(params).await;
     ///     match response {
     ///         Ok(info) => {
@@ -65,19 +84,12 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn cancel_all_order(&self, params: CancelAllOrderParameters) -> Result<CancelAllOrderResponse> {
+    pub async fn cancel_all_order(&self, params: CancelAllOrderParameters) -> Result<CancelAllOrderResponse, BybitError> {
         self.post(PATH, Some(params)).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum CancelAllOrderCategory {
-    Spot,
-    Linear,
-    Inverse,
-    Option,
-}
+pub type CancelAllOrderCategory = Category;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -87,7 +99,7 @@ pub struct CancelAllOrderParameters {
     base_coin: Option<String>,
     settle_coin: Option<String>,
     order_filter: Option<String>,
-    stop_order_type: Option<String>,
+    stop_order_type: Option<StopOrderType>,
 }
 
 impl CancelAllOrderParameters {
@@ -176,13 +188,13 @@ impl CancelAllOrderParameters {
     /// # Returns
     ///
     /// The updated `CancelAllOrderParameters` instance.
-    pub fn with_stop_order_type(mut self, stop_order_type: String) -> Self {
+    pub fn with_stop_order_type(mut self, stop_order_type: StopOrderType) -> Self {
         self.stop_order_type = Some(stop_order_type);
         self
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelAllOrderResponse {
     ret_code: i32,
@@ -190,6 +202,8 @@ pub struct CancelAllOrderResponse {
     result: CancelAllOrderResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl CancelAllOrderResponse {
     pub fn ret_code(&self) -> i32 {
@@ -231,12 +245,26 @@ impl CancelAllOrderResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelAllOrderResult {
     list: Vec<CancelAllOrder>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl CancelAllOrderResult {
     pub fn list(&self) -> &Vec<CancelAllOrder> {
@@ -246,13 +274,23 @@ impl CancelAllOrderResult {
     pub fn set_list(&mut self, list: Vec<CancelAllOrder>) {
         self.list = list;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelAllOrder {
     order_id: String,
     order_link_id: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl CancelAllOrder {
@@ -271,4 +309,12 @@ impl CancelAllOrder {
     pub fn set_order_link_id(&mut self, order_link_id: String) {
         self.order_link_id = order_link_id;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,3 +1,12 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
+use crate::utils::{
+    millis_to_datetime,
+};
+use crate::v5::category::Category;
+use crate::v5::api::post::trade::validation::is_blank;
 use crate::v5::api::{
     BybitApi,
     post::Post,
@@ -7,10 +16,14 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/order/cancel";
+const PATH: &str = "/v5/order/cancel";
 
 impl BybitApi {
     /// Cancels an order on the Bybit exchange.
@@ -35,16 +48,20 @@ impl BybitApi {
     ///     },
     ///     BybitApi,
     /// };
+    /// use rsbit::v5::types::{
+    ///     Side,
+    ///     OrderType,
+    /// };
     /// #[tokio::main]
     /// async fn main() {
     ///     let api = BybitApi::new();
     ///     let params = PlaceOrderParameters::new(
     ///         PlaceOrderCategory::Linear,
     ///         "BTCUSDT".to_string(),
-    ///         "Buy".to_string(),
-    ///         "Limit".to_string(),
-    ///         0.01,
-    ///     ).with_price(30000.0);
+    ///         Side::Buy,
+    ///         OrderType::Limit,
+    ///         "0.01".parse().unwrap(),
+    ///     ).with_price("30000".parse().unwrap());
     ///     let response = api.place_order(params).await;
     ///     match response {
     ///         Ok(info) => {
@@ -65,19 +82,14 @@ impl BybitApi {
     ///         Err(err) => {}
     ///     }
     /// }
-    pub async fn cancel_order(&self, params: CancelOrderParameters) -> Result<CancelOrderResponse> {
-        self.post(PATH, Some(params)).await
+    pub async fn cancel_order(&self, params: CancelOrderParameters) -> Result<CancelOrderResponse, BybitError> {
+        // orderLinkId が空だと Bybit が重複を判定できないので再試行しない
+        let retryable = !is_blank(params.order_link_id.as_deref());
+        self.post_with_retry(PATH, Some(params), retryable).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum CancelOrderCategory {
-    Spot,
-    Linear,
-    Inverse,
-    Option,
-}
+pub type CancelOrderCategory = Category;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -153,7 +165,7 @@ impl CancelOrderParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelOrderResponse {
     ret_code: i32,
@@ -161,6 +173,8 @@ pub struct CancelOrderResponse {
     result: CancelOrderResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl CancelOrderResponse {
     pub fn ret_code(&self) -> i32 {
@@ -202,13 +216,27 @@ impl CancelOrderResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelOrderResult {
     order_id: String,
     order_link_id: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl CancelOrderResult {
     pub fn order_id(&self) -> &str {
@@ -226,4 +254,12 @@ impl CancelOrderResult {
     pub fn set_order_link_id(&mut self, order_link_id: String) {
         self.order_link_id = order_link_id;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,9 +1,30 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::Category,
     v5::api::{
         BybitApi,
         post::Post,
     },
+    v5::api::post::trade::validation::{
+        NewOrder,
+        OrderValidationError,
+        is_blank,
+    },
+    v5::types::{
+        Side,
+        OrderType,
+        TimeInForce,
+        TriggerBy,
+        TpslMode,
+        SmpType,
+        PositionIdx,
+        Number,
+    },
     utils::{
+        millis_to_datetime,
         serialize_as_string,
         serialize_option_as_string,
     },
@@ -13,10 +34,14 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use serde_json::Value;
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/order/create";
+const PATH: &str = "/v5/order/create";
 
 impl BybitApi {
     /// Places an order on the Bybit exchange.
@@ -35,15 +60,19 @@ impl BybitApi {
     ///     },
     ///     BybitApi,
     /// };
+    /// use rsbit::v5::types::{
+    ///     Side,
+    ///     OrderType,
+    /// };
     /// #[tokio::main]
     /// async fn main() {
     ///     let api = BybitApi::new();
     ///     let params = PlaceOrderParameters::new(
-    ///         PlaceOrderCategory::Option,
+    ///         PlaceOrderCategory::Linear,
     ///         "BTCUSDT".to_string(),
-    ///         "Buy".to_string(),
-    ///         "Market".to_string(),
-    ///         0.01,
+    ///         Side::Buy,
+    ///         OrderType::Market,
+    ///         "0.01".parse().unwrap(),
     ///     );
     ///     let response = api.place_order(params).await;
     ///     match response {
@@ -56,19 +85,17 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn place_order(&self, params: PlaceOrderParameters) -> Result<PlaceOrderResponse> {
-        self.post(PATH, Some(params)).await
+    pub async fn place_order(&self, params: PlaceOrderParameters) -> Result<PlaceOrderResponse, BybitError> {
+        if self.order_validation() {
+            params.validate()?;
+        }
+        // orderLinkId が空だと Bybit が重複を判定できないので再試行しない
+        let retryable = !is_blank(params.order_link_id.as_deref());
+        self.post_with_retry(PATH, Some(params), retryable).await
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub enum PlaceOrderCategory {
-    Spot,
-    Linear,
-    Inverse,
-    Option,
-}
+pub type PlaceOrderCategory = Category;
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -77,38 +104,38 @@ pub struct PlaceOrderParameters {
     symbol: String,
     #[serde(serialize_with = "serialize_option_as_string")]
     is_leverage: Option<u8>,
-    side: String,
-    order_type: String,
+    side: Side,
+    order_type: OrderType,
     #[serde(serialize_with = "serialize_as_string")]
-    qty: f64,
+    qty: Number,
     #[serde(serialize_with = "serialize_option_as_string")]
-    price: Option<f64>,
+    price: Option<Number>,
     #[serde(serialize_with = "serialize_option_as_string")]
     trigger_direction: Option<u8>,
     order_filter: Option<String>,
     trigger_price: Option<String>,
-    trigger_by: Option<String>,
+    trigger_by: Option<TriggerBy>,
     order_iv: Option<String>,
-    time_in_force: Option<String>,
+    time_in_force: Option<TimeInForce>,
     #[serde(serialize_with = "serialize_option_as_string")]
-    position_idx: Option<u8>,
+    position_idx: Option<PositionIdx>,
     order_link_id: Option<String>,
     take_profit: Option<String>,
     stop_loss: Option<String>,
-    tp_trigger_by: Option<String>,
-    sl_trigger_by: Option<String>,
+    tp_trigger_by: Option<TriggerBy>,
+    sl_trigger_by: Option<TriggerBy>,
     #[serde(serialize_with = "serialize_option_as_string")]
     reduce_only: Option<bool>,
     #[serde(serialize_with = "serialize_option_as_string")]
     close_on_trigger: Option<bool>,
-    smp_type: Option<String>,
+    smp_type: Option<SmpType>,
     #[serde(serialize_with = "serialize_option_as_string")]
     mmp: Option<bool>,
-    tpsl_mode: Option<String>,
+    tpsl_mode: Option<TpslMode>,
     tp_limit_price: Option<String>,
     sl_limit_price: Option<String>,
-    tp_order_type: Option<String>,
-    sl_order_type: Option<String>,
+    tp_order_type: Option<OrderType>,
+    sl_order_type: Option<OrderType>,
 }
 
 impl PlaceOrderParameters {
@@ -125,7 +152,7 @@ impl PlaceOrderParameters {
     /// # Returns
     ///
     /// A new instance of `PlaceOrderParameters`.
-    pub fn new(category: PlaceOrderCategory, symbol: String, side: String, order_type: String, qty: f64) -> Self {
+    pub fn new(category: PlaceOrderCategory, symbol: String, side: Side, order_type: OrderType, qty: Number) -> Self {
         Self {
             category,
             symbol,
@@ -181,7 +208,7 @@ impl PlaceOrderParameters {
     /// # Returns
     ///
     /// The modified `PlaceOrderParameters` instance.
-    pub fn with_price(mut self, price: f64) -> Self {
+    pub fn with_price(mut self, price: Number) -> Self {
         self.price = Some(price);
         self
     }
@@ -237,7 +264,7 @@ impl PlaceOrderParameters {
     /// # Returns
     ///
     /// The modified `PlaceOrderParameters` instance.
-    pub fn with_trigger_by(mut self, trigger_by: String) -> Self {
+    pub fn with_trigger_by(mut self, trigger_by: TriggerBy) -> Self {
         self.trigger_by = Some(trigger_by);
         self
     }
@@ -265,7 +292,7 @@ impl PlaceOrderParameters {
     /// # Returns
     ///
     /// The modified `PlaceOrderParameters` instance.
-    pub fn with_time_in_force(mut self, time_in_force: String) -> Self {
+    pub fn with_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
         self.time_in_force = Some(time_in_force);
         self
     }
@@ -279,7 +306,7 @@ impl PlaceOrderParameters {
     /// # Returns
     ///
     /// The modified `PlaceOrderParameters` instance.
-    pub fn with_position_idx(mut self, position_idx: u8) -> Self {
+    pub fn with_position_idx(mut self, position_idx: PositionIdx) -> Self {
         self.position_idx = Some(position_idx);
         self
     }
@@ -335,7 +362,7 @@ impl PlaceOrderParameters {
     /// # Returns
     ///
     /// The modified `PlaceOrderParameters` instance.
-    pub fn with_tp_trigger_by(mut self, tp_trigger_by: String) -> Self {
+    pub fn with_tp_trigger_by(mut self, tp_trigger_by: TriggerBy) -> Self {
         self.tp_trigger_by = Some(tp_trigger_by);
         self
     }
@@ -349,7 +376,7 @@ impl PlaceOrderParameters {
     /// # Returns
     ///
     /// The modified `PlaceOrderParameters` instance.
-    pub fn with_sl_trigger_by(mut self, sl_trigger_by: String) -> Self {
+    pub fn with_sl_trigger_by(mut self, sl_trigger_by: TriggerBy) -> Self {
         self.sl_trigger_by = Some(sl_trigger_by);
         self
     }
@@ -391,7 +418,7 @@ impl PlaceOrderParameters {
     /// # Returns
     ///
     /// The modified `PlaceOrderParameters` instance.
-    pub fn with_smp_type(mut self, smp_type: String) -> Self {
+    pub fn with_smp_type(mut self, smp_type: SmpType) -> Self {
         self.smp_type = Some(smp_type);
         self
     }
@@ -419,7 +446,7 @@ impl PlaceOrderParameters {
     /// # Returns
     ///
     /// The modified `PlaceOrderParameters` instance.
-    pub fn with_tpsl_mode(mut self, tpsl_mode: String) -> Self {
+    pub fn with_tpsl_mode(mut self, tpsl_mode: TpslMode) -> Self {
         self.tpsl_mode = Some(tpsl_mode);
         self
     }
@@ -461,7 +488,7 @@ impl PlaceOrderParameters {
     /// # Returns
     ///
     /// The modified `PlaceOrderParameters` instance.
-    pub fn with_tp_order_type(mut self, tp_order_type: String) -> Self {
+    pub fn with_tp_order_type(mut self, tp_order_type: OrderType) -> Self {
         self.tp_order_type = Some(tp_order_type);
         self
     }
@@ -475,14 +502,37 @@ impl PlaceOrderParameters {
     /// # Returns
     ///
     /// The modified `PlaceOrderParameters` instance.
-    pub fn with_sl_order_type(mut self, sl_order_type: String) -> Self {
+    pub fn with_sl_order_type(mut self, sl_order_type: OrderType) -> Self {
         self.sl_order_type = Some(sl_order_type);
         self
     }
 
+    /// Checks the parameters for combinations Bybit would reject.
+    ///
+    /// `BybitApi::place_order` calls this before sending unless it was turned off with
+    /// `BybitApi::with_order_validation(false)`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())`, or an error listing every problem found.
+    pub fn validate(&self) -> Result<(), OrderValidationError> {
+        OrderValidationError::check(NewOrder {
+            category: self.category.kind(),
+            order_type: &self.order_type,
+            qty: self.qty,
+            price: self.price,
+            trigger_direction: self.trigger_direction,
+            trigger_price: self.trigger_price.as_deref(),
+            order_link_id: self.order_link_id.as_deref(),
+            position_idx: self.position_idx.as_ref(),
+            tpsl_mode: self.tpsl_mode.as_ref(),
+            tp_limit_price: self.tp_limit_price.as_deref(),
+            sl_limit_price: self.sl_limit_price.as_deref(),
+        }.issues())
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PlaceOrderResponse {
     ret_code: i32,
@@ -490,6 +540,8 @@ pub struct PlaceOrderResponse {
     result: PlaceOrderResult,
     ret_ext_info: Value,
     time: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl PlaceOrderResponse {
     pub fn ret_code(&self) -> i32 {
@@ -531,13 +583,27 @@ impl PlaceOrderResponse {
     pub fn set_time(&mut self, time: u64) {
         self.time = time;
     }
+
+    pub fn time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.time)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PlaceOrderResult {
     order_id: String,
     order_link_id: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl PlaceOrderResult {
     pub fn order_id(&self) -> &str {
@@ -555,4 +621,12 @@ impl PlaceOrderResult {
     pub fn set_order_link_id(&mut self, order_link_id: String) {
         self.order_link_id = order_link_id;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
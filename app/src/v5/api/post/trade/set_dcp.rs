@@ -7,9 +7,14 @@ use serde::{
     Serialize,
     Deserialize,
 };
-use anyhow::Result;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+use crate::error::BybitError;
 
-const PATH: &'static str = "/v5/order/disconnected-cancel-all";
+const PATH: &str = "/v5/order/disconnected-cancel-all";
 
 impl BybitApi {
     /// set DCP(Disconnect Cancel All).
@@ -44,7 +49,7 @@ impl BybitApi {
     ///     }
     /// }
     /// ```
-    pub async fn set_dcp(&self, params: SetDCPParameters) -> Result<SetDCPResponse> {
+    pub async fn set_dcp(&self, params: SetDCPParameters) -> Result<SetDCPResponse, BybitError> {
         self.post(PATH, Some(params)).await
     }
 }
@@ -72,11 +77,13 @@ impl SetDCPParameters {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SetDCPResponse {
     ret_code: i32,
     ret_msg: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 impl SetDCPResponse {
     pub fn ret_code(&self) -> i32 {
@@ -95,4 +102,12 @@ impl SetDCPResponse {
         self.ret_msg = ret_msg;
     }
 
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
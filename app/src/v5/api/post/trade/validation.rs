@@ -0,0 +1,159 @@
+use crate::v5::{
+    category::CategoryKind,
+    types::{
+        Number,
+        OrderType,
+        PositionIdx,
+        TpslMode,
+    },
+};
+use std::{
+    error::Error,
+    fmt::{
+        Display,
+        Formatter,
+        Result,
+    },
+};
+
+/// A problem found by `validate()` on order parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderValidationIssue {
+    /// `qty` is zero or negative.
+    NonPositiveQty,
+    /// `price` is zero or negative.
+    NonPositivePrice,
+    /// A `Limit` order has no `price`.
+    MissingPrice,
+    /// `trigger_direction` is set without `trigger_price`.
+    TriggerDirectionWithoutTriggerPrice,
+    /// An `option` order has no `order_link_id`, which Bybit requires for options.
+    MissingOrderLinkId,
+    /// `tp_limit_price` or `sl_limit_price` is set without `tpsl_mode` `Partial`.
+    LimitTpslWithoutPartialMode,
+    /// `position_idx` is set on a `spot` order, which has no positions.
+    PositionIdxOnSpot,
+    /// An amendment has neither `order_id` nor `order_link_id`.
+    MissingOrderId,
+    /// A batch has no requests.
+    EmptyBatch,
+    /// A problem with one request of a batch.
+    Request {
+        /// The position of the request in the batch, starting at 0.
+        index: usize,
+        issue: Box<OrderValidationIssue>,
+    },
+}
+
+impl Display for OrderValidationIssue {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            OrderValidationIssue::NonPositiveQty => write!(f, "qty must be positive"),
+            OrderValidationIssue::NonPositivePrice => write!(f, "price must be positive"),
+            OrderValidationIssue::MissingPrice => write!(f, "price is required for a Limit order"),
+            OrderValidationIssue::TriggerDirectionWithoutTriggerPrice => write!(f, "trigger_direction requires trigger_price"),
+            OrderValidationIssue::MissingOrderLinkId => write!(f, "order_link_id is required for an option order"),
+            OrderValidationIssue::LimitTpslWithoutPartialMode => write!(f, "tp_limit_price and sl_limit_price require tpsl_mode Partial"),
+            OrderValidationIssue::PositionIdxOnSpot => write!(f, "position_idx is not supported for a spot order"),
+            OrderValidationIssue::MissingOrderId => write!(f, "order_id or order_link_id is required"),
+            OrderValidationIssue::EmptyBatch => write!(f, "the batch has no requests"),
+            OrderValidationIssue::Request { index, issue } => write!(f, "request {}: {}", index, issue),
+        }
+    }
+}
+
+/// The error returned by `validate()` on order parameters, listing every problem found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderValidationError {
+    issues: Vec<OrderValidationIssue>,
+}
+
+impl OrderValidationError {
+    /// Gets the problems found, in the order they were checked.
+    pub fn issues(&self) -> &[OrderValidationIssue] {
+        &self.issues
+    }
+
+    /// Turns a list of problems into a result.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if `issues` is empty, otherwise an error listing them.
+    pub(crate) fn check(issues: Vec<OrderValidationIssue>) -> std::result::Result<(), OrderValidationError> {
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(OrderValidationError { issues })
+        }
+    }
+}
+
+impl Display for OrderValidationError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "Invalid order parameters: ")?;
+        for (i, issue) in self.issues.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for OrderValidationError {}
+
+/// The fields shared by `PlaceOrderParameters` and `BatchPlaceOrderRequestParameters`.
+pub(crate) struct NewOrder<'a> {
+    pub category: CategoryKind,
+    pub order_type: &'a OrderType,
+    pub qty: Number,
+    pub price: Option<Number>,
+    pub trigger_direction: Option<u8>,
+    pub trigger_price: Option<&'a str>,
+    pub order_link_id: Option<&'a str>,
+    pub position_idx: Option<&'a PositionIdx>,
+    pub tpsl_mode: Option<&'a TpslMode>,
+    pub tp_limit_price: Option<&'a str>,
+    pub sl_limit_price: Option<&'a str>,
+}
+
+impl NewOrder<'_> {
+    pub(crate) fn issues(&self) -> Vec<OrderValidationIssue> {
+        let mut issues = Vec::new();
+        if self.qty <= Number::default() {
+            issues.push(OrderValidationIssue::NonPositiveQty);
+        }
+        match self.price {
+            Some(price) if price <= Number::default() => issues.push(OrderValidationIssue::NonPositivePrice),
+            None if *self.order_type == OrderType::Limit => issues.push(OrderValidationIssue::MissingPrice),
+            _ => {},
+        }
+        if self.trigger_direction.is_some() && is_blank(self.trigger_price) {
+            issues.push(OrderValidationIssue::TriggerDirectionWithoutTriggerPrice);
+        }
+        if self.category == CategoryKind::Option && is_blank(self.order_link_id) {
+            issues.push(OrderValidationIssue::MissingOrderLinkId);
+        }
+        if let Some(issue) = limit_tpsl_issue(self.tpsl_mode, self.tp_limit_price, self.sl_limit_price) {
+            issues.push(issue);
+        }
+        if self.category == CategoryKind::Spot && self.position_idx.is_some() {
+            issues.push(OrderValidationIssue::PositionIdxOnSpot);
+        }
+        issues
+    }
+}
+
+pub(crate) fn limit_tpsl_issue(tpsl_mode: Option<&TpslMode>, tp_limit_price: Option<&str>, sl_limit_price: Option<&str>) -> Option<OrderValidationIssue> {
+    let has_limit_price = !is_blank(tp_limit_price) || !is_blank(sl_limit_price);
+    if has_limit_price && tpsl_mode != Some(&TpslMode::Partial) {
+        Some(OrderValidationIssue::LimitTpslWithoutPartialMode)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn is_blank(value: Option<&str>) -> bool {
+    value.is_none_or(str::is_empty)
+}
@@ -0,0 +1,354 @@
+use crate::{
+    utils::millis_to_datetime,
+    constants::{
+        RATE_LIMIT_HEADER,
+        RATE_LIMIT_STATUS_HEADER,
+        RATE_LIMIT_RESET_TIMESTAMP_HEADER,
+    },
+    error::BybitError,
+    v5::api::BybitApi,
+};
+use reqwest::header::HeaderMap;
+use chrono::{
+    DateTime,
+    Utc,
+};
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+    str::FromStr,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+impl BybitApi {
+    /// Gets the rate limit status last reported by Bybit for an endpoint.
+    ///
+    /// The status is taken from the `X-Bapi-Limit`, `X-Bapi-Limit-Status` and
+    /// `X-Bapi-Limit-Reset-Timestamp` headers and is shared by every clone of this instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - The endpoint path, e.g. `/v5/order/create`.
+    ///
+    /// # Returns
+    ///
+    /// The last reported status, or `None` if the endpoint has not been called yet.
+    pub fn rate_limit_status(&self, endpoint: &str) -> Option<RateLimitStatus> {
+        self.rate_limit_statuses
+            .lock()
+            .expect("rate limit status lock poisoned")
+            .get(endpoint)
+            .cloned()
+    }
+
+    pub(crate) fn update_rate_limit_status(&self, endpoint: &str, headers: &HeaderMap) {
+        if let Some(status) = RateLimitStatus::from_headers(headers) {
+            self.rate_limit_statuses
+                .lock()
+                .expect("rate limit status lock poisoned")
+                .insert(endpoint.to_string(), status);
+        }
+    }
+
+    pub(crate) async fn acquire_rate_limit(&self, endpoint: &str) -> Result<(), BybitError> {
+        match self.rate_limiter() {
+            Some(rate_limiter) => rate_limiter.acquire(endpoint, self.rate_limit_status(endpoint)).await,
+            None => Ok(()),
+        }
+    }
+}
+
+/// The rate limit status reported by Bybit for an endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    limit: u32,
+    remaining: u32,
+    reset_timestamp: u64,
+}
+
+impl RateLimitStatus {
+    /// Parses the rate limit headers of a response.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` - The response headers.
+    ///
+    /// # Returns
+    ///
+    /// The parsed status, or `None` if any of the headers is missing or malformed.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        Some(Self {
+            limit: parse_header(headers, RATE_LIMIT_HEADER)?,
+            remaining: parse_header(headers, RATE_LIMIT_STATUS_HEADER)?,
+            reset_timestamp: parse_header(headers, RATE_LIMIT_RESET_TIMESTAMP_HEADER)?,
+        })
+    }
+
+    /// Gets the number of requests allowed in the current window.
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+
+    /// Gets the number of requests remaining in the current window.
+    pub fn remaining(&self) -> u32 {
+        self.remaining
+    }
+
+    /// Gets the time at which the current window resets, in milliseconds.
+    pub fn reset_timestamp(&self) -> u64 {
+        self.reset_timestamp
+    }
+
+    /// Gets the time at which the current window resets.
+    pub fn reset_timestamp_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.reset_timestamp)
+    }
+
+    /// Gets the time left until the window resets if no request remains in it.
+    ///
+    /// # Returns
+    ///
+    /// `Some` if the limit is exhausted and the window has not reset yet, `None` otherwise.
+    pub fn exhausted_for(&self) -> Option<Duration> {
+        if self.remaining > 0 {
+            return None;
+        }
+        let now = Utc::now().timestamp_millis().max(0) as u64;
+        self.reset_timestamp
+            .checked_sub(now)
+            .filter(|wait| *wait > 0)
+            .map(Duration::from_millis)
+    }
+}
+
+// 値が型に収まらない場合も不正なヘッダーとして扱う
+fn parse_header<T: FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// Groups of endpoints that share a rate limit budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndpointGroup {
+    /// Order create, amend and cancel endpoints under `/v5/order`.
+    Order,
+    /// Endpoints under `/v5/position`.
+    Position,
+    /// Endpoints under `/v5/account`.
+    Account,
+    /// Any other endpoint.
+    Other,
+}
+
+impl EndpointGroup {
+    /// Classifies an endpoint path.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - The endpoint path, e.g. `/v5/order/create`.
+    ///
+    /// # Returns
+    ///
+    /// The group the endpoint belongs to.
+    pub fn from_endpoint(endpoint: &str) -> Self {
+        match endpoint {
+            "/v5/order/create"
+            | "/v5/order/amend"
+            | "/v5/order/cancel"
+            | "/v5/order/cancel-all"
+            | "/v5/order/create-batch"
+            | "/v5/order/amend-batch"
+            | "/v5/order/cancel-batch" => EndpointGroup::Order,
+            _ if endpoint.starts_with("/v5/position/") => EndpointGroup::Position,
+            _ if endpoint.starts_with("/v5/account/") => EndpointGroup::Account,
+            _ => EndpointGroup::Other,
+        }
+    }
+}
+
+/// What the `RateLimiter` does when a budget is exhausted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitMode {
+    /// Waits until the request fits in the budget.
+    Delay,
+    /// Fails with `BybitError::RateLimited` without sending the request.
+    Reject,
+}
+
+/// A request budget: at most `requests` requests within `per`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitBudget {
+    requests: u32,
+    per: Duration,
+}
+
+impl RateLimitBudget {
+    /// Creates a new instance of `RateLimitBudget`.
+    ///
+    /// # Arguments
+    ///
+    /// * `requests` - The number of requests allowed in the window.
+    /// * `per` - The length of the window.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `RateLimitBudget`.
+    pub fn new(requests: u32, per: Duration) -> Self {
+        Self {
+            requests,
+            per,
+        }
+    }
+
+    /// Gets the number of requests allowed per window.
+    pub fn requests(&self) -> u32 {
+        self.requests
+    }
+
+    /// Gets the length of the window.
+    pub fn per(&self) -> Duration {
+        self.per
+    }
+}
+
+/// A client-side rate limiter.
+///
+/// Each `EndpointGroup` has its own sliding window budget. The limiter also honours the
+/// status reported by Bybit, so an endpoint whose limit is exhausted is held back until
+/// the reported reset time. Clones share the same windows.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    mode: RateLimitMode,
+    budgets: HashMap<EndpointGroup, RateLimitBudget>,
+    windows: Arc<Mutex<HashMap<EndpointGroup, VecDeque<Instant>>>>,
+}
+
+impl RateLimiter {
+    /// Creates a new instance of `RateLimiter` with the default budgets.
+    ///
+    /// The defaults follow Bybit's per-UID limits: 10 requests per second for order and
+    /// position endpoints, 5 per second for account endpoints, and the IP limit of 600
+    /// requests per 5 seconds for everything else.
+    pub fn new() -> Self {
+        let mut budgets = HashMap::new();
+        budgets.insert(EndpointGroup::Order, RateLimitBudget::new(10, Duration::from_secs(1)));
+        budgets.insert(EndpointGroup::Position, RateLimitBudget::new(10, Duration::from_secs(1)));
+        budgets.insert(EndpointGroup::Account, RateLimitBudget::new(5, Duration::from_secs(1)));
+        budgets.insert(EndpointGroup::Other, RateLimitBudget::new(600, Duration::from_secs(5)));
+        Self {
+            mode: RateLimitMode::Delay,
+            budgets,
+            windows: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Gets what happens when a budget is exhausted.
+    pub fn mode(&self) -> RateLimitMode {
+        self.mode
+    }
+
+    /// Gets the budget of an endpoint group, if one is set.
+    pub fn budget(&self, group: EndpointGroup) -> Option<&RateLimitBudget> {
+        self.budgets.get(&group)
+    }
+
+    /// Sets what happens when a budget is exhausted.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode to be set.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Self` object.
+    pub fn with_mode(mut self, mode: RateLimitMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the budget of an endpoint group.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The endpoint group.
+    /// * `budget` - The budget to be set.
+    ///
+    /// # Returns
+    ///
+    /// The modified `Self` object.
+    pub fn with_budget(mut self, group: EndpointGroup, budget: RateLimitBudget) -> Self {
+        self.budgets.insert(group, budget);
+        self
+    }
+
+    /// Reserves a slot for a request to an endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - The endpoint path.
+    /// * `status` - The status last reported by Bybit for the endpoint.
+    ///
+    /// # Returns
+    ///
+    /// `Ok` once the request may be sent, or `BybitError::RateLimited` in `Reject` mode.
+    pub async fn acquire(&self, endpoint: &str, status: Option<RateLimitStatus>) -> Result<(), BybitError> {
+        if let Some(wait) = status.and_then(|status| status.exhausted_for()) {
+            self.wait_or_reject(endpoint, wait).await?;
+        }
+
+        let group = EndpointGroup::from_endpoint(endpoint);
+        let budget = match self.budgets.get(&group) {
+            Some(budget) => *budget,
+            None => return Ok(()),
+        };
+
+        loop {
+            let wait = {
+                let mut windows = self.windows.lock().expect("rate limiter lock poisoned");
+                let window = windows.entry(group).or_default();
+                let now = Instant::now();
+                while window.front().is_some_and(|sent| now.duration_since(*sent) >= budget.per) {
+                    window.pop_front();
+                }
+                if (window.len() as u32) < budget.requests {
+                    window.push_back(now);
+                    return Ok(());
+                }
+                match window.front() {
+                    Some(oldest) => budget.per.saturating_sub(now.duration_since(*oldest)),
+                    None => return Ok(()),
+                }
+            };
+            self.wait_or_reject(endpoint, wait).await?;
+        }
+    }
+
+    async fn wait_or_reject(&self, endpoint: &str, wait: Duration) -> Result<(), BybitError> {
+        match self.mode {
+            RateLimitMode::Delay => {
+                tokio::time::sleep(wait).await;
+                Ok(())
+            },
+            RateLimitMode::Reject => {
+                Err(BybitError::RateLimited {
+                    endpoint: endpoint.to_string(),
+                    retry_after: wait,
+                })
+            },
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,42 +1,57 @@
 use crate::{
-    error::ApiKeyError::{
+    error::BybitError::{
         self,
         NotFoundApiKey
     },
     auth::Auth,
-    v5::api::BybitApi,
+    constants::{
+        RATE_LIMIT_HEADER,
+        RATE_LIMIT_STATUS_HEADER,
+        RATE_LIMIT_RESET_TIMESTAMP_HEADER,
+        TRACE_ID_HEADER,
+    },
+    v5::api::{
+        BybitApi,
+        transport::{
+            HttpMethod,
+            HttpRequest,
+        },
+        meta::ResponseMeta,
+    },
 };
-use anyhow::Result;
 use reqwest::header::{
     HeaderMap,
     HeaderValue,
+    RETRY_AFTER,
 };
-use url::{
-    Url,
-    ParseError,
-};
+use url::Url;
 use serde::{
     Serialize,
     Deserialize,
     de::DeserializeOwned,
 };
+use serde_json::Value;
 use chrono::Utc;
 use async_trait::async_trait;
+use std::time::Instant;
 
 
 #[async_trait]
 pub trait BybitApiRequest: Auth {
-    fn build_headers(&self, payload: &str) -> Result<HeaderMap>;
-    fn build_url(&self, endpoint: &str, params: Option<&str>) -> Result<Url, ParseError>;
-    fn require_api_key(&self) -> Result<&str, ApiKeyError>;
-    async fn send_api_request<T: Serialize + Send>(&self, endpoint: &str, params: Option<T>, private: bool, request_type: RequestType) -> Result<String>;
-    fn deserialize_response<P: DeserializeOwned>(&self, body: &str) -> Result<P>;
+    fn build_headers(&self, payload: &str) -> Result<HeaderMap, BybitError>;
+    fn build_url(&self, endpoint: &str, params: Option<&str>) -> Result<Url, BybitError>;
+    fn require_api_key(&self) -> Result<&str, BybitError>;
+    async fn send_api_request<T: Serialize + Sync>(&self, endpoint: &str, params: Option<&T>, private: bool, request_type: RequestType) -> Result<String, BybitError>;
+    fn deserialize_response<P: DeserializeOwned>(&self, endpoint: &str, body: &str) -> Result<P, BybitError>;
 }
 
 #[async_trait]
 impl BybitApiRequest for BybitApi {
-    fn build_headers(&self, payload: &str) -> Result<HeaderMap> {
-        let timestamp = Utc::now().timestamp_millis().to_string();
+    fn build_headers(&self, payload: &str) -> Result<HeaderMap, BybitError> {
+        let timestamp = match self.time_sync() {
+            Some(time_sync) => time_sync.now_millis(),
+            None => Utc::now().timestamp_millis(),
+        }.to_string();
         let recv_window = self.recv_window();
         let api_key = &self.require_api_key()?;
         
@@ -52,72 +67,84 @@ impl BybitApiRequest for BybitApi {
         Ok(headers)
     }
 
-    fn build_url(&self, endpoint: &str, query: Option<&str>) -> Result<Url, ParseError> {
+    fn build_url(&self, endpoint: &str, query: Option<&str>) -> Result<Url, BybitError> {
         let mut url = Url::parse(self.base_url())?.join(endpoint)?;
         url.set_query(query);
         Ok(url)
     }
 
-    fn require_api_key(&self) -> Result<&str, ApiKeyError> {
+    fn require_api_key(&self) -> Result<&str, BybitError> {
         match self.api_key() {
             Some(api_key) => Ok(api_key),
             None => Err(NotFoundApiKey)
         }
     }
     
-    async fn send_api_request<T: Serialize + Send>(&self, endpoint: &str, params: Option<T>, private: bool, request_type: RequestType) -> Result<String> {
+    async fn send_api_request<T: Serialize + Sync>(&self, endpoint: &str, params: Option<&T>, private: bool, request_type: RequestType) -> Result<String, BybitError> {
+        self.acquire_rate_limit(endpoint).await?;
+        if private || matches!(request_type, RequestType::Post) {
+            if let Some(time_sync) = self.time_sync() {
+                time_sync.sync_if_stale(self).await?;
+            }
+        }
 
         let request = match request_type {
             RequestType::Get => {
                 let query = serde_qs::to_string(&params)?;
                 let url = self.build_url(endpoint, Some(&query))?;
-                let client = reqwest::Client::new();
-                let request = client.get(url);
-                if private {
-                    let headers = self.build_headers(&query)?;
-                    request.headers(headers)
+                let headers = if private {
+                    self.build_headers(&query)?
                 } else {
-                    request
-                }
+                    HeaderMap::new()
+                };
+                HttpRequest::new(HttpMethod::Get, url, headers, None)
             },
             RequestType::Post => {
                 let url = self.build_url(endpoint, None)?;
-                let client = reqwest::Client::new();
-                let request = client.post(url).json(&params);
                 let payload = serde_json::to_string(&params)?;
                 let headers = self.build_headers(&payload)?;
-                request.headers(headers)
+                HttpRequest::new(HttpMethod::Post, url, headers, Some(payload))
             },
         };
     
-        let response = request.send().await?;
-        match response.status().as_u16() {
+        let started = Instant::now();
+        let response = self.transport().send(request).await?;
+        let latency = started.elapsed();
+        self.update_rate_limit_status(endpoint, response.headers());
+        match response.status() {
             200 => {
-                let body = response.text().await?;
-                Ok(body)
+                if self.meta_sink.is_some() {
+                    self.record_meta(ResponseMeta::new(endpoint, 200, response.headers(), latency, response.body().to_string()));
+                }
+                Ok(response.into_body())
             },
-            _ => {
-                Err(anyhow::Error::msg(format!(
-                    "Request error status code {}",
-                    response.status().as_u16(),
-                )))
+            status => {
+                let headers = Box::new(diagnostic_headers(response.headers()));
+                Err(BybitError::Http {
+                    status,
+                    endpoint: endpoint.to_string(),
+                    body: response.into_body(),
+                    headers,
+                })
             },
         }
     }
 
-    fn deserialize_response<P: DeserializeOwned>(&self, body: &str) -> Result<P> {
+    fn deserialize_response<P: DeserializeOwned>(&self, endpoint: &str, body: &str) -> Result<P, BybitError> {
         let response: ApiResponse = serde_json::from_str(body)?;
         match response.ret_code {
             0 => {
                 let response: P = serde_json::from_str(body)?;
                 Ok(response)
             },
-            _ => {
-                Err(anyhow::Error::msg(format!(
-                    "Response error ret_code: {}, ret_msg: {}",
-                    response.ret_code,
-                    response.ret_msg
-                )))
+            ret_code => {
+                Err(BybitError::Api {
+                    ret_code,
+                    ret_msg: response.ret_msg,
+                    ret_ext_info: response.ret_ext_info,
+                    status: 200,
+                    endpoint: endpoint.to_string(),
+                })
             }
         }
     }
@@ -128,9 +155,21 @@ pub enum RequestType {
     Post,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct ApiResponse {
     pub ret_code: i32,
     pub ret_msg: String,
-}
\ No newline at end of file
+    #[serde(default)]
+    pub ret_ext_info: Value,
+}
+
+// Keeps the headers of a non-200 response that help to diagnose it.
+fn diagnostic_headers(headers: &HeaderMap) -> HeaderMap {
+    let names = [RETRY_AFTER.as_str(), RATE_LIMIT_HEADER, RATE_LIMIT_STATUS_HEADER, RATE_LIMIT_RESET_TIMESTAMP_HEADER, TRACE_ID_HEADER];
+    headers
+        .iter()
+        .filter(|(name, _)| names.iter().any(|kept| name.as_str().eq_ignore_ascii_case(kept)))
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect()
+}
@@ -0,0 +1,196 @@
+use crate::{
+    constants::{
+        DEFAULT_MAX_RETRIES,
+        DEFAULT_INITIAL_BACKOFF_MILLIS,
+        DEFAULT_MAX_BACKOFF_MILLIS,
+        DEFAULT_BACKOFF_MULTIPLIER,
+    },
+    error::{
+        BybitError,
+        BybitErrorKind,
+    },
+    v5::api::BybitApi,
+};
+use rand::Rng;
+use std::{
+    future::Future,
+    time::Duration,
+};
+
+impl BybitApi {
+    // Runs `request` again after a backoff while it fails with a retryable error and the policy allows it.
+    pub(crate) async fn retry<P, F, Fut>(&self, retryable: bool, mut request: F) -> Result<P, BybitError>
+    where
+        F: FnMut() -> Fut + Send,
+        Fut: Future<Output = Result<P, BybitError>> + Send,
+    {
+        let retry_policy = self.retry_policy();
+        let mut attempt = 0;
+        loop {
+            match request().await {
+                Err(err) if retryable && err.is_retryable() && attempt < retry_policy.max_retries() => {
+                    if let (BybitErrorKind::InvalidTimestamp, Some(time_sync)) = (err.kind(), self.time_sync()) {
+                        time_sync.invalidate();
+                    }
+                    tokio::time::sleep(retry_policy.backoff(attempt)).await;
+                    attempt += 1;
+                },
+                result => return result,
+            }
+        }
+    }
+}
+
+/// The policy used to retry requests that failed with a transient error.
+///
+/// GET requests are always retried under the policy. POST requests to the trade endpoints are
+/// retried only when every order in them is identified by an `order_link_id`, so a retry can
+/// never place an order twice. Other POST requests are never retried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Creates a new instance of `RetryPolicy` with the default values.
+    ///
+    /// The default retries up to 3 times, starting with a 200ms backoff that doubles up to 5s, with jitter.
+    pub fn new() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            initial_backoff: Duration::from_millis(DEFAULT_INITIAL_BACKOFF_MILLIS),
+            max_backoff: Duration::from_millis(DEFAULT_MAX_BACKOFF_MILLIS),
+            multiplier: DEFAULT_BACKOFF_MULTIPLIER,
+            jitter: true,
+        }
+    }
+
+    /// Creates a new instance of `RetryPolicy` that never retries.
+    pub fn disabled() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::new()
+        }
+    }
+
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    pub fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+
+    pub fn max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    pub fn jitter(&self) -> bool {
+        self.jitter
+    }
+
+    /// Sets the maximum number of retries after the first attempt.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_retries` - The maximum number of retries.
+    ///
+    /// # Returns
+    ///
+    /// The modified `RetryPolicy` instance.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the backoff before the first retry.
+    ///
+    /// # Arguments
+    ///
+    /// * `initial_backoff` - The initial backoff.
+    ///
+    /// # Returns
+    ///
+    /// The modified `RetryPolicy` instance.
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the upper bound of the backoff.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_backoff` - The maximum backoff.
+    ///
+    /// # Returns
+    ///
+    /// The modified `RetryPolicy` instance.
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets the factor the backoff is multiplied by after each retry.
+    ///
+    /// # Arguments
+    ///
+    /// * `multiplier` - The backoff multiplier.
+    ///
+    /// # Returns
+    ///
+    /// The modified `RetryPolicy` instance.
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Sets whether the backoff is randomised.
+    ///
+    /// With jitter the actual backoff is drawn uniformly between zero and the computed backoff.
+    ///
+    /// # Arguments
+    ///
+    /// * `jitter` - Whether to apply jitter.
+    ///
+    /// # Returns
+    ///
+    /// The modified `RetryPolicy` instance.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Computes the backoff before a retry.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - The number of retries already made.
+    ///
+    /// # Returns
+    ///
+    /// The time to wait before the next retry.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let backoff = self.initial_backoff.as_secs_f64() * self.multiplier.powi(attempt.min(i32::MAX as u32) as i32);
+        let backoff = Duration::from_secs_f64(backoff.min(self.max_backoff.as_secs_f64()).max(0.0));
+        if self.jitter && !backoff.is_zero() {
+            rand::thread_rng().gen_range(Duration::ZERO..=backoff)
+        } else {
+            backoff
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,299 @@
+use crate::error::BybitError;
+use async_trait::async_trait;
+use reqwest::{
+    Client,
+    Method,
+    header::HeaderMap,
+};
+use url::Url;
+use std::{
+    collections::VecDeque,
+    fmt::Debug,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+/// The HTTP method of a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HttpMethod {
+    Get,
+    Post,
+}
+
+/// A request ready to be sent by a `Transport`.
+///
+/// The URL already carries the query string, and signed requests already carry the `X-BAPI-*` headers.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    method: HttpMethod,
+    url: Url,
+    headers: HeaderMap,
+    body: Option<String>,
+}
+
+impl HttpRequest {
+    /// Creates a new instance of `HttpRequest`.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The HTTP method.
+    /// * `url` - The full URL, including the query string.
+    /// * `headers` - The request headers.
+    /// * `body` - The JSON body, if any.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `HttpRequest`.
+    pub fn new(method: HttpMethod, url: Url, headers: HeaderMap, body: Option<String>) -> Self {
+        Self {
+            method,
+            url,
+            headers,
+            body,
+        }
+    }
+
+    pub fn method(&self) -> HttpMethod {
+        self.method
+    }
+
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    pub fn path(&self) -> &str {
+        self.url.path()
+    }
+
+    pub fn query(&self) -> Option<&str> {
+        self.url.query()
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+}
+
+/// A response returned by a `Transport`.
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    status: u16,
+    headers: HeaderMap,
+    body: String,
+}
+
+impl HttpResponse {
+    /// Creates a new instance of `HttpResponse`.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The HTTP status code.
+    /// * `headers` - The response headers.
+    /// * `body` - The response body.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `HttpResponse`.
+    pub fn new(status: u16, headers: HeaderMap, body: String) -> Self {
+        Self {
+            status,
+            headers,
+            body,
+        }
+    }
+
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    pub fn into_body(self) -> String {
+        self.body
+    }
+}
+
+/// Sends the HTTP requests of `BybitApi`.
+///
+/// Implement this trait to route requests through another HTTP stack or to answer them without a network.
+/// Any status code must be returned as an `HttpResponse`; errors are only for requests that got no response.
+#[async_trait]
+pub trait Transport: Debug + Send + Sync {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, BybitError>;
+}
+
+/// The default `Transport`, backed by a `reqwest::Client`.
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    /// Creates a new instance of `ReqwestTransport`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The HTTP client. It owns the connection pool.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `ReqwestTransport`.
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+        }
+    }
+
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, BybitError> {
+        let method = match request.method {
+            HttpMethod::Get => Method::GET,
+            HttpMethod::Post => Method::POST,
+        };
+        let mut builder = self.client.request(method, request.url).headers(request.headers);
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+        let response = builder.send().await?;
+        let status = response.status().as_u16();
+        let headers = response.headers().clone();
+        let body = response.text().await?;
+        Ok(HttpResponse::new(status, headers, body))
+    }
+}
+
+/// A `Transport` that answers requests with canned responses, for tests without a network.
+///
+/// A request matches a route when the method and path are equal and, if the route has a query,
+/// the query parameters are equal regardless of their order. Routes are tried in the order they
+/// were added. When several responses are added for the same route they are returned one after
+/// another, and the last one keeps being returned. Unmatched requests get a 404 response.
+/// Clones share the routes and the recorded requests.
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    routes: Arc<Mutex<Vec<MockRoute>>>,
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+#[derive(Debug)]
+struct MockRoute {
+    method: HttpMethod,
+    path: String,
+    query: Option<Vec<(String, String)>>,
+    responses: VecDeque<HttpResponse>,
+}
+
+impl MockRoute {
+    fn matches(&self, request: &HttpRequest) -> bool {
+        self.method == request.method
+            && self.path == request.path()
+            && self.query.as_ref().is_none_or(|query| *query == parse_query(request.query()))
+    }
+}
+
+impl MockTransport {
+    /// Creates a new instance of `MockTransport` without routes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a route answered with HTTP 200.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The HTTP method to match.
+    /// * `path` - The path to match, e.g. `/v5/market/kline`.
+    /// * `query` - The query to match, e.g. `category=linear&symbol=BTCUSDT`, or `None` to match any query.
+    /// * `body` - The JSON body of the response.
+    ///
+    /// # Returns
+    ///
+    /// The modified `MockTransport` instance.
+    pub fn with_response(self, method: HttpMethod, path: &str, query: Option<&str>, body: &str) -> Self {
+        self.with_http_response(method, path, query, HttpResponse::new(200, HeaderMap::new(), body.to_string()))
+    }
+
+    /// Adds a route answered with an arbitrary response.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The HTTP method to match.
+    /// * `path` - The path to match.
+    /// * `query` - The query to match, or `None` to match any query.
+    /// * `response` - The response, including status and headers.
+    ///
+    /// # Returns
+    ///
+    /// The modified `MockTransport` instance.
+    pub fn with_http_response(self, method: HttpMethod, path: &str, query: Option<&str>, response: HttpResponse) -> Self {
+        let query = query.map(|query| parse_query(Some(query)));
+        {
+            let mut routes = self.routes.lock().expect("mock transport lock poisoned");
+            match routes.iter_mut().find(|route| route.method == method && route.path == path && route.query == query) {
+                Some(route) => route.responses.push_back(response),
+                None => routes.push(MockRoute {
+                    method,
+                    path: path.to_string(),
+                    query,
+                    responses: VecDeque::from([response]),
+                }),
+            }
+        }
+        self
+    }
+
+    /// Gets the requests received so far, in order.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().expect("mock transport lock poisoned").clone()
+    }
+}
+
+#[async_trait]
+impl Transport for MockTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, BybitError> {
+        let response = {
+            let mut routes = self.routes.lock().expect("mock transport lock poisoned");
+            routes
+                .iter_mut()
+                .find(|route| route.matches(&request))
+                .and_then(|route| {
+                    if route.responses.len() > 1 {
+                        route.responses.pop_front()
+                    } else {
+                        route.responses.front().cloned()
+                    }
+                })
+        };
+        let response = response.unwrap_or_else(|| {
+            HttpResponse::new(404, HeaderMap::new(), format!("no mock response for {:?} {}", request.method, request.url))
+        });
+        self.requests.lock().expect("mock transport lock poisoned").push(request);
+        Ok(response)
+    }
+}
+
+fn parse_query(query: Option<&str>) -> Vec<(String, String)> {
+    let mut pairs = url::form_urlencoded::parse(query.unwrap_or_default().as_bytes())
+        .into_owned()
+        .collect::<Vec<(String, String)>>();
+    pairs.sort();
+    pairs
+}
@@ -0,0 +1,283 @@
+use crate::constants::{
+    CATEGORY_SPOT,
+    CATEGORY_LINEAR,
+    CATEGORY_INVERSE,
+    CATEGORY_OPTION,
+};
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display,
+        Formatter,
+    },
+    hash::{
+        Hash,
+        Hasher,
+    },
+    marker::PhantomData,
+    str::FromStr,
+};
+
+/// A product category, restricted at compile time to the set `S`.
+///
+/// Every endpoint names the categories it supports with a set such as `LinearInverse`,
+/// and only those categories exist as constants on its alias, e.g. `GetKlineCategory::Linear`
+/// compiles while `GetKlineCategory::Option` does not. `Category` without a set accepts every
+/// category (write `<Category>::Linear` in expressions, where the default set is not inferred),
+/// and values move between sets with `to_any` and `narrow`:
+///
+/// ```rust
+/// use rsbit::v5::{
+///     api::get::market::get_kline::GetKlineCategory,
+///     category::{Category, LinearInverse},
+/// };
+///
+/// let category: Category = GetKlineCategory::Linear.to_any();
+/// let contract = category.narrow::<LinearInverse>();
+/// assert!(contract.is_some());
+/// assert!(<Category>::Option.narrow::<LinearInverse>().is_none());
+/// ```
+pub struct Category<S = AnyCategory> {
+    kind: CategoryKind,
+    set: PhantomData<S>,
+}
+
+/// The categories without a compile-time restriction, used for matching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CategoryKind {
+    Spot,
+    Linear,
+    Inverse,
+    Option,
+}
+
+impl CategoryKind {
+    /// Gets the value as sent to and returned by Bybit, e.g. `linear`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CategoryKind::Spot => CATEGORY_SPOT,
+            CategoryKind::Linear => CATEGORY_LINEAR,
+            CategoryKind::Inverse => CATEGORY_INVERSE,
+            CategoryKind::Option => CATEGORY_OPTION,
+        }
+    }
+}
+
+/// A set of categories an endpoint supports.
+pub trait CategorySet {
+    /// Checks whether the set contains a category.
+    fn contains(kind: CategoryKind) -> bool;
+}
+
+/// Implemented by the sets that contain `spot`.
+pub trait HasSpot: CategorySet {}
+/// Implemented by the sets that contain `linear`.
+pub trait HasLinear: CategorySet {}
+/// Implemented by the sets that contain `inverse`.
+pub trait HasInverse: CategorySet {}
+/// Implemented by the sets that contain `option`.
+pub trait HasOption: CategorySet {}
+
+macro_rules! category_set {
+    ($(#[$meta:meta])* $name:ident: $($kind:ident => $marker:ident),+) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name;
+
+        impl CategorySet for $name {
+            fn contains(kind: CategoryKind) -> bool {
+                matches!(kind, $(CategoryKind::$kind)|+)
+            }
+        }
+
+        $(impl $marker for $name {})+
+    };
+}
+
+category_set!(
+    /// Every category.
+    AnyCategory: Spot => HasSpot, Linear => HasLinear, Inverse => HasInverse, Option => HasOption
+);
+category_set!(
+    /// `linear` and `inverse` contracts.
+    LinearInverse: Linear => HasLinear, Inverse => HasInverse
+);
+category_set!(
+    /// `linear` contracts and options.
+    LinearOption: Linear => HasLinear, Option => HasOption
+);
+category_set!(
+    /// Every derivative: `linear`, `inverse` and `option`.
+    LinearInverseOption: Linear => HasLinear, Inverse => HasInverse, Option => HasOption
+);
+category_set!(
+    /// `spot`, `linear` and `option`, as supported by the unified trading account.
+    SpotLinearOption: Spot => HasSpot, Linear => HasLinear, Option => HasOption
+);
+category_set!(
+    /// `spot`, `linear` and `inverse`.
+    SpotLinearInverse: Spot => HasSpot, Linear => HasLinear, Inverse => HasInverse
+);
+category_set!(
+    /// `spot` only.
+    SpotOnly: Spot => HasSpot
+);
+category_set!(
+    /// `linear` only.
+    LinearOnly: Linear => HasLinear
+);
+category_set!(
+    /// `option` only.
+    OptionOnly: Option => HasOption
+);
+
+#[allow(non_upper_case_globals)]
+impl<S: HasSpot> Category<S> {
+    pub const Spot: Self = Self::from_kind(CategoryKind::Spot);
+}
+
+#[allow(non_upper_case_globals)]
+impl<S: HasLinear> Category<S> {
+    pub const Linear: Self = Self::from_kind(CategoryKind::Linear);
+}
+
+#[allow(non_upper_case_globals)]
+impl<S: HasInverse> Category<S> {
+    pub const Inverse: Self = Self::from_kind(CategoryKind::Inverse);
+}
+
+#[allow(non_upper_case_globals)]
+impl<S: HasOption> Category<S> {
+    pub const Option: Self = Self::from_kind(CategoryKind::Option);
+}
+
+impl<S> Category<S> {
+    const fn from_kind(kind: CategoryKind) -> Self {
+        Self {
+            kind,
+            set: PhantomData,
+        }
+    }
+
+    /// Gets the category without its compile-time restriction, e.g. to `match` on it.
+    pub fn kind(&self) -> CategoryKind {
+        self.kind
+    }
+
+    /// Gets the value as sent to and returned by Bybit, e.g. `linear`.
+    pub fn as_str(&self) -> &'static str {
+        self.kind.as_str()
+    }
+
+    /// Removes the compile-time restriction.
+    ///
+    /// # Returns
+    ///
+    /// The same category as a `Category` accepting every category.
+    pub fn to_any(self) -> Category {
+        Category::from_kind(self.kind)
+    }
+
+    /// Moves the category into another set.
+    ///
+    /// # Returns
+    ///
+    /// The same category restricted to `T`, or `None` if `T` does not contain it.
+    pub fn narrow<T: CategorySet>(self) -> Option<Category<T>> {
+        if T::contains(self.kind) {
+            Some(Category::from_kind(self.kind))
+        } else {
+            None
+        }
+    }
+}
+
+// derive だと S にも境界が付いてしまうため手書きする
+impl<S> Clone for Category<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for Category<S> {}
+
+impl<S> PartialEq for Category<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
+}
+
+impl<S> Eq for Category<S> {}
+
+impl<S> Hash for Category<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.kind.hash(state);
+    }
+}
+
+impl<S> Debug for Category<S> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(&self.kind, f)
+    }
+}
+
+impl<S> Display for Category<S> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<CategoryKind> for Category {
+    fn from(kind: CategoryKind) -> Self {
+        Category::from_kind(kind)
+    }
+}
+
+/// The error returned when a string is not a category of the requested set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedCategory(String);
+
+impl Display for UnsupportedCategory {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Unsupported category: {}", self.0)
+    }
+}
+
+impl std::error::Error for UnsupportedCategory {}
+
+impl<S: CategorySet> FromStr for Category<S> {
+    type Err = UnsupportedCategory;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let kind = match value {
+            CATEGORY_SPOT => CategoryKind::Spot,
+            CATEGORY_LINEAR => CategoryKind::Linear,
+            CATEGORY_INVERSE => CategoryKind::Inverse,
+            CATEGORY_OPTION => CategoryKind::Option,
+            _ => return Err(UnsupportedCategory(value.to_string())),
+        };
+        Category::<AnyCategory>::from_kind(kind)
+            .narrow()
+            .ok_or_else(|| UnsupportedCategory(value.to_string()))
+    }
+}
+
+impl<S> Serialize for Category<S> {
+    fn serialize<T: Serializer>(&self, serializer: T) -> Result<T::Ok, T::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de, S: CategorySet> Deserialize<'de> for Category<S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,122 @@
+use crate::constants::{
+    MAINNET_API_URL,
+    TESTNET_API_URL,
+    DEMO_API_URL,
+    MAINNET_PUBLIC_CHANNEL,
+    TESTNET_PUBLIC_CHANNEL,
+    MAINNET_PRIVATE_CHANNEL,
+    TESTNET_PRIVATE_CHANNEL,
+    DEMO_PRIVATE_CHANNEL,
+    MAINNET_TRADE_CHANNEL,
+    TESTNET_TRADE_CHANNEL,
+    DEMO_TRADE_CHANNEL,
+};
+
+/// The Bybit deployment that `BybitApi` and `BybitWS` connect to.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Environment {
+    Mainnet,
+    #[default]
+    Testnet,
+    /// Demo trading. Public streams are served by mainnet.
+    Demo,
+    /// Any other deployment, such as a regional host or a local stand-in server.
+    ///
+    /// `ws_public` is the base of the public streams; the category (`spot`, `linear`, ...) is appended to it.
+    Custom {
+        rest: String,
+        ws_public: String,
+        ws_private: String,
+        ws_trade: String,
+    },
+}
+
+/// Regional Bybit hosts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Region {
+    /// The `bytick.com` mirror of mainnet.
+    Bytick,
+    Netherlands,
+    HongKong,
+    Turkey,
+    Kazakhstan,
+    Georgia,
+    UnitedArabEmirates,
+    EuropeanEconomicArea,
+}
+
+impl Region {
+    /// Gets the domain of the region, e.g. `bybit.nl`.
+    pub fn domain(&self) -> &'static str {
+        match self {
+            Region::Bytick => "bytick.com",
+            Region::Netherlands => "bybit.nl",
+            Region::HongKong => "byhkbit.com",
+            Region::Turkey => "bybit-tr.com",
+            Region::Kazakhstan => "bybit.kz",
+            Region::Georgia => "bybitgeorgia.ge",
+            Region::UnitedArabEmirates => "bybit.ae",
+            Region::EuropeanEconomicArea => "bybit.eu",
+        }
+    }
+}
+
+impl Environment {
+    /// Creates an environment for a regional host.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The region to connect to.
+    ///
+    /// # Returns
+    ///
+    /// A `Custom` environment using `api.<domain>` and `stream.<domain>`.
+    pub fn regional(region: Region) -> Self {
+        let domain = region.domain();
+        Environment::Custom {
+            rest: format!("https://api.{}", domain),
+            ws_public: format!("wss://stream.{}/v5/public", domain),
+            ws_private: format!("wss://stream.{}/v5/private", domain),
+            ws_trade: format!("wss://stream.{}/v5/trade", domain),
+        }
+    }
+
+    /// Gets the base URL of the REST API.
+    pub fn rest_url(&self) -> &str {
+        match self {
+            Environment::Mainnet => MAINNET_API_URL,
+            Environment::Testnet => TESTNET_API_URL,
+            Environment::Demo => DEMO_API_URL,
+            Environment::Custom { rest, .. } => rest,
+        }
+    }
+
+    /// Gets the base URL of the public streams, without the category.
+    pub fn ws_public_url(&self) -> &str {
+        match self {
+            Environment::Mainnet | Environment::Demo => MAINNET_PUBLIC_CHANNEL,
+            Environment::Testnet => TESTNET_PUBLIC_CHANNEL,
+            Environment::Custom { ws_public, .. } => ws_public,
+        }
+    }
+
+    /// Gets the URL of the private stream.
+    pub fn ws_private_url(&self) -> &str {
+        match self {
+            Environment::Mainnet => MAINNET_PRIVATE_CHANNEL,
+            Environment::Testnet => TESTNET_PRIVATE_CHANNEL,
+            Environment::Demo => DEMO_PRIVATE_CHANNEL,
+            Environment::Custom { ws_private, .. } => ws_private,
+        }
+    }
+
+    /// Gets the URL of the order entry stream.
+    pub fn ws_trade_url(&self) -> &str {
+        match self {
+            Environment::Mainnet => MAINNET_TRADE_CHANNEL,
+            Environment::Testnet => TESTNET_TRADE_CHANNEL,
+            Environment::Demo => DEMO_TRADE_CHANNEL,
+            Environment::Custom { ws_trade, .. } => ws_trade,
+        }
+    }
+}
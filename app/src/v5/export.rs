@@ -0,0 +1,364 @@
+use crate::{
+    utils::millis_to_datetime,
+    v5::{
+        api::get::{
+            account::get_transaction_log::TransactionLog,
+            market::get_kline::Kline,
+            position::get_execution::Execution,
+        },
+        ws::public::{
+            kline::PublicKlineData,
+            trade::PublicTradeData,
+        },
+    },
+};
+use chrono::{
+    DateTime,
+    SecondsFormat,
+    Utc,
+};
+use std::{
+    fs::{
+        File,
+        OpenOptions,
+    },
+    io::{
+        self,
+        BufWriter,
+        Write,
+    },
+    marker::PhantomData,
+    path::Path,
+};
+
+/// The file formats records can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// Comma separated values with a header line.
+    Csv,
+    /// One JSON object per line, also known as newline-delimited JSON.
+    JsonLines,
+}
+
+/// A value that can be written as one row of an export.
+///
+/// The columns are fixed per type so that files written by different versions of a
+/// collector can be concatenated. Timestamps are written as ISO 8601 in UTC.
+pub trait Record {
+    /// The column names, in the order of `values`.
+    const COLUMNS: &'static [&'static str];
+
+    /// Gets the values of the columns.
+    ///
+    /// # Returns
+    ///
+    /// One value per column. `None` is written as an empty CSV field or as JSON `null`.
+    fn values(&self) -> Vec<Option<String>>;
+}
+
+/// Writes records of one type to CSV or JSON Lines.
+///
+/// The CSV header is written before the first record. Writes are buffered when the exporter is
+/// created from a path, so call `flush` to make them visible to other readers.
+pub struct Exporter<W: Write, R: Record> {
+    writer: W,
+    format: Format,
+    header: bool,
+    record: PhantomData<fn(&R)>,
+}
+
+impl<W: Write, R: Record> Exporter<W, R> {
+    /// Creates a new instance of Exporter.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The destination of the records.
+    /// * `format` - The format to write.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of Exporter that writes the CSV header before the first record.
+    pub fn new(writer: W, format: Format) -> Self {
+        Self {
+            writer,
+            format,
+            header: format == Format::Csv,
+            record: PhantomData,
+        }
+    }
+
+    /// Sets whether the CSV header is written before the first record.
+    ///
+    /// # Arguments
+    ///
+    /// * `header` - `false` to leave the header out, e.g. when appending to an existing file.
+    ///
+    /// # Returns
+    ///
+    /// The modified Exporter instance.
+    pub fn with_header(mut self, header: bool) -> Self {
+        self.header = header && self.format == Format::Csv;
+        self
+    }
+
+    /// Gets the format being written.
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Writes one record.
+    ///
+    /// # Arguments
+    ///
+    /// * `record` - The record to write.
+    ///
+    /// # Returns
+    ///
+    /// An error if writing to the destination fails.
+    pub fn write(&mut self, record: &R) -> io::Result<()> {
+        if self.header {
+            let columns: Vec<Option<&str>> = R::COLUMNS.iter().map(|column| Some(*column)).collect();
+            write_csv_line(&mut self.writer, &columns)?;
+            self.header = false;
+        }
+        let values = record.values();
+        match self.format {
+            Format::Csv => {
+                let values: Vec<Option<&str>> = values.iter().map(Option::as_deref).collect();
+                write_csv_line(&mut self.writer, &values)
+            },
+            Format::JsonLines => write_json_line(&mut self.writer, R::COLUMNS, &values),
+        }
+    }
+
+    /// Writes several records.
+    ///
+    /// # Arguments
+    ///
+    /// * `records` - The records to write, e.g. the `list` of a REST result or the `data` of a WebSocket message.
+    ///
+    /// # Returns
+    ///
+    /// An error if writing to the destination fails. The records before the failed one are written.
+    pub fn write_all<'a, I>(&mut self, records: I) -> io::Result<()>
+    where
+        I: IntoIterator<Item = &'a R>,
+        R: 'a,
+    {
+        records.into_iter().try_for_each(|record| self.write(record))
+    }
+
+    /// Flushes the buffered records to the destination.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Gets the destination back.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<R: Record> Exporter<BufWriter<File>, R> {
+    /// Creates a file, replacing an existing one, and writes the records to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file.
+    /// * `format` - The format to write.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of Exporter, or an error if the file cannot be created.
+    pub fn create<P: AsRef<Path>>(path: P, format: Format) -> io::Result<Self> {
+        let file = File::create(path)?;
+        Ok(Self::new(BufWriter::new(file), format))
+    }
+
+    /// Opens a file for appending, creating it if it does not exist.
+    ///
+    /// The CSV header is only written if the file is empty, so a collector can be restarted on the same file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file.
+    /// * `format` - The format to write.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of Exporter, or an error if the file cannot be opened.
+    pub fn append<P: AsRef<Path>>(path: P, format: Format) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let empty = file.metadata()?.len() == 0;
+        Ok(Self::new(BufWriter::new(file), format).with_header(empty))
+    }
+}
+
+fn write_csv_line<W: Write>(writer: &mut W, values: &[Option<&str>]) -> io::Result<()> {
+    let line: Vec<String> = values.iter()
+        .map(|value| {
+            let value = value.unwrap_or_default();
+            // 区切り文字や改行を含む場合だけ引用符で囲む (RFC 4180)
+            if value.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value.to_string()
+            }
+        })
+        .collect();
+    writeln!(writer, "{}", line.join(","))
+}
+
+// serde_json::Map はキーを並べ替えてしまうので、列の順番を保つために手で組み立てる
+fn write_json_line<W: Write>(writer: &mut W, columns: &[&str], values: &[Option<String>]) -> io::Result<()> {
+    let fields: Vec<String> = columns.iter()
+        .zip(values)
+        .map(|(column, value)| format!("{}:{}", serde_json::Value::from(*column), serde_json::Value::from(value.clone())))
+        .collect();
+    writeln!(writer, "{{{}}}", fields.join(","))
+}
+
+fn iso(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+fn iso_millis(millis: u64) -> Option<String> {
+    Some(iso(millis_to_datetime(millis)))
+}
+
+fn text<T: ToString>(value: T) -> Option<String> {
+    Some(value.to_string())
+}
+
+impl Record for Kline {
+    const COLUMNS: &'static [&'static str] = &["timestamp", "open", "high", "low", "close", "volume", "turnover"];
+
+    fn values(&self) -> Vec<Option<String>> {
+        vec![
+            iso_millis(self.timestamp()),
+            text(self.open()),
+            text(self.high()),
+            text(self.low()),
+            text(self.close()),
+            text(self.volume()),
+            text(self.turnover()),
+        ]
+    }
+}
+
+impl Record for PublicKlineData {
+    const COLUMNS: &'static [&'static str] = &[
+        "start", "end", "interval", "open", "close", "high", "low", "volume", "turnover", "confirm", "timestamp",
+    ];
+
+    fn values(&self) -> Vec<Option<String>> {
+        vec![
+            iso_millis(self.start()),
+            iso_millis(self.end()),
+            text(self.interval()),
+            text(self.open()),
+            text(self.close()),
+            text(self.high()),
+            text(self.low()),
+            text(self.volume()),
+            text(self.turnover()),
+            text(self.confirm()),
+            iso_millis(self.timestamp()),
+        ]
+    }
+}
+
+impl Record for PublicTradeData {
+    const COLUMNS: &'static [&'static str] = &[
+        "timestamp", "symbol", "side", "volume", "price", "direction_of_price_change", "trade_id", "block_trade",
+    ];
+
+    fn values(&self) -> Vec<Option<String>> {
+        vec![
+            iso_millis(self.timestamp()),
+            text(self.symbol()),
+            text(self.side()),
+            text(self.volume()),
+            text(self.price()),
+            text(self.direction_of_price_change()),
+            text(self.trade_id()),
+            text(self.block_trade()),
+        ]
+    }
+}
+
+impl Record for Execution {
+    const COLUMNS: &'static [&'static str] = &[
+        "exec_id", "exec_time", "symbol", "side", "order_id", "order_link_id", "order_type", "stop_order_type",
+        "exec_type", "exec_price", "exec_qty", "exec_value", "exec_fee", "fee_rate", "is_maker", "order_price",
+        "order_qty", "leaves_qty", "closed_size", "mark_price", "index_price", "underlying_price", "mark_iv",
+        "trade_iv", "block_trade_id", "seq",
+    ];
+
+    fn values(&self) -> Vec<Option<String>> {
+        vec![
+            text(self.exec_id()),
+            iso_millis(self.exec_time()),
+            text(self.symbol()),
+            text(self.side()),
+            text(self.order_id()),
+            self.order_link_id().map(str::to_string),
+            text(self.order_type()),
+            self.stop_order_type().map(ToString::to_string),
+            text(self.exec_type()),
+            text(self.exec_price()),
+            text(self.exec_qty()),
+            text(self.exec_value()),
+            text(self.exec_fee()),
+            text(self.fee_rate()),
+            text(self.is_maker()),
+            text(self.order_price()),
+            text(self.order_qty()),
+            text(self.leaves_qty()),
+            self.closed_size().map(|value| value.to_string()),
+            text(self.mark_price()),
+            self.index_price().map(|value| value.to_string()),
+            self.underlying_price().map(|value| value.to_string()),
+            self.mark_iv().map(str::to_string),
+            self.trade_iv().map(str::to_string),
+            self.block_trade_id().map(str::to_string),
+            text(self.seq()),
+        ]
+    }
+}
+
+impl Record for TransactionLog {
+    const COLUMNS: &'static [&'static str] = &[
+        "id", "transaction_time", "symbol", "category", "side", "type", "currency", "qty", "size", "trade_price",
+        "trade_id", "order_id", "order_link_id", "change", "cash_flow", "cash_balance", "funding", "fee", "fee_rate",
+        "bonus_change",
+    ];
+
+    fn values(&self) -> Vec<Option<String>> {
+        vec![
+            text(self.id()),
+            // 数値でない場合はそのまま書き出す
+            self.transaction_time_datetime()
+                .map(iso)
+                .or_else(|| text(self.transaction_time())),
+            text(self.symbol()),
+            text(self.category()),
+            text(self.side()),
+            text(self.type_field()),
+            text(self.currency()),
+            text(self.qty()),
+            text(self.size()),
+            text(self.trade_price()),
+            text(self.trade_id()),
+            text(self.order_id()),
+            text(self.order_link_id()),
+            text(self.change()),
+            text(self.cash_flow()),
+            text(self.cash_balance()),
+            text(self.funding()),
+            text(self.fee()),
+            text(self.fee_rate()),
+            self.bonus_change().map(|value| value.to_string()),
+        ]
+    }
+}
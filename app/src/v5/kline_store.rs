@@ -0,0 +1,325 @@
+use crate::{
+    error::BybitError,
+    utils::datetime_to_millis,
+    v5::{
+        api::{
+            BybitApi,
+            get::market::get_kline::{
+                GetKlineCategory,
+                Kline,
+            },
+        },
+        types::Interval,
+        ws::public::kline::PublicKlineResponse,
+    },
+};
+use chrono::{
+    DateTime,
+    Utc,
+};
+use futures_util::stream::TryStreamExt;
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    error::Error,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+    fs::{
+        self,
+        OpenOptions,
+    },
+    io::{
+        self,
+        BufWriter,
+        Write,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Mutex,
+};
+
+/// A local on-disk store of confirmed klines, keyed by category, symbol and interval.
+///
+/// Each series is kept in `<root>/<category>/<symbol>/<interval>.jsonl`, one bar per line in the
+/// format Bybit returns it. A series is read from disk the first time it is used and kept in memory.
+/// Symbols may only contain ASCII letters, digits, `-`, `_` and `.`; others are rejected with
+/// `io::ErrorKind::InvalidInput` so that no file is written outside the root.
+pub struct KlineStore {
+    root: PathBuf,
+    series: Mutex<HashMap<PathBuf, BTreeMap<u64, Kline>>>,
+}
+
+impl KlineStore {
+    /// Opens a store, creating its directory if it does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The directory of the store.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of KlineStore, or an error if the directory cannot be created.
+    pub fn open<P: AsRef<Path>>(root: P) -> io::Result<Self> {
+        fs::create_dir_all(root.as_ref())?;
+        Ok(Self {
+            root: root.as_ref().to_path_buf(),
+            series: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Gets the directory of the store.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Adds bars to a series and writes the new ones to disk.
+    ///
+    /// Only confirmed bars should be added: a bar is stored as given and is not replaced by a later
+    /// one with the same open time unless its values differ.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The category of the series.
+    /// * `symbol` - The symbol of the series.
+    /// * `interval` - The interval of the series.
+    /// * `klines` - The bars to add, in any order.
+    ///
+    /// # Returns
+    ///
+    /// The number of bars that were not stored yet, or an error if the file cannot be written.
+    pub fn insert(&self, category: GetKlineCategory, symbol: &str, interval: Interval, klines: &[Kline]) -> io::Result<usize> {
+        self.with_series(category, symbol, interval, |series, path| {
+            let klines: Vec<&Kline> = klines.iter()
+                .filter(|kline| series.get(&kline.timestamp()) != Some(*kline))
+                .collect();
+            if klines.is_empty() {
+                return Ok(0);
+            }
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            let file = OpenOptions::new().create(true).append(true).open(path)?;
+            let mut writer = BufWriter::new(file);
+            for kline in &klines {
+                serde_json::to_writer(&mut writer, kline)?;
+                writeln!(writer)?;
+            }
+            writer.flush()?;
+            for kline in &klines {
+                series.insert(kline.timestamp(), (*kline).clone());
+            }
+            Ok(klines.len())
+        })
+    }
+
+    /// Adds the confirmed bars of a WebSocket kline message to its series.
+    ///
+    /// Bars with `confirm == false` are still open and are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The category of the subscription.
+    /// * `response` - The message, whose topic is `kline.<interval>.<symbol>`.
+    ///
+    /// # Returns
+    ///
+    /// The number of bars that were not stored yet, or an error if the file cannot be written.
+    pub fn insert_confirmed(&self, category: GetKlineCategory, response: &PublicKlineResponse) -> io::Result<usize> {
+        let Some(symbol) = response.topic().splitn(3, '.').nth(2) else {
+            return Ok(0);
+        };
+        let mut inserted = 0;
+        for data in response.data().iter().filter(|data| data.confirm()) {
+            inserted += self.insert(category, symbol, data.interval(), &[Kline::from(data)])?;
+        }
+        Ok(inserted)
+    }
+
+    /// Gets the stored bars between `from` and `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The category of the series.
+    /// * `symbol` - The symbol of the series.
+    /// * `interval` - The interval of the series.
+    /// * `from` - The start of the range, inclusive.
+    /// * `to` - The end of the range, exclusive.
+    ///
+    /// # Returns
+    ///
+    /// The bars opened in the range, oldest first, or an error if the file cannot be read.
+    pub fn range(&self, category: GetKlineCategory, symbol: &str, interval: Interval, from: DateTime<Utc>, to: DateTime<Utc>) -> io::Result<Vec<Kline>> {
+        self.with_series(category, symbol, interval, |series, _| {
+            Ok(bars_between(series, from, to).cloned().collect())
+        })
+    }
+
+    /// Finds the ranges between `from` and `to` that have no stored bars.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The category of the series.
+    /// * `symbol` - The symbol of the series.
+    /// * `interval` - The interval of the series.
+    /// * `from` - The start of the range, inclusive.
+    /// * `to` - The end of the range, exclusive.
+    ///
+    /// # Returns
+    ///
+    /// The missing ranges in time order, each from the open time of the first missing bar to the
+    /// open time of the next stored bar or `to`. An error if the file cannot be read.
+    pub fn gaps(&self, category: GetKlineCategory, symbol: &str, interval: Interval, from: DateTime<Utc>, to: DateTime<Utc>) -> io::Result<Vec<(DateTime<Utc>, DateTime<Utc>)>> {
+        self.with_series(category, symbol, interval, |series, _| {
+            let mut gaps = Vec::new();
+            // from が足の途中なら次の足から数える
+            let mut expected = match interval.bar_open(from) {
+                open if open == from => from,
+                _ => interval.next_bar_open(from),
+            };
+            for kline in bars_between(series, from, to) {
+                let open = kline.timestamp_datetime();
+                if open > expected {
+                    gaps.push((expected, open));
+                }
+                expected = interval.next_bar_open(open);
+            }
+            if expected < to {
+                gaps.push((expected, to));
+            }
+            Ok(gaps)
+        })
+    }
+
+    /// Downloads the bars missing between `from` and `to` with `get_kline` and stores them.
+    ///
+    /// Use it to seed a new series and to fill the gaps left by a restart or a disconnect.
+    /// The bar that is still open is never downloaded. Ranges in which the symbol did not trade
+    /// stay empty and are requested again on the next call.
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - The client to download the bars with.
+    /// * `category` - The category of the series.
+    /// * `symbol` - The symbol of the series.
+    /// * `interval` - The interval of the series.
+    /// * `from` - The start of the range, inclusive.
+    /// * `to` - The end of the range, exclusive.
+    ///
+    /// # Returns
+    ///
+    /// The number of bars added. The bars of the gaps filled before an error are kept.
+    pub async fn backfill(&self, api: &BybitApi, category: GetKlineCategory, symbol: &str, interval: Interval, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<usize, KlineStoreError> {
+        let to = to.min(interval.bar_open(Utc::now()));
+        let mut inserted = 0;
+        for (start, end) in self.gaps(category, symbol, interval, from, to)? {
+            let klines: Vec<Kline> = api.download_klines(category, symbol.to_string(), interval, start, end)
+                .try_collect()
+                .await?;
+            inserted += self.insert(category, symbol, interval, &klines)?;
+        }
+        Ok(inserted)
+    }
+
+    fn path(&self, category: GetKlineCategory, symbol: &str, interval: Interval) -> io::Result<PathBuf> {
+        // ルートの外に書き込まないよう、パスの区切りや . / .. になる銘柄名は受け付けない
+        let valid = !symbol.is_empty()
+            && symbol != "."
+            && symbol != ".."
+            && symbol.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+        if !valid {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("invalid symbol for the kline store: {:?}", symbol)));
+        }
+        Ok(self.root
+            .join(category.as_str())
+            .join(symbol)
+            .join(format!("{}.jsonl", interval)))
+    }
+
+    fn with_series<R>(
+        &self,
+        category: GetKlineCategory,
+        symbol: &str,
+        interval: Interval,
+        f: impl FnOnce(&mut BTreeMap<u64, Kline>, &Path) -> io::Result<R>,
+    ) -> io::Result<R> {
+        let path = self.path(category, symbol, interval)?;
+        let mut series = self.series.lock().expect("kline store lock poisoned");
+        if !series.contains_key(&path) {
+            let loaded = load(&path)?;
+            series.insert(path.clone(), loaded);
+        }
+        let klines = series.get_mut(&path).expect("the series was just loaded");
+        f(klines, &path)
+    }
+}
+
+fn load(path: &Path) -> io::Result<BTreeMap<u64, Kline>> {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+        Err(err) => return Err(err),
+    };
+    // 書き込み途中で止まった行は切り詰め、次の追記がその続きに書かれないようにする
+    let end = bytes.iter().rposition(|byte| *byte == b'\n').map_or(0, |index| index + 1);
+    if end < bytes.len() {
+        OpenOptions::new().write(true).open(path)?.set_len(end as u64)?;
+    }
+    let mut series = BTreeMap::new();
+    for line in bytes[..end].split(|byte| *byte == b'\n') {
+        if let Ok(kline) = serde_json::from_slice::<Kline>(line) {
+            series.insert(kline.timestamp(), kline);
+        }
+    }
+    Ok(series)
+}
+
+fn bars_between(series: &BTreeMap<u64, Kline>, from: DateTime<Utc>, to: DateTime<Utc>) -> impl Iterator<Item = &Kline> {
+    let (from, to) = (datetime_to_millis(from), datetime_to_millis(to));
+    series.range(from..to.max(from)).map(|(_, kline)| kline)
+}
+
+/// Errors returned by `KlineStore::backfill`.
+#[derive(Debug)]
+pub enum KlineStoreError {
+    /// The store could not be read or written.
+    Io(io::Error),
+    /// The bars could not be downloaded.
+    Api(BybitError),
+}
+
+impl Display for KlineStoreError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            KlineStoreError::Io(err) => write!(f, "Kline store error: {}", err),
+            KlineStoreError::Api(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for KlineStoreError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            KlineStoreError::Io(err) => Some(err),
+            KlineStoreError::Api(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for KlineStoreError {
+    fn from(err: io::Error) -> Self {
+        KlineStoreError::Io(err)
+    }
+}
+
+impl From<BybitError> for KlineStoreError {
+    fn from(err: BybitError) -> Self {
+        KlineStoreError::Api(err)
+    }
+}
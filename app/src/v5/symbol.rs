@@ -0,0 +1,408 @@
+use crate::{
+    utils::parse_number,
+    v5::types::Number,
+};
+use chrono::{
+    DateTime,
+    Datelike,
+    Months,
+    NaiveDate,
+    Utc,
+    Weekday,
+};
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use std::{
+    fmt::{
+        Display,
+        Formatter,
+        Result,
+    },
+    str::FromStr,
+};
+
+// 長いものから順に照合する (USDT を USD より先に)
+const QUOTE_COINS: [&str; 8] = ["USDT", "USDC", "USDE", "USD", "EUR", "BTC", "ETH", "DAI"];
+const MONTHS: [&str; 12] = ["JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC"];
+// 先物の限月コード。F = 1月 ... Z = 12月
+const MONTH_CODES: [char; 12] = ['F', 'G', 'H', 'J', 'K', 'M', 'N', 'Q', 'U', 'V', 'X', 'Z'];
+// USDC 建ての先物とオプションは決済通貨がシンボルに含まれない
+const DEFAULT_SETTLE_COIN: &str = "USDC";
+const PERPETUAL_SUFFIX: &str = "PERP";
+// Bybit の先物とオプションは満期日の 08:00 UTC に受渡しされる
+const DELIVERY_HOUR: u32 = 8;
+
+/// The right of an option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OptionType {
+    Call,
+    Put,
+}
+
+impl OptionType {
+    /// Gets the value as returned in `optionsType`, e.g. `Call`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OptionType::Call => "Call",
+            OptionType::Put => "Put",
+        }
+    }
+
+    fn letter(&self) -> &'static str {
+        match self {
+            OptionType::Call => "C",
+            OptionType::Put => "P",
+        }
+    }
+}
+
+impl Display for OptionType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OptionType {
+    type Err = ParseSymbolError;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value {
+            "C" | "Call" => Ok(OptionType::Call),
+            "P" | "Put" => Ok(OptionType::Put),
+            _ => Err(ParseSymbolError(value.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Contract {
+    Pair,
+    Future {
+        expiry: NaiveDate,
+    },
+    Option {
+        expiry: NaiveDate,
+        strike: String,
+        option_type: OptionType,
+    },
+}
+
+/// A parsed Bybit symbol.
+///
+/// Parses spot pairs and perpetuals (`BTCUSDT`, `ETHPERP`), dated futures (`BTCUSDH24`,
+/// `BTC-27DEC24`, `BTCUSDT-27DEC24`) and options (`BTC-29DEC23-40000-C`, `BTC-29DEC23-40000-C-USDT`).
+/// Formatting gives back the symbol as it was parsed.
+///
+/// ```rust
+/// use rsbit::v5::symbol::{OptionType, Symbol};
+/// use chrono::NaiveDate;
+///
+/// let symbol: Symbol = "BTC-29DEC23-40000-C".parse().unwrap();
+/// assert_eq!(symbol.base_coin(), "BTC");
+/// assert_eq!(symbol.quote_coin(), "USDC");
+/// assert_eq!(symbol.expiry(), NaiveDate::from_ymd_opt(2023, 12, 29));
+/// assert_eq!(symbol.option_type(), Some(OptionType::Call));
+/// assert_eq!(symbol.to_string(), "BTC-29DEC23-40000-C");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Symbol {
+    name: String,
+    base_coin: String,
+    quote_coin: String,
+    contract: Contract,
+}
+
+impl Symbol {
+    /// Creates a spot pair or perpetual symbol, e.g. `BTCUSDT`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_coin` - The base coin, e.g. `BTC`.
+    /// * `quote_coin` - The quote coin, e.g. `USDT`.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `Symbol`.
+    pub fn pair(base_coin: &str, quote_coin: &str) -> Self {
+        Self {
+            name: format!("{}{}", base_coin, quote_coin),
+            base_coin: base_coin.to_string(),
+            quote_coin: quote_coin.to_string(),
+            contract: Contract::Pair,
+        }
+    }
+
+    /// Creates a USDC dated future symbol, e.g. `BTC-27DEC24`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_coin` - The base coin, e.g. `BTC`.
+    /// * `expiry` - The expiry date.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `Symbol`.
+    pub fn future(base_coin: &str, expiry: NaiveDate) -> Self {
+        Self {
+            name: format!("{}-{}", base_coin, format_date(expiry)),
+            base_coin: base_coin.to_string(),
+            quote_coin: DEFAULT_SETTLE_COIN.to_string(),
+            contract: Contract::Future { expiry },
+        }
+    }
+
+    /// Creates a USDC option symbol, e.g. `BTC-29DEC23-40000-C`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_coin` - The base coin, e.g. `BTC`.
+    /// * `expiry` - The expiry date.
+    /// * `strike` - The strike price.
+    /// * `option_type` - Call or put.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `Symbol`.
+    pub fn option(base_coin: &str, expiry: NaiveDate, strike: Number, option_type: OptionType) -> Self {
+        let strike = strike.to_string();
+        Self {
+            name: format!("{}-{}-{}-{}", base_coin, format_date(expiry), strike, option_type.letter()),
+            base_coin: base_coin.to_string(),
+            quote_coin: DEFAULT_SETTLE_COIN.to_string(),
+            contract: Contract::Option { expiry, strike, option_type },
+        }
+    }
+
+    /// Gets the symbol as used by Bybit.
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the base coin, e.g. `BTC`.
+    pub fn base_coin(&self) -> &str {
+        &self.base_coin
+    }
+
+    /// Gets the quote coin, e.g. `USDT`. For options and dated futures without one in the name this is `USDC`.
+    pub fn quote_coin(&self) -> &str {
+        &self.quote_coin
+    }
+
+    /// Gets the coin the contract is settled in: the base coin for inverse contracts quoted in `USD`,
+    /// otherwise the quote coin.
+    pub fn settle_coin(&self) -> &str {
+        if self.quote_coin == "USD" {
+            &self.base_coin
+        } else {
+            &self.quote_coin
+        }
+    }
+
+    /// Checks whether the symbol is a spot pair or a perpetual, which share their names.
+    pub fn is_pair(&self) -> bool {
+        self.contract == Contract::Pair
+    }
+
+    /// Checks whether the symbol is a dated future.
+    pub fn is_future(&self) -> bool {
+        matches!(self.contract, Contract::Future { .. })
+    }
+
+    /// Checks whether the symbol is an option.
+    pub fn is_option(&self) -> bool {
+        matches!(self.contract, Contract::Option { .. })
+    }
+
+    /// Gets the expiry date of a dated future or an option.
+    ///
+    /// Inverse futures such as `BTCUSDH24` only name the month and expire on its last Friday.
+    pub fn expiry(&self) -> Option<NaiveDate> {
+        match &self.contract {
+            Contract::Pair => None,
+            Contract::Future { expiry } | Contract::Option { expiry, .. } => Some(*expiry),
+        }
+    }
+
+    /// Gets the delivery time of a dated future or an option, 08:00 UTC on the expiry date.
+    pub fn delivery_time(&self) -> Option<DateTime<Utc>> {
+        self.expiry()
+            .and_then(|expiry| expiry.and_hms_opt(DELIVERY_HOUR, 0, 0))
+            .map(|time| time.and_utc())
+    }
+
+    /// Gets the strike price of an option.
+    pub fn strike(&self) -> Option<Number> {
+        match &self.contract {
+            Contract::Option { strike, .. } => parse_number(strike).ok(),
+            _ => None,
+        }
+    }
+
+    /// Gets whether an option is a call or a put.
+    pub fn option_type(&self) -> Option<OptionType> {
+        match &self.contract {
+            Contract::Option { option_type, .. } => Some(*option_type),
+            _ => None,
+        }
+    }
+
+    /// Checks whether two symbols trade the same coins, e.g. to find the next future to roll into.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The symbol to compare with.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the base and quote coins are equal.
+    pub fn same_underlying(&self, other: &Symbol) -> bool {
+        self.base_coin == other.base_coin && self.quote_coin == other.quote_coin
+    }
+}
+
+impl Display for Symbol {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// The error returned when a string is not a Bybit symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseSymbolError(String);
+
+impl Display for ParseSymbolError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "Invalid symbol: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseSymbolError {}
+
+impl FromStr for Symbol {
+    type Err = ParseSymbolError;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        let error = || ParseSymbolError(value.to_string());
+        let parts: Vec<&str> = value.split('-').collect();
+        let (base_coin, quote_coin, contract) = match parts.as_slice() {
+            [name] => parse_undated(name).ok_or_else(error)?,
+            [prefix, date] => {
+                let (base_coin, quote_coin) = split_pair(prefix)
+                    .unwrap_or_else(|| (prefix.to_string(), DEFAULT_SETTLE_COIN.to_string()));
+                let expiry = parse_date(date).ok_or_else(error)?;
+                (base_coin, quote_coin, Contract::Future { expiry })
+            },
+            [base_coin, date, strike, option_type, settle_coin @ ..] if settle_coin.len() <= 1 => {
+                let expiry = parse_date(date).ok_or_else(error)?;
+                parse_number(strike).map_err(|_| error())?;
+                let option_type = option_type.parse::<OptionType>()?;
+                let quote_coin = settle_coin.first().copied().unwrap_or(DEFAULT_SETTLE_COIN);
+                let contract = Contract::Option {
+                    expiry,
+                    strike: strike.to_string(),
+                    option_type,
+                };
+                (base_coin.to_string(), quote_coin.to_string(), contract)
+            },
+            _ => return Err(error()),
+        };
+        if base_coin.is_empty() || quote_coin.is_empty() {
+            return Err(error());
+        }
+        Ok(Self {
+            name: value.to_string(),
+            base_coin,
+            quote_coin,
+            contract,
+        })
+    }
+}
+
+impl Serialize for Symbol {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.name)
+    }
+}
+
+impl<'de> Deserialize<'de> for Symbol {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+fn split_pair(name: &str) -> Option<(String, String)> {
+    QUOTE_COINS.iter()
+        .find(|quote| name.len() > quote.len() && name.ends_with(*quote))
+        .map(|quote| (name[..name.len() - quote.len()].to_string(), quote.to_string()))
+}
+
+// BTCUSDT, ETHPERP, BTCUSDH24
+fn parse_undated(name: &str) -> Option<(String, String, Contract)> {
+    if let Some(base_coin) = name.strip_suffix(PERPETUAL_SUFFIX) {
+        return Some((base_coin.to_string(), DEFAULT_SETTLE_COIN.to_string(), Contract::Pair));
+    }
+    if let Some(future) = parse_month_code(name) {
+        return Some(future);
+    }
+    split_pair(name).map(|(base_coin, quote_coin)| (base_coin, quote_coin, Contract::Pair))
+}
+
+fn parse_month_code(name: &str) -> Option<(String, String, Contract)> {
+    let split = name.len().checked_sub(3).filter(|split| name.is_char_boundary(*split))?;
+    let (pair, code) = name.split_at(split);
+    let mut chars = code.chars();
+    let letter = chars.next()?;
+    let month = MONTH_CODES.iter().position(|month| *month == letter)? as u32 + 1;
+    let year = chars.as_str();
+    if !year.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let (base_coin, quote_coin) = split_pair(pair)?;
+    let expiry = last_friday(2000 + year.parse::<i32>().ok()?, month)?;
+    Some((base_coin, quote_coin, Contract::Future { expiry }))
+}
+
+fn last_friday(year: i32, month: u32) -> Option<NaiveDate> {
+    let last_day = NaiveDate::from_ymd_opt(year, month, 1)?.checked_add_months(Months::new(1))?.pred_opt()?;
+    let days_back = (last_day.weekday().num_days_from_monday() + 7 - Weekday::Fri.num_days_from_monday()) % 7;
+    last_day.checked_sub_days(chrono::Days::new(days_back as u64))
+}
+
+// 29DEC23 や 5JAN24 のような日付
+fn parse_date(date: &str) -> Option<NaiveDate> {
+    let split = date.len().checked_sub(5).filter(|split| date.is_char_boundary(*split))?;
+    let (day, rest) = date.split_at(split);
+    // 非 ASCII の入力で文字の途中を切らないよう get で取り出す
+    let (month, year) = (rest.get(..3)?, rest.get(3..)?);
+    let month = MONTHS.iter().position(|name| *name == month)? as u32 + 1;
+    if day.is_empty() || !year.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    NaiveDate::from_ymd_opt(2000 + year.parse::<i32>().ok()?, month, day.parse().ok()?)
+}
+
+fn format_date(date: NaiveDate) -> String {
+    format!("{}{}{:02}", date.day(), MONTHS[date.month0() as usize], date.year() % 100)
+}
+
+// 満期の早い順に並べた先物
+pub(crate) fn dated_futures<'a, T>(instruments: impl Iterator<Item = (&'a str, &'a T)>) -> Vec<(Symbol, &'a T)> {
+    let mut futures: Vec<(Symbol, &T)> = instruments
+        .filter_map(|(name, info)| name.parse::<Symbol>().ok().map(|symbol| (symbol, info)))
+        .filter(|(symbol, _)| symbol.is_future())
+        .collect();
+    futures.sort_by_key(|(symbol, _)| symbol.expiry());
+    futures
+}
+
+// current と同じ銘柄で current より後に満期を迎える最初の先物
+pub(crate) fn next_future<'a, T>(instruments: impl Iterator<Item = (&'a str, &'a T)>, current: &Symbol) -> Option<(Symbol, &'a T)> {
+    dated_futures(instruments)
+        .into_iter()
+        .find(|(symbol, _)| symbol.same_underlying(current) && symbol.expiry() > current.expiry())
+}
@@ -0,0 +1,157 @@
+use crate::{
+    error::BybitError,
+    v5::api::BybitApi,
+};
+use chrono::Utc;
+use std::{
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+// 再計測に失敗したとき、次に試すまでの間隔
+const FAILED_SYNC_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Tracks the offset between the local clock and the Bybit server clock.
+///
+/// The offset is measured with `get_bybit_server_time`, assuming the server read its clock
+/// halfway through the round trip. It is applied to `X-BAPI-TIMESTAMP` by `BybitApi` and to
+/// the auth expiry by `BybitWS`, and is measured again once `interval` has elapsed.
+/// Clones share the same offset, so one instance can be given to several clients.
+#[derive(Debug, Clone)]
+pub struct TimeSync {
+    interval: Duration,
+    state: Arc<Mutex<TimeSyncState>>,
+}
+
+#[derive(Debug, Default)]
+struct TimeSyncState {
+    offset: i64,
+    round_trip: Duration,
+    measured: bool,
+    synced_at: Option<Instant>,
+    failed_at: Option<Instant>,
+}
+
+impl TimeSync {
+    /// Creates a new instance of `TimeSync`.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - How long a measured offset is used before it is measured again.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `TimeSync`. The offset is zero until the first sync.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            state: Arc::new(Mutex::new(TimeSyncState::default())),
+        }
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Gets the offset to add to the local clock to get the server clock, in milliseconds.
+    pub fn offset(&self) -> i64 {
+        self.lock().offset
+    }
+
+    /// Gets the round-trip time of the last measurement.
+    pub fn round_trip(&self) -> Duration {
+        self.lock().round_trip
+    }
+
+    /// Checks whether the offset has never been measured or is older than `interval`.
+    pub fn is_stale(&self) -> bool {
+        match self.lock().synced_at {
+            Some(synced_at) => synced_at.elapsed() >= self.interval,
+            None => true,
+        }
+    }
+
+    /// Marks the offset as stale so that it is measured again before the next signed request.
+    pub fn invalidate(&self) {
+        let mut state = self.lock();
+        state.synced_at = None;
+        state.failed_at = None;
+    }
+
+    /// Gets the current server time estimated from the local clock and the offset.
+    ///
+    /// # Returns
+    ///
+    /// The estimated server time in milliseconds.
+    pub fn now_millis(&self) -> i64 {
+        Utc::now().timestamp_millis() + self.offset()
+    }
+
+    /// Measures the offset against the Bybit server.
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - The API used to call `get_bybit_server_time`.
+    ///
+    /// # Returns
+    ///
+    /// The measured offset in milliseconds.
+    pub async fn sync(&self, api: &BybitApi) -> Result<i64, BybitError> {
+        let sent_at = Utc::now().timestamp_millis();
+        let started = Instant::now();
+        let response = api.get_bybit_server_time_once().await?;
+        let round_trip = started.elapsed();
+
+        let server_time = (response.result().time_nano() / 1_000_000) as i64;
+        let local_time = sent_at + (round_trip / 2).as_millis() as i64;
+        let offset = server_time - local_time;
+
+        let mut state = self.lock();
+        state.offset = offset;
+        state.round_trip = round_trip;
+        state.measured = true;
+        state.synced_at = Some(Instant::now());
+        state.failed_at = None;
+        Ok(offset)
+    }
+
+    /// Measures the offset if it is stale.
+    ///
+    /// A failed measurement is only reported if no offset has been measured yet;
+    /// otherwise the previous offset keeps being used and the measurement is not
+    /// tried again for a few seconds, so that an outage does not add a call to every request.
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - The API used to call `get_bybit_server_time`.
+    pub async fn sync_if_stale(&self, api: &BybitApi) -> Result<(), BybitError> {
+        if !self.is_stale() || self.is_backing_off() {
+            return Ok(());
+        }
+        let measured = self.lock().measured;
+        match self.sync(api).await {
+            Ok(_) => Ok(()),
+            Err(_) if measured => {
+                self.lock().failed_at = Some(Instant::now());
+                Ok(())
+            },
+            Err(err) => Err(err),
+        }
+    }
+
+    fn is_backing_off(&self) -> bool {
+        self.lock()
+            .failed_at
+            .is_some_and(|failed_at| failed_at.elapsed() < FAILED_SYNC_BACKOFF)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, TimeSyncState> {
+        self.state.lock().expect("time sync lock poisoned")
+    }
+}
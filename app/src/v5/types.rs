@@ -0,0 +1,469 @@
+use chrono::{
+    DateTime,
+    Datelike,
+    Duration,
+    Months,
+    TimeZone,
+    Utc,
+};
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use std::{
+    convert::Infallible,
+    fmt::{
+        Display,
+        Formatter,
+        Result,
+    },
+    str::FromStr,
+};
+
+/// The type of prices, quantities and balances.
+///
+/// This is `f64` by default and `rust_decimal::Decimal` with the `decimal` feature,
+/// which keeps values exact and never serializes them with an exponent.
+#[cfg(not(feature = "decimal"))]
+pub type Number = f64;
+
+/// The type of prices, quantities and balances.
+///
+/// This is `f64` by default and `rust_decimal::Decimal` with the `decimal` feature,
+/// which keeps values exact and never serializes them with an exponent.
+#[cfg(feature = "decimal")]
+pub type Number = rust_decimal::Decimal;
+
+// Defines an enum of the string values Bybit uses for a field.
+// Values not known to this crate are kept in `Unknown` so that new values added by Bybit do not break deserialization.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $value:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+            /// A value not known to this crate.
+            Unknown(String),
+        }
+
+        impl $name {
+            /// Gets the value as sent to and returned by Bybit.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter) -> Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => $name::$variant,)*
+                    value => $name::Unknown(value.to_string()),
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                $name::from(value.as_str())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+                Ok($name::from(value))
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                Ok($name::from(value))
+            }
+        }
+    };
+}
+
+string_enum! {
+    /// The side of an order, execution or position.
+    Side {
+        Buy => "Buy",
+        Sell => "Sell",
+    }
+}
+
+string_enum! {
+    /// The type of an order.
+    OrderType {
+        Market => "Market",
+        Limit => "Limit",
+    }
+}
+
+string_enum! {
+    /// How long an order stays active.
+    TimeInForce {
+        /// Good till cancelled.
+        Gtc => "GTC",
+        /// Immediate or cancel.
+        Ioc => "IOC",
+        /// Fill or kill.
+        Fok => "FOK",
+        /// Cancelled instead of taking liquidity.
+        PostOnly => "PostOnly",
+    }
+}
+
+string_enum! {
+    /// The price that triggers a conditional order, take profit or stop loss.
+    TriggerBy {
+        LastPrice => "LastPrice",
+        IndexPrice => "IndexPrice",
+        MarkPrice => "MarkPrice",
+    }
+}
+
+string_enum! {
+    /// The status of an order.
+    OrderStatus {
+        Created => "Created",
+        New => "New",
+        Rejected => "Rejected",
+        PartiallyFilled => "PartiallyFilled",
+        PartiallyFilledCanceled => "PartiallyFilledCanceled",
+        Filled => "Filled",
+        Cancelled => "Cancelled",
+        Untriggered => "Untriggered",
+        Triggered => "Triggered",
+        Deactivated => "Deactivated",
+        Active => "Active",
+    }
+}
+
+string_enum! {
+    /// The kind of a conditional order.
+    StopOrderType {
+        TakeProfit => "TakeProfit",
+        StopLoss => "StopLoss",
+        TrailingStop => "TrailingStop",
+        Stop => "Stop",
+        PartialTakeProfit => "PartialTakeProfit",
+        PartialStopLoss => "PartialStopLoss",
+        TpslOrder => "tpslOrder",
+        OcoOrder => "OcoOrder",
+        MmRateClose => "MmRateClose",
+        BidirectionalTpslOrder => "BidirectionalTpslOrder",
+    }
+}
+
+string_enum! {
+    /// The reason an order was cancelled.
+    CancelType {
+        /// The order has not been cancelled.
+        NotCancelled => "UNKNOWN",
+        CancelByUser => "CancelByUser",
+        CancelByReduceOnly => "CancelByReduceOnly",
+        CancelByPrepareLiq => "CancelByPrepareLiq",
+        CancelAllBeforeLiq => "CancelAllBeforeLiq",
+        CancelByPrepareAdl => "CancelByPrepareAdl",
+        CancelAllBeforeAdl => "CancelAllBeforeAdl",
+        CancelByAdmin => "CancelByAdmin",
+        CancelBySettle => "CancelBySettle",
+        CancelByTpSlTsClear => "CancelByTpSlTsClear",
+        CancelBySmp => "CancelBySmp",
+        CancelByDcp => "CancelByDCP",
+    }
+}
+
+string_enum! {
+    /// Whether a take profit or stop loss applies to the whole position or part of it.
+    TpslMode {
+        Full => "Full",
+        Partial => "Partial",
+    }
+}
+
+string_enum! {
+    /// The self-match prevention applied to an order.
+    SmpType {
+        None => "None",
+        CancelMaker => "CancelMaker",
+        CancelTaker => "CancelTaker",
+        CancelBoth => "CancelBoth",
+    }
+}
+
+/// The position an order or position belongs to.
+///
+/// Bybit sends this as an integer, so unknown values are kept as integers too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PositionIdx {
+    /// One-way mode.
+    OneWay,
+    /// The buy side in hedge mode.
+    HedgeBuy,
+    /// The sell side in hedge mode.
+    HedgeSell,
+    /// A value not known to this crate.
+    Unknown(u8),
+}
+
+impl PositionIdx {
+    /// Gets the value as sent to and returned by Bybit.
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            PositionIdx::OneWay => 0,
+            PositionIdx::HedgeBuy => 1,
+            PositionIdx::HedgeSell => 2,
+            PositionIdx::Unknown(value) => *value,
+        }
+    }
+}
+
+impl From<u8> for PositionIdx {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => PositionIdx::OneWay,
+            1 => PositionIdx::HedgeBuy,
+            2 => PositionIdx::HedgeSell,
+            value => PositionIdx::Unknown(value),
+        }
+    }
+}
+
+impl Display for PositionIdx {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}", self.as_u8())
+    }
+}
+
+impl Serialize for PositionIdx {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.as_u8())
+    }
+}
+
+impl<'de> Deserialize<'de> for PositionIdx {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = u8::deserialize(deserializer)?;
+        Ok(PositionIdx::from(value))
+    }
+}
+
+/// The interval of a kline, shared by the REST kline endpoints and the kline stream.
+///
+/// Bars open on multiples of the interval since the Unix epoch in UTC, except weekly bars,
+/// which open on Monday 00:00 UTC, and monthly bars, which open on the first day of the month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Interval {
+    Minute1,
+    Minute3,
+    Minute5,
+    Minute15,
+    Minute30,
+    Hour1,
+    Hour2,
+    Hour4,
+    Hour6,
+    Hour12,
+    Day,
+    Week,
+    Month,
+}
+
+impl Interval {
+    /// Every interval, from the shortest to the longest.
+    pub const ALL: [Interval; 13] = [
+        Interval::Minute1,
+        Interval::Minute3,
+        Interval::Minute5,
+        Interval::Minute15,
+        Interval::Minute30,
+        Interval::Hour1,
+        Interval::Hour2,
+        Interval::Hour4,
+        Interval::Hour6,
+        Interval::Hour12,
+        Interval::Day,
+        Interval::Week,
+        Interval::Month,
+    ];
+
+    /// Gets the value as sent to and returned by Bybit, e.g. `60` or `D`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Interval::Minute1 => "1",
+            Interval::Minute3 => "3",
+            Interval::Minute5 => "5",
+            Interval::Minute15 => "15",
+            Interval::Minute30 => "30",
+            Interval::Hour1 => "60",
+            Interval::Hour2 => "120",
+            Interval::Hour4 => "240",
+            Interval::Hour6 => "360",
+            Interval::Hour12 => "720",
+            Interval::Day => "D",
+            Interval::Week => "W",
+            Interval::Month => "M",
+        }
+    }
+
+    /// Gets the length of a bar.
+    ///
+    /// # Returns
+    ///
+    /// The length of a bar, or `None` for `Month`, whose bars have different lengths.
+    pub fn duration(&self) -> Option<Duration> {
+        let minutes = match self {
+            Interval::Minute1 => 1,
+            Interval::Minute3 => 3,
+            Interval::Minute5 => 5,
+            Interval::Minute15 => 15,
+            Interval::Minute30 => 30,
+            Interval::Hour1 => 60,
+            Interval::Hour2 => 120,
+            Interval::Hour4 => 240,
+            Interval::Hour6 => 360,
+            Interval::Hour12 => 720,
+            Interval::Day => 1440,
+            Interval::Week => 10080,
+            Interval::Month => return None,
+        };
+        Some(Duration::minutes(minutes))
+    }
+
+    /// Gets the open time of the bar containing a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - Any time within the bar.
+    ///
+    /// # Returns
+    ///
+    /// The open time of the bar.
+    pub fn bar_open(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            Interval::Month => Utc
+                .with_ymd_and_hms(time.year(), time.month(), 1, 0, 0, 0)
+                .single()
+                .expect("the first day of a month is a valid date"),
+            Interval::Week => {
+                let days_from_monday = time.weekday().num_days_from_monday() as i64;
+                Interval::Day.bar_open(time) - Duration::days(days_from_monday)
+            },
+            _ => {
+                let length = self.duration().map(|duration| duration.num_milliseconds()).unwrap_or(1);
+                let millis = time.timestamp_millis();
+                Utc.timestamp_millis_opt(millis - millis.rem_euclid(length))
+                    .single()
+                    .expect("an aligned timestamp is a valid time")
+            },
+        }
+    }
+
+    /// Gets the open time of the bar following the one containing a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - Any time within the current bar.
+    ///
+    /// # Returns
+    ///
+    /// The open time of the next bar, which is also the close time of the current one.
+    pub fn next_bar_open(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let open = self.bar_open(time);
+        match self.duration() {
+            Some(duration) => open + duration,
+            None => open
+                .checked_add_months(Months::new(1))
+                .expect("the next month is a valid date"),
+        }
+    }
+
+    /// Gets the open time of the bar containing a timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - Any timestamp within the bar, in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// The open time of the bar in milliseconds.
+    pub fn bar_open_millis(&self, timestamp: u64) -> u64 {
+        match Utc.timestamp_millis_opt(timestamp as i64).single() {
+            Some(time) => self.bar_open(time).timestamp_millis() as u64,
+            None => timestamp,
+        }
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The error returned when a string is not a kline interval.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIntervalError(String);
+
+impl Display for ParseIntervalError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "Invalid kline interval: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseIntervalError {}
+
+impl FromStr for Interval {
+    type Err = ParseIntervalError;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        Interval::ALL
+            .into_iter()
+            .find(|interval| interval.as_str() == value)
+            .ok_or_else(|| ParseIntervalError(value.to_string()))
+    }
+}
+
+impl Serialize for Interval {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Interval {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
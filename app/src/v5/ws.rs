@@ -3,17 +3,12 @@ pub mod private;
 pub mod public;
 
 use crate::{
+    auth::parse_rsa_private_key,
     constants::{
-        MAINNET_SPOT_PUBLIC_CHANNEL,
-        MAINNET_LINEAR_PUBLIC_CHANNEL,
-        MAINNET_INVERSE_PUBLIC_CHANNEL,
-        MAINNET_OPTION_PUBLIC_CHANNEL,
-        TESTNET_SPOT_PUBLIC_CHANNEL,
-        TESTNET_LINEAR_PUBLIC_CHANNEL,
-        TESTNET_INVERSE_PUBLIC_CHANNEL,
-        TESTNET_OPTION_PUBLIC_CHANNEL,
-        MAINNET_PRIVATE_CHANNEL,
-        TESTNET_PRIVATE_CHANNEL,
+        CATEGORY_LINEAR,
+        CATEGORY_SPOT,
+        CATEGORY_INVERSE,
+        CATEGORY_OPTION,
         PUBLIC_TRADE_TOPIC,
         PUBLIC_ORDERBOOK_TOPIC,
         PUBLIC_TICKERS_TOPIC,
@@ -24,6 +19,15 @@ use crate::{
         PRIVATE_ORDER_TOPIC,
         PRIVATE_WALLET_TOPIC,
     },
+    v5::{
+        api::BybitApi,
+        category::{
+            Category,
+            CategoryKind,
+        },
+        environment::Environment,
+        time_sync::TimeSync,
+    },
     v5::ws::{
         public::{
             trade::PublicTradeResponse,
@@ -45,9 +49,14 @@ use crate::{
         },
     },
 };
-use serde::Deserialize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use chrono::Utc;
 use std::collections::HashMap;
-use serde_json::{Value, to_string};
+use serde_json::{Map, Value, to_string};
+use crate::utils::deserialize_extra;
 use tokio_tungstenite::{
     WebSocketStream,
     MaybeTlsStream,
@@ -61,6 +70,8 @@ use futures_util::{
     },
 };
 use tokio::net::TcpStream;
+use ring::signature::RsaKeyPair;
+use std::sync::Arc;
 use anyhow::Result;
 
 #[derive(Debug, Clone)]
@@ -77,7 +88,9 @@ pub enum Channel {
     TestnetPrivateChannel,
 }
 
-#[derive(Debug, Clone)]
+// untagged なので各メッセージは受信した時と同じ JSON になる
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum DeserializedMessage {
     SubscribePublicSuccess(SubscribePublicSuccessResponse),
     PublicTrade(PublicTradeResponse),
@@ -94,29 +107,31 @@ pub enum DeserializedMessage {
     PrivateWallet(PrivateWalletResponse),
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct SubscribePublicSuccessResponse {
     pub success: bool,
     pub ret_msg: Option<String>,
     pub conn_id: String,
     pub req_id: Option<String>,
-    pub op: Option<String>
+    pub op: Option<String>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    pub extra: Map<String, Value>,
 }
 
 
 impl Channel {
-    fn to_string(&self) -> &'static str {
+    pub fn environment(&self) -> Environment {
         match self {
-            Channel::MainnetSpotPublicChannel => MAINNET_SPOT_PUBLIC_CHANNEL,
-            Channel::MainnetLinearPublicChannel => MAINNET_LINEAR_PUBLIC_CHANNEL,
-            Channel::MainnetInversePublicChannel => MAINNET_INVERSE_PUBLIC_CHANNEL,
-            Channel::MainnetOptionPublicChannel => MAINNET_OPTION_PUBLIC_CHANNEL,
-            Channel::TestnetSpotPublicChannel => TESTNET_SPOT_PUBLIC_CHANNEL,
-            Channel::TestnetLinearPublicChannel => TESTNET_LINEAR_PUBLIC_CHANNEL,
-            Channel::TestnetInversePublicChannel => TESTNET_INVERSE_PUBLIC_CHANNEL,
-            Channel::TestnetOptionPublicChannel => TESTNET_OPTION_PUBLIC_CHANNEL,
-            Channel::MainnetPrivateChannel => MAINNET_PRIVATE_CHANNEL,
-            Channel::TestnetPrivateChannel => TESTNET_PRIVATE_CHANNEL,
+            Channel::MainnetSpotPublicChannel
+            | Channel::MainnetLinearPublicChannel
+            | Channel::MainnetInversePublicChannel
+            | Channel::MainnetOptionPublicChannel
+            | Channel::MainnetPrivateChannel => Environment::Mainnet,
+            Channel::TestnetSpotPublicChannel
+            | Channel::TestnetLinearPublicChannel
+            | Channel::TestnetInversePublicChannel
+            | Channel::TestnetOptionPublicChannel
+            | Channel::TestnetPrivateChannel => Environment::Testnet,
         }
     }
 
@@ -152,6 +167,7 @@ impl Channel {
 
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ChannelCategory {
     Linear,
     Inverse,
@@ -160,30 +176,81 @@ pub enum ChannelCategory {
     Private,
 }
 
+impl ChannelCategory {
+    // public channelのURLの末尾に付くcategory。private channelはNone。
+    pub fn as_str(&self) -> Option<&'static str> {
+        match self {
+            ChannelCategory::Linear => Some(CATEGORY_LINEAR),
+            ChannelCategory::Inverse => Some(CATEGORY_INVERSE),
+            ChannelCategory::Spot => Some(CATEGORY_SPOT),
+            ChannelCategory::Option => Some(CATEGORY_OPTION),
+            ChannelCategory::Private => None,
+        }
+    }
+}
+
+impl<S> From<Category<S>> for ChannelCategory {
+    fn from(category: Category<S>) -> Self {
+        match category.kind() {
+            CategoryKind::Linear => ChannelCategory::Linear,
+            CategoryKind::Inverse => ChannelCategory::Inverse,
+            CategoryKind::Spot => ChannelCategory::Spot,
+            CategoryKind::Option => ChannelCategory::Option,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BybitWS {
-    channel: Channel,
+    environment: Environment,
+    category: ChannelCategory,
     api_key: Option<String>,
     api_secret: Option<String>,
+    rsa_private_key: Option<String>,
+    rsa_key_pair: Option<Arc<RsaKeyPair>>,
     args: Vec<String>,
+    time_sync: Option<TimeSync>,
+    // サーバー時刻の取得に使う。呼び出しごとに HTTP クライアントを作らないよう使い回す
+    time_sync_api: Option<BybitApi>,
 }
 
 impl BybitWS {
     pub fn new(channel: Channel) -> Self {
+        Self::from_environment(channel.environment(), channel.channel_category())
+    }
+
+    // 任意のEnvironment(地域のホストやローカルのサーバーなど)に接続する。
+    pub fn from_environment(environment: Environment, category: ChannelCategory) -> Self {
         Self {
-            channel,
+            environment,
+            category,
             api_key: None,
             api_secret: None,
+            rsa_private_key: None,
+            rsa_key_pair: None,
             args: Vec::new(),
+            time_sync: None,
+            time_sync_api: None,
+        }
+    }
+
+    pub fn channel(&self) -> String {
+        match self.category.as_str() {
+            Some(category) => format!("{}/{}", self.environment.ws_public_url().trim_end_matches('/'), category),
+            None => self.environment.ws_private_url().to_string(),
         }
     }
 
-    pub fn channel(&self) -> &'static str {
-        self.channel.to_string()
+    pub fn environment(&self) -> &Environment {
+        &self.environment
+    }
+
+    pub fn category(&self) -> &ChannelCategory {
+        &self.category
     }
 
     pub fn is_private_channel(&self) -> bool {
-        self.channel.is_private()
+        self.category == ChannelCategory::Private
     }
 
     pub fn api_key(&self) -> Option<&str> {
@@ -200,10 +267,22 @@ impl BybitWS {
         }
     }
 
+    pub fn rsa_private_key(&self) -> Option<&str> {
+        self.rsa_private_key.as_deref()
+    }
+
+    pub(crate) fn rsa_key_pair(&self) -> Option<&RsaKeyPair> {
+        self.rsa_key_pair.as_deref()
+    }
+
     pub fn args(&self) -> &Vec<String> {
         &self.args
     }
 
+    pub fn time_sync(&self) -> Option<&TimeSync> {
+        self.time_sync.as_ref()
+    }
+
     pub fn with_api_key(mut self, api_key: String) -> Self {
         self.api_key = Some(api_key);
         self
@@ -214,6 +293,32 @@ impl BybitWS {
         self
     }
 
+    // 設定するとprivate channelの認証にAPI secretの代わりにRSA秘密鍵(PEM)を使う。
+    // 鍵はここで一度だけ解析し、不正な鍵はBybitError::InvalidPrivateKeyを返す。
+    pub fn with_rsa_private_key(mut self, rsa_private_key: String) -> Result<Self> {
+        self.rsa_key_pair = Some(parse_rsa_private_key(&rsa_private_key)?);
+        self.rsa_private_key = Some(rsa_private_key);
+        Ok(self)
+    }
+
+    // private channelの認証のexpiresをBybitのサーバー時刻に合わせる。
+    pub fn with_time_sync(mut self, time_sync: TimeSync) -> Self {
+        self.time_sync = Some(time_sync);
+        self.time_sync_api = Some(BybitApi::new().with_environment(self.environment.clone()));
+        self
+    }
+
+    // 認証に使う現在時刻(ミリ秒)。time_syncが設定されていればサーバー時刻とのずれを補正する。
+    pub(crate) async fn now_millis(&self) -> Result<i64> {
+        match (&self.time_sync, &self.time_sync_api) {
+            (Some(time_sync), Some(api)) => {
+                time_sync.sync_if_stale(api).await?;
+                Ok(time_sync.now_millis())
+            },
+            _ => Ok(Utc::now().timestamp_millis()),
+        }
+    }
+
     // argsに追加したtopicをsubscribeする。
     pub async fn execute(&self) -> Result<(
         SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>,
@@ -256,7 +361,7 @@ impl BybitWS {
                     Ok(DeserializedMessage::PublicOrderbook(response))
                 },
                 Some(topic) if topic.contains(PUBLIC_TICKERS_TOPIC) => {
-                    match self.channel.channel_category() {
+                    match self.category {
                         ChannelCategory::Linear => {
                             let response: PublicLinearTickersResponse = serde_json::from_str(&message)?;
                             Ok(DeserializedMessage::PublicLinearTickers(response))
@@ -314,7 +419,7 @@ impl BybitWS {
 pub fn deserialize_message(channel: Channel, message: &str) -> Result<DeserializedMessage> {
 
     if message.contains("conn_id") {
-        let response: SubscribePublicSuccessResponse = serde_json::from_str(&message)?;
+        let response: SubscribePublicSuccessResponse = serde_json::from_str(message)?;
         if response.success {
             Ok(DeserializedMessage::SubscribePublicSuccess(response))
         } else {
@@ -322,27 +427,27 @@ pub fn deserialize_message(channel: Channel, message: &str) -> Result<Deserializ
         }
     } else {
         if message.contains(PUBLIC_TRADE_TOPIC) {
-            let response: PublicTradeResponse = serde_json::from_str(&message)?;
+            let response: PublicTradeResponse = serde_json::from_str(message)?;
             Ok(DeserializedMessage::PublicTrade(response))
         } else if message.contains(PUBLIC_ORDERBOOK_TOPIC) {
-            let response: PublicOrderbookResponse = serde_json::from_str(&message)?;
+            let response: PublicOrderbookResponse = serde_json::from_str(message)?;
             Ok(DeserializedMessage::PublicOrderbook(response))
         }else if message.contains(PUBLIC_TICKERS_TOPIC) {
             match channel.channel_category() {
                 ChannelCategory::Linear => {
-                    let response: PublicLinearTickersResponse = serde_json::from_str(&message)?;
+                    let response: PublicLinearTickersResponse = serde_json::from_str(message)?;
                     Ok(DeserializedMessage::PublicLinearTickers(response))
                 },
                 ChannelCategory::Spot => {
-                    let response: PublicSpotTickersResponse = serde_json::from_str(&message)?;
+                    let response: PublicSpotTickersResponse = serde_json::from_str(message)?;
                     Ok(DeserializedMessage::PublicSpotTickers(response))
                 },
                 ChannelCategory::Inverse => {
-                    let response: PublicInverseTickersResponse = serde_json::from_str(&message)?;
+                    let response: PublicInverseTickersResponse = serde_json::from_str(message)?;
                     Ok(DeserializedMessage::PublicInverseTickers(response))
                 },
                 ChannelCategory::Option => {
-                    let response: PublicOptionTickersResponse = serde_json::from_str(&message)?;
+                    let response: PublicOptionTickersResponse = serde_json::from_str(message)?;
                     Ok(DeserializedMessage::PublicOptionTickers(response))
                 },
                 ChannelCategory::Private => {
@@ -350,22 +455,22 @@ pub fn deserialize_message(channel: Channel, message: &str) -> Result<Deserializ
                 }
             }
         } else if message.contains(PUBLIC_KLINE_TOPIC) {
-            let response: PublicKlineResponse = serde_json::from_str(&message)?;
+            let response: PublicKlineResponse = serde_json::from_str(message)?;
             Ok(DeserializedMessage::PublicKline(response))
         } else if message.contains(PUBLIC_LIQUIDATION_TOPIC) {
-            let response: PublicLiquidationResponse = serde_json::from_str(&message)?;
+            let response: PublicLiquidationResponse = serde_json::from_str(message)?;
             Ok(DeserializedMessage::PublicLiquidation(response))
         } else if message.contains(PRIVATE_POSITION_TOPIC) {
-            let response: PrivatePositionResponse = serde_json::from_str(&message)?;
+            let response: PrivatePositionResponse = serde_json::from_str(message)?;
             Ok(DeserializedMessage::PrivatePosition(response))
         } else if message.contains(PRIVATE_EXECUTION_TOPIC) {
-            let response: PrivateExecutionResponse = serde_json::from_str(&message)?;
+            let response: PrivateExecutionResponse = serde_json::from_str(message)?;
             Ok(DeserializedMessage::PrivateExecution(response))
         } else if message.contains(PRIVATE_ORDER_TOPIC) {
-            let response: PrivateOrderResponse = serde_json::from_str(&message)?;
+            let response: PrivateOrderResponse = serde_json::from_str(message)?;
             Ok(DeserializedMessage::PrivateOrder(response))
         } else if message.contains(PRIVATE_WALLET_TOPIC) {
-            let response: PrivateWalletResponse = serde_json::from_str(&message)?;
+            let response: PrivateWalletResponse = serde_json::from_str(message)?;
             Ok(DeserializedMessage::PrivateWallet(response))
         } else {
             Err(anyhow::anyhow!("Unknown message"))
@@ -4,7 +4,6 @@ use crate::{
 };
 use anyhow::Result;
 use tokio::net::TcpStream;
-use chrono::Utc;
 use tokio_tungstenite::{
     WebSocketStream,
     connect_async,
@@ -38,8 +37,8 @@ impl BybitWS {
         SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>,
         SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>
     )> {
-        let (ws_stream, _) = connect_async(self.channel()).await?;
-        let expires = (Utc::now().timestamp_millis() + 10000).to_string();
+        let (ws_stream, _) = connect_async(self.channel().as_str()).await?;
+        let expires = (self.now_millis().await? + 10000).to_string();
         let signature = self.create_signature(&expires)?;
 
         let auth = json!({
@@ -56,7 +55,7 @@ impl BybitWS {
         SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>,
         SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>
     )> {
-        let (ws_stream, _) = connect_async(self.channel()).await?;
+        let (ws_stream, _) = connect_async(self.channel().as_str()).await?;
         let (write, read) = ws_stream.split();
         Ok((write, read))
     }
@@ -1,31 +1,44 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::Category,
     v5::ws::BybitWS,
     constants::PRIVATE_EXECUTION_TOPIC,
+    v5::types::{
+        Side,
+        OrderType,
+        StopOrderType,
+        Number,
+    },
     utils::{
-        deserialize_f64,
+        serialize_as_string,
+        serialize_option_as_string,
+        millis_to_datetime,
+        deserialize_number,
         deserialize_string_to_u64,
-        deserialize_option_f64,
+        deserialize_option_number,
+        deserialize_option_enum,
     },
 };
-use serde::Deserialize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
 
-pub enum ExecutionCategory {
-    Linear,
-    Inverse,
-    Option,
-    Spot,
-}
+pub type ExecutionCategory = Category;
 
 impl BybitWS {
     pub fn add_execution_args(&mut self, category: Option<ExecutionCategory>) {
         match category {
             Some(category) => {
-                self.args.push(match category {
-                    ExecutionCategory::Linear => format!("{}.{}", PRIVATE_EXECUTION_TOPIC, "linear"),
-                    ExecutionCategory::Inverse => format!("{}.{}", PRIVATE_EXECUTION_TOPIC, "inverse"),
-                    ExecutionCategory::Option => format!("{}.{}", PRIVATE_EXECUTION_TOPIC, "option"),
-                    ExecutionCategory::Spot => format!("{}.{}", PRIVATE_EXECUTION_TOPIC, "spot"),
-                });
+                self.args.push(format!("{}.{}", PRIVATE_EXECUTION_TOPIC, category.as_str()));
             },
             None => {
                 self.args.push(PRIVATE_EXECUTION_TOPIC.to_string());
@@ -34,13 +47,15 @@ impl BybitWS {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivateExecutionResponse {
     id: String,
     topic: String,
     creation_time: u64,
     data: Vec<PrivateExecutionData>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PrivateExecutionResponse {
@@ -68,6 +83,10 @@ impl PrivateExecutionResponse {
         self.creation_time = creation_time;
     }
 
+    pub fn creation_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.creation_time)
+    }
+
     pub fn data(&self) -> &Vec<PrivateExecutionData> {
         &self.data
     }
@@ -75,53 +94,64 @@ impl PrivateExecutionResponse {
     pub fn set_data(&mut self, data: Vec<PrivateExecutionData>) {
         self.data = data;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivateExecutionData {
     category: String,
     symbol: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    exec_fee: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    exec_fee: Number,
     exec_id: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    exec_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    exec_qty: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    exec_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    exec_qty: Number,
     exec_type: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    exec_value: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    exec_value: Number,
     is_maker: bool,
-    #[serde(deserialize_with = "deserialize_f64")]
-    fee_rate: f64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    trade_iv: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    mark_iv: Option<f64>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    fee_rate: Number,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    trade_iv: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    mark_iv: Option<Number>,
     block_trade_id: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    mark_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    index_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    underlying_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    leaves_qty: f64,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    mark_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    index_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    underlying_price: Option<Number>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    leaves_qty: Number,
     order_id: String,
     order_link_id: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    order_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    order_qty: f64,
-    order_type: String,
-    stop_order_type: String,
-    side: String,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    order_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    order_qty: Number,
+    order_type: OrderType,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    stop_order_type: Option<StopOrderType>,
+    side: Side,
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     exec_time: u64,
     is_leverage: String,
     closed_size: String,
     seq: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PrivateExecutionData {
@@ -141,11 +171,11 @@ impl PrivateExecutionData {
         self.symbol = symbol;
     }
 
-    pub fn exec_fee(&self) -> f64 {
+    pub fn exec_fee(&self) -> Number {
         self.exec_fee
     }
 
-    pub fn set_exec_fee(&mut self, exec_fee: f64) {
+    pub fn set_exec_fee(&mut self, exec_fee: Number) {
         self.exec_fee = exec_fee;
     }
 
@@ -157,19 +187,19 @@ impl PrivateExecutionData {
         self.exec_id = exec_id;
     }
 
-    pub fn exec_price(&self) -> f64 {
+    pub fn exec_price(&self) -> Number {
         self.exec_price
     }
 
-    pub fn set_exec_price(&mut self, exec_price: f64) {
+    pub fn set_exec_price(&mut self, exec_price: Number) {
         self.exec_price = exec_price;
     }
 
-    pub fn exec_qty(&self) -> f64 {
+    pub fn exec_qty(&self) -> Number {
         self.exec_qty
     }
 
-    pub fn set_exec_qty(&mut self, exec_qty: f64) {
+    pub fn set_exec_qty(&mut self, exec_qty: Number) {
         self.exec_qty = exec_qty;
     }
 
@@ -181,11 +211,11 @@ impl PrivateExecutionData {
         self.exec_type = exec_type;
     }
 
-    pub fn exec_value(&self) -> f64 {
+    pub fn exec_value(&self) -> Number {
         self.exec_value
     }
 
-    pub fn set_exec_value(&mut self, exec_value: f64) {
+    pub fn set_exec_value(&mut self, exec_value: Number) {
         self.exec_value = exec_value;
     }
 
@@ -197,27 +227,27 @@ impl PrivateExecutionData {
         self.is_maker = is_maker;
     }
 
-    pub fn fee_rate(&self) -> f64 {
+    pub fn fee_rate(&self) -> Number {
         self.fee_rate
     }
 
-    pub fn set_fee_rate(&mut self, fee_rate: f64) {
+    pub fn set_fee_rate(&mut self, fee_rate: Number) {
         self.fee_rate = fee_rate;
     }
 
-    pub fn trade_iv(&self) -> Option<f64> {
+    pub fn trade_iv(&self) -> Option<Number> {
         self.trade_iv
     }
 
-    pub fn set_trade_iv(&mut self, trade_iv: Option<f64>) {
+    pub fn set_trade_iv(&mut self, trade_iv: Option<Number>) {
         self.trade_iv = trade_iv;
     }
 
-    pub fn mark_iv(&self) -> Option<f64> {
+    pub fn mark_iv(&self) -> Option<Number> {
         self.mark_iv
     }
 
-    pub fn set_mark_iv(&mut self, mark_iv: Option<f64>) {
+    pub fn set_mark_iv(&mut self, mark_iv: Option<Number>) {
         self.mark_iv = mark_iv;
     }
 
@@ -229,35 +259,35 @@ impl PrivateExecutionData {
         self.block_trade_id = block_trade_id;
     }
 
-    pub fn mark_price(&self) -> Option<f64> {
+    pub fn mark_price(&self) -> Option<Number> {
         self.mark_price
     }
 
-    pub fn set_mark_price(&mut self, mark_price: f64) {
+    pub fn set_mark_price(&mut self, mark_price: Number) {
         self.mark_price = Some(mark_price);
     }
 
-    pub fn index_price(&self) -> Option<f64> {
+    pub fn index_price(&self) -> Option<Number> {
         self.index_price
     }
 
-    pub fn set_index_price(&mut self, index_price: Option<f64>) {
+    pub fn set_index_price(&mut self, index_price: Option<Number>) {
         self.index_price = index_price;
     }
 
-    pub fn underlying_price(&self) -> Option<f64> {
+    pub fn underlying_price(&self) -> Option<Number> {
         self.underlying_price
     }
 
-    pub fn set_underlying_price(&mut self, underlying_price: Option<f64>) {
+    pub fn set_underlying_price(&mut self, underlying_price: Option<Number>) {
         self.underlying_price = underlying_price;
     }
 
-    pub fn leaves_qty(&self) -> f64 {
+    pub fn leaves_qty(&self) -> Number {
         self.leaves_qty
     }
 
-    pub fn set_leaves_qty(&mut self, leaves_qty: f64) {
+    pub fn set_leaves_qty(&mut self, leaves_qty: Number) {
         self.leaves_qty = leaves_qty;
     }
 
@@ -277,43 +307,43 @@ impl PrivateExecutionData {
         self.order_link_id = order_link_id;
     }
 
-    pub fn order_price(&self) -> f64 {
+    pub fn order_price(&self) -> Number {
         self.order_price
     }
 
-    pub fn set_order_price(&mut self, order_price: f64) {
+    pub fn set_order_price(&mut self, order_price: Number) {
         self.order_price = order_price;
     }
 
-    pub fn order_qty(&self) -> f64 {
+    pub fn order_qty(&self) -> Number {
         self.order_qty
     }
 
-    pub fn set_order_qty(&mut self, order_qty: f64) {
+    pub fn set_order_qty(&mut self, order_qty: Number) {
         self.order_qty = order_qty;
     }
 
-    pub fn order_type(&self) -> &str {
+    pub fn order_type(&self) -> &OrderType {
         &self.order_type
     }
 
-    pub fn set_order_type(&mut self, order_type: String) {
+    pub fn set_order_type(&mut self, order_type: OrderType) {
         self.order_type = order_type;
     }
 
-    pub fn stop_order_type(&self) -> &str {
-        &self.stop_order_type
+    pub fn stop_order_type(&self) -> Option<&StopOrderType> {
+        self.stop_order_type.as_ref()
     }
 
-    pub fn set_stop_order_type(&mut self, stop_order_type: String) {
-        self.stop_order_type = stop_order_type;
+    pub fn set_stop_order_type(&mut self, stop_order_type: StopOrderType) {
+        self.stop_order_type = Some(stop_order_type);
     }
 
-    pub fn side(&self) -> &str {
+    pub fn side(&self) -> &Side {
         &self.side
     }
 
-    pub fn set_side(&mut self, side: String) {
+    pub fn set_side(&mut self, side: Side) {
         self.side = side;
     }
 
@@ -325,6 +355,10 @@ impl PrivateExecutionData {
         self.exec_time = exec_time;
     }
 
+    pub fn exec_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.exec_time)
+    }
+
     pub fn is_leverage(&self) -> &str {
         &self.is_leverage
     }
@@ -348,4 +382,12 @@ impl PrivateExecutionData {
     pub fn set_seq(&mut self, seq: u64) {
         self.seq = seq;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,31 +1,51 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::Category,
     v5::ws::BybitWS,
     constants::PRIVATE_ORDER_TOPIC,
+    v5::types::{
+        Side,
+        OrderType,
+        TimeInForce,
+        TriggerBy,
+        OrderStatus,
+        StopOrderType,
+        CancelType,
+        SmpType,
+        PositionIdx,
+        TpslMode,
+        Number,
+    },
     utils::{
-        deserialize_f64,
+        serialize_as_string,
+        serialize_option_as_string,
+        millis_to_datetime,
+        deserialize_number,
         deserialize_string_to_u64,
-        deserialize_option_f64,
+        deserialize_option_number,
+        deserialize_option_enum,
     },
 };
-use serde::Deserialize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
 
-pub enum OrderCategory {
-    Linear,
-    Inverse,
-    Option,
-    Spot,
-}
+pub type OrderCategory = Category;
 
 impl BybitWS {
     pub fn add_order_args(&mut self, category: Option<OrderCategory>) {
         match category {
             Some(category) => {
-                self.args.push(match category {
-                    OrderCategory::Linear => format!("{}.{}", PRIVATE_ORDER_TOPIC, "linear"),
-                    OrderCategory::Inverse => format!("{}.{}", PRIVATE_ORDER_TOPIC, "inverse"),
-                    OrderCategory::Option => format!("{}.{}", PRIVATE_ORDER_TOPIC, "option"),
-                    OrderCategory::Spot => format!("{}.{}", PRIVATE_ORDER_TOPIC, "spot"),
-                });
+                self.args.push(format!("{}.{}", PRIVATE_ORDER_TOPIC, category.as_str()));
             },
             None => {
                 self.args.push(PRIVATE_ORDER_TOPIC.to_string());
@@ -34,13 +54,15 @@ impl BybitWS {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivateOrderResponse {
     id: String,
     topic: String,
     creation_time: u64,
     data: Vec<PrivateOrderData>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PrivateOrderResponse {
@@ -68,6 +90,10 @@ impl PrivateOrderResponse {
         self.creation_time = creation_time;
     }
 
+    pub fn creation_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.creation_time)
+    }
+
     pub fn data(&self) -> &Vec<PrivateOrderData> {
         &self.data
     }
@@ -75,64 +101,84 @@ impl PrivateOrderResponse {
     pub fn set_data(&mut self, data: Vec<PrivateOrderData>) {
         self.data = data;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivateOrderData {
     symbol: String,
     order_id: String,
-    side: String,
-    order_type: String,
-    cancel_type: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    qty: f64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    order_iv: Option<f64>,
-    time_in_force: String,
-    order_status: String,
+    side: Side,
+    order_type: OrderType,
+    cancel_type: CancelType,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    qty: Number,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    order_iv: Option<Number>,
+    time_in_force: TimeInForce,
+    order_status: OrderStatus,
     order_link_id: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    last_price_on_created: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    last_price_on_created: Option<Number>,
     reduce_only: bool,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    leaves_qty: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    leaves_value: Option<f64>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    cum_exec_qty: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    cum_exec_value: f64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    avg_price: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    leaves_qty: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    leaves_value: Option<Number>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    cum_exec_qty: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    cum_exec_value: Number,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    avg_price: Option<Number>,
     block_trade_id: String,
-    position_idx: u64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    cum_exec_fee: f64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    position_idx: PositionIdx,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    cum_exec_fee: Number,
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     created_time: u64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     updated_time: u64,
     reject_reason: String,
-    stop_order_type: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    trigger_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    take_profit: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    stop_loss: Option<f64>,
-    tp_trigger_by: String,
-    sl_trigger_by: String,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    stop_order_type: Option<StopOrderType>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    trigger_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    take_profit: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    stop_loss: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    tp_trigger_by: Option<TriggerBy>,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    sl_trigger_by: Option<TriggerBy>,
     trigger_direction: u8,
-    trigger_by: String,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    trigger_by: Option<TriggerBy>,
     close_on_trigger: bool,
     category: String,
     place_type: String,
-    smp_type: String,
+    smp_type: SmpType,
     smp_group: u64,
     smp_order_id: String,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    tpsl_mode: Option<TpslMode>,
+    #[serde(default)]
+    tp_limit_price: String,
+    #[serde(default)]
+    sl_limit_price: String,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PrivateOrderData {
@@ -152,67 +198,67 @@ impl PrivateOrderData {
         self.order_id = order_id;
     }
 
-    pub fn side(&self) -> &str {
+    pub fn side(&self) -> &Side {
         &self.side
     }
 
-    pub fn set_side(&mut self, side: String) {
+    pub fn set_side(&mut self, side: Side) {
         self.side = side;
     }
 
-    pub fn order_type(&self) -> &str {
+    pub fn order_type(&self) -> &OrderType {
         &self.order_type
     }
 
-    pub fn set_order_type(&mut self, order_type: String) {
+    pub fn set_order_type(&mut self, order_type: OrderType) {
         self.order_type = order_type;
     }
 
-    pub fn cancel_type(&self) -> &str {
+    pub fn cancel_type(&self) -> &CancelType {
         &self.cancel_type
     }
 
-    pub fn set_cancel_type(&mut self, cancel_type: String) {
+    pub fn set_cancel_type(&mut self, cancel_type: CancelType) {
         self.cancel_type = cancel_type;
     }
 
-    pub fn price(&self) -> f64 {
+    pub fn price(&self) -> Number {
         self.price
     }
 
-    pub fn set_price(&mut self, price: f64) {
+    pub fn set_price(&mut self, price: Number) {
         self.price = price;
     }
 
-    pub fn qty(&self) -> f64 {
+    pub fn qty(&self) -> Number {
         self.qty
     }
 
-    pub fn set_qty(&mut self, qty: f64) {
+    pub fn set_qty(&mut self, qty: Number) {
         self.qty = qty;
     }
 
-    pub fn order_iv(&self) -> Option<f64> {
+    pub fn order_iv(&self) -> Option<Number> {
         self.order_iv
     }
 
-    pub fn set_order_iv(&mut self, order_iv: Option<f64>) {
+    pub fn set_order_iv(&mut self, order_iv: Option<Number>) {
         self.order_iv = order_iv;
     }
 
-    pub fn time_in_force(&self) -> &str {
+    pub fn time_in_force(&self) -> &TimeInForce {
         &self.time_in_force
     }
 
-    pub fn set_time_in_force(&mut self, time_in_force: String) {
+    pub fn set_time_in_force(&mut self, time_in_force: TimeInForce) {
         self.time_in_force = time_in_force;
     }
 
-    pub fn order_status(&self) -> &str {
+    pub fn order_status(&self) -> &OrderStatus {
         &self.order_status
     }
 
-    pub fn set_order_status(&mut self, order_status: String) {
+    pub fn set_order_status(&mut self, order_status: OrderStatus) {
         self.order_status = order_status;
     }
 
@@ -224,11 +270,11 @@ impl PrivateOrderData {
         self.order_link_id = order_link_id;
     }
 
-    pub fn last_price_on_created(&self) -> Option<f64> {
+    pub fn last_price_on_created(&self) -> Option<Number> {
         self.last_price_on_created
     }
 
-    pub fn set_last_price_on_created(&mut self, last_price_on_created: Option<f64>) {
+    pub fn set_last_price_on_created(&mut self, last_price_on_created: Option<Number>) {
         self.last_price_on_created = last_price_on_created;
     }
 
@@ -240,43 +286,43 @@ impl PrivateOrderData {
         self.reduce_only = reduce_only;
     }
 
-    pub fn leaves_qty(&self) -> Option<f64> {
+    pub fn leaves_qty(&self) -> Option<Number> {
         self.leaves_qty
     }
 
-    pub fn set_leaves_qty(&mut self, leaves_qty: Option<f64>) {
+    pub fn set_leaves_qty(&mut self, leaves_qty: Option<Number>) {
         self.leaves_qty = leaves_qty;
     }
 
-    pub fn leaves_value(&self) -> Option<f64> {
+    pub fn leaves_value(&self) -> Option<Number> {
         self.leaves_value
     }
 
-    pub fn set_leaves_value(&mut self, leaves_value: Option<f64>) {
+    pub fn set_leaves_value(&mut self, leaves_value: Option<Number>) {
         self.leaves_value = leaves_value;
     }
 
-    pub fn cum_exec_qty(&self) -> f64 {
+    pub fn cum_exec_qty(&self) -> Number {
         self.cum_exec_qty
     }
 
-    pub fn set_cum_exec_qty(&mut self, cum_exec_qty: f64) {
+    pub fn set_cum_exec_qty(&mut self, cum_exec_qty: Number) {
         self.cum_exec_qty = cum_exec_qty;
     }
 
-    pub fn cum_exec_value(&self) -> f64 {
+    pub fn cum_exec_value(&self) -> Number {
         self.cum_exec_value
     }
 
-    pub fn set_cum_exec_value(&mut self, cum_exec_value: f64) {
+    pub fn set_cum_exec_value(&mut self, cum_exec_value: Number) {
         self.cum_exec_value = cum_exec_value;
     }
 
-    pub fn avg_price(&self) -> Option<f64> {
+    pub fn avg_price(&self) -> Option<Number> {
         self.avg_price
     }
 
-    pub fn set_avg_price(&mut self, avg_price: f64) {
+    pub fn set_avg_price(&mut self, avg_price: Number) {
         self.avg_price = Some(avg_price);
     }
 
@@ -288,19 +334,19 @@ impl PrivateOrderData {
         self.block_trade_id = block_trade_id;
     }
 
-    pub fn position_idx(&self) -> u64 {
+    pub fn position_idx(&self) -> PositionIdx {
         self.position_idx
     }
 
-    pub fn set_position_idx(&mut self, position_idx: u64) {
+    pub fn set_position_idx(&mut self, position_idx: PositionIdx) {
         self.position_idx = position_idx;
     }
 
-    pub fn cum_exec_fee(&self) -> f64 {
+    pub fn cum_exec_fee(&self) -> Number {
         self.cum_exec_fee
     }
 
-    pub fn set_cum_exec_fee(&mut self, cum_exec_fee: f64) {
+    pub fn set_cum_exec_fee(&mut self, cum_exec_fee: Number) {
         self.cum_exec_fee = cum_exec_fee;
     }
 
@@ -312,6 +358,10 @@ impl PrivateOrderData {
         self.created_time = created_time;
     }
 
+    pub fn created_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.created_time)
+    }
+
     pub fn updated_time(&self) -> u64 {
         self.updated_time
     }
@@ -320,6 +370,10 @@ impl PrivateOrderData {
         self.updated_time = updated_time;
     }
 
+    pub fn updated_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.updated_time)
+    }
+
     pub fn reject_reason(&self) -> &str {
         &self.reject_reason
     }
@@ -328,52 +382,52 @@ impl PrivateOrderData {
         self.reject_reason = reject_reason;
     }
 
-    pub fn stop_order_type(&self) -> &str {
-        &self.stop_order_type
+    pub fn stop_order_type(&self) -> Option<&StopOrderType> {
+        self.stop_order_type.as_ref()
     }
 
-    pub fn set_stop_order_type(&mut self, stop_order_type: String) {
-        self.stop_order_type = stop_order_type;
+    pub fn set_stop_order_type(&mut self, stop_order_type: StopOrderType) {
+        self.stop_order_type = Some(stop_order_type);
     }
 
-    pub fn trigger_price(&self) -> Option<f64> {
+    pub fn trigger_price(&self) -> Option<Number> {
         self.trigger_price
     }
 
-    pub fn set_trigger_price(&mut self, trigger_price: Option<f64>) {
+    pub fn set_trigger_price(&mut self, trigger_price: Option<Number>) {
         self.trigger_price = trigger_price;
     }
 
-    pub fn take_profit(&self) -> Option<f64> {
+    pub fn take_profit(&self) -> Option<Number> {
         self.take_profit
     }
 
-    pub fn set_take_profit(&mut self, take_profit: Option<f64>) {
+    pub fn set_take_profit(&mut self, take_profit: Option<Number>) {
         self.take_profit = take_profit;
     }
 
-    pub fn stop_loss(&self) -> Option<f64> {
+    pub fn stop_loss(&self) -> Option<Number> {
         self.stop_loss
     }
 
-    pub fn set_stop_loss(&mut self, stop_loss: Option<f64>) {
+    pub fn set_stop_loss(&mut self, stop_loss: Option<Number>) {
         self.stop_loss = stop_loss;
     }
 
-    pub fn tp_trigger_by(&self) -> &str {
-        &self.tp_trigger_by
+    pub fn tp_trigger_by(&self) -> Option<&TriggerBy> {
+        self.tp_trigger_by.as_ref()
     }
 
-    pub fn set_tp_trigger_by(&mut self, tp_trigger_by: String) {
-        self.tp_trigger_by = tp_trigger_by;
+    pub fn set_tp_trigger_by(&mut self, tp_trigger_by: TriggerBy) {
+        self.tp_trigger_by = Some(tp_trigger_by);
     }
 
-    pub fn sl_trigger_by(&self) -> &str {
-        &self.sl_trigger_by
+    pub fn sl_trigger_by(&self) -> Option<&TriggerBy> {
+        self.sl_trigger_by.as_ref()
     }
 
-    pub fn set_sl_trigger_by(&mut self, sl_trigger_by: String) {
-        self.sl_trigger_by = sl_trigger_by;
+    pub fn set_sl_trigger_by(&mut self, sl_trigger_by: TriggerBy) {
+        self.sl_trigger_by = Some(sl_trigger_by);
     }
 
     pub fn trigger_direction(&self) -> u8 {
@@ -384,12 +438,12 @@ impl PrivateOrderData {
         self.trigger_direction = trigger_direction;
     }
 
-    pub fn trigger_by(&self) -> &str {
-        &self.trigger_by
+    pub fn trigger_by(&self) -> Option<&TriggerBy> {
+        self.trigger_by.as_ref()
     }
 
-    pub fn set_trigger_by(&mut self, trigger_by: String) {
-        self.trigger_by = trigger_by;
+    pub fn set_trigger_by(&mut self, trigger_by: TriggerBy) {
+        self.trigger_by = Some(trigger_by);
     }
 
     pub fn close_on_trigger(&self) -> bool {
@@ -416,11 +470,11 @@ impl PrivateOrderData {
         self.place_type = place_type;
     }
 
-    pub fn smp_type(&self) -> &str {
+    pub fn smp_type(&self) -> &SmpType {
         &self.smp_type
     }
 
-    pub fn set_smp_type(&mut self, smp_type: String) {
+    pub fn set_smp_type(&mut self, smp_type: SmpType) {
         self.smp_type = smp_type;
     }
 
@@ -440,4 +494,36 @@ impl PrivateOrderData {
         self.smp_order_id = smp_order_id;
     }
 
+    pub fn tpsl_mode(&self) -> Option<&TpslMode> {
+        self.tpsl_mode.as_ref()
+    }
+
+    pub fn set_tpsl_mode(&mut self, tpsl_mode: TpslMode) {
+        self.tpsl_mode = Some(tpsl_mode);
+    }
+
+    pub fn tp_limit_price(&self) -> &str {
+        &self.tp_limit_price
+    }
+
+    pub fn set_tp_limit_price(&mut self, tp_limit_price: String) {
+        self.tp_limit_price = tp_limit_price;
+    }
+
+    pub fn sl_limit_price(&self) -> &str {
+        &self.sl_limit_price
+    }
+
+    pub fn set_sl_limit_price(&mut self, sl_limit_price: String) {
+        self.sl_limit_price = sl_limit_price;
+    }
+
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
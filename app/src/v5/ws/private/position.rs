@@ -1,29 +1,48 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
+    v5::category::{
+        Category,
+        LinearInverseOption,
+    },
     v5::ws::BybitWS,
     constants::PRIVATE_POSITION_TOPIC,
+    v5::types::{
+        Side,
+        TpslMode,
+        PositionIdx,
+        Number,
+    },
     utils::{
-        deserialize_f64,
+        serialize_as_string,
+        serialize_option_as_string,
+        millis_to_datetime,
+        str_millis_to_datetime,
+        deserialize_number,
         deserialize_string_to_u64,
-        deserialize_option_f64,
+        deserialize_option_number,
+        deserialize_option_enum,
     },
 };
-use serde::Deserialize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
 
-pub enum PositionCategory {
-    Linear,
-    Inverse,
-    Option,
-}
+pub type PositionCategory = Category<LinearInverseOption>;
 
 impl BybitWS {
     pub fn add_position_args(&mut self, category: Option<PositionCategory>) {
         match category {
             Some(category) => {
-                self.args.push(match category {
-                    PositionCategory::Linear => format!("{}.{}", PRIVATE_POSITION_TOPIC, "linear"),
-                    PositionCategory::Inverse => format!("{}.{}", PRIVATE_POSITION_TOPIC, "inverse"),
-                    PositionCategory::Option => format!("{}.{}", PRIVATE_POSITION_TOPIC, "option"),
-                });
+                self.args.push(format!("{}.{}", PRIVATE_POSITION_TOPIC, category.as_str()));
             },
             None => {
                 self.args.push(PRIVATE_POSITION_TOPIC.to_string());
@@ -32,13 +51,15 @@ impl BybitWS {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivatePositionResponse {
     id: String,
     topic: String,
     creation_time: u64,
     data: Vec<PrivatePositionData>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PrivatePositionResponse {
@@ -66,6 +87,10 @@ impl PrivatePositionResponse {
         self.creation_time = creation_time;
     }
 
+    pub fn creation_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.creation_time)
+    }
+
     pub fn data(&self) -> &Vec<PrivatePositionData> {
         &self.data
     }
@@ -73,53 +98,63 @@ impl PrivatePositionResponse {
     pub fn set_data(&mut self, data: Vec<PrivatePositionData>) {
         self.data = data;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivatePositionData {
-    position_idx: u64,
+    position_idx: PositionIdx,
     trade_mode: u8,
     risk_id: i64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    risk_limit_value: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    risk_limit_value: Option<Number>,
     symbol: String,
-    side: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    size: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    entry_price: f64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    leverage: Option<f64>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    position_value: f64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    position_balance: Option<f64>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    mark_price: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    position_i_m: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    position_m_m: f64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    take_profit: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    stop_loss: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    trailing_stop: Option<f64>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    unrealised_pnl: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    cum_realised_pnl: f64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    side: Option<Side>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    size: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    entry_price: Number,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    leverage: Option<Number>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    position_value: Number,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    position_balance: Option<Number>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    mark_price: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    position_i_m: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    position_m_m: Number,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    take_profit: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    stop_loss: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    trailing_stop: Option<Number>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    unrealised_pnl: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    cum_realised_pnl: Number,
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     created_time: u64,
-    #[serde(deserialize_with = "deserialize_string_to_u64")]
+    #[serde(deserialize_with = "deserialize_string_to_u64", serialize_with = "serialize_as_string")]
     updated_time: u64,
-    tpsl_mode: Option<String>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    liq_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    bust_price: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_enum")]
+    tpsl_mode: Option<TpslMode>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    liq_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    bust_price: Option<Number>,
     category: String,
     position_status: String,
     adl_rank_indicator: u8,
@@ -128,14 +163,16 @@ pub struct PrivatePositionData {
     mmr_sys_updated_time: Option<String>,
     seq: u64,
     is_reduce_only: bool,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PrivatePositionData {
-    pub fn position_idx(&self) -> u64 {
+    pub fn position_idx(&self) -> PositionIdx {
         self.position_idx
     }
 
-    pub fn set_position_idx(&mut self, position_idx: u64) {
+    pub fn set_position_idx(&mut self, position_idx: PositionIdx) {
         self.position_idx = position_idx;
     }
 
@@ -155,11 +192,11 @@ impl PrivatePositionData {
         self.risk_id = risk_id;
     }
 
-    pub fn risk_limit_value(&self) -> Option<f64> {
+    pub fn risk_limit_value(&self) -> Option<Number> {
         self.risk_limit_value
     }
 
-    pub fn set_risk_limit_value(&mut self, risk_limit_value: f64) {
+    pub fn set_risk_limit_value(&mut self, risk_limit_value: Number) {
         self.risk_limit_value = Some(risk_limit_value);
     }
 
@@ -171,115 +208,115 @@ impl PrivatePositionData {
         self.symbol = symbol;
     }
 
-    pub fn side(&self) -> &str {
-        &self.side
+    pub fn side(&self) -> Option<&Side> {
+        self.side.as_ref()
     }
 
-    pub fn set_side(&mut self, side: String) {
-        self.side = side;
+    pub fn set_side(&mut self, side: Side) {
+        self.side = Some(side);
     }
 
-    pub fn size(&self) -> f64 {
+    pub fn size(&self) -> Number {
         self.size
     }
 
-    pub fn set_size(&mut self, size: f64) {
+    pub fn set_size(&mut self, size: Number) {
         self.size = size;
     }
 
-    pub fn entry_price(&self) -> f64 {
+    pub fn entry_price(&self) -> Number {
         self.entry_price
     }
 
-    pub fn set_entry_price(&mut self, entry_price: f64) {
+    pub fn set_entry_price(&mut self, entry_price: Number) {
         self.entry_price = entry_price;
     }
 
-    pub fn leverage(&self) -> Option<f64> {
+    pub fn leverage(&self) -> Option<Number> {
         self.leverage
     }
 
-    pub fn set_leverage(&mut self, leverage: f64) {
+    pub fn set_leverage(&mut self, leverage: Number) {
         self.leverage = Some(leverage);
     }
 
-    pub fn position_value(&self) -> f64 {
+    pub fn position_value(&self) -> Number {
         self.position_value
     }
 
-    pub fn set_position_value(&mut self, position_value: f64) {
+    pub fn set_position_value(&mut self, position_value: Number) {
         self.position_value = position_value;
     }
 
-    pub fn position_balance(&self) -> Option<f64> {
+    pub fn position_balance(&self) -> Option<Number> {
         self.position_balance
     }
 
-    pub fn set_position_balance(&mut self, position_balance: f64) {
+    pub fn set_position_balance(&mut self, position_balance: Number) {
         self.position_balance = Some(position_balance);
     }
 
-    pub fn mark_price(&self) -> f64 {
+    pub fn mark_price(&self) -> Number {
         self.mark_price
     }
 
-    pub fn set_mark_price(&mut self, mark_price: f64) {
+    pub fn set_mark_price(&mut self, mark_price: Number) {
         self.mark_price = mark_price;
     }
 
-    pub fn position_i_m(&self) -> f64 {
+    pub fn position_i_m(&self) -> Number {
         self.position_i_m
     }
 
-    pub fn set_position_i_m(&mut self, position_i_m: f64) {
+    pub fn set_position_i_m(&mut self, position_i_m: Number) {
         self.position_i_m = position_i_m;
     }
 
-    pub fn position_m_m(&self) -> f64 {
+    pub fn position_m_m(&self) -> Number {
         self.position_m_m
     }
 
-    pub fn set_position_m_m(&mut self, position_m_m: f64) {
+    pub fn set_position_m_m(&mut self, position_m_m: Number) {
         self.position_m_m = position_m_m;
     }
 
-    pub fn take_profit(&self) -> Option<f64> {
+    pub fn take_profit(&self) -> Option<Number> {
         self.take_profit
     }
 
-    pub fn set_take_profit(&mut self, take_profit: f64) {
+    pub fn set_take_profit(&mut self, take_profit: Number) {
         self.take_profit = Some(take_profit);
     }
 
-    pub fn stop_loss(&self) -> Option<f64> {
+    pub fn stop_loss(&self) -> Option<Number> {
         self.stop_loss
     }
 
-    pub fn set_stop_loss(&mut self, stop_loss: f64) {
+    pub fn set_stop_loss(&mut self, stop_loss: Number) {
         self.stop_loss = Some(stop_loss);
     }
 
-    pub fn trailing_stop(&self) -> Option<f64> {
+    pub fn trailing_stop(&self) -> Option<Number> {
         self.trailing_stop
     }
 
-    pub fn set_trailing_stop(&mut self, trailing_stop: f64) {
+    pub fn set_trailing_stop(&mut self, trailing_stop: Number) {
         self.trailing_stop = Some(trailing_stop);
     }
 
-    pub fn unrealised_pnl(&self) -> f64 {
+    pub fn unrealised_pnl(&self) -> Number {
         self.unrealised_pnl
     }
 
-    pub fn set_unrealised_pnl(&mut self, unrealised_pnl: f64) {
+    pub fn set_unrealised_pnl(&mut self, unrealised_pnl: Number) {
         self.unrealised_pnl = unrealised_pnl;
     }
 
-    pub fn cum_realised_pnl(&self) -> f64 {
+    pub fn cum_realised_pnl(&self) -> Number {
         self.cum_realised_pnl
     }
 
-    pub fn set_cum_realised_pnl(&mut self, cum_realised_pnl: f64) {
+    pub fn set_cum_realised_pnl(&mut self, cum_realised_pnl: Number) {
         self.cum_realised_pnl = cum_realised_pnl;
     }
 
@@ -291,6 +328,10 @@ impl PrivatePositionData {
         self.created_time = created_time;
     }
 
+    pub fn created_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.created_time)
+    }
+
     pub fn updated_time(&self) -> u64 {
         self.updated_time
     }
@@ -299,27 +340,31 @@ impl PrivatePositionData {
         self.updated_time = updated_time;
     }
 
-    pub fn tpsl_mode(&self) -> &Option<String> {
-        &self.tpsl_mode
+    pub fn updated_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.updated_time)
+    }
+
+    pub fn tpsl_mode(&self) -> Option<&TpslMode> {
+        self.tpsl_mode.as_ref()
     }
 
-    pub fn set_tpsl_mode(&mut self, tpsl_mode: String) {
+    pub fn set_tpsl_mode(&mut self, tpsl_mode: TpslMode) {
         self.tpsl_mode = Some(tpsl_mode);
     }
 
-    pub fn liq_price(&self) -> Option<f64> {
+    pub fn liq_price(&self) -> Option<Number> {
         self.liq_price
     }
 
-    pub fn set_liq_price(&mut self, liq_price: f64) {
+    pub fn set_liq_price(&mut self, liq_price: Number) {
         self.liq_price = Some(liq_price);
     }
 
-    pub fn bust_price(&self) -> Option<f64> {
+    pub fn bust_price(&self) -> Option<Number> {
         self.bust_price
     }
 
-    pub fn set_bust_price(&mut self, bust_price: f64) {
+    pub fn set_bust_price(&mut self, bust_price: Number) {
         self.bust_price = Some(bust_price);
     }
 
@@ -363,6 +408,10 @@ impl PrivatePositionData {
         self.leverage_sys_updated_time = Some(leverage_sys_updated_time);
     }
 
+    pub fn leverage_sys_updated_time_datetime(&self) -> Option<DateTime<Utc>> {
+        self.leverage_sys_updated_time.as_deref().and_then(str_millis_to_datetime)
+    }
+
     pub fn mmr_sys_updated_time(&self) -> &Option<String> {
         &self.mmr_sys_updated_time
     }
@@ -371,6 +420,10 @@ impl PrivatePositionData {
         self.mmr_sys_updated_time = Some(mmr_sys_updated_time);
     }
 
+    pub fn mmr_sys_updated_time_datetime(&self) -> Option<DateTime<Utc>> {
+        self.mmr_sys_updated_time.as_deref().and_then(str_millis_to_datetime)
+    }
+
     pub fn seq(&self) -> u64 {
         self.seq
     }
@@ -386,4 +439,12 @@ impl PrivatePositionData {
     pub fn set_is_reduce_only(&mut self, is_reduce_only: bool) {
         self.is_reduce_only = is_reduce_only;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,12 +1,28 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
     v5::ws::BybitWS,
     constants::PRIVATE_WALLET_TOPIC,
+    v5::types::Number,
     utils::{
-        deserialize_f64,
-        deserialize_option_f64,
+        serialize_as_string,
+        serialize_option_as_string,
+        millis_to_datetime,
+        deserialize_number,
+        deserialize_option_number,
     },
 };
-use serde::Deserialize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
 
 
 impl BybitWS {
@@ -15,13 +31,15 @@ impl BybitWS {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivateWalletResponse {
     id: String,
     topic: String,
     creation_time: u64,
     data: Vec<PrivateWalletData>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PrivateWalletResponse {
@@ -49,6 +67,10 @@ impl PrivateWalletResponse {
         self.creation_time = creation_time;
     }
 
+    pub fn creation_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.creation_time)
+    }
+
     pub fn data(&self) -> &Vec<PrivateWalletData> {
         &self.data
     }
@@ -56,113 +78,123 @@ impl PrivateWalletResponse {
     pub fn set_data(&mut self, data: Vec<PrivateWalletData>) {
         self.data = data;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PrivateWalletData {
-    #[serde(deserialize_with = "deserialize_f64")]
-    account_i_m_rate: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    account_m_m_rate: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    total_equity: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    total_wallet_balance: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    total_margin_balance: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    total_available_balance: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    total_perp_u_p_l: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    total_initial_margin: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    total_maintenance_margin: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    account_l_t_v: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    account_i_m_rate: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    account_m_m_rate: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    total_equity: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    total_wallet_balance: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    total_margin_balance: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    total_available_balance: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    total_perp_u_p_l: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    total_initial_margin: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    total_maintenance_margin: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    account_l_t_v: Number,
     account_type: String,
     coin: Vec<Coin>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PrivateWalletData {
-    pub fn account_i_m_rate(&self) -> f64 {
+    pub fn account_i_m_rate(&self) -> Number {
         self.account_i_m_rate
     }
 
-    pub fn set_account_i_m_rate(&mut self, account_i_m_rate: f64) {
+    pub fn set_account_i_m_rate(&mut self, account_i_m_rate: Number) {
         self.account_i_m_rate = account_i_m_rate;
     }
 
-    pub fn account_m_m_rate(&self) -> f64 {
+    pub fn account_m_m_rate(&self) -> Number {
         self.account_m_m_rate
     }
 
-    pub fn set_account_m_m_rate(&mut self, account_m_m_rate: f64) {
+    pub fn set_account_m_m_rate(&mut self, account_m_m_rate: Number) {
         self.account_m_m_rate = account_m_m_rate;
     }
 
-    pub fn total_equity(&self) -> f64 {
+    pub fn total_equity(&self) -> Number {
         self.total_equity
     }
 
-    pub fn set_total_equity(&mut self, total_equity: f64) {
+    pub fn set_total_equity(&mut self, total_equity: Number) {
         self.total_equity = total_equity;
     }
 
-    pub fn total_wallet_balance(&self) -> f64 {
+    pub fn total_wallet_balance(&self) -> Number {
         self.total_wallet_balance
     }
 
-    pub fn set_total_wallet_balance(&mut self, total_wallet_balance: f64) {
+    pub fn set_total_wallet_balance(&mut self, total_wallet_balance: Number) {
         self.total_wallet_balance = total_wallet_balance;
     }
 
-    pub fn total_margin_balance(&self) -> f64 {
+    pub fn total_margin_balance(&self) -> Number {
         self.total_margin_balance
     }
 
-    pub fn set_total_margin_balance(&mut self, total_margin_balance: f64) {
+    pub fn set_total_margin_balance(&mut self, total_margin_balance: Number) {
         self.total_margin_balance = total_margin_balance;
     }
 
-    pub fn total_available_balance(&self) -> f64 {
+    pub fn total_available_balance(&self) -> Number {
         self.total_available_balance
     }
 
-    pub fn set_total_available_balance(&mut self, total_available_balance: f64) {
+    pub fn set_total_available_balance(&mut self, total_available_balance: Number) {
         self.total_available_balance = total_available_balance;
     }
 
-    pub fn total_perp_u_p_l(&self) -> f64 {
+    pub fn total_perp_u_p_l(&self) -> Number {
         self.total_perp_u_p_l
     }
 
-    pub fn set_total_perp_u_p_l(&mut self, total_perp_u_p_l: f64) {
+    pub fn set_total_perp_u_p_l(&mut self, total_perp_u_p_l: Number) {
         self.total_perp_u_p_l = total_perp_u_p_l;
     }
 
-    pub fn total_initial_margin(&self) -> f64 {
+    pub fn total_initial_margin(&self) -> Number {
         self.total_initial_margin
     }
 
-    pub fn set_total_initial_margin(&mut self, total_initial_margin: f64) {
+    pub fn set_total_initial_margin(&mut self, total_initial_margin: Number) {
         self.total_initial_margin = total_initial_margin;
     }
 
-    pub fn total_maintenance_margin(&self) -> f64 {
+    pub fn total_maintenance_margin(&self) -> Number {
         self.total_maintenance_margin
     }
 
-    pub fn set_total_maintenance_margin(&mut self, total_maintenance_margin: f64) {
+    pub fn set_total_maintenance_margin(&mut self, total_maintenance_margin: Number) {
         self.total_maintenance_margin = total_maintenance_margin;
     }
 
-    pub fn account_l_t_v(&self) -> f64 {
+    pub fn account_l_t_v(&self) -> Number {
         self.account_l_t_v
     }
 
-    pub fn set_account_l_t_v(&mut self, account_l_t_v: f64) {
+    pub fn set_account_l_t_v(&mut self, account_l_t_v: Number) {
         self.account_l_t_v = account_l_t_v;
     }
 
@@ -181,39 +213,49 @@ impl PrivateWalletData {
     pub fn set_coin(&mut self, coin: Vec<Coin>) {
         self.coin = coin;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Coin {
     coin: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    equity: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    usd_value: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    wallet_balance: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    available_to_withdraw: f64,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    available_to_borrow: Option<f64>,
-    #[serde(deserialize_with = "deserialize_f64")]
-    borrow_amount: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    accrued_interest: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    equity: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    usd_value: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    wallet_balance: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    available_to_withdraw: Number,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    available_to_borrow: Option<Number>,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    borrow_amount: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    accrued_interest: Number,
     total_order_i_m: String,
     total_position_i_m: String,
     total_position_m_m: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    unrealised_pnl: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    cum_realised_pnl: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    bonus: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    unrealised_pnl: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    cum_realised_pnl: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    bonus: Number,
     collateral_switch: bool,
     margin_collateral: bool,
-    #[serde(deserialize_with = "deserialize_f64")]
-    locked: f64,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    locked: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl Coin {
@@ -225,59 +267,59 @@ impl Coin {
         self.coin = coin;
     }
 
-    pub fn equity(&self) -> f64 {
+    pub fn equity(&self) -> Number {
         self.equity
     }
 
-    pub fn set_equity(&mut self, equity: f64) {
+    pub fn set_equity(&mut self, equity: Number) {
         self.equity = equity;
     }
 
-    pub fn usd_value(&self) -> f64 {
+    pub fn usd_value(&self) -> Number {
         self.usd_value
     }
 
-    pub fn set_usd_value(&mut self, usd_value: f64) {
+    pub fn set_usd_value(&mut self, usd_value: Number) {
         self.usd_value = usd_value;
     }
 
-    pub fn wallet_balance(&self) -> f64 {
+    pub fn wallet_balance(&self) -> Number {
         self.wallet_balance
     }
 
-    pub fn set_wallet_balance(&mut self, wallet_balance: f64) {
+    pub fn set_wallet_balance(&mut self, wallet_balance: Number) {
         self.wallet_balance = wallet_balance;
     }
 
-    pub fn available_to_withdraw(&self) -> f64 {
+    pub fn available_to_withdraw(&self) -> Number {
         self.available_to_withdraw
     }
 
-    pub fn set_available_to_withdraw(&mut self, available_to_withdraw: f64) {
+    pub fn set_available_to_withdraw(&mut self, available_to_withdraw: Number) {
         self.available_to_withdraw = available_to_withdraw;
     }
 
-    pub fn available_to_borrow(&self) -> Option<f64> {
+    pub fn available_to_borrow(&self) -> Option<Number> {
         self.available_to_borrow
     }
 
-    pub fn set_available_to_borrow(&mut self, available_to_borrow: f64) {
+    pub fn set_available_to_borrow(&mut self, available_to_borrow: Number) {
         self.available_to_borrow = Some(available_to_borrow);
     }
 
-    pub fn borrow_amount(&self) -> f64 {
+    pub fn borrow_amount(&self) -> Number {
         self.borrow_amount
     }
 
-    pub fn set_borrow_amount(&mut self, borrow_amount: f64) {
+    pub fn set_borrow_amount(&mut self, borrow_amount: Number) {
         self.borrow_amount = borrow_amount;
     }
 
-    pub fn accrued_interest(&self) -> f64 {
+    pub fn accrued_interest(&self) -> Number {
         self.accrued_interest
     }
 
-    pub fn set_accrued_interest(&mut self, accrued_interest: f64) {
+    pub fn set_accrued_interest(&mut self, accrued_interest: Number) {
         self.accrued_interest = accrued_interest;
     }
 
@@ -305,27 +347,27 @@ impl Coin {
         self.total_position_m_m = total_position_m_m;
     }
 
-    pub fn unrealised_pnl(&self) -> f64 {
+    pub fn unrealised_pnl(&self) -> Number {
         self.unrealised_pnl
     }
 
-    pub fn set_unrealised_pnl(&mut self, unrealised_pnl: f64) {
+    pub fn set_unrealised_pnl(&mut self, unrealised_pnl: Number) {
         self.unrealised_pnl = unrealised_pnl;
     }
 
-    pub fn cum_realised_pnl(&self) -> f64 {
+    pub fn cum_realised_pnl(&self) -> Number {
         self.cum_realised_pnl
     }
 
-    pub fn set_cum_realised_pnl(&mut self, cum_realised_pnl: f64) {
+    pub fn set_cum_realised_pnl(&mut self, cum_realised_pnl: Number) {
         self.cum_realised_pnl = cum_realised_pnl;
     }
 
-    pub fn bonus(&self) -> f64 {
+    pub fn bonus(&self) -> Number {
         self.bonus
     }
 
-    pub fn set_bonus(&mut self, bonus: f64) {
+    pub fn set_bonus(&mut self, bonus: Number) {
         self.bonus = bonus;
     }
 
@@ -345,12 +387,20 @@ impl Coin {
         self.margin_collateral = margin_collateral;
     }
 
-    pub fn locked(&self) -> f64 {
+    pub fn locked(&self) -> Number {
         self.locked
     }
 
-    pub fn set_locked(&mut self, locked: f64) {
+    pub fn set_locked(&mut self, locked: Number) {
         self.locked = locked;
     }
 
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,17 +1,37 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
     v5::ws::BybitWS,
     constants::PUBLIC_KLINE_TOPIC,
-    utils::deserialize_f64,
+    v5::types::{
+        Interval,
+        Number,
+    },
+    utils::{
+        serialize_as_string,
+        deserialize_number,
+        millis_to_datetime,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::{
+    Map,
+    Value,
 };
-use serde::Deserialize;
+use crate::utils::deserialize_extra;
 
 impl BybitWS {
-    pub fn add_kline_args(&mut self, interval: &str, symbol: &str) {
+    pub fn add_kline_args(&mut self, interval: Interval, symbol: &str) {
         self.args.push(format!("{}.{}.{}", PUBLIC_KLINE_TOPIC, interval, symbol));
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PublicKlineResponse {
     topic: String,
@@ -19,6 +39,8 @@ pub struct PublicKlineResponse {
     type_field: String,
     ts: u64,
     data: Vec<PublicKlineData>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PublicKlineResponse {
@@ -46,6 +68,10 @@ impl PublicKlineResponse {
         self.ts = ts;
     }
 
+    pub fn ts_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.ts)
+    }
+
     pub fn data(&self) -> &Vec<PublicKlineData> {
         &self.data
     }
@@ -53,27 +79,37 @@ impl PublicKlineResponse {
     pub fn set_data(&mut self, data: Vec<PublicKlineData>) {
         self.data = data;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct PublicKlineData {
     start: u64,
     end: u64,
-    interval: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    open: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    close: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    high: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    low: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    volume: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    turnover: f64,
+    interval: Interval,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    open: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    close: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    high: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    low: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    volume: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    turnover: Number,
     confirm: bool,
     timestamp: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PublicKlineData {
@@ -85,6 +121,10 @@ impl PublicKlineData {
         self.start = start;
     }
 
+    pub fn start_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.start)
+    }
+
     pub fn end(&self) -> u64 {
         self.end
     }
@@ -93,59 +133,63 @@ impl PublicKlineData {
         self.end = end;
     }
 
-    pub fn interval(&self) -> &str {
-        &self.interval
+    pub fn end_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.end)
+    }
+
+    pub fn interval(&self) -> Interval {
+        self.interval
     }
 
-    pub fn set_interval(&mut self, interval: String) {
+    pub fn set_interval(&mut self, interval: Interval) {
         self.interval = interval;
     }
 
-    pub fn open(&self) -> f64 {
+    pub fn open(&self) -> Number {
         self.open
     }
 
-    pub fn set_open(&mut self, open: f64) {
+    pub fn set_open(&mut self, open: Number) {
         self.open = open;
     }
 
-    pub fn close(&self) -> f64 {
+    pub fn close(&self) -> Number {
         self.close
     }
 
-    pub fn set_close(&mut self, close: f64) {
+    pub fn set_close(&mut self, close: Number) {
         self.close = close;
     }
 
-    pub fn high(&self) -> f64 {
+    pub fn high(&self) -> Number {
         self.high
     }
 
-    pub fn set_high(&mut self, high: f64) {
+    pub fn set_high(&mut self, high: Number) {
         self.high = high;
     }
 
-    pub fn low(&self) -> f64 {
+    pub fn low(&self) -> Number {
         self.low
     }
 
-    pub fn set_low(&mut self, low: f64) {
+    pub fn set_low(&mut self, low: Number) {
         self.low = low;
     }
 
-    pub fn volume(&self) -> f64 {
+    pub fn volume(&self) -> Number {
         self.volume
     }
 
-    pub fn set_volume(&mut self, volume: f64) {
+    pub fn set_volume(&mut self, volume: Number) {
         self.volume = volume;
     }
 
-    pub fn turnover(&self) -> f64 {
+    pub fn turnover(&self) -> Number {
         self.turnover
     }
 
-    pub fn set_turnover(&mut self, turnover: f64) {
+    pub fn set_turnover(&mut self, turnover: Number) {
         self.turnover = turnover;
     }
 
@@ -164,4 +208,16 @@ impl PublicKlineData {
     pub fn set_timestamp(&mut self, timestamp: u64) {
         self.timestamp = timestamp;
     }
+
+    pub fn timestamp_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.timestamp)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,9 +1,29 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
     v5::ws::BybitWS,
     constants::PUBLIC_LIQUIDATION_TOPIC,
-    utils::deserialize_f64,
+    v5::types::{
+        Side,
+        Number,
+    },
+    utils::{
+        serialize_as_string,
+        deserialize_number,
+        millis_to_datetime,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
 };
-use serde::Deserialize;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
 
 impl BybitWS {
     pub fn add_liquidation_args(&mut self, symbol: &str) {
@@ -11,7 +31,7 @@ impl BybitWS {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PublicLiquidationResponse {
     topic: String,
@@ -19,6 +39,8 @@ pub struct PublicLiquidationResponse {
     type_field: String,
     ts: u64,
     data: PublicLiquidationData,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PublicLiquidationResponse {
@@ -46,6 +68,10 @@ impl PublicLiquidationResponse {
         self.ts = ts;
     }
 
+    pub fn ts_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.ts)
+    }
+
     pub fn data(&self) -> &PublicLiquidationData {
         &self.data
     }
@@ -53,18 +79,28 @@ impl PublicLiquidationResponse {
     pub fn set_data(&mut self, data: PublicLiquidationData) {
         self.data = data;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PublicLiquidationData {
     updated_time: u64,
     symbol: String,
-    side: String,
-    #[serde(deserialize_with = "deserialize_f64")]
-    size: f64,
-    #[serde(deserialize_with = "deserialize_f64")]
-    price: f64,
+    side: Side,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    size: Number,
+    #[serde(deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    price: Number,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PublicLiquidationData {
@@ -76,6 +112,10 @@ impl PublicLiquidationData {
         self.updated_time = updated_time;
     }
 
+    pub fn updated_time_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.updated_time)
+    }
+
     pub fn symbol(&self) -> &str {
         &self.symbol
     }
@@ -84,27 +124,35 @@ impl PublicLiquidationData {
         self.symbol = symbol;
     }
 
-    pub fn side(&self) -> &str {
+    pub fn side(&self) -> &Side {
         &self.side
     }
 
-    pub fn set_side(&mut self, side: String) {
+    pub fn set_side(&mut self, side: Side) {
         self.side = side;
     }
 
-    pub fn size(&self) -> f64 {
+    pub fn size(&self) -> Number {
         self.size
     }
 
-    pub fn set_size(&mut self, size: f64) {
+    pub fn set_size(&mut self, size: Number) {
         self.size = size;
     }
 
-    pub fn price(&self) -> f64 {
+    pub fn price(&self) -> Number {
         self.price
     }
 
-    pub fn set_price(&mut self, price: f64) {
+    pub fn set_price(&mut self, price: Number) {
         self.price = price;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,11 +1,27 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
     v5::ws::BybitWS,
+    v5::types::Number,
     constants::PUBLIC_ORDERBOOK_TOPIC,
+    utils::{
+        parse_number,
+        millis_to_datetime,
+    },
 };
 use serde::{
+    Serialize,
+    Serializer,
     Deserialize,
     Deserializer
 };
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
 
 impl BybitWS {
     pub fn add_orderbook_args(&mut self, depth: &str, symbol: &str) {
@@ -13,7 +29,7 @@ impl BybitWS {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PublicOrderbookResponse {
     topic: String,
@@ -22,6 +38,8 @@ pub struct PublicOrderbookResponse {
     ts: u64,
     data: PublicOrderbookData,
     cts: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PublicOrderbookResponse {
@@ -49,6 +67,10 @@ impl PublicOrderbookResponse {
         self.ts = ts;
     }
 
+    pub fn ts_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.ts)
+    }
+
     pub fn data(&self) -> &PublicOrderbookData {
         &self.data
     }
@@ -64,9 +86,21 @@ impl PublicOrderbookResponse {
     pub fn set_cts(&mut self, cts: u64) {
         self.cts = cts;
     }
+
+    pub fn cts_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.cts)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct PublicOrderbookData {
     #[serde(rename = "s")]
     symbol: String,
@@ -77,6 +111,8 @@ pub struct PublicOrderbookData {
     #[serde(rename = "u")]
     update_id: u64,
     seq: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PublicOrderbookData {
@@ -119,28 +155,36 @@ impl PublicOrderbookData {
     pub fn set_seq(&mut self, seq: u64) {
         self.seq = seq;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Order {
-    price: f64,
-    size: f64,
+    price: Number,
+    size: Number,
 }
 
 impl Order {
-    pub fn price(&self) -> f64 {
+    pub fn price(&self) -> Number {
         self.price
     }
 
-    pub fn set_price(&mut self, price: f64) {
+    pub fn set_price(&mut self, price: Number) {
         self.price = price;
     }
 
-    pub fn size(&self) -> f64 {
+    pub fn size(&self) -> Number {
         self.size
     }
 
-    pub fn set_size(&mut self, size: f64) {
+    pub fn set_size(&mut self, size: Number) {
         self.size = size;
     }
 }
@@ -153,11 +197,21 @@ impl<'de> Deserialize<'de> for Order {
         D: Deserializer<'de>,
     {
         let (price_str, size_str): (String, String) = Deserialize::deserialize(deserializer)?;
-        let price = price_str.parse::<f64>()
+        let price = parse_number(&price_str)
             .map_err(serde::de::Error::custom)?;
-        let size = size_str.parse::<f64>()
+        let size = parse_number(&size_str)
             .map_err(serde::de::Error::custom)?;
         
         Ok(Order { price, size })
     }
 }
+
+// Convert an Order back to the JSON tuple it was read from
+impl Serialize for Order {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (self.price.to_string(), self.size.to_string()).serialize(serializer)
+    }
+}
@@ -1,10 +1,26 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::utils::{
-    deserialize_option_f64,
+    serialize_option_as_string,
+    millis_to_datetime,
+    option_millis_to_datetime,
+    deserialize_option_number,
     deserialize_string_to_option_u64,
 };
-use serde::Deserialize;
+use crate::v5::types::Number;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PublicLinearTickersResponse {
     topic: String,
@@ -13,6 +29,8 @@ pub struct PublicLinearTickersResponse {
     data: LinearTickers,
     cs: u64,
     ts: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PublicLinearTickersResponse {
@@ -55,49 +73,65 @@ impl PublicLinearTickersResponse {
     pub fn set_ts(&mut self, ts: u64) {
         self.ts = ts;
     }
+
+    pub fn ts_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.ts)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LinearTickers {
     symbol: String,
-    tick_direction: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    price24h_pcnt: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    last_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    prev_price24h: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    high_price24h: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    low_price24h: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    prev_price1h: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    mark_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    index_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    open_interest: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    open_interest_value: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    turnover24h: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    volume24h: Option<f64>,
-    #[serde(deserialize_with = "deserialize_string_to_option_u64")]
+    // delta メッセージでは変化がなければ省略される
+    #[serde(default)]
+    tick_direction: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    price24h_pcnt: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    last_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    prev_price24h: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    high_price24h: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    low_price24h: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    prev_price1h: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    mark_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    index_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    open_interest: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    open_interest_value: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    turnover24h: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    volume24h: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_string_to_option_u64", serialize_with = "serialize_option_as_string")]
     next_funding_time: Option<u64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    funding_rate: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    bid1_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    bid1_size: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    ask1_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    ask1_size: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    funding_rate: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    bid1_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    bid1_size: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    ask1_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    ask1_size: Option<Number>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl LinearTickers {
@@ -109,107 +143,107 @@ impl LinearTickers {
         self.symbol = symbol;
     }
 
-    pub fn tick_direction(&self) -> &str {
-        &self.tick_direction
+    pub fn tick_direction(&self) -> Option<&str> {
+        self.tick_direction.as_deref()
     }
 
     pub fn set_tick_direction(&mut self, tick_direction: String) {
-        self.tick_direction = tick_direction;
+        self.tick_direction = Some(tick_direction);
     }
 
-    pub fn price24h_pcnt(&self) -> Option<f64> {
+    pub fn price24h_pcnt(&self) -> Option<Number> {
         self.price24h_pcnt
     }
 
-    pub fn set_price24h_pcnt(&mut self, price24h_pcnt: f64) {
+    pub fn set_price24h_pcnt(&mut self, price24h_pcnt: Number) {
         self.price24h_pcnt = Some(price24h_pcnt);
     }
 
-    pub fn last_price(&self) -> Option<f64> {
+    pub fn last_price(&self) -> Option<Number> {
         self.last_price
     }
 
-    pub fn set_last_price(&mut self, last_price: f64) {
+    pub fn set_last_price(&mut self, last_price: Number) {
         self.last_price = Some(last_price);
     }
 
-    pub fn prev_price24h(&self) -> Option<f64> {
+    pub fn prev_price24h(&self) -> Option<Number> {
         self.prev_price24h
     }
 
-    pub fn set_prev_price24h(&mut self, prev_price24h: f64) {
+    pub fn set_prev_price24h(&mut self, prev_price24h: Number) {
         self.prev_price24h = Some(prev_price24h);
     }
 
-    pub fn high_price24h(&self) -> Option<f64> {
+    pub fn high_price24h(&self) -> Option<Number> {
         self.high_price24h
     }
 
-    pub fn set_high_price24h(&mut self, high_price24h: f64) {
+    pub fn set_high_price24h(&mut self, high_price24h: Number) {
         self.high_price24h = Some(high_price24h);
     }
 
-    pub fn low_price24h(&self) -> Option<f64> {
+    pub fn low_price24h(&self) -> Option<Number> {
         self.low_price24h
     }
 
-    pub fn set_low_price24h(&mut self, low_price24h: f64) {
+    pub fn set_low_price24h(&mut self, low_price24h: Number) {
         self.low_price24h = Some(low_price24h);
     }
 
-    pub fn prev_price1h(&self) -> Option<f64> {
+    pub fn prev_price1h(&self) -> Option<Number> {
         self.prev_price1h
     }
 
-    pub fn set_prev_price1h(&mut self, prev_price1h: f64) {
+    pub fn set_prev_price1h(&mut self, prev_price1h: Number) {
         self.prev_price1h = Some(prev_price1h);
     }
 
-    pub fn mark_price(&self) -> Option<f64> {
+    pub fn mark_price(&self) -> Option<Number> {
         self.mark_price
     }
 
-    pub fn set_mark_price(&mut self, mark_price: f64) {
+    pub fn set_mark_price(&mut self, mark_price: Number) {
         self.mark_price = Some(mark_price);
     }
 
-    pub fn index_price(&self) -> Option<f64> {
+    pub fn index_price(&self) -> Option<Number> {
         self.index_price
     }
 
-    pub fn set_index_price(&mut self, index_price: f64) {
+    pub fn set_index_price(&mut self, index_price: Number) {
         self.index_price = Some(index_price);
     }
 
-    pub fn open_interest(&self) -> Option<f64> {
+    pub fn open_interest(&self) -> Option<Number> {
         self.open_interest
     }
 
-    pub fn set_open_interest(&mut self, open_interest: f64) {
+    pub fn set_open_interest(&mut self, open_interest: Number) {
         self.open_interest = Some(open_interest);
     }
 
-    pub fn open_interest_value(&self) -> Option<f64> {
+    pub fn open_interest_value(&self) -> Option<Number> {
         self.open_interest_value
     }
 
-    pub fn set_open_interest_value(&mut self, open_interest_value: f64) {
+    pub fn set_open_interest_value(&mut self, open_interest_value: Number) {
         self.open_interest_value = Some(open_interest_value);
     }
 
-    pub fn turnover24h(&self) -> Option<f64> {
+    pub fn turnover24h(&self) -> Option<Number> {
         self.turnover24h
     }
 
-    pub fn set_turnover24h(&mut self, turnover24h: f64) {
+    pub fn set_turnover24h(&mut self, turnover24h: Number) {
         self.turnover24h = Some(turnover24h);
     }
 
-    pub fn volume24h(&self) -> Option<f64> {
+    pub fn volume24h(&self) -> Option<Number> {
         self.volume24h
     }
 
-    pub fn set_volume24h(&mut self, volume24h: f64) {
+    pub fn set_volume24h(&mut self, volume24h: Number) {
         self.volume24h = Some(volume24h);
     }
 
@@ -221,43 +255,55 @@ impl LinearTickers {
         self.next_funding_time = Some(next_funding_time);
     }
 
-    pub fn funding_rate(&self) -> Option<f64> {
+    pub fn next_funding_time_datetime(&self) -> Option<DateTime<Utc>> {
+        option_millis_to_datetime(self.next_funding_time)
+    }
+
+    pub fn funding_rate(&self) -> Option<Number> {
         self.funding_rate
     }
 
-    pub fn set_funding_rate(&mut self, funding_rate: f64) {
+    pub fn set_funding_rate(&mut self, funding_rate: Number) {
         self.funding_rate = Some(funding_rate);
     }
 
-    pub fn bid1_price(&self) -> Option<f64> {
+    pub fn bid1_price(&self) -> Option<Number> {
         self.bid1_price
     }
 
-    pub fn set_bid1_price(&mut self, bid1_price: f64) {
+    pub fn set_bid1_price(&mut self, bid1_price: Number) {
         self.bid1_price = Some(bid1_price);
     }
 
-    pub fn bid1_size(&self) -> Option<f64> {
+    pub fn bid1_size(&self) -> Option<Number> {
         self.bid1_size
     }
 
-    pub fn set_bid1_size(&mut self, bid1_size: f64) {
+    pub fn set_bid1_size(&mut self, bid1_size: Number) {
         self.bid1_size = Some(bid1_size);
     }
 
-    pub fn ask1_price(&self) -> Option<f64> {
+    pub fn ask1_price(&self) -> Option<Number> {
         self.ask1_price
     }
 
-    pub fn set_ask1_price(&mut self, ask1_price: f64) {
+    pub fn set_ask1_price(&mut self, ask1_price: Number) {
         self.ask1_price = Some(ask1_price);
     }
 
-    pub fn ask1_size(&self) -> Option<f64> {
+    pub fn ask1_size(&self) -> Option<Number> {
         self.ask1_size
     }
 
-    pub fn set_ask1_size(&mut self, ask1_size: f64) {
+    pub fn set_ask1_size(&mut self, ask1_size: Number) {
         self.ask1_size = Some(ask1_size);
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
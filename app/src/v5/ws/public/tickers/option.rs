@@ -1,7 +1,28 @@
-use crate::utils::deserialize_option_f64;
-use serde::Deserialize;
-
-#[derive(Debug, Clone, Deserialize)]
+use chrono::{
+    DateTime,
+    Utc,
+};
+use crate::utils::{
+    serialize_option_as_string,
+    millis_to_datetime,
+};
+use crate::utils::deserialize_option_number;
+use crate::v5::types::Number;
+use crate::v5::symbol::{
+    ParseSymbolError,
+    Symbol,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PublicOptionTickersResponse {
     id: String,
@@ -10,6 +31,8 @@ pub struct PublicOptionTickersResponse {
     type_field: String,
     data: OptionTickers,
     ts: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PublicOptionTickersResponse {
@@ -52,58 +75,72 @@ impl PublicOptionTickersResponse {
     pub fn set_ts(&mut self, ts: u64) {
         self.ts = ts;
     }
+
+    pub fn ts_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.ts)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OptionTickers {
     symbol: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    bid_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    bid_size: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    bid_iv: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    ask_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    ask_size: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    ask_iv: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    last_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    high_price24h: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    low_price24h: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    mark_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    index_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    underlying_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    open_interest: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    turnover24h: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    volume24h: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    total_volume: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    total_turnover: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    delta: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    gamma: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    vega: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    theta: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    predicted_delivery_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    change24h: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    bid_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    bid_size: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    bid_iv: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    ask_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    ask_size: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    ask_iv: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    last_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    high_price24h: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    low_price24h: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    mark_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    index_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    underlying_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    open_interest: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    turnover24h: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    volume24h: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    total_volume: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    total_turnover: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    delta: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    gamma: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    vega: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    theta: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    predicted_delivery_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    change24h: Option<Number>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl OptionTickers {
@@ -115,188 +152,200 @@ impl OptionTickers {
         self.symbol = symbol;
     }
 
-    pub fn bid_price(&self) -> Option<f64> {
+    pub fn parsed_symbol(&self) -> Result<Symbol, ParseSymbolError> {
+        self.symbol.parse()
+    }
+
+    pub fn bid_price(&self) -> Option<Number> {
         self.bid_price
     }
 
-    pub fn set_bid_price(&mut self, bid_price: Option<f64>) {
+    pub fn set_bid_price(&mut self, bid_price: Option<Number>) {
         self.bid_price = bid_price;
     }
 
-    pub fn bid_size(&self) -> Option<f64> {
+    pub fn bid_size(&self) -> Option<Number> {
         self.bid_size
     }
 
-    pub fn set_bid_size(&mut self, bid_size: Option<f64>) {
+    pub fn set_bid_size(&mut self, bid_size: Option<Number>) {
         self.bid_size = bid_size;
     }
 
-    pub fn bid_iv(&self) -> Option<f64> {
+    pub fn bid_iv(&self) -> Option<Number> {
         self.bid_iv
     }
 
-    pub fn set_bid_iv(&mut self, bid_iv: Option<f64>) {
+    pub fn set_bid_iv(&mut self, bid_iv: Option<Number>) {
         self.bid_iv = bid_iv;
     }
 
-    pub fn ask_price(&self) -> Option<f64> {
+    pub fn ask_price(&self) -> Option<Number> {
         self.ask_price
     }
 
-    pub fn set_ask_price(&mut self, ask_price: Option<f64>) {
+    pub fn set_ask_price(&mut self, ask_price: Option<Number>) {
         self.ask_price = ask_price;
     }
 
-    pub fn ask_size(&self) -> Option<f64> {
+    pub fn ask_size(&self) -> Option<Number> {
         self.ask_size
     }
 
-    pub fn set_ask_size(&mut self, ask_size: Option<f64>) {
+    pub fn set_ask_size(&mut self, ask_size: Option<Number>) {
         self.ask_size = ask_size;
     }
 
-    pub fn ask_iv(&self) -> Option<f64> {
+    pub fn ask_iv(&self) -> Option<Number> {
         self.ask_iv
     }
 
-    pub fn set_ask_iv(&mut self, ask_iv: Option<f64>) {
+    pub fn set_ask_iv(&mut self, ask_iv: Option<Number>) {
         self.ask_iv = ask_iv;
     }
 
-    pub fn last_price(&self) -> Option<f64> {
+    pub fn last_price(&self) -> Option<Number> {
         self.last_price
     }
 
-    pub fn set_last_price(&mut self, last_price: Option<f64>) {
+    pub fn set_last_price(&mut self, last_price: Option<Number>) {
         self.last_price = last_price;
     }
 
-    pub fn high_price24h(&self) -> Option<f64> {
+    pub fn high_price24h(&self) -> Option<Number> {
         self.high_price24h
     }
 
-    pub fn set_high_price24h(&mut self, high_price24h: Option<f64>) {
+    pub fn set_high_price24h(&mut self, high_price24h: Option<Number>) {
         self.high_price24h = high_price24h;
     }
 
-    pub fn low_price24h(&self) -> Option<f64> {
+    pub fn low_price24h(&self) -> Option<Number> {
         self.low_price24h
     }
 
-    pub fn set_low_price24h(&mut self, low_price24h: Option<f64>) {
+    pub fn set_low_price24h(&mut self, low_price24h: Option<Number>) {
         self.low_price24h = low_price24h;
     }
 
-    pub fn mark_price(&self) -> Option<f64> {
+    pub fn mark_price(&self) -> Option<Number> {
         self.mark_price
     }
 
-    pub fn set_mark_price(&mut self, mark_price: Option<f64>) {
+    pub fn set_mark_price(&mut self, mark_price: Option<Number>) {
         self.mark_price = mark_price;
     }
 
-    pub fn index_price(&self) -> Option<f64> {
+    pub fn index_price(&self) -> Option<Number> {
         self.index_price
     }
 
-    pub fn set_index_price(&mut self, index_price: Option<f64>) {
+    pub fn set_index_price(&mut self, index_price: Option<Number>) {
         self.index_price = index_price;
     }
 
-    pub fn underlying_price(&self) -> Option<f64> {
+    pub fn underlying_price(&self) -> Option<Number> {
         self.underlying_price
     }
 
-    pub fn set_underlying_price(&mut self, underlying_price: Option<f64>) {
+    pub fn set_underlying_price(&mut self, underlying_price: Option<Number>) {
         self.underlying_price = underlying_price;
     }
 
-    pub fn open_interest(&self) -> Option<f64> {
+    pub fn open_interest(&self) -> Option<Number> {
         self.open_interest
     }
 
-    pub fn set_open_interest(&mut self, open_interest: Option<f64>) {
+    pub fn set_open_interest(&mut self, open_interest: Option<Number>) {
         self.open_interest = open_interest;
     }
 
-    pub fn turnover24h(&self) -> Option<f64> {
+    pub fn turnover24h(&self) -> Option<Number> {
         self.turnover24h
     }
 
-    pub fn set_turnover24h(&mut self, turnover24h: Option<f64>) {
+    pub fn set_turnover24h(&mut self, turnover24h: Option<Number>) {
         self.turnover24h = turnover24h;
     }
 
-    pub fn volume24h(&self) -> Option<f64> {
+    pub fn volume24h(&self) -> Option<Number> {
         self.volume24h
     }
 
-    pub fn set_volume24h(&mut self, volume24h: Option<f64>) {
+    pub fn set_volume24h(&mut self, volume24h: Option<Number>) {
         self.volume24h = volume24h;
     }
 
-    pub fn total_volume(&self) -> Option<f64> {
+    pub fn total_volume(&self) -> Option<Number> {
         self.total_volume
     }
 
-    pub fn set_total_volume(&mut self, total_volume: Option<f64>) {
+    pub fn set_total_volume(&mut self, total_volume: Option<Number>) {
         self.total_volume = total_volume;
     }
 
-    pub fn total_turnover(&self) -> Option<f64> {
+    pub fn total_turnover(&self) -> Option<Number> {
         self.total_turnover
     }
 
-    pub fn set_total_turnover(&mut self, total_turnover: Option<f64>) {
+    pub fn set_total_turnover(&mut self, total_turnover: Option<Number>) {
         self.total_turnover = total_turnover;
     }
 
-    pub fn delta(&self) -> Option<f64> {
+    pub fn delta(&self) -> Option<Number> {
         self.delta
     }
 
-    pub fn set_delta(&mut self, delta: Option<f64>) {
+    pub fn set_delta(&mut self, delta: Option<Number>) {
         self.delta = delta;
     }
 
-    pub fn gamma(&self) -> Option<f64> {
+    pub fn gamma(&self) -> Option<Number> {
         self.gamma
     }
 
-    pub fn set_gamma(&mut self, gamma: Option<f64>) {
+    pub fn set_gamma(&mut self, gamma: Option<Number>) {
         self.gamma = gamma;
     }
 
-    pub fn vega(&self) -> Option<f64> {
+    pub fn vega(&self) -> Option<Number> {
         self.vega
     }
 
-    pub fn set_vega(&mut self, vega: Option<f64>) {
+    pub fn set_vega(&mut self, vega: Option<Number>) {
         self.vega = vega;
     }
 
-    pub fn theta(&self) -> Option<f64> {
+    pub fn theta(&self) -> Option<Number> {
         self.theta
     }
 
-    pub fn set_theta(&mut self, theta: Option<f64>) {
+    pub fn set_theta(&mut self, theta: Option<Number>) {
         self.theta = theta;
     }
 
-    pub fn predicted_delivery_price(&self) -> Option<f64> {
+    pub fn predicted_delivery_price(&self) -> Option<Number> {
         self.predicted_delivery_price
     }
 
-    pub fn set_predicted_delivery_price(&mut self, predicted_delivery_price: Option<f64>) {
+    pub fn set_predicted_delivery_price(&mut self, predicted_delivery_price: Option<Number>) {
         self.predicted_delivery_price = predicted_delivery_price;
     }
 
-    pub fn change24h(&self) -> Option<f64> {
+    pub fn change24h(&self) -> Option<Number> {
         self.change24h
     }
 
-    pub fn set_change24h(&mut self, change24h: Option<f64>) {
+    pub fn set_change24h(&mut self, change24h: Option<Number>) {
         self.change24h = change24h;
     }
 
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -1,7 +1,24 @@
-use crate::utils::deserialize_option_f64;
-use serde::Deserialize;
-
-#[derive(Debug, Clone, Deserialize)]
+use chrono::{
+    DateTime,
+    Utc,
+};
+use crate::utils::{
+    serialize_option_as_string,
+    millis_to_datetime,
+};
+use crate::utils::deserialize_option_number;
+use crate::v5::types::Number;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PublicSpotTickersResponse {
     topic: String,
@@ -10,6 +27,8 @@ pub struct PublicSpotTickersResponse {
     data: SpotTickers,
     cs: u64,
     ts: u64,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PublicSpotTickersResponse {
@@ -52,28 +71,42 @@ impl PublicSpotTickersResponse {
     pub fn set_ts(&mut self, ts: u64) {
         self.ts = ts;
     }
+
+    pub fn ts_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.ts)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotTickers {
     symbol: String,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    last_price: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    high_price24h: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    low_price24h: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    prev_price24h: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    volume24h: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    turnover24h: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    price24h_pcnt: Option<f64>,
-    #[serde(deserialize_with = "deserialize_option_f64")]
-    usd_index_price: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    last_price: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    high_price24h: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    low_price24h: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    prev_price24h: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    volume24h: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    turnover24h: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    price24h_pcnt: Option<Number>,
+    #[serde(default, deserialize_with = "deserialize_option_number", serialize_with = "serialize_option_as_string")]
+    usd_index_price: Option<Number>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl SpotTickers {
@@ -85,67 +118,75 @@ impl SpotTickers {
         self.symbol = symbol;
     }
 
-    pub fn last_price(&self) -> Option<f64> {
+    pub fn last_price(&self) -> Option<Number> {
         self.last_price
     }
 
-    pub fn set_last_price(&mut self, last_price: Option<f64>) {
+    pub fn set_last_price(&mut self, last_price: Option<Number>) {
         self.last_price = last_price;
     }
 
-    pub fn high_price24h(&self) -> Option<f64> {
+    pub fn high_price24h(&self) -> Option<Number> {
         self.high_price24h
     }
 
-    pub fn set_high_price24h(&mut self, high_price24h: Option<f64>) {
+    pub fn set_high_price24h(&mut self, high_price24h: Option<Number>) {
         self.high_price24h = high_price24h;
     }
 
-    pub fn low_price24h(&self) -> Option<f64> {
+    pub fn low_price24h(&self) -> Option<Number> {
         self.low_price24h
     }
 
-    pub fn set_low_price24h(&mut self, low_price24h: Option<f64>) {
+    pub fn set_low_price24h(&mut self, low_price24h: Option<Number>) {
         self.low_price24h = low_price24h;
     }
 
-    pub fn prev_price24h(&self) -> Option<f64> {
+    pub fn prev_price24h(&self) -> Option<Number> {
         self.prev_price24h
     }
 
-    pub fn set_prev_price24h(&mut self, prev_price24h: Option<f64>) {
+    pub fn set_prev_price24h(&mut self, prev_price24h: Option<Number>) {
         self.prev_price24h = prev_price24h;
     }
 
-    pub fn volume24h(&self) -> Option<f64> {
+    pub fn volume24h(&self) -> Option<Number> {
         self.volume24h
     }
 
-    pub fn set_volume24h(&mut self, volume24h: Option<f64>) {
+    pub fn set_volume24h(&mut self, volume24h: Option<Number>) {
         self.volume24h = volume24h;
     }
 
-    pub fn turnover24h(&self) -> Option<f64> {
+    pub fn turnover24h(&self) -> Option<Number> {
         self.turnover24h
     }
 
-    pub fn set_turnover24h(&mut self, turnover24h: Option<f64>) {
+    pub fn set_turnover24h(&mut self, turnover24h: Option<Number>) {
         self.turnover24h = turnover24h;
     }
 
-    pub fn price24h_pcnt(&self) -> Option<f64> {
+    pub fn price24h_pcnt(&self) -> Option<Number> {
         self.price24h_pcnt
     }
 
-    pub fn set_price24h_pcnt(&mut self, price24h_pcnt: Option<f64>) {
+    pub fn set_price24h_pcnt(&mut self, price24h_pcnt: Option<Number>) {
         self.price24h_pcnt = price24h_pcnt;
     }
 
-    pub fn usd_index_price(&self) -> Option<f64> {
+    pub fn usd_index_price(&self) -> Option<Number> {
         self.usd_index_price
     }
 
-    pub fn set_usd_index_price(&mut self, usd_index_price: Option<f64>) {
+    pub fn set_usd_index_price(&mut self, usd_index_price: Option<Number>) {
         self.usd_index_price = usd_index_price;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
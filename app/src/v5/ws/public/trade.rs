@@ -1,9 +1,29 @@
+use chrono::{
+    DateTime,
+    Utc,
+};
 use crate::{
     v5::ws::BybitWS,
     constants::PUBLIC_TRADE_TOPIC,
-    utils::deserialize_f64,
+    v5::types::{
+        Side,
+        Number,
+    },
+    utils::{
+        serialize_as_string,
+        deserialize_number,
+        millis_to_datetime,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
 };
-use serde::Deserialize;
+use serde_json::{
+    Map,
+    Value,
+};
+use crate::utils::deserialize_extra;
 
 impl BybitWS {
     pub fn add_trade_args(&mut self, symbol: &str) {
@@ -11,7 +31,7 @@ impl BybitWS {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PublicTradeResponse {
     topic: String,
@@ -19,6 +39,8 @@ pub struct PublicTradeResponse {
     type_field: String,
     ts: u64,
     data: Vec<PublicTradeData>,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PublicTradeResponse {
@@ -46,6 +68,10 @@ impl PublicTradeResponse {
         self.ts = ts;
     }
 
+    pub fn ts_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.ts)
+    }
+
     pub fn data(&self) -> &Vec<PublicTradeData> {
         &self.data
     }
@@ -53,26 +79,36 @@ impl PublicTradeResponse {
     pub fn set_data(&mut self, data: Vec<PublicTradeData>) {
         self.data = data;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct PublicTradeData {
     #[serde(rename = "T")]
     timestamp: u64,
     #[serde(rename = "s")]
     symbol: String,
     #[serde(rename = "S")]
-    side: String,
-    #[serde(rename = "v", deserialize_with = "deserialize_f64")]
-    volume: f64,
-    #[serde(rename = "p", deserialize_with = "deserialize_f64")]
-    price: f64,
+    side: Side,
+    #[serde(rename = "v", deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    volume: Number,
+    #[serde(rename = "p", deserialize_with = "deserialize_number", serialize_with = "serialize_as_string")]
+    price: Number,
     #[serde(rename = "L")]
     direction_of_price_change: String,
     #[serde(rename = "i")]
     trade_id: String,
     #[serde(rename = "BT")]
     block_trade: bool,
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
+    extra: Map<String, Value>,
 }
 
 impl PublicTradeData {
@@ -84,6 +120,10 @@ impl PublicTradeData {
         self.timestamp = timestamp;
     }
 
+    pub fn timestamp_datetime(&self) -> DateTime<Utc> {
+        millis_to_datetime(self.timestamp)
+    }
+
     pub fn symbol(&self) -> &str {
         &self.symbol
     }
@@ -92,27 +132,27 @@ impl PublicTradeData {
         self.symbol = symbol;
     }
 
-    pub fn side(&self) -> &str {
+    pub fn side(&self) -> &Side {
         &self.side
     }
 
-    pub fn set_side(&mut self, side: String) {
+    pub fn set_side(&mut self, side: Side) {
         self.side = side;
     }
 
-    pub fn volume(&self) -> f64 {
+    pub fn volume(&self) -> Number {
         self.volume
     }
 
-    pub fn set_volume(&mut self, volume: f64) {
+    pub fn set_volume(&mut self, volume: Number) {
         self.volume = volume;
     }
 
-    pub fn price(&self) -> f64 {
+    pub fn price(&self) -> Number {
         self.price
     }
 
-    pub fn set_price(&mut self, price: f64) {
+    pub fn set_price(&mut self, price: Number) {
         self.price = price;
     }
 
@@ -139,4 +179,12 @@ impl PublicTradeData {
     pub fn set_block_trade(&mut self, block_trade: bool) {
         self.block_trade = block_trade;
     }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    pub fn set_extra(&mut self, extra: Map<String, Value>) {
+        self.extra = extra;
+    }
 }
\ No newline at end of file
@@ -0,0 +1,55 @@
+use rsbit::{
+    v5::{
+        api::BybitApi,
+        environment::{
+            Environment,
+            Region,
+        },
+        ws::{
+            BybitWS,
+            Channel,
+            ChannelCategory,
+        },
+    },
+    constants::{
+        MAINNET_API_URL,
+        TESTNET_API_URL,
+        MAINNET_LINEAR_PUBLIC_CHANNEL,
+        TESTNET_OPTION_PUBLIC_CHANNEL,
+        MAINNET_PRIVATE_CHANNEL,
+    },
+};
+
+#[test]
+fn test_api_environment() {
+    assert_eq!(BybitApi::new().base_url(), TESTNET_API_URL);
+    assert_eq!(BybitApi::new().with_mainnet().base_url(), MAINNET_API_URL);
+    assert_eq!(BybitApi::new().with_mainnet().with_testnet().base_url(), TESTNET_API_URL);
+    assert_eq!(BybitApi::new().with_demo().base_url(), "https://api-demo.bybit.com");
+
+    let api = BybitApi::new().with_environment(Environment::regional(Region::Netherlands));
+    assert_eq!(api.base_url(), "https://api.bybit.nl");
+
+    let api = BybitApi::new().with_environment(Environment::Custom {
+        rest: "http://127.0.0.1:8080".to_string(),
+        ws_public: "ws://127.0.0.1:8081/v5/public".to_string(),
+        ws_private: "ws://127.0.0.1:8081/v5/private".to_string(),
+        ws_trade: "ws://127.0.0.1:8081/v5/trade".to_string(),
+    });
+    assert_eq!(api.base_url(), "http://127.0.0.1:8080");
+}
+
+#[test]
+fn test_ws_environment() {
+    assert_eq!(BybitWS::new(Channel::MainnetLinearPublicChannel).channel(), MAINNET_LINEAR_PUBLIC_CHANNEL);
+    assert_eq!(BybitWS::new(Channel::TestnetOptionPublicChannel).channel(), TESTNET_OPTION_PUBLIC_CHANNEL);
+    assert_eq!(BybitWS::new(Channel::MainnetPrivateChannel).channel(), MAINNET_PRIVATE_CHANNEL);
+
+    let ws = BybitWS::from_environment(Environment::regional(Region::Turkey), ChannelCategory::Spot);
+    assert_eq!(ws.channel(), "wss://stream.bybit-tr.com/v5/public/spot");
+    assert!(!ws.is_private_channel());
+
+    let ws = BybitWS::from_environment(Environment::Demo, ChannelCategory::Private);
+    assert_eq!(ws.channel(), "wss://stream-demo.bybit.com/v5/private");
+    assert!(ws.is_private_channel());
+}
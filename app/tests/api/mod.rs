@@ -0,0 +1,4 @@
+mod rate_limit_test;
+mod retry_test;
+mod time_sync_test;
+mod environment_test;
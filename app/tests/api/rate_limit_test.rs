@@ -0,0 +1,76 @@
+use rsbit::{
+    v5::api::rate_limit::{
+        EndpointGroup,
+        RateLimitBudget,
+        RateLimitMode,
+        RateLimitStatus,
+        RateLimiter,
+    },
+    error::BybitErrorKind,
+};
+use reqwest::header::{
+    HeaderMap,
+    HeaderValue,
+};
+use std::time::{
+    Duration,
+    Instant,
+};
+
+#[test]
+fn test_rate_limit_status_from_headers() {
+    let mut headers = HeaderMap::new();
+    headers.insert("X-Bapi-Limit", HeaderValue::from_static("10"));
+    headers.insert("X-Bapi-Limit-Status", HeaderValue::from_static("9"));
+    headers.insert("X-Bapi-Limit-Reset-Timestamp", HeaderValue::from_static("1672738134824"));
+
+    let status = RateLimitStatus::from_headers(&headers).expect("Failed to parse rate limit headers");
+    assert_eq!(status.limit(), 10);
+    assert_eq!(status.remaining(), 9);
+    assert_eq!(status.reset_timestamp(), 1672738134824);
+    assert_eq!(status.exhausted_for(), None);
+
+    // u32 に収まらない値は切り捨てずに不正として扱う
+    headers.insert("X-Bapi-Limit-Status", HeaderValue::from_static("4294967305"));
+    assert!(RateLimitStatus::from_headers(&headers).is_none());
+
+    headers.remove("X-Bapi-Limit-Status");
+    assert!(RateLimitStatus::from_headers(&headers).is_none());
+}
+
+#[test]
+fn test_endpoint_group_from_endpoint() {
+    assert_eq!(EndpointGroup::from_endpoint("/v5/order/create"), EndpointGroup::Order);
+    assert_eq!(EndpointGroup::from_endpoint("/v5/order/cancel-batch"), EndpointGroup::Order);
+    assert_eq!(EndpointGroup::from_endpoint("/v5/order/realtime"), EndpointGroup::Other);
+    assert_eq!(EndpointGroup::from_endpoint("/v5/position/set-leverage"), EndpointGroup::Position);
+    assert_eq!(EndpointGroup::from_endpoint("/v5/account/wallet-balance"), EndpointGroup::Account);
+    assert_eq!(EndpointGroup::from_endpoint("/v5/market/kline"), EndpointGroup::Other);
+}
+
+#[tokio::test]
+async fn test_rate_limiter_reject() {
+    let limiter = RateLimiter::new()
+        .with_mode(RateLimitMode::Reject)
+        .with_budget(EndpointGroup::Order, RateLimitBudget::new(2, Duration::from_secs(60)));
+    let shared = limiter.clone();
+
+    assert!(limiter.acquire("/v5/order/create", None).await.is_ok());
+    assert!(shared.acquire("/v5/order/amend", None).await.is_ok());
+    match limiter.acquire("/v5/order/cancel", None).await {
+        Ok(_) => assert!(false, "Request should have been rejected"),
+        Err(err) => assert_eq!(err.kind(), BybitErrorKind::RateLimitExceeded),
+    }
+    assert!(limiter.acquire("/v5/position/list", None).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_rate_limiter_delay() {
+    let limiter = RateLimiter::new()
+        .with_budget(EndpointGroup::Order, RateLimitBudget::new(1, Duration::from_millis(100)));
+
+    let started = Instant::now();
+    assert!(limiter.acquire("/v5/order/create", None).await.is_ok());
+    assert!(limiter.acquire("/v5/order/create", None).await.is_ok());
+    assert!(started.elapsed() >= Duration::from_millis(100));
+}
@@ -0,0 +1,53 @@
+use rsbit::{
+    v5::api::retry::RetryPolicy,
+    error::BybitError,
+};
+use serde_json::json;
+use std::time::Duration;
+
+#[test]
+fn test_retry_policy_backoff() {
+    let policy = RetryPolicy::new()
+        .with_initial_backoff(Duration::from_millis(100))
+        .with_max_backoff(Duration::from_millis(500))
+        .with_multiplier(2.0)
+        .with_jitter(false);
+
+    assert_eq!(policy.backoff(0), Duration::from_millis(100));
+    assert_eq!(policy.backoff(1), Duration::from_millis(200));
+    assert_eq!(policy.backoff(2), Duration::from_millis(400));
+    assert_eq!(policy.backoff(3), Duration::from_millis(500));
+    assert_eq!(policy.backoff(100), Duration::from_millis(500));
+
+    let policy = policy.with_jitter(true);
+    for attempt in 0..10 {
+        assert!(policy.backoff(attempt) <= Duration::from_millis(500));
+    }
+
+    assert_eq!(RetryPolicy::disabled().max_retries(), 0);
+}
+
+#[test]
+fn test_bybit_error_is_retryable() {
+    let api_error = |ret_code| BybitError::Api {
+        ret_code,
+        ret_msg: String::new(),
+        ret_ext_info: json!({}),
+        status: 200,
+        endpoint: "/v5/order/create".to_string(),
+    };
+    let http_error = |status| BybitError::Http {
+        status,
+        endpoint: "/v5/market/kline".to_string(),
+        body: String::new(),
+        headers: Box::default(),
+    };
+
+    assert!(api_error(10002).is_retryable());
+    assert!(api_error(10016).is_retryable());
+    assert!(!api_error(10001).is_retryable());
+    assert!(!api_error(110007).is_retryable());
+    assert!(http_error(502).is_retryable());
+    assert!(!http_error(403).is_retryable());
+    assert!(!BybitError::NotFoundApiKey.is_retryable());
+}
@@ -0,0 +1,31 @@
+use rsbit::v5::time_sync::TimeSync;
+use crate::common::setup_api_public;
+use std::time::Duration;
+
+#[test]
+fn test_time_sync_initial_state() {
+    let time_sync = TimeSync::new(Duration::from_secs(60));
+    assert!(time_sync.is_stale());
+    assert_eq!(time_sync.offset(), 0);
+    assert_eq!(time_sync.interval(), Duration::from_secs(60));
+}
+
+#[tokio::test]
+async fn test_time_sync_success() {
+    let api = setup_api_public();
+    let time_sync = TimeSync::new(Duration::from_secs(60));
+    let shared = time_sync.clone();
+
+    let result = time_sync.sync(&api).await;
+    match result {
+        Ok(offset) => {
+            assert_eq!(shared.offset(), offset);
+            assert!(!shared.is_stale());
+            shared.invalidate();
+            assert!(time_sync.is_stale());
+        },
+        Err(err) => {
+            assert!(false, "Failed to sync time: {:?}", err);
+        }
+    }
+}
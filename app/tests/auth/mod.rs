@@ -0,0 +1 @@
+mod rsa_signature_test;
@@ -1,10 +1,16 @@
 use rsbit::v5::{
-    api::BybitApi,
+    api::{
+        BybitApi,
+        retry::RetryPolicy,
+        transport::MockTransport,
+    },
+    types::Number,
     ws::BybitWS,
     ws::Channel
 };
 use dotenv::dotenv;
 use std::env;
+use std::time::Duration;
 
 pub fn setup_api_private() -> BybitApi {
     dotenv().ok();
@@ -20,6 +26,14 @@ pub fn setup_api_public() -> BybitApi {
     BybitApi::new()
 }
 
+pub fn setup_api_mock(transport: &MockTransport) -> BybitApi {
+    BybitApi::new()
+        .with_api_key("XXXXXXXXXX".to_string())
+        .with_api_secret("XXXXXXXXXXXXXXXXXXXX".to_string())
+        .with_retry_policy(RetryPolicy::new().with_initial_backoff(Duration::from_millis(1)))
+        .with_transport(transport.clone())
+}
+
 pub fn setup_ws(channel: Channel) -> BybitWS {
     dotenv().ok();
     if channel.is_private(){
@@ -32,4 +46,9 @@ pub fn setup_ws(channel: Channel) -> BybitWS {
     } else {
         BybitWS::new(channel)
     }
-}
\ No newline at end of file
+}
+
+// Builds a price or quantity that compiles both with and without the `decimal` feature.
+pub fn number(value: &str) -> Number {
+    value.parse().expect("Invalid number")
+}
@@ -0,0 +1,79 @@
+use rsbit::error::{
+    BybitError,
+    BybitErrorKind,
+};
+use serde_json::json;
+use reqwest::header::{
+    HeaderMap,
+    HeaderValue,
+};
+use std::time::Duration;
+
+#[test]
+fn test_bybit_error_kind_from_ret_code() {
+    let cases = vec![
+        (10001, BybitErrorKind::InvalidParameter),
+        (10002, BybitErrorKind::InvalidTimestamp),
+        (10003, BybitErrorKind::Authentication),
+        (10004, BybitErrorKind::Authentication),
+        (10006, BybitErrorKind::RateLimitExceeded),
+        (110007, BybitErrorKind::InsufficientBalance),
+        (110043, BybitErrorKind::LeverageNotModified),
+        (999999, BybitErrorKind::Other),
+    ];
+
+    for (ret_code, kind) in cases.into_iter() {
+        assert_eq!(BybitErrorKind::from_ret_code(ret_code), kind, "Unexpected kind for ret_code {}", ret_code);
+    }
+}
+
+#[test]
+fn test_bybit_error_api_accessors() {
+    let err = BybitError::Api {
+        ret_code: 110007,
+        ret_msg: "ab not enough for new order".to_string(),
+        ret_ext_info: json!({}),
+        status: 200,
+        endpoint: "/v5/order/create".to_string(),
+    };
+
+    assert_eq!(err.kind(), BybitErrorKind::InsufficientBalance);
+    assert_eq!(err.ret_code(), Some(110007));
+    assert_eq!(err.ret_msg(), Some("ab not enough for new order"));
+    assert_eq!(err.status(), Some(200));
+    assert_eq!(err.endpoint(), Some("/v5/order/create"));
+}
+
+#[test]
+fn test_bybit_error_http_kind() {
+    let err = BybitError::Http {
+        status: 403,
+        endpoint: "/v5/market/kline".to_string(),
+        body: String::new(),
+        headers: Box::default(),
+    };
+
+    assert_eq!(err.kind(), BybitErrorKind::IpBanned);
+    assert_eq!(err.ret_code(), None);
+    assert_eq!(BybitError::NotFoundApiKey.kind(), BybitErrorKind::Authentication);
+}
+
+#[test]
+fn test_bybit_error_http_diagnostics() {
+    let mut headers = HeaderMap::new();
+    headers.insert("Retry-After", HeaderValue::from_static("30"));
+    headers.insert("Traceid", HeaderValue::from_static("4e4e2b5ee1b6b8e6"));
+    let err = BybitError::Http {
+        status: 429,
+        endpoint: "/v5/order/create".to_string(),
+        body: "Too many visits!".to_string(),
+        headers: Box::new(headers),
+    };
+
+    assert_eq!(err.body(), Some("Too many visits!"));
+    assert_eq!(err.retry_after(), Some(Duration::from_secs(30)));
+    assert_eq!(
+        err.to_string(),
+        "Request error status code 429, endpoint: /v5/order/create, traceid: 4e4e2b5ee1b6b8e6, retry after 30s, body: Too many visits!",
+    );
+}
@@ -0,0 +1 @@
+mod bybit_error_test;
@@ -1,3 +1,4 @@
+use rsbit::v5::types::Side;
 use rsbit::v5::api::get::trade::get_borrow_quota::{
     GetBorrowQuotaParameters,
     GetBorrowQuotaCategory,
@@ -8,7 +9,7 @@ use crate::common::setup_api_private;
 async fn test_get_borrow_quota_success() {
     let api = setup_api_private();
     let categories = vec![
-        (GetBorrowQuotaCategory::Spot, "BTCUSDT".to_string(), "Buy".to_string()),
+        (GetBorrowQuotaCategory::Spot, "BTCUSDT".to_string(), Side::Buy),
     ];
 
     for (category, symbol, side) in categories.into_iter() {
@@ -38,7 +39,7 @@ async fn test_get_borrow_quota_fail() {
     let params = GetBorrowQuotaParameters::new(
         GetBorrowQuotaCategory::Spot,
         "XXXXXXX".to_string(),
-        "Buy".to_string(),
+        Side::Buy,
     );
 
     let result = api.get_borrow_quota(params).await;
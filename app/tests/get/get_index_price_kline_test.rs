@@ -1,3 +1,4 @@
+use rsbit::v5::types::Interval;
 use rsbit::v5::api::get::market::get_index_price_kline::{
     GetIndexPriceKlineParameters,
     GetIndexPriceKlineCategory,
@@ -8,15 +9,15 @@ use crate::common::setup_api_public;
 async fn test_get_index_price_kline_success() {
     let api = setup_api_public();
     let categories = vec![
-        (GetIndexPriceKlineCategory::Linear, "BTCUSDT".to_string(), "1".to_string()),
-        (GetIndexPriceKlineCategory::Inverse, "BTCUSD".to_string(), "360".to_string()),
+        (GetIndexPriceKlineCategory::Linear, "BTCUSDT".to_string(), Interval::Minute1),
+        (GetIndexPriceKlineCategory::Inverse, "BTCUSD".to_string(), Interval::Hour6),
     ];
 
     for (category, symbol, interval) in categories.into_iter() {
         let params = GetIndexPriceKlineParameters::new(
             category,
             symbol.clone(),
-            interval.clone(),
+            interval,
         );
     
         let result = api.get_index_price_kline(params).await;
@@ -41,7 +42,7 @@ async fn test_get_index_price_kline_fail() {
     let params = GetIndexPriceKlineParameters::new(
         GetIndexPriceKlineCategory::Linear,
         "XXXXXXX".to_string(),
-        "1".to_string(),
+        Interval::Minute1,
     );
 
     let result = api.get_index_price_kline(params).await;
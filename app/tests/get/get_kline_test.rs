@@ -1,3 +1,4 @@
+use rsbit::v5::types::Interval;
 use rsbit::v5::api::get::market::get_kline::{
     GetKlineParameters,
     GetKlineCategory,
@@ -8,16 +9,16 @@ use crate::common::setup_api_public;
 async fn test_get_kline_success() {
     let api = setup_api_public();
     let categories = vec![
-        (GetKlineCategory::Linear, "BTCUSDT".to_string(), "1".to_string(), 100),
-        (GetKlineCategory::Inverse, "BTCUSD".to_string(), "360".to_string(), 100),
-        (GetKlineCategory::Spot, "BTCUSDT".to_string(), "D".to_string(), 100),
+        (GetKlineCategory::Linear, "BTCUSDT".to_string(), Interval::Minute1, 100),
+        (GetKlineCategory::Inverse, "BTCUSD".to_string(), Interval::Hour6, 100),
+        (GetKlineCategory::Spot, "BTCUSDT".to_string(), Interval::Day, 100),
     ];
 
     for (category, symbol, interval, limit) in categories.into_iter() {
         let params = GetKlineParameters::new(
             category,
             symbol.clone(),
-            interval.clone(),
+            interval,
         ).with_limit(limit);
     
         let result = api.get_kline(params).await;
@@ -42,7 +43,7 @@ async fn test_get_kline_fail() {
     let params = GetKlineParameters::new(
         GetKlineCategory::Linear,
         "XXXXXXX".to_string(),
-        "1".to_string(),
+        Interval::Minute1,
     );
 
     let result = api.get_kline(params).await;
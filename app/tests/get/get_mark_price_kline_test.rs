@@ -1,3 +1,4 @@
+use rsbit::v5::types::Interval;
 use rsbit::v5::api::get::market::get_mark_price_kline::{
     GetMarkPriceKlineParameters,
     GetMarkPriceKlineCategory,
@@ -8,15 +9,15 @@ use crate::common::setup_api_public;
 async fn test_get_mark_price_kline_success() {
     let api = setup_api_public();
     let categories = vec![
-        (GetMarkPriceKlineCategory::Linear, "BTCUSDT".to_string(), "1".to_string()),
-        (GetMarkPriceKlineCategory::Inverse, "BTCUSD".to_string(), "360".to_string()),
+        (GetMarkPriceKlineCategory::Linear, "BTCUSDT".to_string(), Interval::Minute1),
+        (GetMarkPriceKlineCategory::Inverse, "BTCUSD".to_string(), Interval::Hour6),
     ];
 
     for (category, symbol, interval) in categories.into_iter() {
         let params = GetMarkPriceKlineParameters::new(
             category,
             symbol.clone(),
-            interval.clone(),
+            interval,
         );
     
         let result = api.get_mark_price_kline(params).await;
@@ -41,7 +42,7 @@ async fn test_get_mark_price_kline_fail() {
     let params = GetMarkPriceKlineParameters::new(
         GetMarkPriceKlineCategory::Linear,
         "XXXXXXX".to_string(),
-        "1".to_string(),
+        Interval::Minute1,
     );
 
     let result = api.get_mark_price_kline(params).await;
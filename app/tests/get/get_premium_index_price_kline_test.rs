@@ -1,3 +1,4 @@
+use rsbit::v5::types::Interval;
 use rsbit::v5::api::get::market::get_premium_index_price_kline::{
     GetPremiumIndexPriceKlineParameters,
     GetPremiumIndexPriceKlineCategory,
@@ -8,14 +9,14 @@ use crate::common::setup_api_public;
 async fn test_get_premium_index_price_kline_success() {
     let api = setup_api_public();
     let categories = vec![
-        (GetPremiumIndexPriceKlineCategory::Linear, "BTCUSDT".to_string(), "1".to_string()),
+        (GetPremiumIndexPriceKlineCategory::Linear, "BTCUSDT".to_string(), Interval::Minute1),
     ];
 
     for (category, symbol, interval) in categories.into_iter() {
         let params = GetPremiumIndexPriceKlineParameters::new(
             category,
             symbol.clone(),
-            interval.clone(),
+            interval,
         );
     
         let result = api.get_premium_index_price_kline(params).await;
@@ -40,7 +41,7 @@ async fn test_get_premium_index_price_kline_fail() {
     let params = GetPremiumIndexPriceKlineParameters::new(
         GetPremiumIndexPriceKlineCategory::Linear,
         "XXXXXXX".to_string(),
-        "1".to_string(),
+        Interval::Minute1,
     );
 
     let result = api.get_premium_index_price_kline(params).await;
@@ -0,0 +1,85 @@
+use rsbit::{
+    v5::{
+        api::{
+            post::trade::{
+                batch_cancel_order::BatchCancelOrderResponse,
+                batch_place_order::{
+                    BatchPlaceOrderParameters,
+                    BatchPlaceOrderRequestParameters,
+                    BatchPlaceOrderCategory,
+                },
+            },
+            transport::{
+                HttpMethod,
+                MockTransport,
+            },
+        },
+        types::{
+            Side,
+            OrderType,
+        },
+    },
+    error::BybitErrorKind,
+};
+use serde_json::json;
+use crate::common::{
+    setup_api_mock,
+    number,
+};
+
+const BATCH_PLACE_ORDER_PARTIAL: &str = include_str!("../fixtures/batch_place_order_partial.json");
+
+#[tokio::test]
+async fn test_batch_place_order_items() {
+    let transport = MockTransport::new().with_response(HttpMethod::Post, "/v5/order/create-batch", None, BATCH_PLACE_ORDER_PARTIAL);
+    let api = setup_api_mock(&transport);
+    let params = BatchPlaceOrderParameters::new(BatchPlaceOrderCategory::Linear, vec![
+        BatchPlaceOrderRequestParameters::new("BTCUSDT".to_string(), Side::Buy, OrderType::Market, number("0.01"))
+            .with_order_link_id("linear-btc-01".to_string()),
+        BatchPlaceOrderRequestParameters::new("BTCUSDT".to_string(), Side::Buy, OrderType::Market, number("100"))
+            .with_order_link_id("linear-btc-02".to_string()),
+    ]);
+
+    match api.batch_place_order(params).await {
+        Ok(response) => {
+            let items = response.items();
+            assert_eq!(items.len(), 2);
+            match &items[0] {
+                Ok(order) => {
+                    assert_eq!(order.order_id(), "1666800494330512128");
+                    assert_eq!(order.create_at(), Some(1713434102752));
+                },
+                Err(err) => assert!(false, "Unexpected error: {:?}", err),
+            }
+            match &items[1] {
+                Ok(order) => assert!(false, "Request should have failed: {:?}", order),
+                Err(err) => {
+                    assert_eq!(err.index(), 1);
+                    assert_eq!(err.code(), 110007);
+                    assert_eq!(err.msg(), "ab not enough for new order");
+                    assert_eq!(err.kind(), BybitErrorKind::InsufficientBalance);
+                },
+            }
+        },
+        Err(err) => assert!(false, "Failed to batch place order: {:?}", err),
+    }
+}
+
+#[test]
+fn test_batch_items_without_status() {
+    // retExtInfo が空の場合は全て成功として扱う
+    let response: BatchCancelOrderResponse = serde_json::from_value(json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {
+            "list": [
+                { "category": "spot", "symbol": "BTCUSDT", "orderId": "1", "orderLinkId": "" },
+            ],
+        },
+        "retExtInfo": {},
+        "time": 1713434299047u64,
+    })).unwrap();
+    let items = response.items();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].as_ref().map(|order| order.order_id()), Ok("1"));
+}
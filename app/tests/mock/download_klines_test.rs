@@ -0,0 +1,114 @@
+use rsbit::v5::{
+    api::{
+        get::market::get_kline::GetKlineCategory,
+        transport::{
+            HttpMethod,
+            MockTransport,
+        },
+    },
+    types::Interval,
+};
+use chrono::{
+    DateTime,
+    TimeZone,
+    Utc,
+};
+use futures_util::StreamExt;
+use serde_json::json;
+use crate::common::setup_api_mock;
+
+const PATH: &str = "/v5/market/kline";
+// 2024-01-01T00:00:00Z
+const FROM: u64 = 1704067200000;
+const MINUTE: u64 = 60_000;
+
+fn page(symbol: &str, timestamps: &[u64]) -> String {
+    json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {
+            "symbol": symbol,
+            "category": "linear",
+            "list": timestamps.iter()
+                .map(|timestamp| json!([timestamp.to_string(), "100", "110", "90", "105", "1", "100"]))
+                .collect::<Vec<_>>(),
+        },
+        "retExtInfo": {},
+        "time": 1704157200000u64,
+    }).to_string()
+}
+
+fn query(symbol: &str, start: u64, end: u64) -> String {
+    format!("category=linear&symbol={}&interval=1&start={}&end={}&limit=1000", symbol, start, end)
+}
+
+fn range() -> (DateTime<Utc>, DateTime<Utc>) {
+    let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    (from, from + chrono::Duration::minutes(1500))
+}
+
+// 1500 本を 1000 本ずつの 2 ページで返す
+fn with_pages(transport: MockTransport, symbol: &str) -> MockTransport {
+    let second = FROM + 1000 * MINUTE;
+    transport
+        .with_response(HttpMethod::Get, PATH, Some(&query(symbol, FROM, second - 1)), &page(symbol, &[FROM + 2 * MINUTE, FROM + MINUTE, FROM]))
+        // 前のページの最後の足が重なって返ってきても一度だけ返す
+        .with_response(HttpMethod::Get, PATH, Some(&query(symbol, second, FROM + 1500 * MINUTE - 1)), &page(symbol, &[second + MINUTE, second, FROM + 2 * MINUTE]))
+}
+
+#[tokio::test]
+async fn test_download_klines() {
+    let transport = with_pages(MockTransport::new(), "BTCUSDT");
+    let api = setup_api_mock(&transport);
+    let (from, to) = range();
+
+    let timestamps: Vec<u64> = api.download_klines(GetKlineCategory::Linear, "BTCUSDT".to_string(), Interval::Minute1, from, to)
+        .map(|kline| kline.unwrap().timestamp())
+        .collect()
+        .await;
+    let second = FROM + 1000 * MINUTE;
+    assert_eq!(timestamps, vec![FROM, FROM + MINUTE, FROM + 2 * MINUTE, second, second + MINUTE]);
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_download_klines_ends_on_error() {
+    let transport = MockTransport::new()
+        .with_response(HttpMethod::Get, PATH, Some(&query("BTCUSDT", FROM, FROM + 1000 * MINUTE - 1)), &page("BTCUSDT", &[FROM]));
+    let api = setup_api_mock(&transport);
+    let (from, to) = range();
+
+    let klines: Vec<_> = api.download_klines(GetKlineCategory::Linear, "BTCUSDT".to_string(), Interval::Minute1, from, to)
+        .collect()
+        .await;
+    assert_eq!(klines.len(), 2);
+    assert!(klines[0].is_ok());
+    match &klines[1] {
+        Ok(kline) => assert!(false, "Request should have failed: {:?}", kline),
+        Err(err) => assert_eq!(err.status(), Some(404)),
+    }
+}
+
+#[tokio::test]
+async fn test_download_klines_concurrently() {
+    let transport = with_pages(with_pages(MockTransport::new(), "BTCUSDT"), "ETHUSDT");
+    let api = setup_api_mock(&transport);
+    let (from, to) = range();
+    let symbols = vec!["BTCUSDT".to_string(), "ETHUSDT".to_string()];
+
+    let klines: Vec<(String, u64)> = api.download_klines_concurrently(GetKlineCategory::Linear, symbols, Interval::Minute1, from, to, 2)
+        .map(|(symbol, kline)| (symbol, kline.unwrap().timestamp()))
+        .collect()
+        .await;
+    assert_eq!(klines.len(), 10);
+    // 銘柄ごとには古い順に並ぶ
+    for symbol in ["BTCUSDT", "ETHUSDT"] {
+        let timestamps: Vec<u64> = klines.iter()
+            .filter(|(name, _)| name == symbol)
+            .map(|(_, timestamp)| *timestamp)
+            .collect();
+        assert_eq!(timestamps.len(), 5);
+        assert!(timestamps.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+    assert_eq!(transport.requests().len(), 4);
+}
@@ -0,0 +1,210 @@
+use rsbit::{
+    error::BybitError,
+    v5::{
+        api::{
+            get::market::get_kline::{
+                GetKlineCategory,
+                Kline,
+            },
+            transport::{
+                HttpMethod,
+                MockTransport,
+            },
+        },
+        kline_store::{
+            KlineStore,
+            KlineStoreError,
+        },
+        types::Interval,
+        ws::public::kline::PublicKlineResponse,
+    },
+};
+use chrono::{
+    DateTime,
+    Duration,
+    TimeZone,
+    Utc,
+};
+use serde_json::json;
+use std::{
+    fs,
+    path::PathBuf,
+};
+use crate::common::{
+    setup_api_mock,
+    number,
+};
+
+const PATH: &str = "/v5/market/kline";
+const SYMBOL: &str = "BTCUSDT";
+// 2024-01-01T00:00:00Z
+const FROM: u64 = 1704067200000;
+const MINUTE: u64 = 60_000;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rsbit_kline_store_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn kline(timestamp: u64) -> Kline {
+    Kline::new(timestamp, number("100"), number("110"), number("90"), number("105"), number("1"), number("100"))
+}
+
+fn minute(n: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + Duration::minutes(n)
+}
+
+fn timestamps(klines: &[Kline]) -> Vec<u64> {
+    klines.iter().map(Kline::timestamp).collect()
+}
+
+#[test]
+fn test_kline_store_persists() {
+    let dir = temp_dir("persists");
+    {
+        let store = KlineStore::open(&dir).unwrap();
+        let inserted = store.insert(GetKlineCategory::Linear, SYMBOL, Interval::Minute1, &[kline(FROM + MINUTE), kline(FROM)]).unwrap();
+        assert_eq!(inserted, 2);
+        // 保存済みの足は書き込まない
+        let inserted = store.insert(GetKlineCategory::Linear, SYMBOL, Interval::Minute1, &[kline(FROM)]).unwrap();
+        assert_eq!(inserted, 0);
+    }
+
+    // 再起動後もディスクから読み出せる
+    let store = KlineStore::open(&dir).unwrap();
+    let klines = store.range(GetKlineCategory::Linear, SYMBOL, Interval::Minute1, minute(0), minute(10)).unwrap();
+    assert_eq!(timestamps(&klines), vec![FROM, FROM + MINUTE]);
+    assert_eq!(klines[0], kline(FROM));
+    // 他の系列とは混ざらない
+    let klines = store.range(GetKlineCategory::Spot, SYMBOL, Interval::Minute1, minute(0), minute(10)).unwrap();
+    assert!(klines.is_empty());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_kline_store_recovers_torn_line() {
+    let dir = temp_dir("torn");
+    let file = dir.join("linear").join(SYMBOL).join("1.jsonl");
+    fs::create_dir_all(file.parent().unwrap()).unwrap();
+    // 2 行目の書き込み途中で止まったファイル
+    fs::write(&file, format!("{}\n[\"{}\",\"100\",\"1", serde_json::to_string(&kline(FROM)).unwrap(), FROM + MINUTE)).unwrap();
+    {
+        let store = KlineStore::open(&dir).unwrap();
+        assert_eq!(store.insert(GetKlineCategory::Linear, SYMBOL, Interval::Minute1, &[kline(FROM + 2 * MINUTE)]).unwrap(), 1);
+    }
+
+    // 切り詰めた後に追記した足も読み出せる
+    let store = KlineStore::open(&dir).unwrap();
+    let klines = store.range(GetKlineCategory::Linear, SYMBOL, Interval::Minute1, minute(0), minute(10)).unwrap();
+    assert_eq!(timestamps(&klines), vec![FROM, FROM + 2 * MINUTE]);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_kline_store_rejects_unsafe_symbols() {
+    let dir = temp_dir("unsafe");
+    let store = KlineStore::open(&dir).unwrap();
+    for symbol in ["", "..", "../BTCUSDT", "BTC/USDT", "BTC\\USDT"] {
+        match store.insert(GetKlineCategory::Linear, symbol, Interval::Minute1, &[kline(FROM)]) {
+            Ok(inserted) => assert!(false, "Symbol {:?} should have been rejected: {}", symbol, inserted),
+            Err(err) => assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput),
+        }
+    }
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_kline_store_gaps() {
+    let dir = temp_dir("gaps");
+    let store = KlineStore::open(&dir).unwrap();
+    store.insert(GetKlineCategory::Linear, SYMBOL, Interval::Minute1, &[kline(FROM), kline(FROM + MINUTE), kline(FROM + 3 * MINUTE)]).unwrap();
+
+    let gaps = store.gaps(GetKlineCategory::Linear, SYMBOL, Interval::Minute1, minute(0), minute(5)).unwrap();
+    assert_eq!(gaps, vec![(minute(2), minute(3)), (minute(4), minute(5))]);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_kline_store_insert_confirmed() {
+    let dir = temp_dir("confirmed");
+    let store = KlineStore::open(&dir).unwrap();
+    let bar = |start: u64, confirm: bool| json!({
+        "start": start,
+        "end": start + MINUTE - 1,
+        "interval": "1",
+        "open": "100",
+        "close": "105",
+        "high": "110",
+        "low": "90",
+        "volume": "1",
+        "turnover": "100",
+        "confirm": confirm,
+        "timestamp": start + MINUTE - 1,
+    });
+    let response: PublicKlineResponse = serde_json::from_value(json!({
+        "topic": "kline.1.BTCUSDT",
+        "type": "snapshot",
+        "ts": FROM + 2 * MINUTE,
+        "data": [bar(FROM, true), bar(FROM + MINUTE, false)],
+    })).unwrap();
+
+    assert_eq!(store.insert_confirmed(GetKlineCategory::Linear, &response).unwrap(), 1);
+    let klines = store.range(GetKlineCategory::Linear, SYMBOL, Interval::Minute1, minute(0), minute(10)).unwrap();
+    assert_eq!(klines, vec![kline(FROM)]);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_kline_store_backfill() {
+    let query = format!("category=linear&symbol={}&interval=1&start={}&end={}&limit=1000", SYMBOL, FROM + 3 * MINUTE, FROM + 5 * MINUTE - 1);
+    let body = json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {
+            "symbol": SYMBOL,
+            "category": "linear",
+            "list": [
+                [(FROM + 4 * MINUTE).to_string(), "100", "110", "90", "105", "1", "100"],
+                [(FROM + 3 * MINUTE).to_string(), "100", "110", "90", "105", "1", "100"],
+            ],
+        },
+        "retExtInfo": {},
+        "time": 1704067500000u64,
+    }).to_string();
+    let transport = MockTransport::new().with_response(HttpMethod::Get, PATH, Some(&query), &body);
+    let api = setup_api_mock(&transport);
+    let dir = temp_dir("backfill");
+    let store = KlineStore::open(&dir).unwrap();
+    store.insert(GetKlineCategory::Linear, SYMBOL, Interval::Minute1, &[kline(FROM), kline(FROM + MINUTE), kline(FROM + 2 * MINUTE)]).unwrap();
+
+    let inserted = store.backfill(&api, GetKlineCategory::Linear, SYMBOL, Interval::Minute1, minute(0), minute(5)).await.unwrap();
+    assert_eq!(inserted, 2);
+    let klines = store.range(GetKlineCategory::Linear, SYMBOL, Interval::Minute1, minute(0), minute(5)).unwrap();
+    assert_eq!(timestamps(&klines), (0..5).map(|n| FROM + n * MINUTE).collect::<Vec<u64>>());
+
+    // 欠けがなければリクエストしない
+    let inserted = store.backfill(&api, GetKlineCategory::Linear, SYMBOL, Interval::Minute1, minute(0), minute(5)).await.unwrap();
+    assert_eq!(inserted, 0);
+    assert_eq!(transport.requests().len(), 1);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_kline_store_backfill_error_source() {
+    let transport = MockTransport::new();
+    let api = setup_api_mock(&transport);
+    let dir = temp_dir("backfill_error");
+    let store = KlineStore::open(&dir).unwrap();
+
+    match store.backfill(&api, GetKlineCategory::Linear, SYMBOL, Interval::Minute1, minute(0), minute(5)).await {
+        Ok(inserted) => assert!(false, "Backfill should have failed: {}", inserted),
+        Err(err) => {
+            assert!(matches!(err, KlineStoreError::Api(_)), "{:?}", err);
+            // 元の BybitError に辿れる
+            let source = std::error::Error::source(&err).and_then(|source| source.downcast_ref::<BybitError>());
+            assert_eq!(source.and_then(BybitError::status), Some(404));
+        },
+    }
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,283 @@
+use rsbit::{
+    v5::{
+        api::{
+            get::market::get_kline::{
+                GetKlineParameters,
+                GetKlineCategory,
+            },
+            post::trade::place_order::{
+                PlaceOrderParameters,
+                PlaceOrderCategory,
+            },
+            transport::{
+                HttpMethod,
+                HttpResponse,
+                MockTransport,
+            },
+        },
+        time_sync::TimeSync,
+        types::{
+            Interval,
+            Side,
+            OrderType,
+        },
+    },
+    error::BybitErrorKind,
+};
+use reqwest::header::{
+    HeaderMap,
+    HeaderValue,
+};
+use crate::common::{
+    setup_api_mock,
+    number,
+};
+use std::time::Duration;
+
+const GET_KLINE: &str = include_str!("../fixtures/get_kline.json");
+const PLACE_ORDER: &str = include_str!("../fixtures/place_order.json");
+const PLACE_ORDER_INSUFFICIENT_BALANCE: &str = include_str!("../fixtures/place_order_insufficient_balance.json");
+const GET_BYBIT_SERVER_TIME: &str = include_str!("../fixtures/get_bybit_server_time.json");
+
+fn place_order_params() -> PlaceOrderParameters {
+    PlaceOrderParameters::new(
+        PlaceOrderCategory::Linear,
+        "BTCUSDT".to_string(),
+        Side::Buy,
+        OrderType::Limit,
+        number("0.01"),
+    ).with_price(number("15000"))
+}
+
+#[tokio::test]
+async fn test_mock_get_kline_success() {
+    let transport = MockTransport::new().with_response(
+        HttpMethod::Get,
+        "/v5/market/kline",
+        Some("symbol=BTCUSDT&category=linear&interval=60"),
+        GET_KLINE,
+    );
+    let api = setup_api_mock(&transport);
+    let params = GetKlineParameters::new(GetKlineCategory::Linear, "BTCUSDT".to_string(), Interval::Hour1);
+
+    match api.get_kline(params).await {
+        Ok(result) => {
+            assert_eq!(result.ret_code(), 0);
+            assert_eq!(result.result().list().len(), 3);
+            assert_eq!(result.result().list()[0].timestamp(), 1670608800000);
+            assert_eq!(result.result().list()[0].close(), number("17055.5"));
+        },
+        Err(err) => {
+            assert!(false, "Failed to get kline: {:?}", err);
+        }
+    }
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].headers().get("X-BAPI-SIGN").is_none());
+}
+
+#[tokio::test]
+async fn test_mock_unmatched_request() {
+    let transport = MockTransport::new();
+    let api = setup_api_mock(&transport);
+    let params = GetKlineParameters::new(GetKlineCategory::Linear, "BTCUSDT".to_string(), Interval::Hour1);
+
+    match api.get_kline(params).await {
+        Ok(result) => assert!(false, "Request should not have succeeded: {:?}", result),
+        Err(err) => assert_eq!(err.status(), Some(404)),
+    }
+}
+
+#[tokio::test]
+async fn test_mock_place_order_signed() {
+    let transport = MockTransport::new().with_response(HttpMethod::Post, "/v5/order/create", None, PLACE_ORDER);
+    let api = setup_api_mock(&transport);
+
+    match api.place_order(place_order_params()).await {
+        Ok(result) => assert_eq!(result.result().order_id(), "1321003749386327552"),
+        Err(err) => assert!(false, "Failed to place order: {:?}", err),
+    }
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    let headers = requests[0].headers();
+    assert_eq!(headers.get("X-BAPI-API-KEY").and_then(|value| value.to_str().ok()), Some("XXXXXXXXXX"));
+    assert!(headers.get("X-BAPI-SIGN").is_some());
+    assert!(headers.get("X-BAPI-TIMESTAMP").is_some());
+    let body: serde_json::Value = serde_json::from_str(requests[0].body().unwrap_or_default()).expect("Body is not JSON");
+    assert_eq!(body["symbol"], "BTCUSDT");
+    assert_eq!(body["side"], "Buy");
+    assert_eq!(body["orderType"], "Limit");
+    assert_eq!(body["qty"], "0.01");
+    assert_eq!(body["price"], "15000");
+}
+
+#[tokio::test]
+async fn test_mock_place_order_error() {
+    let transport = MockTransport::new().with_response(HttpMethod::Post, "/v5/order/create", None, PLACE_ORDER_INSUFFICIENT_BALANCE);
+    let api = setup_api_mock(&transport);
+
+    match api.place_order(place_order_params()).await {
+        Ok(result) => assert!(false, "Request should not have succeeded: {:?}", result),
+        Err(err) => {
+            assert_eq!(err.kind(), BybitErrorKind::InsufficientBalance);
+            assert_eq!(err.endpoint(), Some("/v5/order/create"));
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_mock_retry_get() {
+    let transport = MockTransport::new()
+        .with_http_response(HttpMethod::Get, "/v5/market/kline", None, HttpResponse::new(502, HeaderMap::new(), String::new()))
+        .with_response(HttpMethod::Get, "/v5/market/kline", None, GET_KLINE);
+    let api = setup_api_mock(&transport);
+    let params = GetKlineParameters::new(GetKlineCategory::Linear, "BTCUSDT".to_string(), Interval::Hour1);
+
+    assert!(api.get_kline(params).await.is_ok());
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_mock_retry_post_requires_order_link_id() {
+    let transport = MockTransport::new()
+        .with_http_response(HttpMethod::Post, "/v5/order/create", None, HttpResponse::new(502, HeaderMap::new(), String::new()))
+        .with_response(HttpMethod::Post, "/v5/order/create", None, PLACE_ORDER);
+    let api = setup_api_mock(&transport);
+
+    assert!(api.place_order(place_order_params()).await.is_err());
+    assert_eq!(transport.requests().len(), 1);
+
+    let params = place_order_params().with_order_link_id("spot-test-postonly".to_string());
+    assert!(api.place_order(params).await.is_ok());
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_mock_retry_post_ignores_empty_order_link_id() {
+    let transport = MockTransport::new()
+        .with_http_response(HttpMethod::Post, "/v5/order/create", None, HttpResponse::new(502, HeaderMap::new(), String::new()))
+        .with_response(HttpMethod::Post, "/v5/order/create", None, PLACE_ORDER);
+    let api = setup_api_mock(&transport);
+
+    // 空の orderLinkId では重複を防げないので再試行しない
+    let params = place_order_params().with_order_link_id(String::new());
+    match api.place_order(params).await {
+        Ok(response) => assert!(false, "Request should not have been retried: {:?}", response),
+        Err(err) => assert_eq!(err.status(), Some(502)),
+    }
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn test_mock_rate_limit_status() {
+    let mut headers = HeaderMap::new();
+    headers.insert("X-Bapi-Limit", HeaderValue::from_static("10"));
+    headers.insert("X-Bapi-Limit-Status", HeaderValue::from_static("9"));
+    headers.insert("X-Bapi-Limit-Reset-Timestamp", HeaderValue::from_static("1672211918471"));
+    let transport = MockTransport::new()
+        .with_http_response(HttpMethod::Post, "/v5/order/create", None, HttpResponse::new(200, headers, PLACE_ORDER.to_string()));
+    let api = setup_api_mock(&transport);
+    let shared = api.clone();
+
+    assert!(api.place_order(place_order_params()).await.is_ok());
+    match shared.rate_limit_status("/v5/order/create") {
+        Some(status) => {
+            assert_eq!(status.limit(), 10);
+            assert_eq!(status.remaining(), 9);
+        },
+        None => assert!(false, "Rate limit status was not recorded"),
+    }
+}
+
+#[tokio::test]
+async fn test_mock_time_sync() {
+    let transport = MockTransport::new()
+        .with_response(HttpMethod::Get, "/v5/market/time", None, GET_BYBIT_SERVER_TIME)
+        .with_response(HttpMethod::Post, "/v5/order/create", None, PLACE_ORDER);
+    let time_sync = TimeSync::new(Duration::from_secs(60));
+    let api = setup_api_mock(&transport).with_time_sync(time_sync.clone());
+
+    assert!(api.place_order(place_order_params()).await.is_ok());
+    assert!(!time_sync.is_stale());
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].path(), "/v5/market/time");
+    let timestamp = requests[1].headers().get("X-BAPI-TIMESTAMP")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<i64>().ok())
+        .unwrap_or_default();
+    assert!((timestamp - 1688639403423).abs() < 60_000, "Timestamp was not shifted to server time: {}", timestamp);
+}
+
+#[tokio::test]
+async fn test_mock_time_sync_backs_off_after_failure() {
+    let synced = MockTransport::new().with_response(HttpMethod::Get, "/v5/market/time", None, GET_BYBIT_SERVER_TIME);
+    let time_sync = TimeSync::new(Duration::from_secs(60));
+    assert!(time_sync.sync(&setup_api_mock(&synced)).await.is_ok());
+    time_sync.invalidate();
+
+    // サーバー時刻が取れない間は前回のずれを使い、リクエストごとに取り直さない
+    let transport = MockTransport::new().with_response(HttpMethod::Post, "/v5/order/create", None, PLACE_ORDER);
+    let api = setup_api_mock(&transport).with_time_sync(time_sync.clone());
+    assert!(api.place_order(place_order_params()).await.is_ok());
+    assert!(api.place_order(place_order_params()).await.is_ok());
+
+    let requests = transport.requests();
+    let paths: Vec<&str> = requests.iter().map(|request| request.path()).collect();
+    assert_eq!(paths, vec!["/v5/market/time", "/v5/order/create", "/v5/order/create"]);
+}
+
+#[tokio::test]
+async fn test_mock_http_error_keeps_body_and_headers() {
+    let mut headers = HeaderMap::new();
+    headers.insert("X-Bapi-Limit-Reset-Timestamp", HeaderValue::from_static("1672211918471"));
+    headers.insert("Traceid", HeaderValue::from_static("4e4e2b5ee1b6b8e6"));
+    headers.insert("Content-Type", HeaderValue::from_static("text/html"));
+    let body = "<html><body>The request could not be satisfied.</body></html>";
+    let transport = MockTransport::new()
+        .with_http_response(HttpMethod::Get, "/v5/market/kline", None, HttpResponse::new(403, headers, body.to_string()));
+    let api = setup_api_mock(&transport);
+    let params = GetKlineParameters::new(GetKlineCategory::Linear, "BTCUSDT".to_string(), Interval::Hour1);
+
+    match api.get_kline(params).await {
+        Ok(result) => assert!(false, "Request should not have succeeded: {:?}", result),
+        Err(err) => {
+            assert_eq!(err.kind(), BybitErrorKind::IpBanned);
+            assert_eq!(err.endpoint(), Some("/v5/market/kline"));
+            assert_eq!(err.body(), Some(body));
+            let headers = err.headers().expect("Headers were not kept");
+            assert_eq!(headers.get("Traceid").and_then(|value| value.to_str().ok()), Some("4e4e2b5ee1b6b8e6"));
+            assert!(headers.get("X-Bapi-Limit-Reset-Timestamp").is_some());
+            assert!(headers.get("Content-Type").is_none());
+            assert_eq!(err.retry_after(), Some(Duration::ZERO));
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_mock_with_meta() {
+    let mut headers = HeaderMap::new();
+    headers.insert("X-Bapi-Limit", HeaderValue::from_static("10"));
+    headers.insert("X-Bapi-Limit-Status", HeaderValue::from_static("9"));
+    headers.insert("X-Bapi-Limit-Reset-Timestamp", HeaderValue::from_static("1672211918471"));
+    headers.insert("Traceid", HeaderValue::from_static("4e4e2b5ee1b6b8e6"));
+    let transport = MockTransport::new()
+        .with_http_response(HttpMethod::Post, "/v5/order/create", None, HttpResponse::new(200, headers, PLACE_ORDER.to_string()));
+    let api = setup_api_mock(&transport);
+
+    match api.with_meta(|api| async move { api.place_order(place_order_params()).await }).await {
+        Ok(response) => {
+            assert_eq!(response.result().result().order_id(), "1321003749386327552");
+            let meta = response.meta();
+            assert_eq!(meta.endpoint(), "/v5/order/create");
+            assert_eq!(meta.status(), 200);
+            assert_eq!(meta.trace_id(), Some("4e4e2b5ee1b6b8e6"));
+            assert_eq!(meta.rate_limit_status().map(|status| status.remaining()), Some(9));
+            assert_eq!(meta.raw(), PLACE_ORDER);
+        },
+        Err(err) => assert!(false, "Failed to place order: {:?}", err),
+    }
+}
@@ -0,0 +1,6 @@
+mod mock_transport_test;
+mod order_validation_test;
+mod batch_items_test;
+mod pagination_test;
+mod download_klines_test;
+mod kline_store_test;
@@ -0,0 +1,146 @@
+use rsbit::{
+    v5::{
+        api::{
+            post::trade::{
+                amend_order::{
+                    AmendOrderParameters,
+                    AmendOrderCategory,
+                },
+                batch_place_order::{
+                    BatchPlaceOrderParameters,
+                    BatchPlaceOrderRequestParameters,
+                    BatchPlaceOrderCategory,
+                },
+                place_order::{
+                    PlaceOrderParameters,
+                    PlaceOrderCategory,
+                },
+                validation::OrderValidationIssue,
+            },
+            transport::{
+                HttpMethod,
+                MockTransport,
+            },
+        },
+        types::{
+            Side,
+            OrderType,
+            PositionIdx,
+            TpslMode,
+        },
+    },
+    error::{
+        BybitError,
+        BybitErrorKind,
+    },
+};
+use crate::common::{
+    setup_api_mock,
+    number,
+};
+
+const PLACE_ORDER: &str = include_str!("../fixtures/place_order.json");
+
+#[test]
+fn test_place_order_validate() {
+    let params = PlaceOrderParameters::new(
+        PlaceOrderCategory::Spot,
+        "BTCUSDT".to_string(),
+        Side::Buy,
+        OrderType::Limit,
+        number("0.01"),
+    )
+        .with_trigger_direction(1)
+        .with_tp_limit_price("31000".to_string())
+        .with_position_idx(PositionIdx::OneWay);
+
+    let err = params.validate().unwrap_err();
+    assert_eq!(err.issues(), &[
+        OrderValidationIssue::MissingPrice,
+        OrderValidationIssue::TriggerDirectionWithoutTriggerPrice,
+        OrderValidationIssue::LimitTpslWithoutPartialMode,
+        OrderValidationIssue::PositionIdxOnSpot,
+    ]);
+
+    let params = PlaceOrderParameters::new(
+        PlaceOrderCategory::Option,
+        "BTC-29DEC23-40000-C".to_string(),
+        Side::Buy,
+        OrderType::Market,
+        number("0.01"),
+    );
+    assert_eq!(params.validate().unwrap_err().issues(), &[OrderValidationIssue::MissingOrderLinkId]);
+    assert!(params.with_order_link_id("option-1".to_string()).validate().is_ok());
+
+    let params = PlaceOrderParameters::new(
+        PlaceOrderCategory::Linear,
+        "BTCUSDT".to_string(),
+        Side::Buy,
+        OrderType::Limit,
+        number("0.01"),
+    )
+        .with_price(number("30000"))
+        .with_tpsl_mode(TpslMode::Partial)
+        .with_tp_limit_price("31000".to_string());
+    assert!(params.validate().is_ok());
+}
+
+#[test]
+fn test_amend_and_batch_validate() {
+    let params = AmendOrderParameters::new(AmendOrderCategory::Linear, "BTCUSDT".to_string())
+        .with_sl_limit_price("29000".to_string());
+    assert_eq!(params.validate().unwrap_err().issues(), &[
+        OrderValidationIssue::MissingOrderId,
+        OrderValidationIssue::LimitTpslWithoutPartialMode,
+    ]);
+
+    let params = BatchPlaceOrderParameters::new(BatchPlaceOrderCategory::Option, vec![
+        BatchPlaceOrderRequestParameters::new("BTC-29DEC23-40000-C".to_string(), Side::Buy, OrderType::Market, number("0.01"))
+            .with_order_link_id("option-1".to_string()),
+        BatchPlaceOrderRequestParameters::new("BTC-29DEC23-40000-C".to_string(), Side::Buy, OrderType::Limit, number("0.01")),
+    ]);
+    let err = params.validate().unwrap_err();
+    assert_eq!(err.issues(), &[
+        OrderValidationIssue::Request { index: 1, issue: Box::new(OrderValidationIssue::MissingPrice) },
+        OrderValidationIssue::Request { index: 1, issue: Box::new(OrderValidationIssue::MissingOrderLinkId) },
+    ]);
+    assert_eq!(
+        err.to_string(),
+        "Invalid order parameters: request 1: price is required for a Limit order; request 1: order_link_id is required for an option order",
+    );
+
+    let params = BatchPlaceOrderParameters::new(BatchPlaceOrderCategory::Linear, vec![]);
+    assert_eq!(params.validate().unwrap_err().issues(), &[OrderValidationIssue::EmptyBatch]);
+}
+
+#[tokio::test]
+async fn test_place_order_rejected_before_sending() {
+    let transport = MockTransport::new().with_response(HttpMethod::Post, "/v5/order/create", None, PLACE_ORDER);
+    let api = setup_api_mock(&transport);
+    let params = PlaceOrderParameters::new(
+        PlaceOrderCategory::Linear,
+        "BTCUSDT".to_string(),
+        Side::Buy,
+        OrderType::Limit,
+        number("0.01"),
+    );
+
+    match api.place_order(params.clone()).await {
+        Ok(result) => assert!(false, "Request should not have succeeded: {:?}", result),
+        Err(err) => {
+            assert_eq!(err.kind(), BybitErrorKind::InvalidParameter);
+            match err {
+                BybitError::InvalidOrder(err) => assert_eq!(err.issues(), &[OrderValidationIssue::MissingPrice]),
+                err => assert!(false, "Unexpected error: {:?}", err),
+            }
+        }
+    }
+    assert!(transport.requests().is_empty());
+
+    let api = api.with_order_validation(false);
+    match api.place_order(params).await {
+        Ok(result) => assert_eq!(result.result().order_id(), "1321003749386327552"),
+        Err(err) => assert!(false, "Failed to place order: {:?}", err),
+    }
+    assert_eq!(transport.requests().len(), 1);
+}
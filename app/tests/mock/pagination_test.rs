@@ -0,0 +1,140 @@
+use rsbit::v5::{
+    api::{
+        get::position::get_closed_pnl::{
+            GetClosedPnlParameters,
+            GetClosedPnlCategory,
+        },
+        transport::{
+            HttpMethod,
+            MockTransport,
+        },
+    },
+};
+use chrono::{
+    TimeZone,
+    Utc,
+};
+use futures_util::StreamExt;
+use serde_json::{
+    json,
+    Value,
+};
+use crate::common::setup_api_mock;
+
+const PATH: &str = "/v5/position/closed-pnl";
+
+fn closed_pnl(order_id: &str, created_time: u64) -> Value {
+    json!({
+        "symbol": "BTCUSDT",
+        "orderType": "Market",
+        "leverage": "10",
+        "updatedTime": "1672214887236",
+        "side": "Sell",
+        "orderId": order_id,
+        "closedPnl": "-0.00161199",
+        "avgEntryPrice": "16823.5",
+        "qty": "0.01",
+        "cumEntryValue": "168.235",
+        "createdTime": created_time.to_string(),
+        "orderPrice": "15991.5",
+        "closedSize": "0.01",
+        "avgExitPrice": "16832",
+        "execType": "Trade",
+        "fillCount": "1",
+        "cumExitValue": "168.32",
+    })
+}
+
+fn page(order_ids: &[&str], cursor: &str) -> String {
+    let list: Vec<(&str, u64)> = order_ids.iter().map(|order_id| (*order_id, 1672214887231)).collect();
+    timed_page(&list, cursor)
+}
+
+fn timed_page(list: &[(&str, u64)], cursor: &str) -> String {
+    json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {
+            "nextPageCursor": cursor,
+            "category": "linear",
+            "list": list.iter().map(|(order_id, created_time)| closed_pnl(order_id, *created_time)).collect::<Vec<Value>>(),
+        },
+        "retExtInfo": {},
+        "time": 1672284129153u64,
+    }).to_string()
+}
+
+fn setup_transport() -> MockTransport {
+    MockTransport::new()
+        .with_response(HttpMethod::Get, PATH, Some("category=linear&limit=2"), &page(&["1", "2"], "page2"))
+        .with_response(HttpMethod::Get, PATH, Some("category=linear&limit=2&cursor=page2"), &page(&["3", "4"], "page3"))
+        .with_response(HttpMethod::Get, PATH, Some("category=linear&limit=2&cursor=page3"), &page(&["5"], ""))
+}
+
+#[tokio::test]
+async fn test_stream_follows_cursor() {
+    let transport = setup_transport();
+    let api = setup_api_mock(&transport);
+    let params = GetClosedPnlParameters::new(GetClosedPnlCategory::Linear).with_limit(2);
+
+    let order_ids: Vec<String> = api.get_closed_pnl_stream(params, None)
+        .map(|item| item.unwrap().order_id().to_string())
+        .collect()
+        .await;
+    assert_eq!(order_ids, vec!["1", "2", "3", "4", "5"]);
+    assert_eq!(transport.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn test_stream_max_items() {
+    let transport = setup_transport();
+    let api = setup_api_mock(&transport);
+    let params = GetClosedPnlParameters::new(GetClosedPnlCategory::Linear).with_limit(2);
+
+    let order_ids: Vec<String> = api.get_closed_pnl_stream(params, Some(3))
+        .map(|item| item.unwrap().order_id().to_string())
+        .collect()
+        .await;
+    assert_eq!(order_ids, vec!["1", "2", "3"]);
+    // 上限に達したら次のページは取得しない
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_stream_ends_on_error() {
+    let transport = MockTransport::new()
+        .with_response(HttpMethod::Get, PATH, Some("category=linear&limit=2"), &page(&["1", "2"], "page2"));
+    let api = setup_api_mock(&transport);
+    let params = GetClosedPnlParameters::new(GetClosedPnlCategory::Linear).with_limit(2);
+
+    let items: Vec<_> = api.get_closed_pnl_stream(params, None).collect().await;
+    assert_eq!(items.len(), 3);
+    assert!(items[0].is_ok());
+    assert!(items[1].is_ok());
+    match &items[2] {
+        Ok(item) => assert!(false, "Request should have failed: {:?}", item),
+        Err(err) => assert_eq!(err.status(), Some(404)),
+    }
+}
+
+#[tokio::test]
+async fn test_range_stream_splits_windows() {
+    let transport = MockTransport::new()
+        .with_response(HttpMethod::Get, PATH, Some("category=linear&startTime=1704067200000&endTime=1704671999999"), &timed_page(&[("3", 1704600000000), ("2", 1704500000000)], "w1p2"))
+        .with_response(HttpMethod::Get, PATH, Some("category=linear&startTime=1704067200000&endTime=1704671999999&cursor=w1p2"), &timed_page(&[("1", 1704100000000)], ""))
+        .with_response(HttpMethod::Get, PATH, Some("category=linear&startTime=1704672000000&endTime=1705276799999"), &timed_page(&[("3", 1704800000000), ("4", 1704700000000), ("3", 1704600000000)], ""))
+        .with_response(HttpMethod::Get, PATH, Some("category=linear&startTime=1705276800000&endTime=1705363199999"), &timed_page(&[], ""));
+    let api = setup_api_mock(&transport);
+    let params = GetClosedPnlParameters::new(GetClosedPnlCategory::Linear);
+    let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2024, 1, 16, 0, 0, 0).unwrap();
+
+    let order_ids: Vec<String> = api.get_closed_pnl_range_stream(params, from, to)
+        .map(|item| item.unwrap().order_id().to_string())
+        .collect()
+        .await;
+    // 古い順に並び、窓の境界で重複した "3" は一度だけ返る
+    // 同じ注文でも作成時刻の異なる記録は別に返る
+    assert_eq!(order_ids, vec!["1", "2", "3", "4", "3"]);
+    assert_eq!(transport.requests().len(), 4);
+}
@@ -1,4 +1,11 @@
+#![allow(clippy::assertions_on_constants)]
+
 mod get;
 mod post;
 mod common;
-mod ws;
\ No newline at end of file
+mod ws;
+mod error;
+mod api;
+mod auth;
+mod mock;
+mod types;
\ No newline at end of file
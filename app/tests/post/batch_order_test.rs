@@ -1,3 +1,7 @@
+use rsbit::v5::types::{
+    Side,
+    OrderType,
+};
 use rsbit::v5::api::{
     get::market::get_tickers::{
         GetTickersParameters,
@@ -25,7 +29,10 @@ use rsbit::v5::api::{
         }
     }
 };
-use crate::common::setup_api_private;
+use crate::common::{
+    setup_api_private,
+    number,
+};
 
 #[tokio::test]
 async fn test_batch_order_success() {
@@ -43,12 +50,12 @@ async fn test_batch_order_success() {
         panic!();
     }).list()[0].last_price();
     
-    let target_price = price - 1000.0;
+    let target_price = price - number("1000.0");
     let request = BatchPlaceOrderRequestParameters::new(
         target_symbol.clone(),
-        "Buy".to_string(),
-        "Limit".to_string(),
-        0.01,
+        Side::Buy,
+        OrderType::Limit,
+        number("0.01"),
     ).with_price(target_price);
     let params = BatchPlaceOrderParameters::new(
         BatchPlaceOrderCategory::Linear,
@@ -66,9 +73,9 @@ async fn test_batch_order_success() {
             let params = BatchAmendOrderParameters::new(
                 BatchAmendOrderCategory::Linear,
                 vec![
-                    BatchAmendOrderRequestParameters::new(target_symbol.clone()).with_order_id(result.result().list()[0].order_id().to_string()).with_qty(0.02),
-                    BatchAmendOrderRequestParameters::new(target_symbol.clone()).with_order_id(result.result().list()[1].order_id().to_string()).with_qty(0.02),
-                    BatchAmendOrderRequestParameters::new(target_symbol.clone()).with_order_id(result.result().list()[2].order_id().to_string()).with_qty(0.02),
+                    BatchAmendOrderRequestParameters::new(target_symbol.clone()).with_order_id(result.result().list()[0].order_id().to_string()).with_qty(number("0.02")),
+                    BatchAmendOrderRequestParameters::new(target_symbol.clone()).with_order_id(result.result().list()[1].order_id().to_string()).with_qty(number("0.02")),
+                    BatchAmendOrderRequestParameters::new(target_symbol.clone()).with_order_id(result.result().list()[2].order_id().to_string()).with_qty(number("0.02")),
                 ],
             );
             let amend_result = api.batch_amend_order(params).await;
@@ -135,9 +142,9 @@ async fn test_batch_order_fail() {
     let api = setup_api_private();
     let request = BatchPlaceOrderRequestParameters::new(
         "XXXXXXX".to_string(),
-        "Buy".to_string(),
-        "Market".to_string(),
-        0.01,
+        Side::Buy,
+        OrderType::Market,
+        number("0.01"),
     );
     let params = BatchPlaceOrderParameters::new(
         BatchPlaceOrderCategory::Linear,
@@ -167,14 +174,14 @@ async fn test_batch_order_fail() {
         Ok(result) => {
             // Error of batch amend order is output to ret_ext_info
             if let Some(list) = result.ret_ext_info().get("list").and_then(|list| list.as_array()) {
-                if let Some(item) = list.get(0) {
+                if let Some(item) = list.first() {
                     let code = item.get("code").and_then(|code| code.as_i64());
                     let msg = item.get("msg").and_then(|msg| msg.as_str());
                     if code.is_some() && msg.is_some() {
                         match code {
                             Some(code) => {
                                 if code == 0 {
-                                    assert!(false, "Request should not have succeeded: {:?}", msg.unwrap());
+                                    assert!(false, "Request should not have succeeded: {:?}", msg);
                                 }
                             },
                             None => {
@@ -203,14 +210,14 @@ async fn test_batch_order_fail() {
         Ok(result) => {
             // Error of batch cancel order is output to ret_ext_info
             if let Some(list) = result.ret_ext_info().get("list").and_then(|list| list.as_array()) {
-                if let Some(item) = list.get(0) {
+                if let Some(item) = list.first() {
                     let code = item.get("code").and_then(|code| code.as_i64());
                     let msg = item.get("msg").and_then(|msg| msg.as_str());
                     if code.is_some() && msg.is_some() {
                         match code {
                             Some(code) => {
                                 if code == 0 {
-                                    assert!(false, "Request should not have succeeded: {:?}", msg.unwrap());
+                                    assert!(false, "Request should not have succeeded: {:?}", msg);
                                 }
                             },
                             None => {
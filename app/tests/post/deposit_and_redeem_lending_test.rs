@@ -3,14 +3,17 @@ use rsbit::v5::api::post::lending::{
     redeem_funds::RedeemFundsParameters,
     // cancel_redeem::CancelRedeemParameters
 };
-use crate::common::setup_api_private;
+use crate::common::{
+    setup_api_private,
+    number,
+};
 
 #[tokio::test]
 async fn test_deposit_funds_success() {
     let api = setup_api_private();
     let params = DepositFundsParameters::new(
         "USDT".to_string(),
-        10.0,
+        number("10.0"),
     );
     let result = api.deposit_funds(params).await;
     match result {
@@ -25,7 +28,7 @@ async fn test_deposit_funds_success() {
 
     let params = RedeemFundsParameters::new(
         "USDT".to_string(),
-        10.0,
+        number("10.0"),
     );
 
     let result = api.redeem_funds(params).await;
@@ -1,3 +1,7 @@
+use rsbit::v5::types::{
+    Side,
+    OrderType,
+};
 use rsbit::v5::api::{
     get::{
         market::get_tickers::{
@@ -24,7 +28,10 @@ use rsbit::v5::api::{
         }
     }
 };
-use crate::common::setup_api_private;
+use crate::common::{
+    setup_api_private,
+    number,
+};
 
 #[tokio::test]
 async fn test_order_success() {
@@ -42,13 +49,13 @@ async fn test_order_success() {
         panic!();
     }).list()[0].last_price();
     
-    let target_price = price - 1000.0;
+    let target_price = price - number("1000.0");
     let params = PlaceOrderParameters::new(
         PlaceOrderCategory::Linear,
         target_symbol.clone(),
-        "Buy".to_string(),
-        "Limit".to_string(),
-        0.01,
+        Side::Buy,
+        OrderType::Limit,
+        number("0.01"),
     ).with_price(target_price);
     
     let result = api.place_order(params).await;
@@ -64,7 +71,7 @@ async fn test_order_success() {
                 target_symbol.clone(),
             ).with_order_id(
                 order_id.to_string()
-            ).with_qty(0.02);
+            ).with_qty(number("0.02"));
 
             let result = api.amend_order(params).await;
             match result {
@@ -122,9 +129,9 @@ async fn test_order_fail() {
     let params = PlaceOrderParameters::new(
         PlaceOrderCategory::Linear,
         "XXXXXXX".to_string(),
-        "Buy".to_string(),
-        "Market".to_string(),
-        0.01,
+        Side::Buy,
+        OrderType::Market,
+        number("0.01"),
     );
 
     let result = api.place_order(params).await;
@@ -142,7 +149,7 @@ async fn test_order_fail() {
         "XXXXXXX".to_string(),
     ).with_order_id(
         "1234567890".to_string()
-    ).with_qty(0.02);
+    ).with_qty(number("0.02"));
 
     let result = api.amend_order(params).await;
     match result {
@@ -2,7 +2,10 @@ use rsbit::v5::api::post::position::set_leverage::{
     SetLeverageCategory,
     SetLeverageParameters,
 };
-use crate::common::setup_api_private;
+use crate::common::{
+    setup_api_private,
+    number,
+};
 
 #[tokio::test]
 async fn test_set_leverage_success() {
@@ -10,8 +13,8 @@ async fn test_set_leverage_success() {
     let params = SetLeverageParameters::new(
         SetLeverageCategory::Linear,
         "BTCUSDT".to_string(),
-        10.0,
-        10.0,
+        number("10.0"),
+        number("10.0"),
     );
     let result = api.set_leverage(params).await;
     match result {
@@ -27,8 +30,8 @@ async fn test_set_leverage_success() {
     let params = SetLeverageParameters::new(
         SetLeverageCategory::Linear,
         "BTCUSDT".to_string(),
-        12.0,
-        12.0,
+        number("12.0"),
+        number("12.0"),
     );
     let result = api.set_leverage(params).await;
     match result {
@@ -48,8 +51,8 @@ async fn test_set_leverage_fail() {
     let params = SetLeverageParameters::new(
         SetLeverageCategory::Linear,
         "XXXXXXX".to_string(),
-        10.0,
-        10.0,
+        number("10.0"),
+        number("10.0"),
     );
     let result = api.set_leverage(params).await;
     match result {
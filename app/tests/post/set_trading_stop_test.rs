@@ -1,3 +1,4 @@
+use rsbit::v5::types::PositionIdx;
 use rsbit::v5::api::{
     get::market::get_tickers::{
         GetTickersParameters,
@@ -8,7 +9,10 @@ use rsbit::v5::api::{
         SetTradingStopParameters,
     }
 };
-use crate::common::setup_api_private;
+use crate::common::{
+    setup_api_private,
+    number,
+};
 
 #[tokio::test]
 async fn test_set_trading_stop_success() {
@@ -29,8 +33,8 @@ async fn test_set_trading_stop_success() {
     let params = SetTradingStopParameters::new(
         SetTradingStopCategory::Linear,
         "BTCUSDT".to_string(),
-        0
-    ).with_take_profit(price + 10000.0);
+        PositionIdx::OneWay
+    ).with_take_profit(price + number("10000.0"));
     let result = api.set_trading_stop(params).await;
     match result {
         Ok(result) => {
@@ -45,8 +49,8 @@ async fn test_set_trading_stop_success() {
     let params = SetTradingStopParameters::new(
         SetTradingStopCategory::Linear,
         "BTCUSDT".to_string(),
-        0
-    ).with_take_profit(price + 10001.0);
+        PositionIdx::OneWay
+    ).with_take_profit(price + number("10001.0"));
     let result = api.set_trading_stop(params).await;
     match result {
         Ok(result) => {
@@ -66,7 +70,7 @@ async fn test_set_trading_stop_fail() {
     let params = SetTradingStopParameters::new(
         SetTradingStopCategory::Inverse,
         "XXXXXXX".to_string(),
-        1
+        PositionIdx::HedgeBuy
     );
     let result = api.set_trading_stop(params).await;
     match result {
@@ -2,7 +2,10 @@ use rsbit::v5::api::post::position::switch_cross_isolated_margin::{
     SwitchCrossIsolatedMarginCategory,
     SwitchCrossIsolatedMarginParameters,
 };
-use crate::common::setup_api_private;
+use crate::common::{
+    setup_api_private,
+    number,
+};
 
 #[tokio::test]
 async fn test_switch_cross_isolated_margin_success() {
@@ -11,8 +14,8 @@ async fn test_switch_cross_isolated_margin_success() {
         SwitchCrossIsolatedMarginCategory::Inverse,
         "BTCUSD".to_string(),
         1,
-        10.0,
-        10.0,
+        number("10.0"),
+        number("10.0"),
     );
     let result = api.switch_cross_isolated_margin(params).await;
     match result {
@@ -29,8 +32,8 @@ async fn test_switch_cross_isolated_margin_success() {
         SwitchCrossIsolatedMarginCategory::Inverse,
         "BTCUSD".to_string(),
         0,
-        10.0,
-        10.0,
+        number("10.0"),
+        number("10.0"),
     );
     let result = api.switch_cross_isolated_margin(params).await;
     match result {
@@ -51,8 +54,8 @@ async fn test_switch_cross_isolated_margin_fail() {
         SwitchCrossIsolatedMarginCategory::Inverse,
         "XXXXXXX".to_string(),
         0,
-        10.0,
-        10.0,
+        number("10.0"),
+        number("10.0"),
     );
     let result = api.switch_cross_isolated_margin(params).await;
     match result {
@@ -0,0 +1,43 @@
+use rsbit::v5::{
+    api::get::market::{
+        get_kline::GetKlineCategory,
+        get_open_interest::GetOpenInterestCategory,
+    },
+    category::{
+        Category,
+        CategoryKind,
+        LinearInverse,
+        LinearInverseOption,
+        OptionOnly,
+    },
+    ws::{
+        ChannelCategory,
+        private::position::PositionCategory,
+    },
+};
+
+#[test]
+fn test_category_serialize() {
+    assert_eq!(serde_json::to_value(GetKlineCategory::Spot).unwrap(), serde_json::json!("spot"));
+    assert_eq!(serde_json::to_value(GetOpenInterestCategory::Inverse).unwrap(), serde_json::json!("inverse"));
+    assert_eq!(<Category>::Option.to_string(), "option");
+}
+
+#[test]
+fn test_category_parse() {
+    assert_eq!("linear".parse::<GetOpenInterestCategory>(), Ok(GetOpenInterestCategory::Linear));
+    assert!("spot".parse::<GetOpenInterestCategory>().is_err());
+    assert!("futures".parse::<Category>().is_err());
+    assert!(serde_json::from_str::<Category<OptionOnly>>(r#""linear""#).is_err());
+    assert_eq!(serde_json::from_str::<Category<OptionOnly>>(r#""option""#).unwrap(), Category::Option);
+}
+
+#[test]
+fn test_category_conversion() {
+    let category = GetKlineCategory::Inverse.to_any();
+    assert_eq!(category.kind(), CategoryKind::Inverse);
+    assert_eq!(category.narrow::<LinearInverse>(), Some(GetOpenInterestCategory::Inverse));
+    assert_eq!(category.narrow::<LinearInverseOption>(), Some(PositionCategory::Inverse));
+    assert_eq!(GetKlineCategory::Spot.narrow::<LinearInverse>(), None);
+    assert_eq!(ChannelCategory::from(GetOpenInterestCategory::Linear), ChannelCategory::Linear);
+}
@@ -0,0 +1,59 @@
+use rsbit::v5::api::{
+    get::market::{
+        get_bybit_server_time::GetBybitServerTimeResponse,
+        get_kline::{
+            GetKlineParameters,
+            GetKlineCategory,
+        },
+    },
+    transport::{
+        HttpMethod,
+        MockTransport,
+    },
+};
+use rsbit::v5::types::Interval;
+use chrono::{
+    Duration,
+    TimeZone,
+    Utc,
+};
+use crate::common::setup_api_mock;
+
+const GET_KLINE: &str = include_str!("../fixtures/get_kline.json");
+const GET_BYBIT_SERVER_TIME: &str = include_str!("../fixtures/get_bybit_server_time.json");
+
+#[test]
+fn test_server_time_datetime() {
+    let response: GetBybitServerTimeResponse = serde_json::from_str(GET_BYBIT_SERVER_TIME).unwrap();
+    let time = Utc.with_ymd_and_hms(2023, 7, 6, 10, 30, 3).unwrap();
+
+    assert_eq!(response.result().time_second_datetime(), time);
+    assert_eq!(response.time_datetime(), time + Duration::milliseconds(423));
+    assert_eq!(response.result().time_nano_datetime(), time + Duration::nanoseconds(423213947));
+}
+
+#[tokio::test]
+async fn test_kline_datetime() {
+    let start = Utc.with_ymd_and_hms(2022, 12, 9, 16, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2022, 12, 9, 18, 0, 0).unwrap();
+    let transport = MockTransport::new().with_response(
+        HttpMethod::Get,
+        "/v5/market/kline",
+        Some("category=linear&symbol=BTCUSDT&interval=60&start=1670601600000&end=1670608800000"),
+        GET_KLINE,
+    );
+    let api = setup_api_mock(&transport);
+    let params = GetKlineParameters::new(GetKlineCategory::Linear, "BTCUSDT".to_string(), Interval::Hour1)
+        .with_start_datetime(start)
+        .with_end_datetime(end);
+
+    match api.get_kline(params).await {
+        Ok(result) => {
+            assert_eq!(result.result().list()[0].timestamp_datetime(), end);
+            assert_eq!(result.result().list()[2].timestamp_datetime(), start);
+        },
+        Err(err) => {
+            assert!(false, "Failed to get kline: {:?}", err);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use rsbit::v5::{
+    api::get::market::get_kline::GetKlineResponse,
+    export::{
+        Exporter,
+        Format,
+    },
+    ws::public::trade::PublicTradeData,
+};
+use serde_json::json;
+use std::fs;
+
+const GET_KLINE: &str = include_str!("../fixtures/get_kline.json");
+
+fn trade(trade_id: &str) -> PublicTradeData {
+    serde_json::from_value(json!({
+        "T": 1672304486865u64,
+        "s": "BTCUSDT",
+        "S": "Buy",
+        "v": "0.001",
+        "p": "16578.5",
+        "L": "PlusTick",
+        "i": trade_id,
+        "BT": false,
+    })).unwrap()
+}
+
+#[test]
+fn test_export_klines_csv() {
+    let response: GetKlineResponse = serde_json::from_str(GET_KLINE).unwrap();
+    let mut exporter = Exporter::new(Vec::new(), Format::Csv);
+    exporter.write_all(response.result().list()).unwrap();
+
+    let csv = String::from_utf8(exporter.into_inner()).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0], "timestamp,open,high,low,close,volume,turnover");
+    assert_eq!(lines[1], "2022-12-09T18:00:00.000Z,17071,17073,17027,17055.5,268611,15.74462667");
+}
+
+#[test]
+fn test_export_trades_json_lines() {
+    let mut exporter = Exporter::new(Vec::new(), Format::JsonLines);
+    exporter.write(&trade("20f43950-d8dd-5b31-9112-a178eb6023af")).unwrap();
+
+    let jsonl = String::from_utf8(exporter.into_inner()).unwrap();
+    // 列の順番どおりに書き出す
+    assert_eq!(
+        jsonl,
+        "{\"timestamp\":\"2022-12-29T09:01:26.865Z\",\"symbol\":\"BTCUSDT\",\"side\":\"Buy\",\"volume\":\"0.001\",\"price\":\"16578.5\",\"direction_of_price_change\":\"PlusTick\",\"trade_id\":\"20f43950-d8dd-5b31-9112-a178eb6023af\",\"block_trade\":\"false\"}\n",
+    );
+}
+
+#[test]
+fn test_export_csv_quotes_fields() {
+    let mut exporter = Exporter::new(Vec::new(), Format::Csv).with_header(false);
+    exporter.write(&trade("a,\"b\"")).unwrap();
+
+    let csv = String::from_utf8(exporter.into_inner()).unwrap();
+    assert!(csv.ends_with(",\"a,\"\"b\"\"\",false\n"), "{}", csv);
+}
+
+#[test]
+fn test_export_append_writes_header_once() {
+    let path = std::env::temp_dir().join(format!("rsbit_export_test_{}.csv", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    // 再起動した収集プロセスが同じファイルに追記する想定
+    for trade_id in ["1", "2"] {
+        let mut exporter = Exporter::append(&path, Format::Csv).unwrap();
+        exporter.write(&trade(trade_id)).unwrap();
+        exporter.flush().unwrap();
+    }
+
+    let csv = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("timestamp,symbol,side"));
+    assert!(lines[1].ends_with(",1,false"));
+    assert!(lines[2].ends_with(",2,false"));
+}
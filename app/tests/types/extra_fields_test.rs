@@ -0,0 +1,60 @@
+use rsbit::v5::{
+    api::get::market::get_bybit_server_time::GetBybitServerTimeResponse,
+    ws::public::tickers::linear::PublicLinearTickersResponse,
+};
+use serde_json::json;
+
+fn server_time_with_unknown_keys() -> serde_json::Value {
+    json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {
+            "timeSecond": "1688639403",
+            "timeNano": "1688639403423213947",
+            "timeZone": "UTC",
+        },
+        "retExtInfo": {},
+        "time": 1688639403423u64,
+        "traceId": "abc",
+    })
+}
+
+#[cfg(not(feature = "strict"))]
+#[test]
+fn test_extra_keeps_unknown_keys() {
+    let response: GetBybitServerTimeResponse = serde_json::from_value(server_time_with_unknown_keys()).unwrap();
+    assert_eq!(response.extra().get("traceId"), Some(&json!("abc")));
+    assert_eq!(response.result().extra().get("timeZone"), Some(&json!("UTC")));
+    assert!(!response.extra().contains_key("retCode"));
+
+    // 未知のキーもシリアライズ時に元の位置へ戻る
+    assert_eq!(serde_json::to_value(&response).unwrap(), server_time_with_unknown_keys());
+}
+
+#[cfg(feature = "strict")]
+#[test]
+fn test_strict_rejects_unknown_keys() {
+    let error = serde_json::from_value::<GetBybitServerTimeResponse>(server_time_with_unknown_keys()).unwrap_err();
+    assert!(error.to_string().contains("timeZone"), "{}", error);
+}
+
+#[test]
+fn test_missing_optional_fields() {
+    // delta メッセージは変化したフィールドしか含まない
+    let response: PublicLinearTickersResponse = serde_json::from_value(json!({
+        "topic": "tickers.BTCUSDT",
+        "type": "delta",
+        "data": {
+            "symbol": "BTCUSDT",
+            "bid1Price": "17215.50",
+            "bid1Size": "84.489",
+        },
+        "cs": 24987956059u64,
+        "ts": 1673272861686u64,
+    })).unwrap();
+    let data = response.data();
+    assert_eq!(data.tick_direction(), None);
+    assert_eq!(data.last_price(), None);
+    assert!(data.bid1_price().is_some());
+    assert!(data.extra().is_empty());
+}
@@ -0,0 +1,67 @@
+use rsbit::v5::{
+    types::Interval,
+    ws::public::kline::PublicKlineResponse,
+};
+use chrono::{
+    Duration,
+    TimeZone,
+    Utc,
+};
+
+#[test]
+fn test_interval_parse() {
+    for interval in Interval::ALL.into_iter() {
+        assert_eq!(interval.as_str().parse::<Interval>(), Ok(interval));
+    }
+    assert_eq!("240".parse::<Interval>(), Ok(Interval::Hour4));
+    assert!("2".parse::<Interval>().is_err());
+    assert_eq!(serde_json::to_value(Interval::Week).unwrap(), serde_json::json!("W"));
+}
+
+#[test]
+fn test_interval_duration() {
+    assert_eq!(Interval::Minute15.duration(), Some(Duration::minutes(15)));
+    assert_eq!(Interval::Hour12.duration(), Some(Duration::hours(12)));
+    assert_eq!(Interval::Week.duration(), Some(Duration::days(7)));
+    assert_eq!(Interval::Month.duration(), None);
+}
+
+#[test]
+fn test_interval_bar_open() {
+    // 2024-02-29 (Thursday) 13:47:12.345 UTC
+    let time = Utc.with_ymd_and_hms(2024, 2, 29, 13, 47, 12).unwrap() + Duration::milliseconds(345);
+
+    assert_eq!(Interval::Minute5.bar_open(time), Utc.with_ymd_and_hms(2024, 2, 29, 13, 45, 0).unwrap());
+    assert_eq!(Interval::Hour4.bar_open(time), Utc.with_ymd_and_hms(2024, 2, 29, 12, 0, 0).unwrap());
+    assert_eq!(Interval::Day.bar_open(time), Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap());
+    assert_eq!(Interval::Week.bar_open(time), Utc.with_ymd_and_hms(2024, 2, 26, 0, 0, 0).unwrap());
+    assert_eq!(Interval::Month.bar_open(time), Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap());
+    assert_eq!(Interval::Month.next_bar_open(time), Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap());
+    assert_eq!(Interval::Hour1.next_bar_open(time), Utc.with_ymd_and_hms(2024, 2, 29, 14, 0, 0).unwrap());
+    assert_eq!(Interval::Minute1.bar_open_millis(1709214432345), 1709214420000);
+}
+
+#[test]
+fn test_public_kline_interval() {
+    let message = r#"{
+        "topic": "kline.5.BTCUSDT",
+        "type": "snapshot",
+        "ts": 1672324988882,
+        "data": [{
+            "start": 1672324800000,
+            "end": 1672325099999,
+            "interval": "5",
+            "open": "16649.5",
+            "close": "16677",
+            "high": "16677",
+            "low": "16608",
+            "volume": "2.081",
+            "turnover": "34666.4005",
+            "confirm": false,
+            "timestamp": 1672324988882
+        }]
+    }"#;
+    let response: PublicKlineResponse = serde_json::from_str(message).expect("Failed to deserialize");
+
+    assert_eq!(response.data()[0].interval(), Interval::Minute5);
+}
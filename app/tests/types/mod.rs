@@ -0,0 +1,9 @@
+mod trading_enum_test;
+mod number_test;
+mod interval_test;
+mod category_test;
+mod datetime_test;
+mod round_trip_test;
+mod symbol_test;
+mod extra_fields_test;
+mod export_test;
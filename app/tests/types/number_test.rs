@@ -0,0 +1,43 @@
+use rsbit::v5::{
+    api::{
+        get::market::get_tickers::GetTickersResponse,
+        post::trade::place_order::{
+            PlaceOrderParameters,
+            PlaceOrderCategory,
+        },
+    },
+    types::{
+        Side,
+        OrderType,
+    },
+};
+use crate::common::number;
+
+#[test]
+fn test_number_serialized_without_exponent() {
+    let params = PlaceOrderParameters::new(
+        PlaceOrderCategory::Spot,
+        "BTCUSDT".to_string(),
+        Side::Buy,
+        OrderType::Limit,
+        number("0.0000001"),
+    ).with_price(number("42000.5"));
+
+    let body = serde_json::to_value(&params).expect("Failed to serialize");
+    assert_eq!(body["qty"], "0.0000001");
+    assert_eq!(body["price"], "42000.5");
+}
+
+#[test]
+fn test_number_deserialized_from_string() {
+    let body = include_str!("../fixtures/get_tickers_spot.json");
+    let response: GetTickersResponse = serde_json::from_str(body).expect("Failed to deserialize");
+
+    match response.result().spot() {
+        Some(spot) => {
+            assert_eq!(spot.list()[0].last_price(), number("16597.00"));
+            assert_eq!(spot.list()[0].volume24h(), number("0.0000001"));
+        },
+        None => assert!(false, "No spot tickers"),
+    }
+}
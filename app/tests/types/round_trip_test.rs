@@ -0,0 +1,65 @@
+use rsbit::v5::{
+    api::{
+        get::market::{
+            get_bybit_server_time::GetBybitServerTimeResponse,
+            get_kline::GetKlineResponse,
+            get_tickers::GetTickersResponse,
+        },
+        post::trade::place_order::PlaceOrderResponse,
+    },
+    ws::{
+        private::order::PrivateOrderResponse,
+        public::orderbook::PublicOrderbookResponse,
+    },
+};
+use serde::{
+    de::DeserializeOwned,
+    Serialize,
+};
+use std::fmt::Debug;
+use crate::common::number;
+
+const GET_KLINE: &str = include_str!("../fixtures/get_kline.json");
+const GET_TICKERS_SPOT: &str = include_str!("../fixtures/get_tickers_spot.json");
+const GET_BYBIT_SERVER_TIME: &str = include_str!("../fixtures/get_bybit_server_time.json");
+const PLACE_ORDER: &str = include_str!("../fixtures/place_order.json");
+const WS_PRIVATE_ORDER: &str = include_str!("../fixtures/ws_private_order.json");
+const WS_PUBLIC_ORDERBOOK: &str = include_str!("../fixtures/ws_public_orderbook.json");
+
+fn assert_round_trip<T>(json: &str) -> serde_json::Value
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let response: T = serde_json::from_str(json).unwrap();
+    let serialized = serde_json::to_value(&response).unwrap();
+    let deserialized: T = serde_json::from_value(serialized.clone()).unwrap();
+    assert_eq!(deserialized, response);
+    serialized
+}
+
+#[test]
+fn test_round_trip_rest() {
+    let kline = assert_round_trip::<GetKlineResponse>(GET_KLINE);
+    // 配列の行と文字列の数値は Bybit と同じ形で書き出される
+    assert_eq!(kline["result"]["list"][0][0], "1670608800000");
+    assert_eq!(kline["result"]["list"][0][4], "17055.5");
+
+    let tickers = assert_round_trip::<GetTickersResponse>(GET_TICKERS_SPOT);
+    assert_eq!(tickers["result"]["category"], "spot");
+
+    assert_round_trip::<GetBybitServerTimeResponse>(GET_BYBIT_SERVER_TIME);
+    assert_round_trip::<PlaceOrderResponse>(PLACE_ORDER);
+}
+
+#[test]
+fn test_round_trip_ws() {
+    let order = assert_round_trip::<PrivateOrderResponse>(WS_PRIVATE_ORDER);
+    assert_eq!(order["data"][0]["cumExecFee"], "0.358635");
+    assert_eq!(order["data"][0]["createdTime"], "1672364262444");
+    assert_eq!(order["data"][0]["avgPrice"], "75");
+    assert!(order["data"][0]["orderIv"].is_null());
+
+    let orderbook = assert_round_trip::<PublicOrderbookResponse>(WS_PUBLIC_ORDERBOOK);
+    assert_eq!(number(orderbook["data"]["b"][1][0].as_str().unwrap()), number("16493"));
+    assert_eq!(number(orderbook["data"]["b"][1][1].as_str().unwrap()), number("0.1"));
+}
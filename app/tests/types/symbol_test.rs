@@ -0,0 +1,185 @@
+use rsbit::v5::{
+    api::get::market::get_instruments_info::{
+        inverse::InverseInstrumentsInfoResult,
+        option::OptionInstrumentsInfoResult,
+    },
+    symbol::{
+        OptionType,
+        Symbol,
+    },
+};
+use chrono::{
+    NaiveDate,
+    TimeZone,
+    Utc,
+};
+use serde_json::{
+    json,
+    Value,
+};
+use crate::common::number;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn option_info(symbol: &str, options_type: &str) -> Value {
+    json!({
+        "symbol": symbol,
+        "status": "Trading",
+        "baseCoin": "BTC",
+        "quoteCoin": "USD",
+        "settleCoin": "USDC",
+        "optionsType": options_type,
+        "launchTime": "1703232000000",
+        "deliveryTime": "1703836800000",
+        "deliveryFeeRate": "0.00015",
+        "priceFilter": { "minPrice": "5", "maxPrice": "10000000", "tickSize": "5" },
+        "lotSizeFilter": { "maxOrderQty": "500", "minOrderQty": "0.01", "qtyStep": "0.01" },
+    })
+}
+
+fn inverse_info(symbol: &str, contract_type: &str) -> Value {
+    json!({
+        "symbol": symbol,
+        "contractType": contract_type,
+        "status": "Trading",
+        "baseCoin": "BTC",
+        "quoteCoin": "USD",
+        "launchTime": "1692950400000",
+        "deliveryTime": "0",
+        "deliveryFeeRate": "",
+        "priceScale": "1",
+        "leverageFilter": { "minLeverage": "1", "maxLeverage": "100.00", "leverageStep": "0.01" },
+        "priceFilter": { "minPrice": "0.5", "maxPrice": "999999.0", "tickSize": "0.5" },
+        "lotSizeFilter": { "maxOrderQty": "100000", "minOrderQty": "1", "qtyStep": "1", "postOnlyMaxOrderQty": "1000000" },
+        "unifiedMarginTrade": true,
+        "fundingInterval": 480,
+        "settleCoin": "BTC",
+    })
+}
+
+#[test]
+fn test_symbol_parse_pair() {
+    let symbol: Symbol = "BTCUSDT".parse().unwrap();
+    assert!(symbol.is_pair());
+    assert_eq!(symbol.base_coin(), "BTC");
+    assert_eq!(symbol.quote_coin(), "USDT");
+    assert_eq!(symbol.settle_coin(), "USDT");
+    assert_eq!(symbol.expiry(), None);
+    assert_eq!(symbol.strike(), None);
+
+    let symbol: Symbol = "ETHUSD".parse().unwrap();
+    assert_eq!(symbol.base_coin(), "ETH");
+    assert_eq!(symbol.settle_coin(), "ETH");
+
+    let symbol: Symbol = "ETHPERP".parse().unwrap();
+    assert_eq!(symbol.base_coin(), "ETH");
+    assert_eq!(symbol.quote_coin(), "USDC");
+
+    assert_eq!(Symbol::pair("SOL", "USDT"), "SOLUSDT".parse().unwrap());
+}
+
+#[test]
+fn test_symbol_parse_future() {
+    let symbol: Symbol = "BTC-27DEC24".parse().unwrap();
+    assert!(symbol.is_future());
+    assert_eq!(symbol.base_coin(), "BTC");
+    assert_eq!(symbol.quote_coin(), "USDC");
+    assert_eq!(symbol.expiry(), Some(date(2024, 12, 27)));
+    assert_eq!(symbol.delivery_time(), Some(Utc.with_ymd_and_hms(2024, 12, 27, 8, 0, 0).unwrap()));
+    assert_eq!(Symbol::future("BTC", date(2024, 12, 27)), symbol);
+
+    let symbol: Symbol = "BTCUSDT-3JAN25".parse().unwrap();
+    assert_eq!(symbol.quote_coin(), "USDT");
+    assert_eq!(symbol.expiry(), Some(date(2025, 1, 3)));
+
+    // 限月コードの先物は月の最終金曜日に満期を迎える
+    let symbol: Symbol = "BTCUSDH24".parse().unwrap();
+    assert!(symbol.is_future());
+    assert_eq!(symbol.base_coin(), "BTC");
+    assert_eq!(symbol.quote_coin(), "USD");
+    assert_eq!(symbol.settle_coin(), "BTC");
+    assert_eq!(symbol.expiry(), Some(date(2024, 3, 29)));
+}
+
+#[test]
+fn test_symbol_parse_option() {
+    let symbol: Symbol = "BTC-29DEC23-40000-C".parse().unwrap();
+    assert!(symbol.is_option());
+    assert_eq!(symbol.base_coin(), "BTC");
+    assert_eq!(symbol.settle_coin(), "USDC");
+    assert_eq!(symbol.expiry(), Some(date(2023, 12, 29)));
+    assert_eq!(symbol.strike(), Some(number("40000")));
+    assert_eq!(symbol.option_type(), Some(OptionType::Call));
+    assert_eq!(Symbol::option("BTC", date(2023, 12, 29), number("40000"), OptionType::Call), symbol);
+
+    let symbol: Symbol = "ETH-5JAN24-2250.5-P-USDT".parse().unwrap();
+    assert_eq!(symbol.quote_coin(), "USDT");
+    assert_eq!(symbol.expiry(), Some(date(2024, 1, 5)));
+    assert_eq!(symbol.strike(), Some(number("2250.5")));
+    assert_eq!(symbol.option_type(), Some(OptionType::Put));
+    assert_eq!(symbol.to_string(), "ETH-5JAN24-2250.5-P-USDT");
+}
+
+#[test]
+fn test_symbol_parse_error() {
+    for value in ["", "BTC", "USDT", "BTC-30FEB24", "BTC-29DEC23-abc-C", "BTC-29DEC23-40000-X", "BTC-29DEC23-40000-C-USDT-1", "BTC-1DEÉ2", "BTC-É1DEC", "BTCÉ"] {
+        assert!(value.parse::<Symbol>().is_err(), "{}", value);
+    }
+}
+
+#[test]
+fn test_symbol_serde() {
+    let symbol: Symbol = serde_json::from_value(json!("BTC-29DEC23-40000-C")).unwrap();
+    assert_eq!(serde_json::to_value(&symbol).unwrap(), json!("BTC-29DEC23-40000-C"));
+    assert!(serde_json::from_value::<Symbol>(json!("BTC-29DEC23-40000")).is_err());
+}
+
+#[test]
+fn test_option_chain() {
+    let result: OptionInstrumentsInfoResult = serde_json::from_value(json!({
+        "list": [
+            option_info("BTC-5JAN24-45000-C", "Call"),
+            option_info("BTC-29DEC23-42000-P", "Put"),
+            option_info("BTC-29DEC23-40000-P", "Put"),
+            option_info("BTC-29DEC23-40000-C", "Call"),
+        ],
+        "nextPageCursor": "",
+    })).unwrap();
+
+    let chain = result.option_chain();
+    let expiries: Vec<NaiveDate> = chain.keys().copied().collect();
+    assert_eq!(expiries, vec![date(2023, 12, 29), date(2024, 1, 5)]);
+    let symbols: Vec<String> = chain[&date(2023, 12, 29)].iter().map(|(symbol, _)| symbol.to_string()).collect();
+    assert_eq!(symbols, vec!["BTC-29DEC23-40000-C", "BTC-29DEC23-40000-P", "BTC-29DEC23-42000-P"]);
+    for (symbol, info) in chain.values().flatten() {
+        assert_eq!(symbol.option_type().map(|t| t.as_str()), Some(info.options_type().as_str()));
+    }
+}
+
+#[test]
+fn test_next_future() {
+    let result: InverseInstrumentsInfoResult = serde_json::from_value(json!({
+        "list": [
+            inverse_info("BTCUSD", "InversePerpetual"),
+            inverse_info("BTCUSDU24", "InverseFutures"),
+            inverse_info("ETHUSDH24", "InverseFutures"),
+            inverse_info("BTCUSDH24", "InverseFutures"),
+            inverse_info("BTCUSDM24", "InverseFutures"),
+        ],
+        "nextPageCursor": "",
+    })).unwrap();
+
+    let futures: Vec<&str> = result.dated_futures().iter().map(|(_, info)| info.symbol().as_str()).collect();
+    assert_eq!(futures, vec!["ETHUSDH24", "BTCUSDH24", "BTCUSDM24", "BTCUSDU24"]);
+
+    let current = result.list()[3].parsed_symbol().unwrap();
+    let (next, info) = result.next_future(&current).unwrap();
+    assert_eq!(next.to_string(), "BTCUSDM24");
+    assert_eq!(info.symbol(), "BTCUSDM24");
+
+    let perpetual: Symbol = "BTCUSD".parse().unwrap();
+    assert_eq!(result.next_future(&perpetual).unwrap().0.to_string(), "BTCUSDH24");
+    assert!(result.next_future(&"BTCUSDU24".parse().unwrap()).is_none());
+}
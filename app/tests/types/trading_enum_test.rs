@@ -0,0 +1,39 @@
+use rsbit::v5::types::{
+    Side,
+    OrderType,
+    TimeInForce,
+    OrderStatus,
+    StopOrderType,
+    CancelType,
+    PositionIdx,
+};
+use serde_json::json;
+
+#[test]
+fn test_string_enum_round_trip() {
+    assert_eq!(serde_json::to_value(Side::Buy).unwrap(), json!("Buy"));
+    assert_eq!(serde_json::to_value(TimeInForce::PostOnly).unwrap(), json!("PostOnly"));
+    assert_eq!(serde_json::to_value(StopOrderType::TpslOrder).unwrap(), json!("tpslOrder"));
+    assert_eq!(serde_json::from_value::<OrderType>(json!("Market")).unwrap(), OrderType::Market);
+    assert_eq!(serde_json::from_value::<TimeInForce>(json!("IOC")).unwrap(), TimeInForce::Ioc);
+    assert_eq!(serde_json::from_value::<CancelType>(json!("UNKNOWN")).unwrap(), CancelType::NotCancelled);
+    assert_eq!("PartiallyFilled".parse::<OrderStatus>().unwrap(), OrderStatus::PartiallyFilled);
+}
+
+#[test]
+fn test_string_enum_unknown() {
+    let status = serde_json::from_value::<OrderStatus>(json!("PendingNew")).unwrap();
+
+    assert_eq!(status, OrderStatus::Unknown("PendingNew".to_string()));
+    assert_eq!(status.as_str(), "PendingNew");
+    assert_eq!(serde_json::to_value(&status).unwrap(), json!("PendingNew"));
+    assert_eq!(Side::from("buy"), Side::Unknown("buy".to_string()));
+}
+
+#[test]
+fn test_position_idx() {
+    assert_eq!(serde_json::from_value::<PositionIdx>(json!(2)).unwrap(), PositionIdx::HedgeSell);
+    assert_eq!(serde_json::from_value::<PositionIdx>(json!(7)).unwrap(), PositionIdx::Unknown(7));
+    assert_eq!(serde_json::to_value(PositionIdx::OneWay).unwrap(), json!(0));
+    assert_eq!(PositionIdx::HedgeBuy.to_string(), "1");
+}
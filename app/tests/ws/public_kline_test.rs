@@ -1,4 +1,5 @@
 use rsbit::{
+    v5::types::Interval,
     v5::ws::{
         Channel,
         DeserializedMessage,
@@ -12,7 +13,7 @@ use futures_util::stream::StreamExt;
 async fn test_public_kline_success() {
     let mut ws = setup_ws(Channel::TestnetLinearPublicChannel);
     let symbol = "BTCUSDT";
-    let interval = "1";
+    let interval = Interval::Minute1;
     ws.add_kline_args(interval, symbol);
     let result = ws.execute().await;
 
@@ -103,6 +103,7 @@ async fn test_public_inverse_tickers_success() {
 }
 
 #[tokio::test]
+#[allow(clippy::never_loop)]
 async fn test_public_option_tickers_success() {
     let mut ws = setup_ws(Channel::TestnetOptionPublicChannel);
     let symbol = "BTC-6JAN23-17500-C";